@@ -15,7 +15,7 @@ fn format_arg() -> Arg {
     .value_name("FORMAT")
     .help("Specifies the output format")
     .long_help(
-      "There are multiple format specifiers you can use: %a: artist, %b: album, %p: playlist, \
+      "There are multiple format specifiers you can use: %n: name, %a: artist, %b: album, %p: playlist, \
 %t: track, %h: show, %f: flags (shuffle, repeat, like), %s: playback status, %v: volume, %d: current device. \
 Example: spt pb -s -f 'playing on %d at %v%'",
     )
@@ -331,19 +331,29 @@ pub fn search_subcommand() -> Command {
     .author(env!("CARGO_PKG_AUTHORS"))
     .about("Searches for tracks, albums and more")
     .long_about(
-      "This will search for something on spotify and displays you the items. The output \
-format can be changed with the `--format` flag and the limit can be changed with \
-the `--limit` flag (between 1 and 50). The type can't be inferred, so you have to \
-specify it.",
+      "This will search for something on spotify and displays you the items. By default \
+the results are printed as aligned columns. The output can be changed with the `--format` \
+flag, which also accepts named placeholders like {name}, {artist}, {album} and {uri} \
+(and `\\t` for a tab), or with `--json`. The limit can be changed with the `--limit` flag \
+(between 1 and 50). The type can't be inferred, so you have to specify it with `--type` or \
+one of its shorthand flags. If nothing is found, spt prints nothing and exits with 1.
+
+Example: spt search 'lofi' --type playlist --format '{name}\\t{uri}' | fzf | cut -f2",
     )
     .visible_alias("s")
+    .arg(format_arg().conflicts_with("json"))
     .arg(
-      format_arg()
-        .default_value_if("tracks", ArgPredicate::IsPresent, "%t - %a (%u)")
-        .default_value_if("playlists", ArgPredicate::IsPresent, "%p (%u)")
-        .default_value_if("artists", ArgPredicate::IsPresent, "%a (%u)")
-        .default_value_if("albums", ArgPredicate::IsPresent, "%b - %a (%u)")
-        .default_value_if("shows", ArgPredicate::IsPresent, "%h - %a (%u)"),
+      Arg::new("json")
+        .long("json")
+        .action(ArgAction::SetTrue)
+        .help("Prints the results as JSON"),
+    )
+    .arg(
+      Arg::new("type")
+        .long("type")
+        .value_name("TYPE")
+        .value_parser(["track", "album", "artist", "playlist", "show"])
+        .help("Specifies what to look for"),
     )
     .arg(
       Arg::new("search")
@@ -393,7 +403,7 @@ specify it.",
     )
     .group(
       ArgGroup::new("searchable")
-        .args(["playlists", "tracks", "albums", "artists", "shows"])
+        .args(["playlists", "tracks", "albums", "artists", "shows", "type"])
         .required(true)
         .multiple(false),
     )
@@ -1,7 +1,8 @@
+use crate::core::app::next_repeat_state;
 use crate::core::user_config::UserConfig;
 use crate::infra::network::{IoEvent, Network};
 
-use super::util::{Flag, Format, FormatType, JumpDirection, Type};
+use super::util::{align_columns, Flag, Format, FormatType, JumpDirection, Type};
 
 use anyhow::{anyhow, Result};
 use rand::{thread_rng, Rng};
@@ -22,7 +23,8 @@ pub struct CliApp {
 // I just .await all processes and directly interact
 // by calling network.handle_network_event
 impl CliApp {
-  pub fn new(net: Network, config: UserConfig) -> Self {
+  pub fn new(mut net: Network, config: UserConfig) -> Self {
+    net.coalesce_liked_checks = false;
     Self { net, config }
   }
 
@@ -42,16 +44,57 @@ impl CliApp {
   }
 
   pub fn format_output(&self, mut format: String, values: Vec<Format>) -> String {
+    // Allow a literal `\t` so column templates work without shell quoting tricks
+    format = format.replace("\\t", "\t");
     for val in values {
-      format = format.replace(val.get_placeholder(), &val.inner(self.config.clone()));
+      let inner = val.inner(self.config.clone());
+      format = format
+        .replace(val.get_placeholder(), &inner)
+        .replace(&format!("{{{}}}", val.get_field_name()), &inner);
     }
     // Replace unsupported flags with 'None'
-    for p in &["%a", "%b", "%t", "%p", "%h", "%u", "%d", "%v", "%f", "%s"] {
+    for p in &[
+      "%n",
+      "%a",
+      "%b",
+      "%t",
+      "%p",
+      "%h",
+      "%u",
+      "%d",
+      "%v",
+      "%f",
+      "%s",
+      "{name}",
+      "{artist}",
+      "{album}",
+      "{track}",
+      "{playlist}",
+      "{show}",
+      "{uri}",
+      "{device}",
+      "{volume}",
+      "{flags}",
+      "{status}",
+    ] {
       format = format.replace(p, "None");
     }
     format.trim().to_string()
   }
 
+  fn format_json(&self, values: Vec<Format>) -> serde_json::Value {
+    let fields = values
+      .iter()
+      .map(|val| {
+        (
+          val.get_field_name().to_string(),
+          serde_json::Value::String(val.inner(self.config.clone())),
+        )
+      })
+      .collect::<serde_json::Map<String, serde_json::Value>>();
+    serde_json::Value::Object(fields)
+  }
+
   // spt playback -t
   pub async fn toggle_playback(&mut self) {
     let context = self.net.app.lock().await.current_playback_context.clone();
@@ -402,7 +445,7 @@ impl CliApp {
       Flag::Repeat => {
         self
           .net
-          .handle_network_event(IoEvent::Repeat(c.repeat_state))
+          .handle_network_event(IoEvent::Repeat(next_repeat_state(c.repeat_state)))
           .await;
       }
     }
@@ -633,101 +676,84 @@ impl CliApp {
   }
 
   // spt query -s SEARCH ...
-  pub async fn query(&mut self, search: String, format: String, item: Type) -> String {
+  pub async fn query(
+    &mut self,
+    search: String,
+    format: Option<String>,
+    json: bool,
+    item: Type,
+  ) -> Result<String> {
     self
       .net
       .handle_network_event(IoEvent::GetSearchResults(search.clone(), None))
       .await;
 
     let app = self.net.app.lock().await;
-    match item {
-      Type::Playlist => {
-        if let Some(results) = &app.search_results.playlists {
-          results
-            .items
-            .iter()
-            .map(|r| {
-              self.format_output(
-                format.clone(),
-                Format::from_type(FormatType::Playlist(Box::new(r.clone()))),
-              )
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-        } else {
-          format!("no playlists with name '{}'", search)
-        }
-      }
-      Type::Track => {
-        if let Some(results) = &app.search_results.tracks {
-          results
-            .items
-            .iter()
-            .map(|r| {
-              self.format_output(
-                format.clone(),
-                Format::from_type(FormatType::Track(Box::new(r.clone()))),
-              )
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-        } else {
-          format!("no tracks with name '{}'", search)
-        }
-      }
-      Type::Artist => {
-        if let Some(results) = &app.search_results.artists {
-          results
-            .items
-            .iter()
-            .map(|r| {
-              self.format_output(
-                format.clone(),
-                Format::from_type(FormatType::Artist(Box::new(r.clone()))),
-              )
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-        } else {
-          format!("no artists with name '{}'", search)
-        }
-      }
-      Type::Show => {
-        if let Some(results) = &app.search_results.shows {
-          results
-            .items
-            .iter()
-            .map(|r| {
-              self.format_output(
-                format.clone(),
-                Format::from_type(FormatType::Show(Box::new(r.clone()))),
-              )
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-        } else {
-          format!("no shows with name '{}'", search)
-        }
-      }
-      Type::Album => {
-        if let Some(results) = &app.search_results.albums {
-          results
-            .items
-            .iter()
-            .map(|r| {
-              self.format_output(
-                format.clone(),
-                Format::from_type(FormatType::Album(Box::new(r.clone()))),
-              )
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
-        } else {
-          format!("no albums with name '{}'", search)
-        }
-      }
+    let results = &app.search_results;
+    let rows: Vec<Vec<Format>> = match item {
+      Type::Playlist => results
+        .playlists
+        .iter()
+        .flat_map(|r| &r.items)
+        .map(|r| Format::from_type(FormatType::Playlist(Box::new(r.clone()))))
+        .collect(),
+      Type::Track => results
+        .tracks
+        .iter()
+        .flat_map(|r| &r.items)
+        .map(|r| Format::from_type(FormatType::Track(Box::new(r.clone()))))
+        .collect(),
+      Type::Artist => results
+        .artists
+        .iter()
+        .flat_map(|r| &r.items)
+        .map(|r| Format::from_type(FormatType::Artist(Box::new(r.clone()))))
+        .collect(),
+      Type::Show => results
+        .shows
+        .iter()
+        .flat_map(|r| &r.items)
+        .map(|r| Format::from_type(FormatType::Show(Box::new(r.clone()))))
+        .collect(),
+      Type::Album => results
+        .albums
+        .iter()
+        .flat_map(|r| &r.items)
+        .map(|r| Format::from_type(FormatType::Album(Box::new(r.clone()))))
+        .collect(),
       // Enforced by clap
       _ => unreachable!(),
+    };
+
+    if rows.is_empty() {
+      return Err(anyhow!("no {} with name '{}'", item.plural(), search));
     }
+
+    let output = if json {
+      let items = rows
+        .into_iter()
+        .map(|row| self.format_json(row))
+        .collect::<Vec<serde_json::Value>>();
+      serde_json::to_string_pretty(&items)?
+    } else if let Some(format) = format {
+      rows
+        .into_iter()
+        .map(|row| self.format_output(format.clone(), row))
+        .collect::<Vec<String>>()
+        .join("\n")
+    } else {
+      let cells = rows
+        .into_iter()
+        .map(|row| {
+          item
+            .search_columns()
+            .iter()
+            .map(|column| self.format_output(column.to_string(), row.clone()))
+            .collect()
+        })
+        .collect::<Vec<Vec<String>>>();
+      align_columns(&cells)
+    };
+    Ok(output)
   }
 }
@@ -119,7 +119,8 @@ pub async fn handle_matches(
       Ok(cli.list(category, &format).await)
     }
     "search" => {
-      let format = matches.get_one::<String>("format").unwrap().to_string();
+      let format = matches.get_one::<String>("format").cloned();
+      let json = matches.get_flag("json");
 
       // Update the limits for the list and search functions
       // I think the small and big search limits are very confusing
@@ -129,15 +130,14 @@ pub async fn handle_matches(
       }
 
       let category = Type::search_from_matches(matches);
-      Ok(
-        cli
-          .query(
-            matches.get_one::<String>("search").unwrap().to_string(),
-            format,
-            category,
-          )
-          .await,
-      )
+      cli
+        .query(
+          matches.get_one::<String>("search").unwrap().to_string(),
+          format,
+          json,
+          category,
+        )
+        .await
     }
     // Clap enforces that one of the things above is specified
     _ => unreachable!(),
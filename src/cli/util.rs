@@ -8,6 +8,7 @@ use rspotify::{
   },
 };
 use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
 
 use crate::core::user_config::UserConfig;
 
@@ -58,6 +59,17 @@ impl Type {
   }
 
   pub fn search_from_matches(m: &ArgMatches) -> Self {
+    if let Some(kind) = m.get_one::<String>("type") {
+      return match kind.as_str() {
+        "playlist" => Self::Playlist,
+        "track" => Self::Track,
+        "artist" => Self::Artist,
+        "album" => Self::Album,
+        "show" => Self::Show,
+        // Enforced by clap
+        _ => unreachable!(),
+      };
+    }
     if m.get_flag("playlists") {
       Self::Playlist
     } else if m.get_flag("tracks") {
@@ -75,6 +87,31 @@ impl Type {
     }
   }
 
+  /// Plural name used in messages, e.g. "no tracks with name ..."
+  pub fn plural(&self) -> &str {
+    match self {
+      Self::Playlist => "playlists",
+      Self::Track => "tracks",
+      Self::Artist => "artists",
+      Self::Album => "albums",
+      Self::Show => "shows",
+      Self::Device => "devices",
+      Self::Liked => "liked songs",
+    }
+  }
+
+  /// Columns of the default `search` output, as format placeholders
+  pub fn search_columns(&self) -> &[&str] {
+    match self {
+      Self::Playlist => &["%p", "%u"],
+      Self::Track => &["%t", "%a", "%b", "%u"],
+      Self::Artist => &["%a", "%u"],
+      Self::Album => &["%b", "%a", "%u"],
+      Self::Show => &["%h", "%a", "%u"],
+      Self::Device | Self::Liked => &["%u"],
+    }
+  }
+
   pub fn list_from_matches(m: &ArgMatches) -> Self {
     if m.get_flag("playlists") {
       Self::Playlist
@@ -163,6 +200,8 @@ pub enum FormatType {
 // Types that can be formatted
 #[derive(Clone)]
 pub enum Format {
+  // Name of the item itself, whatever its type
+  Name(String),
   Album(String),
   Artist(String),
   Playlist(String),
@@ -193,6 +232,7 @@ impl Format {
         let joined_artists = join_artists(a.artists.clone());
         let uri = get_uri_or_fallback(&a.id, &a.external_urls);
         vec![
+          Self::Name(a.name.clone()),
           Self::Album(a.name),
           Self::Artist(joined_artists),
           Self::Uri(uri),
@@ -200,16 +240,25 @@ impl Format {
       }
       FormatType::Artist(a) => {
         let uri = a.id.uri();
-        vec![Self::Artist(a.name), Self::Uri(uri)]
+        vec![
+          Self::Name(a.name.clone()),
+          Self::Artist(a.name),
+          Self::Uri(uri),
+        ]
       }
       FormatType::Playlist(p) => {
         let uri = p.id.uri();
-        vec![Self::Playlist(p.name), Self::Uri(uri)]
+        vec![
+          Self::Name(p.name.clone()),
+          Self::Playlist(p.name),
+          Self::Uri(uri),
+        ]
       }
       FormatType::Track(t) => {
         let joined_artists = join_artists(t.artists.clone());
         let uri = get_uri_or_fallback(&t.id, &t.external_urls);
         vec![
+          Self::Name(t.name.clone()),
           Self::Album(t.album.name),
           Self::Artist(joined_artists),
           Self::Track(t.name),
@@ -219,6 +268,7 @@ impl Format {
       FormatType::Show(r) => {
         let uri = r.id.uri();
         vec![
+          Self::Name(r.name.clone()),
           Self::Artist(r.publisher),
           Self::Show(r.name),
           Self::Uri(uri),
@@ -227,6 +277,7 @@ impl Format {
       FormatType::Episode(e) => {
         let uri = e.id.uri();
         vec![
+          Self::Name(e.name.clone()),
           Self::Show(e.show.name),
           Self::Artist(e.show.publisher),
           Self::Track(e.name),
@@ -239,6 +290,7 @@ impl Format {
   // Is there a better way?
   pub fn inner(&self, conf: UserConfig) -> String {
     match self {
+      Self::Name(s) => s.clone(),
       Self::Album(s) => s.clone(),
       Self::Artist(s) => s.clone(),
       Self::Playlist(s) => s.clone(),
@@ -292,6 +344,7 @@ impl Format {
 
   pub fn get_placeholder(&self) -> &str {
     match self {
+      Self::Name(_) => "%n",
       Self::Album(_) => "%b",
       Self::Artist(_) => "%a",
       Self::Playlist(_) => "%p",
@@ -305,4 +358,69 @@ impl Format {
       Self::Playing(_) => "%s",
     }
   }
+
+  /// Long form of the placeholder, usable as `{name}` in templates and as the
+  /// key in `--json` output
+  pub fn get_field_name(&self) -> &str {
+    match self {
+      Self::Name(_) => "name",
+      Self::Album(_) => "album",
+      Self::Artist(_) => "artist",
+      Self::Playlist(_) => "playlist",
+      Self::Track(_) => "track",
+      Self::Show(_) => "show",
+      Self::Uri(_) => "uri",
+      Self::Device(_) => "device",
+      Self::Volume(_) => "volume",
+      Self::Position(_) => "position",
+      Self::Flags(_) => "flags",
+      Self::Playing(_) => "status",
+    }
+  }
+}
+
+/// Lay out rows as columns padded to the widest cell (by display width)
+pub fn align_columns(rows: &[Vec<String>]) -> String {
+  let mut widths: Vec<usize> = Vec::new();
+  for row in rows {
+    for (i, cell) in row.iter().enumerate() {
+      let width = cell.width();
+      match widths.get_mut(i) {
+        Some(w) => *w = (*w).max(width),
+        None => widths.push(width),
+      }
+    }
+  }
+
+  rows
+    .iter()
+    .map(|row| {
+      let mut line = String::new();
+      for (i, cell) in row.iter().enumerate() {
+        line.push_str(cell);
+        if i + 1 < row.len() {
+          line.push_str(&" ".repeat(widths[i] - cell.width() + 2));
+        }
+      }
+      line
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::align_columns;
+
+  #[test]
+  fn columns_align_by_display_width() {
+    let rows = vec![
+      vec!["日本語".to_string(), "a".to_string(), "uri1".to_string()],
+      vec!["abc".to_string(), "longer".to_string(), "uri2".to_string()],
+    ];
+    assert_eq!(
+      align_columns(&rows),
+      "日本語  a       uri1\nabc     longer  uri2"
+    );
+  }
 }
@@ -1,33 +1,43 @@
 use crate::cli::UpdateInfo;
+use crate::core::enhance::{self, PlaylistEnhance};
+use crate::core::locale;
+use crate::core::skip_stats;
 use crate::core::sort::{SortContext, SortState};
-use crate::core::user_config::UserConfig;
+use crate::core::user_config::{
+  BehaviorConfig, SearchBlockLayout, UserConfig, KEY_BINDINGS, KEY_LIST_SEPARATOR, MAX_FADE_MS,
+  MIN_WIDE_LAYOUT_WIDTH,
+};
+use crate::infra::keep_awake::KeepAwake;
+use crate::infra::network::requests::{is_premium_required_error, PREMIUM_REQUIRED_MESSAGE};
 use crate::infra::network::IoEvent;
+use crate::tui::ui::util::{millis_to_minutes, timestamped_link};
 use anyhow::anyhow;
-use ratatui::layout::Size;
+use ratatui::layout::{Rect, Size};
 use rspotify::{
-  model::enums::Country,
+  model::enums::{Country, RepeatState},
   model::{
     album::{FullAlbum, SavedAlbum, SimplifiedAlbum},
-    artist::FullArtist,
+    artist::{FullArtist, SimplifiedArtist},
     context::CurrentPlaybackContext,
     device::DevicePayload,
-    idtypes::{ArtistId, PlaylistId, ShowId, TrackId},
+    idtypes::{AlbumId, ArtistId, PlayableId, PlaylistId, ShowId, TrackId},
     page::{CursorBasedPage, Page},
     playing::PlayHistory,
     playlist::{PlaylistItem, SimplifiedPlaylist},
     show::{FullShow, Show, SimplifiedEpisode, SimplifiedShow},
     track::{FullTrack, SavedTrack, SimplifiedTrack},
-    user::PrivateUser,
-    PlayableItem,
+    user::{PrivateUser, PublicUser},
+    PlayableItem, SubscriptionLevel,
   },
   prelude::*, // Adds Id trait for .id() method
 };
-use std::cell::Cell;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
 use std::sync::mpsc::Sender;
 #[cfg(feature = "streaming")]
 use std::sync::Arc;
 use std::{
-  cmp::{max, min},
+  cmp::min,
   collections::HashSet,
   time::{Duration, Instant, SystemTime},
 };
@@ -35,13 +45,14 @@ use std::{
 use arboard::Clipboard;
 use log::info;
 
-pub const LIBRARY_OPTIONS: [&str; 6] = [
+pub const LIBRARY_OPTIONS: [&str; 7] = [
   "Discover",
   "Recently Played",
   "Liked Songs",
   "Albums",
   "Artists",
   "Podcasts",
+  "Queue",
 ];
 
 const DEFAULT_ROUTE: Route = Route {
@@ -54,6 +65,15 @@ const DEFAULT_ROUTE: Route = Route {
 /// This prevents the UI from jumping back to old positions while the seek completes
 pub const SEEK_POSITION_IGNORE_MS: u128 = 500;
 
+/// How often the queue is refreshed for the playbar's queue length
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long after sending a volume change a playback poll may still predate it
+const VOLUME_DIRTY_WINDOW: Duration = Duration::from_secs(2);
+
+/// Every refresh refetches the whole library, so it's never done more often than this
+pub const MIN_PLAYLIST_AUTO_REFRESH_SECS: u64 = 30;
+
 #[derive(Clone)]
 pub struct ScrollableResultPages<T> {
   pub index: usize,
@@ -83,6 +103,48 @@ impl<T> ScrollableResultPages<T> {
   }
 }
 
+/// An upcoming track or episode from the player queue
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueueItem {
+  pub id: String,
+  pub uri: String,
+  pub name: String,
+  /// Artists for a track, the show for an episode
+  pub artist: String,
+  pub duration_ms: u64,
+  /// Queued from this spotatui session rather than coming from the context
+  pub added_here: bool,
+}
+
+impl QueueItem {
+  /// Read an item of the Web API `me/player/queue` response
+  pub fn from_json(value: &serde_json::Value) -> Option<QueueItem> {
+    let artist = match value.get("artists").and_then(|artists| artists.as_array()) {
+      Some(artists) => artists
+        .iter()
+        .filter_map(|artist| artist.get("name")?.as_str())
+        .collect::<Vec<_>>()
+        .join(", "),
+      None => value
+        .get("show")
+        .and_then(|show| show.get("name")?.as_str())
+        .unwrap_or_default()
+        .to_string(),
+    };
+    Some(QueueItem {
+      id: value.get("id")?.as_str()?.to_string(),
+      uri: value.get("uri")?.as_str()?.to_string(),
+      name: value.get("name")?.as_str()?.to_string(),
+      artist,
+      duration_ms: value
+        .get("duration_ms")
+        .and_then(|ms| ms.as_u64())
+        .unwrap_or(0),
+      added_here: false,
+    })
+  }
+}
+
 #[derive(Default)]
 pub struct SpotifyResultAndSelectedIndex<T> {
   pub index: usize,
@@ -99,7 +161,16 @@ pub struct Library {
   pub show_episodes: ScrollableResultPages<Page<SimplifiedEpisode>>,
 }
 
-#[derive(PartialEq, Debug)]
+/// What the Home view shows below the banner
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HomeMode {
+  #[default]
+  Changelog,
+  Stats,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum SearchResultBlock {
   AlbumSearch,
   SongSearch,
@@ -109,7 +180,7 @@ pub enum SearchResultBlock {
   Empty,
 }
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ArtistBlock {
   TopTracks,
   Albums,
@@ -117,12 +188,46 @@ pub enum ArtistBlock {
   Empty,
 }
 
+/// Something on screen the mouse can point at
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MouseTarget {
+  Input,
+  Help,
+  Settings,
+  Library,
+  Playlists,
+  /// The table filling the content area of the current route
+  ContentTable,
+  SearchResult(SearchResultBlock),
+  Artist(ArtistBlock),
+  SettingsTabs,
+  SettingsList,
+  UnsavedPromptYes,
+  UnsavedPromptNo,
+}
+
+/// Where the last frame drew a `MouseTarget`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MouseRegion {
+  pub target: MouseTarget,
+  pub area: Rect,
+  /// The first item shown, for lists and tables that scroll
+  pub offset: usize,
+}
+
+/// Two clicks on the same row within this count as a double-click
+pub const DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(400);
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum DialogContext {
   PlaylistWindow,
   PlaylistSearch,
   AddTrackToPlaylistPicker,
   RemoveTrackFromPlaylistConfirm,
+  BulkRemoveFromLibraryConfirm,
+  DuplicateTrackAddConfirm,
+  CachePlaylistOfflineConfirm,
+  ArtistPicker,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -148,12 +253,15 @@ pub enum ActiveBlock {
   Discover,
   Artists,
   BasicView,
+  NowPlaying,
+  TvView,
   Dialog(DialogContext),
   UpdatePrompt,
   AnnouncementPrompt,
   ExitPrompt,
   Settings,
   SortMenu,
+  Queue,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -163,6 +271,8 @@ pub enum RouteId {
   AlbumList,
   Artist,
   BasicView,
+  NowPlaying,
+  TvView,
   Error,
   Home,
   RecentlyPlayed,
@@ -180,6 +290,7 @@ pub enum RouteId {
   ExitPrompt,
   Settings,
   HelpMenu,
+  Queue,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -189,6 +300,56 @@ pub enum AnnouncementLevel {
   Critical,
 }
 
+/// Spotify's own account, which owns the editorial playlists
+pub const SPOTIFY_USER_ID: &str = "spotify";
+
+pub fn user_profile_url(user_id: &str) -> String {
+  format!("https://open.spotify.com/user/{}", user_id)
+}
+
+/// How many seek steps a large seek jumps
+pub const LARGE_SEEK_MULTIPLIER: u32 = 4;
+
+/// Seek step for display, e.g. "5s" or "2.5s"
+fn seek_step_label(ms: u32) -> String {
+  if ms.is_multiple_of(1_000) {
+    format!("{}s", ms / 1_000)
+  } else {
+    format!("{:.1}s", ms as f64 / 1_000.0)
+  }
+}
+
+/// The seek step for `item`: episodes use the podcast step, tracks and nothing
+/// playing use the music one
+pub fn seek_step_for(item: Option<&PlayableItem>, behavior: &BehaviorConfig) -> u32 {
+  match item {
+    Some(PlayableItem::Episode(_)) => behavior.podcast_seek_milliseconds,
+    _ => behavior.seek_milliseconds,
+  }
+}
+
+/// The repeat mode that follows `state` when cycling with the repeat key
+pub fn next_repeat_state(state: RepeatState) -> RepeatState {
+  match state {
+    RepeatState::Off => RepeatState::Context,
+    RepeatState::Context => RepeatState::Track,
+    RepeatState::Track => RepeatState::Off,
+  }
+}
+
+/// The repeat mode the one/all toggle switches to: between track and context,
+/// or back to `preferred` from Off
+pub fn toggled_repeat_state(state: RepeatState, preferred: RepeatState) -> RepeatState {
+  match state {
+    RepeatState::Track => RepeatState::Context,
+    RepeatState::Context => RepeatState::Track,
+    RepeatState::Off => preferred,
+  }
+}
+
+/// Id of the built-in announcement asking whether to join the global song counter
+pub const SONG_COUNT_PROMPT_ID: &str = "global-song-count-opt-in";
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct Announcement {
   pub id: String,
@@ -231,7 +392,8 @@ pub enum EpisodeTableContext {
 }
 
 /// Time range for Top Tracks/Artists in Discover feature
-#[derive(Clone, PartialEq, Debug, Copy, Default)]
+#[derive(Clone, PartialEq, Debug, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DiscoverTimeRange {
   /// Last 4 weeks
   Short,
@@ -272,6 +434,8 @@ impl DiscoverTimeRange {
 pub enum RecommendationsContext {
   Artist,
   Song,
+  /// Seeded from a song, with tracks from the user's library first
+  Library,
 }
 
 pub struct SearchResult {
@@ -302,6 +466,28 @@ pub struct PendingPlaylistTrackAdd {
   pub track_name: String,
 }
 
+/// A track picked for a playlist, kept around while checking for duplicates
+#[derive(Clone)]
+pub struct PlaylistTrackAdd {
+  pub playlist_id: PlaylistId<'static>,
+  pub playlist_name: String,
+  pub track_id: TrackId<'static>,
+  pub track_name: String,
+}
+
+/// Name and description typed into the prompt for editing an owned playlist
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlaylistDetailsEdit {
+  pub playlist_id: PlaylistId<'static>,
+  pub name: String,
+  /// Filled in with the current description once it has loaded
+  pub description: String,
+  /// The description the playlist had, `None` until it has loaded
+  pub original_description: Option<String>,
+  /// Typing goes into the description instead of the name
+  pub editing_description: bool,
+}
+
 #[derive(Clone)]
 pub struct PendingPlaylistTrackRemoval {
   pub playlist_id: PlaylistId<'static>,
@@ -311,6 +497,22 @@ pub struct PendingPlaylistTrackRemoval {
   pub position: usize,
 }
 
+/// Items marked for removal from the user's library in one confirmed batch
+#[derive(Clone)]
+pub enum PendingBulkRemoval {
+  Tracks(Vec<TrackId<'static>>),
+  Albums(Vec<AlbumId<'static>>),
+}
+
+impl PendingBulkRemoval {
+  pub fn count(&self) -> usize {
+    match self {
+      PendingBulkRemoval::Tracks(ids) => ids.len(),
+      PendingBulkRemoval::Albums(ids) => ids.len(),
+    }
+  }
+}
+
 #[derive(Clone)]
 pub struct SelectedShow {
   pub show: SimplifiedShow,
@@ -326,6 +528,8 @@ pub struct SelectedAlbum {
   pub album: SimplifiedAlbum,
   pub tracks: Page<SimplifiedTrack>,
   pub selected_index: usize,
+  /// The full album for its label and copyrights, `None` if fetching it failed
+  pub details: Option<FullAlbum>,
 }
 
 #[derive(Clone)]
@@ -350,6 +554,12 @@ pub struct Artist {
   pub artist_selected_block: ArtistBlock,
 }
 
+/// Peak level below which captured audio counts as silence
+#[cfg(any(feature = "audio-viz", feature = "audio-viz-cpal"))]
+const SILENT_SPECTRUM_PEAK: f32 = 0.001;
+/// How long playback has to go unheard before the visualizer suggests fixing capture
+const SILENT_SPECTRUM_SECS: u64 = 5;
+
 /// Spectrum data for local audio visualization
 #[derive(Clone, Default)]
 pub struct SpectrumData {
@@ -372,8 +582,7 @@ pub enum LyricsStatus {
 pub struct NativeTrackInfo {
   pub name: String,
   pub artists_display: String,
-  #[allow(dead_code)]
-  pub album: String, // Reserved for future use (e.g., displaying album in playbar)
+  pub album: String,
   pub duration_ms: u32,
 }
 
@@ -416,8 +625,12 @@ pub enum PlaylistFolderItem {
     /// Folder ID this playlist is visible in
     current_id: usize,
   },
+  /// Heads the pinned playlists at the top of the root. Only ever shown, never stored.
+  PinnedHeader,
 }
 
+static PINNED_HEADER: PlaylistFolderItem = PlaylistFolderItem::PinnedHeader;
+
 /// Settings screen category tabs
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 pub enum SettingsCategory {
@@ -425,6 +638,8 @@ pub enum SettingsCategory {
   Behavior,
   Keybindings,
   Theme,
+  /// Every other category in one list, for searching across them
+  All,
 }
 
 impl SettingsCategory {
@@ -433,6 +648,7 @@ impl SettingsCategory {
       SettingsCategory::Behavior,
       SettingsCategory::Keybindings,
       SettingsCategory::Theme,
+      SettingsCategory::All,
     ]
   }
 
@@ -441,6 +657,7 @@ impl SettingsCategory {
       SettingsCategory::Behavior => "Behavior",
       SettingsCategory::Keybindings => "Keybindings",
       SettingsCategory::Theme => "Theme",
+      SettingsCategory::All => "All",
     }
   }
 
@@ -449,6 +666,7 @@ impl SettingsCategory {
       SettingsCategory::Behavior => 0,
       SettingsCategory::Keybindings => 1,
       SettingsCategory::Theme => 2,
+      SettingsCategory::All => 3,
     }
   }
 
@@ -457,6 +675,7 @@ impl SettingsCategory {
       0 => SettingsCategory::Behavior,
       1 => SettingsCategory::Keybindings,
       2 => SettingsCategory::Theme,
+      3 => SettingsCategory::All,
       _ => SettingsCategory::Behavior,
     }
   }
@@ -502,8 +721,32 @@ pub struct App {
   navigation_stack: Vec<Route>,
   pub spectrum_data: Option<SpectrumData>,
   pub audio_capture_active: bool,
+  /// Since when captured audio has been silent, to spot a capture device that hears nothing
+  pub silent_spectrum_since: Option<Instant>,
   pub home_scroll: u16,
+  /// Furthest the Home changelog can scroll, recorded at draw time
+  pub home_scroll_max: Cell<u16>,
+  /// What the last frame drew where, for the mouse handler to hit-test against
+  pub mouse_regions: RefCell<Vec<MouseRegion>>,
+  /// When and where the last left click landed, to spot double-clicks
+  pub last_click: Option<(Instant, MouseTarget, usize)>,
+  pub home_last_auto_scroll: Instant,
+  pub home_mode: HomeMode,
   pub user_config: UserConfig,
+  /// First key of a chord waiting for its second key, and when it was pressed
+  pub pending_chord: Option<(char, Instant)>,
+  /// Id and name of the last non-spotatui device that played, for the native toggle
+  pub last_external_device: Option<(String, String)>,
+  /// Number of items after the current one, from the last queue refresh
+  pub queue_length: Option<usize>,
+  /// When the queue was last requested; `None` forces a refresh on the next tick
+  pub last_queue_poll: Option<Instant>,
+  pub is_fetching_queue: bool,
+  pub queue: SpotifyResultAndSelectedIndex<Vec<QueueItem>>,
+  /// The last refresh came back empty, so `queue` may be out of date
+  pub queue_stale: bool,
+  /// Uris added with the queue key this session, to label them in the Queue view
+  pub locally_queued_uris: Vec<String>,
   pub artists: Vec<FullArtist>,
   pub artist: Option<Artist>,
   pub album_table_context: AlbumTableContext,
@@ -529,6 +772,7 @@ pub struct App {
   pub followed_artist_ids_set: HashSet<String>,
   pub saved_album_ids_set: HashSet<String>,
   pub saved_show_ids_set: HashSet<String>,
+  pub saved_episode_ids_set: HashSet<String>,
   pub large_search_limit: u32,
   pub library: Library,
   pub playlist_offset: u32,
@@ -548,7 +792,12 @@ pub struct App {
   #[allow(dead_code)]
   pub small_search_limit: u32,
   pub song_progress_ms: u128,
+  /// The position last reported by the player or the API and when it was, which
+  /// progress is extrapolated from while playing
+  pub song_progress_anchor: (u128, Instant),
   pub seek_ms: Option<u128>,
+  /// The seek step the last seek used, to announce the step when it changes
+  pub last_seek_step_ms: Option<u32>,
   /// Last time a native seek was actually sent to the player (for throttling)
   #[cfg(feature = "streaming")]
   pub last_native_seek: Option<Instant>,
@@ -557,8 +806,22 @@ pub struct App {
   pub pending_native_seek: Option<u32>,
   /// Last time an API seek was sent (for throttling external device control)
   pub last_api_seek: Option<Instant>,
+  /// When previous track was last pressed, a second press soon after always skips back
+  pub last_previous_press: Option<Instant>,
+  /// When to re-fetch playback to confirm a shuffle/repeat change sent to an external device
+  pub playback_confirm_at: Option<Instant>,
   /// Pending seek position for API (throttled to avoid overwhelming Spotify API)
   pub pending_api_seek: Option<u32>,
+  /// Last time a volume change was sent to the API (for throttling external device control)
+  pub last_api_volume: Option<Instant>,
+  /// Latest volume waiting to be sent to the API, older key presses are dropped
+  pub pending_volume: Option<u8>,
+  /// Until when a polled device volume may predate our own latest change
+  pub volume_dirty_until: Option<Instant>,
+  /// A request sent while quitting that should finish before the process exits
+  pub wait_on_exit: bool,
+  /// Printed to the terminal after the UI closes, e.g. the playback handoff result
+  pub exit_message: Option<String>,
   pub track_table: TrackTable,
   pub episode_table_context: EpisodeTableContext,
   pub selected_show_simplified: Option<SelectedShow>,
@@ -574,6 +837,8 @@ pub struct App {
   pub help_menu_max_lines: u32,
   pub help_menu_offset: u32,
   pub is_loading: bool,
+  /// Whether the user and their playlists have arrived since startup
+  pub first_load_complete: bool,
   io_tx: Option<Sender<IoEvent>>,
   pub is_fetching_current_playback: bool,
   pub spotify_token_expiry: SystemTime,
@@ -585,21 +850,35 @@ pub struct App {
   pub pending_announcements: Vec<Announcement>,
   pub lyrics: Option<Vec<(u128, String)>>,
   pub lyrics_status: LyricsStatus,
+  /// Lyrics view shows only the lyrics, with no playbar or borders
+  pub lyrics_focus_mode: bool,
   pub global_song_count: Option<u64>,
   pub global_song_count_failed: bool,
+  /// Last track counted towards the global song counter, so re-polls don't count it twice
+  pub last_counted_track_id: Option<String>,
   // Settings screen state
   pub settings_category: SettingsCategory,
   pub settings_items: Vec<SettingItem>,
   pub settings_saved_items: Vec<SettingItem>,
+  /// Compiled-in defaults for `settings_items`, in the same order
+  pub settings_default_items: Vec<SettingItem>,
   pub settings_selected_index: usize,
   pub settings_edit_mode: bool,
   pub settings_edit_buffer: String,
+  /// Text typed after `/` on the settings screen; only matching settings are shown
+  pub settings_filter: Option<String>,
   pub settings_unsaved_prompt_visible: bool,
   pub settings_unsaved_prompt_save_selected: bool,
+  /// Asking whether to reset every setting in the current category
+  pub settings_reset_prompt_visible: bool,
   /// Immediate track info from native player for instant UI updates
   pub native_track_info: Option<NativeTrackInfo>,
   /// Whether native streaming is active (disables API-based progress calculation)
   pub is_streaming_active: bool,
+  /// Stream format of the native player, shown in the playbar while it is active
+  pub native_stream_format: Option<String>,
+  /// Why native streaming couldn't start, shown in the playbar for the whole session
+  pub streaming_warning: Option<String>,
   /// Device id for the native streaming device when known
   #[allow(dead_code)]
   pub native_device_id: Option<String>,
@@ -648,10 +927,28 @@ pub struct App {
   pub playlist_track_positions: Option<Vec<usize>>,
   /// Selected playlist index in the add-to-playlist picker dialog
   pub playlist_picker_selected_index: usize,
+  /// Artists to choose between when opening the artist of a multi-artist track
+  pub artist_picker: Vec<SimplifiedArtist>,
+  /// Selected artist index in the artist picker dialog
+  pub artist_picker_selected_index: usize,
   /// Pending track to add in add-to-playlist dialog flow
   pub pending_playlist_track_add: Option<PendingPlaylistTrackAdd>,
+  /// Add waiting on confirmation because the playlist already has the track
+  pub pending_duplicate_track_add: Option<PlaylistTrackAdd>,
   /// Pending track removal info in remove-from-playlist confirmation flow
   pub pending_playlist_track_removal: Option<PendingPlaylistTrackRemoval>,
+  /// Liked Songs track ids marked for bulk removal
+  pub marked_track_ids: HashSet<String>,
+  /// Saved album ids marked for bulk removal
+  pub marked_album_ids: HashSet<String>,
+  /// Pending bulk library removal awaiting confirmation
+  pub pending_bulk_removal: Option<PendingBulkRemoval>,
+  /// Playlist to download for offline playback once confirmed
+  pub pending_offline_cache: Option<(PlaylistId<'static>, String)>,
+  /// Playlist being downloaded for offline playback: name, songs done and total
+  pub offline_cache_progress: Option<(String, usize, usize)>,
+  /// Suggestions mixed into the open playlist's tracks, while Enhance is on
+  pub playlist_enhance: Option<PlaylistEnhance>,
   /// Full flat list of all user playlists (all pages combined)
   pub all_playlists: Vec<SimplifiedPlaylist>,
   /// Folder tree from rootlist (None if not fetched or streaming disabled)
@@ -660,14 +957,56 @@ pub struct App {
   pub playlist_folder_items: Vec<PlaylistFolderItem>,
   /// Current folder ID being viewed (0 = root)
   pub current_playlist_folder_id: usize,
+  /// Query typed into the playlist sidebar filter, `None` when it is closed
+  pub playlist_filter: Option<String>,
+  /// Sidebar selection to restore once the filter closes
+  pub playlist_filter_restore_index: Option<usize>,
+  /// Name and visibility typed into the new playlist prompt, `None` when it is closed
+  pub new_playlist: Option<(String, bool)>,
+  /// The prompt for renaming an owned playlist, `None` when it is closed
+  pub playlist_details_edit: Option<PlaylistDetailsEdit>,
+  /// Query and selected match in the library search overlay, `None` when it is closed
+  pub library_search: Option<(String, usize)>,
   /// Incremented every time playlists are refreshed to guard stale background tasks
-  pub _playlist_refresh_generation: u64,
+  pub playlist_refresh_generation: u64,
+  /// Whether every page of the user's playlists has been fetched
+  pub playlists_fully_loaded: bool,
+  /// When the playlist panel was last refetched in the background
+  pub last_playlist_auto_refresh: Option<Instant>,
   /// Reference to the native streaming player for direct control (bypasses event channel)
   #[cfg(feature = "streaming")]
   pub streaming_player: Option<Arc<crate::player::StreamingPlayer>>,
   /// Reference to MPRIS manager for emitting Seeked signals after native seeks
   #[cfg(all(feature = "mpris", target_os = "linux"))]
   pub mpris_manager: Option<Arc<crate::mpris::MprisManager>>,
+  /// Holds off system sleep while playing, when `prevent_sleep_while_playing` is on
+  pub keep_awake: KeepAwake,
+  /// A-B repeat points in ms, looped by the native player until the track changes
+  pub ab_loop: Option<(u32, u32)>,
+  /// Point A of an A-B repeat still waiting for its B
+  pub ab_loop_start: Option<u32>,
+  /// Track the A-B points were set on
+  pub ab_loop_track: Option<String>,
+  /// The 30-second preview clip playing locally, when full playback is unavailable
+  #[cfg(feature = "preview")]
+  pub preview: Option<PreviewTrack>,
+}
+
+/// What the playbar shows while a preview clip plays
+#[cfg(feature = "preview")]
+#[derive(Clone, Debug)]
+pub struct PreviewTrack {
+  pub name: String,
+  pub artists: String,
+  pub started_at: Instant,
+}
+
+#[cfg(feature = "preview")]
+impl PreviewTrack {
+  pub fn progress_ms(&self) -> u32 {
+    let elapsed = self.started_at.elapsed().as_millis();
+    elapsed.min(u128::from(crate::infra::preview::PREVIEW_DURATION_MS)) as u32
+  }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -681,6 +1020,7 @@ impl Default for App {
     App {
       spectrum_data: None,
       audio_capture_active: false,
+      silent_spectrum_since: None,
       album_table_context: AlbumTableContext::Full,
       album_list_index: 0,
       discover_selected_index: 0,
@@ -700,6 +1040,19 @@ impl Default for App {
       selected_album_simplified: None,
       selected_album_full: None,
       home_scroll: 0,
+      home_scroll_max: Cell::new(0),
+      mouse_regions: RefCell::new(Vec::new()),
+      last_click: None,
+      home_last_auto_scroll: Instant::now(),
+      home_mode: HomeMode::default(),
+      pending_chord: None,
+      last_external_device: None,
+      queue_length: None,
+      last_queue_poll: None,
+      is_fetching_queue: false,
+      queue: SpotifyResultAndSelectedIndex::default(),
+      queue_stale: false,
+      locally_queued_uris: Vec::new(),
       library: Library {
         saved_tracks: ScrollableResultPages::new(),
         saved_albums: ScrollableResultPages::new(),
@@ -712,6 +1065,7 @@ impl Default for App {
       followed_artist_ids_set: HashSet::new(),
       saved_album_ids_set: HashSet::new(),
       saved_show_ids_set: HashSet::new(),
+      saved_episode_ids_set: HashSet::new(),
       navigation_stack: vec![DEFAULT_ROUTE],
       large_search_limit: 20,
       small_search_limit: 4,
@@ -744,13 +1098,22 @@ impl Default for App {
         tracks: None,
       },
       song_progress_ms: 0,
+      song_progress_anchor: (0, Instant::now()),
       seek_ms: None,
+      last_seek_step_ms: None,
       #[cfg(feature = "streaming")]
       last_native_seek: None,
       #[cfg(feature = "streaming")]
       pending_native_seek: None,
       last_api_seek: None,
+      last_previous_press: None,
+      playback_confirm_at: None,
       pending_api_seek: None,
+      last_api_volume: None,
+      pending_volume: None,
+      volume_dirty_until: None,
+      wait_on_exit: false,
+      exit_message: None,
       selected_device_index: None,
       selected_playlist_index: None,
       active_playlist_index: None,
@@ -766,6 +1129,7 @@ impl Default for App {
       help_menu_max_lines: 0,
       help_menu_offset: 0,
       is_loading: false,
+      first_load_complete: false,
       io_tx: None,
       is_fetching_current_playback: false,
       spotify_token_expiry: SystemTime::now(),
@@ -777,19 +1141,26 @@ impl Default for App {
       pending_announcements: Vec::new(),
       lyrics: None,
       lyrics_status: LyricsStatus::default(),
+      lyrics_focus_mode: false,
       global_song_count: None,
       global_song_count_failed: false,
+      last_counted_track_id: None,
       // Settings defaults
       settings_category: SettingsCategory::default(),
       settings_items: Vec::new(),
       settings_saved_items: Vec::new(),
+      settings_default_items: Vec::new(),
       settings_selected_index: 0,
       settings_edit_mode: false,
       settings_edit_buffer: String::new(),
+      settings_filter: None,
       settings_unsaved_prompt_visible: false,
       settings_unsaved_prompt_save_selected: true,
+      settings_reset_prompt_visible: false,
       native_track_info: None,
       is_streaming_active: false,
+      native_stream_format: None,
+      streaming_warning: None,
       native_device_id: None,
       native_is_playing: None,
       last_device_activation: None,
@@ -808,19 +1179,41 @@ impl Default for App {
       pending_track_table_selection: None,
       playlist_track_positions: None,
       playlist_picker_selected_index: 0,
+      artist_picker: Vec::new(),
+      artist_picker_selected_index: 0,
       pending_playlist_track_add: None,
+      pending_duplicate_track_add: None,
       pending_playlist_track_removal: None,
+      marked_track_ids: HashSet::new(),
+      marked_album_ids: HashSet::new(),
+      pending_bulk_removal: None,
+      pending_offline_cache: None,
+      offline_cache_progress: None,
+      playlist_enhance: None,
       all_playlists: Vec::new(),
       _playlist_folder_nodes: None,
       playlist_folder_items: Vec::new(),
       current_playlist_folder_id: 0,
-      _playlist_refresh_generation: 0,
+      playlist_filter: None,
+      playlist_filter_restore_index: None,
+      new_playlist: None,
+      playlist_details_edit: None,
+      library_search: None,
+      playlist_refresh_generation: 0,
+      last_playlist_auto_refresh: None,
+      playlists_fully_loaded: false,
       #[cfg(feature = "streaming")]
       streaming_player: None,
       #[cfg(all(feature = "mpris", target_os = "linux"))]
       mpris_manager: None,
       #[cfg(feature = "cover-art")]
       cover_art: crate::tui::cover_art::CoverArt::new(),
+      keep_awake: KeepAwake::default(),
+      ab_loop: None,
+      ab_loop_start: None,
+      ab_loop_track: None,
+      #[cfg(feature = "preview")]
+      preview: None,
     }
   }
 }
@@ -831,12 +1224,17 @@ impl App {
     user_config: UserConfig,
     spotify_token_expiry: SystemTime,
   ) -> App {
-    App {
+    let mut app = App {
       io_tx: Some(io_tx),
+      home_scroll: user_config.saved_home_scroll(),
+      discover_time_range: user_config.behavior.discover_time_range,
+      home_mode: user_config.behavior.home_mode,
       user_config,
       spotify_token_expiry,
       ..App::default()
-    }
+    };
+    app.search_results.hovered_block = app.visible_search_block(SearchResultBlock::SongSearch);
+    app
   }
 
   // Send a network event to the network thread
@@ -852,7 +1250,6 @@ impl App {
     }
   }
 
-  #[allow(dead_code)]
   pub fn enqueue_announcements(&mut self, announcements: Vec<Announcement>) {
     if announcements.is_empty() {
       return;
@@ -883,6 +1280,72 @@ impl App {
     self.pending_announcements.extend(incoming);
   }
 
+  /// Show the first-run prompt asking whether to join the global song counter
+  pub fn ask_song_count_choice(&mut self) {
+    self.enqueue_announcements(vec![Announcement {
+      id: SONG_COUNT_PROMPT_ID.to_string(),
+      title: "Global Song Counter".to_string(),
+      body: "spotatui can contribute to a global counter showing the total number of songs \
+played by all users worldwide.
+
+This is completely anonymous: no personal information, song names, artists or listening \
+history are sent, only a simple increment when a new song starts."
+        .to_string(),
+      level: AnnouncementLevel::Info,
+      url: None,
+      received_at: Instant::now(),
+    }]);
+    if self.get_current_route().active_block != ActiveBlock::AnnouncementPrompt {
+      self.push_navigation_stack(RouteId::AnnouncementPrompt, ActiveBlock::AnnouncementPrompt);
+    }
+  }
+
+  pub fn is_song_count_prompt_open(&self) -> bool {
+    self
+      .active_announcement
+      .as_ref()
+      .is_some_and(|announcement| announcement.id == SONG_COUNT_PROMPT_ID)
+  }
+
+  /// Counts a newly started track towards the global song counter, once per track.
+  /// `native` is true when the play came from the native player rather than a
+  /// polled external device.
+  pub fn count_song_play(&mut self, track_id: &str, native: bool) {
+    let behavior = &self.user_config.behavior;
+    if !cfg!(feature = "telemetry") || !behavior.enable_global_song_count {
+      return;
+    }
+    if behavior.global_song_count_native_only && !(native && self.is_streaming_active) {
+      return;
+    }
+    // Native events carry the full URI, playback polls only the id
+    let id = track_id.rsplit(':').next().unwrap_or(track_id);
+    if self.last_counted_track_id.as_deref() == Some(id) {
+      return;
+    }
+    self.last_counted_track_id = Some(id.to_string());
+    self.dispatch(IoEvent::IncrementGlobalSongCount);
+  }
+
+  /// Save the answer to the song counter prompt and close it
+  pub fn answer_song_count_prompt(&mut self, enable: bool) {
+    self.dismiss_active_announcement();
+    if self.active_announcement.is_none() {
+      self.pop_navigation_stack();
+    }
+
+    if let Err(e) = self.user_config.set_global_song_count(enable) {
+      self.handle_error(anyhow!("Failed to save song counter choice: {}", e));
+      return;
+    }
+    if enable {
+      self.dispatch(IoEvent::FetchGlobalSongCount);
+      self.set_status_message("Thank you for participating!", 5);
+    } else {
+      self.set_status_message("Opted out. You can change this anytime in Settings", 5);
+    }
+  }
+
   pub fn dismiss_active_announcement(&mut self) -> Option<String> {
     let dismissed_id = self
       .active_announcement
@@ -897,6 +1360,47 @@ impl App {
     dismissed_id
   }
 
+  /// Run the configured quit behavior: hand native playback off to
+  /// `behavior.handoff_device`, or pause when `behavior.pause_on_exit` is set.
+  /// The native player is shut down when pausing so the Connect device leaves
+  /// cleanly; requests sent over the API set `wait_on_exit` so the caller lets
+  /// them finish.
+  pub fn prepare_exit(&mut self) {
+    #[cfg(feature = "streaming")]
+    if self.is_native_streaming_active_for_playback() {
+      if let Some(device) = self.user_config.behavior.handoff_device.clone() {
+        info!("handing playback off to {} before exit", device);
+        self.dispatch(IoEvent::HandoffPlayback(device));
+        self.wait_on_exit = true;
+        return;
+      }
+    }
+
+    if !self.user_config.behavior.pause_on_exit {
+      return;
+    }
+
+    #[cfg(feature = "streaming")]
+    if self.is_native_streaming_active_for_playback() {
+      if let Some(ref player) = self.streaming_player {
+        info!("pausing native playback before exit");
+        player.pause();
+        player.shutdown();
+        return;
+      }
+    }
+
+    let is_playing = self
+      .current_playback_context
+      .as_ref()
+      .is_some_and(|c| c.is_playing);
+    if is_playing {
+      info!("pausing playback before exit");
+      self.dispatch(IoEvent::PausePlayback);
+      self.wait_on_exit = true;
+    }
+  }
+
   // Close the IO channel to allow the network thread to exit gracefully
   pub fn close_io_channel(&mut self) {
     self.io_tx = None;
@@ -904,10 +1408,67 @@ impl App {
 
   pub fn clear_playlist_track_dialog_state(&mut self) {
     self.pending_playlist_track_add = None;
+    self.pending_duplicate_track_add = None;
     self.pending_playlist_track_removal = None;
     self.playlist_picker_selected_index = 0;
   }
 
+  /// Forget where the last frame drew things, before drawing the next one
+  pub fn clear_mouse_regions(&self) {
+    self.mouse_regions.borrow_mut().clear();
+  }
+
+  /// Note that this frame drew `target` in `area`, scrolled to `offset`
+  pub fn record_mouse_region(&self, target: MouseTarget, area: Rect, offset: usize) {
+    self.mouse_regions.borrow_mut().push(MouseRegion {
+      target,
+      area,
+      offset,
+    });
+  }
+
+  /// The topmost region the last frame drew under `column`, `row`
+  pub fn mouse_region_at(&self, column: u16, row: u16) -> Option<MouseRegion> {
+    self
+      .mouse_regions
+      .borrow()
+      .iter()
+      .rev()
+      .find(|region| region.area.contains((column, row).into()))
+      .copied()
+  }
+
+  /// Note a left click on row `index` of `target`, returning whether it completes a
+  /// double-click on that row
+  pub fn register_click(&mut self, target: MouseTarget, index: usize) -> bool {
+    let now = Instant::now();
+    let double = self
+      .last_click
+      .is_some_and(|(at, last_target, last_index)| {
+        last_target == target && last_index == index && now.duration_since(at) < DOUBLE_CLICK_WINDOW
+      });
+    // A third click starts over rather than making another double-click
+    self.last_click = (!double).then_some((now, target, index));
+    double
+  }
+
+  /// Jump the progress bar to a position the player reported (or that we asked for)
+  pub fn set_song_progress(&mut self, position_ms: u128) {
+    self.song_progress_ms = position_ms;
+    self.song_progress_anchor = (position_ms, Instant::now());
+  }
+
+  /// Take the position from a playback poll, unless a seek just sent may not have
+  /// reached Spotify yet, so the bar stays on the seek target
+  pub fn sync_polled_progress(&mut self, position_ms: u128) {
+    let recently_seeked = self
+      .last_api_seek
+      .is_some_and(|t| t.elapsed().as_millis() < SEEK_POSITION_IGNORE_MS);
+    if !recently_seeked {
+      self.set_song_progress(position_ms);
+    }
+  }
+
   pub fn set_status_message(&mut self, message: impl Into<String>, ttl_secs: u64) {
     self.status_message = Some(message.into());
     self.status_message_expires_at = Some(Instant::now() + Duration::from_secs(ttl_secs));
@@ -946,83 +1507,384 @@ impl App {
     );
   }
 
+  /// Whether a track is in Liked Songs or an episode is in Your Episodes.
+  pub fn is_playable_saved(&self, id: &PlayableId) -> bool {
+    match id {
+      PlayableId::Track(track_id) => self.liked_song_ids_set.contains(track_id.id()),
+      PlayableId::Episode(episode_id) => self.saved_episode_ids_set.contains(episode_id.id()),
+    }
+  }
+
+  /// Record the result of a save toggle, playing the heart animation on save.
+  pub fn apply_playable_saved(&mut self, id: &PlayableId, saved: bool) {
+    let (set, added, removed) = match id {
+      PlayableId::Track(_) => (
+        &mut self.liked_song_ids_set,
+        "Added to Liked Songs",
+        "Removed from Liked Songs",
+      ),
+      PlayableId::Episode(_) => (
+        &mut self.saved_episode_ids_set,
+        "Saved to Your Episodes",
+        "Removed from Your Episodes",
+      ),
+    };
+    let raw_id = match id {
+      PlayableId::Track(track_id) => track_id.id(),
+      PlayableId::Episode(episode_id) => episode_id.id(),
+    };
+
+    if saved {
+      set.insert(raw_id.to_string());
+      self.liked_song_animation_frame = Some(10);
+      self.set_status_message(added, 3);
+    } else {
+      set.remove(raw_id);
+      self.set_status_message(removed, 3);
+    }
+  }
+
+  pub fn begin_bulk_library_removal(&mut self, removal: PendingBulkRemoval) {
+    if removal.count() == 0 {
+      return;
+    }
+
+    self.dialog = None;
+    self.confirm = false;
+    self.clear_playlist_track_dialog_state();
+    self.pending_bulk_removal = Some(removal);
+    self.push_navigation_stack(
+      RouteId::Dialog,
+      ActiveBlock::Dialog(DialogContext::BulkRemoveFromLibraryConfirm),
+    );
+  }
+
+  /// Ask before downloading a playlist into the native player's audio cache,
+  /// since a long playlist is a lot of data
+  pub fn begin_offline_cache(&mut self, playlist_id: PlaylistId<'static>, name: String) {
+    #[cfg(feature = "streaming")]
+    let caches_audio = self
+      .streaming_player
+      .as_ref()
+      .is_some_and(|player| player.caches_audio());
+    #[cfg(not(feature = "streaming"))]
+    let caches_audio = false;
+    if !caches_audio {
+      self.set_status_message(locale::t("status.offline_cache_needs_streaming"), 5);
+      return;
+    }
+    if self.offline_cache_progress.is_some() {
+      self.set_status_message(locale::t("status.offline_cache_busy"), 3);
+      return;
+    }
+
+    self.dialog = None;
+    self.confirm = false;
+    self.clear_playlist_track_dialog_state();
+    self.pending_offline_cache = Some((playlist_id, name));
+    self.push_navigation_stack(
+      RouteId::Dialog,
+      ActiveBlock::Dialog(DialogContext::CachePlaylistOfflineConfirm),
+    );
+  }
+
+  /// Mix suggested tracks into the open playlist, or take them out again
+  pub fn toggle_playlist_enhance(&mut self, playlist_id: PlaylistId<'static>) {
+    if let Some(enhance) = self.playlist_enhance.take() {
+      let tracks = std::mem::take(&mut self.track_table.tracks);
+      // Stay on the same playlist row, or the one before a suggestion
+      let row = (0..=self.track_table.selected_index)
+        .rev()
+        .find_map(|index| enhance.playlist_row(&tracks, index))
+        .unwrap_or(0);
+      self.track_table.tracks = enhance.strip(tracks);
+      self.track_table.selected_index = row;
+      self.set_status_message(locale::t("status.enhance_off"), 3);
+      if enhance.playlist_id == playlist_id {
+        return;
+      }
+    }
+
+    let seeds: Vec<TrackId<'static>> = self
+      .track_table
+      .tracks
+      .iter()
+      .filter_map(|track| track.id.clone())
+      .take(enhance::SEED_TRACKS)
+      .collect();
+    if seeds.is_empty() {
+      self.set_status_message(locale::t("status.enhance_no_seeds"), 4);
+      return;
+    }
+    self.playlist_enhance = Some(PlaylistEnhance::new(playlist_id.clone()));
+    self.set_status_message(locale::t("status.enhance_finding"), 5);
+    self.dispatch(IoEvent::EnhancePlaylist(playlist_id, seeds));
+  }
+
   pub fn is_playlist_item_visible_in_current_folder(&self, item: &PlaylistFolderItem) -> bool {
     match item {
       PlaylistFolderItem::Folder(f) => f.current_id == self.current_playlist_folder_id,
       PlaylistFolderItem::Playlist { current_id, .. } => {
         *current_id == self.current_playlist_folder_id
       }
+      PlaylistFolderItem::PinnedHeader => false,
     }
   }
 
-  /// Get the number of items visible in the current folder level.
-  pub fn get_playlist_display_count(&self) -> usize {
+  pub fn is_playlist_pinned(&self, index: usize) -> bool {
+    self.all_playlists.get(index).is_some_and(|playlist| {
+      self
+        .user_config
+        .behavior
+        .pinned_playlists
+        .iter()
+        .any(|id| id == playlist.id.id())
+    })
+  }
+
+  /// The pinned playlists in the order they were pinned, each as the first place
+  /// it appears in the folder tree. Pins for playlists no longer in the library
+  /// are skipped.
+  fn pinned_playlist_items(&self) -> impl Iterator<Item = &PlaylistFolderItem> + '_ {
     self
+      .user_config
+      .behavior
+      .pinned_playlists
+      .iter()
+      .filter_map(move |id| {
+        self.playlist_folder_items.iter().find(|item| match item {
+          PlaylistFolderItem::Playlist { index, .. } => self
+            .all_playlists
+            .get(*index)
+            .is_some_and(|playlist| playlist.id.id() == id),
+          PlaylistFolderItem::Folder(_) | PlaylistFolderItem::PinnedHeader => false,
+        })
+      })
+  }
+
+  /// Items shown in the sidebar: the current folder, or every matching playlist
+  /// across all folders while the sidebar filter is open. The root starts with a
+  /// "Pinned" section of the pinned playlists, which then aren't repeated further down it.
+  fn visible_playlist_items(&self) -> impl Iterator<Item = &PlaylistFolderItem> + '_ {
+    let query = self
+      .playlist_filter
+      .as_ref()
+      .map(|query| query.to_lowercase());
+    let at_root = query.is_none() && self.current_playlist_folder_id == 0;
+    let mut pinned = self
+      .pinned_playlist_items()
+      .filter(move |_| at_root)
+      .peekable();
+    let header = pinned.peek().is_some().then_some(&PINNED_HEADER);
+    let mut seen = HashSet::new();
+    let rest = self
       .playlist_folder_items
       .iter()
-      .filter(|item| self.is_playlist_item_visible_in_current_folder(item))
-      .count()
+      .filter(move |item| match (&query, item) {
+        (Some(query), PlaylistFolderItem::Playlist { index, .. }) => {
+          seen.insert(*index)
+            && self
+              .all_playlists
+              .get(*index)
+              .is_some_and(|playlist| playlist.name.to_lowercase().contains(query.as_str()))
+        }
+        (Some(_), PlaylistFolderItem::Folder(_) | PlaylistFolderItem::PinnedHeader) => false,
+        (None, PlaylistFolderItem::Playlist { index, .. }) if at_root => {
+          self.is_playlist_item_visible_in_current_folder(item) && !self.is_playlist_pinned(*index)
+        }
+        (None, item) => self.is_playlist_item_visible_in_current_folder(item),
+      });
+    header.into_iter().chain(pinned).chain(rest)
+  }
+
+  /// Get the number of items visible in the current folder level.
+  pub fn get_playlist_display_count(&self) -> usize {
+    self.visible_playlist_items().count()
   }
 
   /// Get a visible item by display index in the current folder.
   pub fn get_playlist_display_item_at(&self, display_index: usize) -> Option<&PlaylistFolderItem> {
-    self
-      .playlist_folder_items
-      .iter()
-      .filter(|item| self.is_playlist_item_visible_in_current_folder(item))
-      .nth(display_index)
+    self.visible_playlist_items().nth(display_index)
   }
 
   /// Get visible playlist items in the current folder (used by UI rendering).
   pub fn get_playlist_display_items(&self) -> Vec<&PlaylistFolderItem> {
-    self
-      .playlist_folder_items
-      .iter()
-      .filter(|item| self.is_playlist_item_visible_in_current_folder(item))
-      .collect()
+    self.visible_playlist_items().collect()
   }
 
-  /// Get the SimplifiedPlaylist for a PlaylistFolderItem::Playlist variant
-  #[allow(dead_code)]
-  pub fn get_playlist_for_item(&self, item: &PlaylistFolderItem) -> Option<&SimplifiedPlaylist> {
-    match item {
-      PlaylistFolderItem::Playlist { index, .. } => self.all_playlists.get(*index),
-      PlaylistFolderItem::Folder(_) => None,
+  pub fn open_playlist_filter(&mut self) {
+    if self.playlist_filter.is_none() {
+      self.playlist_filter_restore_index = self.selected_playlist_index;
+      self.playlist_filter = Some(String::new());
     }
   }
 
-  /// Get the currently selected playlist id in the visible playlist list.
-  #[allow(dead_code)]
-  pub fn get_selected_playlist_id(&self) -> Option<String> {
-    let selected_index = self.selected_playlist_index?;
-    if let Some(PlaylistFolderItem::Playlist { index, .. }) =
-      self.get_playlist_display_item_at(selected_index)
-    {
-      return self
-        .all_playlists
-        .get(*index)
-        .map(|p| p.id.id().to_string());
+  /// Close the sidebar filter and return to the folder view it was opened from.
+  pub fn close_playlist_filter(&mut self) {
+    if self.playlist_filter.take().is_some() {
+      self.selected_playlist_index = self.playlist_filter_restore_index.take();
     }
+  }
 
-    self
-      .playlists
-      .as_ref()
-      .and_then(|playlists| playlists.items.get(selected_index))
-      .map(|playlist| playlist.id.id().to_string())
+  /// The filter only applies while the sidebar has focus, so moving anywhere else
+  /// brings back the folder view
+  fn close_playlist_filter_if_unfocused(&mut self) {
+    if self.get_current_route().active_block != ActiveBlock::MyPlaylists {
+      self.close_playlist_filter();
+    }
   }
 
-  fn apply_seek(&mut self, seek_ms: u32) {
-    if let Some(CurrentPlaybackContext {
-      item: Some(item), ..
-    }) = &self.current_playback_context
-    {
-      let duration_ms = match item {
-        PlayableItem::Track(track) => track.duration.num_milliseconds() as u32,
-        PlayableItem::Episode(episode) => episode.duration.num_milliseconds() as u32,
-      };
+  /// Pin the selected playlist to the top of the sidebar, or unpin it
+  pub fn toggle_pin_selected_playlist(&mut self) {
+    let selected = self
+      .selected_playlist_index
+      .and_then(|selected_index| self.get_playlist_display_item_at(selected_index));
+    let Some(PlaylistFolderItem::Playlist { index, .. }) = selected else {
+      return;
+    };
+    let index = *index;
+    let Some(playlist) = self.all_playlists.get(index) else {
+      return;
+    };
+    let id = playlist.id.id().to_string();
+    let name = playlist.name.clone();
 
-      let event = if seek_ms < duration_ms {
-        IoEvent::Seek(seek_ms)
+    let pinned = &mut self.user_config.behavior.pinned_playlists;
+    let message = if let Some(position) = pinned.iter().position(|pinned_id| *pinned_id == id) {
+      pinned.remove(position);
+      locale::tf("status.playlist_unpinned", &[("name", &name)])
+    } else {
+      pinned.push(id);
+      locale::tf("status.playlist_pinned", &[("name", &name)])
+    };
+    let _ = self.user_config.save_config();
+
+    // Follow the playlist to wherever it now shows up in the sidebar
+    if let Some(display_index) = self
+      .get_playlist_display_items()
+      .iter()
+      .position(|item| matches!(item, PlaylistFolderItem::Playlist { index: i, .. } if *i == index))
+    {
+      self.selected_playlist_index = Some(display_index);
+    }
+    self.set_status_message(message, 4);
+  }
+
+  pub fn open_new_playlist_prompt(&mut self) {
+    self.new_playlist = Some((
+      String::new(),
+      self.user_config.behavior.default_playlist_public,
+    ));
+  }
+
+  /// Create the playlist typed into the prompt and close it
+  pub fn submit_new_playlist(&mut self) {
+    let Some((name, public)) = self.new_playlist.take() else {
+      return;
+    };
+    let name = name.trim().to_string();
+    if name.is_empty() {
+      self.set_status_message(locale::t("status.playlist_name_empty"), 4);
+      self.new_playlist = Some((name, public));
+      return;
+    }
+    self.dispatch(IoEvent::CreatePlaylist(name, public));
+  }
+
+  /// Open the rename prompt on the selected playlist, if the user owns it
+  pub fn open_playlist_details_prompt(&mut self) {
+    if let Some(playlist) = self.selected_owned_playlist() {
+      let playlist_id = playlist.id.into_static();
+      self.playlist_details_edit = Some(PlaylistDetailsEdit {
+        playlist_id: playlist_id.clone(),
+        name: playlist.name,
+        description: String::new(),
+        original_description: None,
+        editing_description: false,
+      });
+      self.dispatch(IoEvent::GetPlaylistDescription(playlist_id));
+    }
+  }
+
+  /// Save what was typed into the rename prompt and close it
+  pub fn submit_playlist_details(&mut self) {
+    let Some(edit) = self.playlist_details_edit.take() else {
+      return;
+    };
+    let name = edit.name.trim().to_string();
+    if name.is_empty() {
+      self.set_status_message(locale::t("status.playlist_name_empty"), 4);
+      self.playlist_details_edit = Some(edit);
+      return;
+    }
+    let renamed = !self
+      .all_playlists
+      .iter()
+      .any(|playlist| playlist.id == edit.playlist_id && playlist.name == name);
+    // Sent whenever it changed, so emptying it clears the description. Until the
+    // current one has loaded only something typed counts as a change.
+    let description = edit.description.trim();
+    let description_changed = match &edit.original_description {
+      Some(original) => description != original.trim(),
+      None => !description.is_empty(),
+    };
+    let name = renamed.then_some(name);
+    let description = description_changed.then(|| description.to_string());
+    if name.is_some() || description.is_some() {
+      self.dispatch(IoEvent::ChangePlaylistDetails(
+        edit.playlist_id,
+        name,
+        description,
+      ));
+    }
+  }
+
+  pub fn is_playlist_filter_open(&self) -> bool {
+    self.playlist_filter.is_some()
+      && self.get_current_route().active_block == ActiveBlock::MyPlaylists
+  }
+
+  /// Get the SimplifiedPlaylist for a PlaylistFolderItem::Playlist variant
+  #[allow(dead_code)]
+  pub fn get_playlist_for_item(&self, item: &PlaylistFolderItem) -> Option<&SimplifiedPlaylist> {
+    match item {
+      PlaylistFolderItem::Playlist { index, .. } => self.all_playlists.get(*index),
+      PlaylistFolderItem::Folder(_) | PlaylistFolderItem::PinnedHeader => None,
+    }
+  }
+
+  /// Get the currently selected playlist id in the visible playlist list.
+  #[allow(dead_code)]
+  pub fn get_selected_playlist_id(&self) -> Option<String> {
+    let selected_index = self.selected_playlist_index?;
+    if let Some(PlaylistFolderItem::Playlist { index, .. }) =
+      self.get_playlist_display_item_at(selected_index)
+    {
+      return self
+        .all_playlists
+        .get(*index)
+        .map(|p| p.id.id().to_string());
+    }
+
+    self
+      .playlists
+      .as_ref()
+      .and_then(|playlists| playlists.items.get(selected_index))
+      .map(|playlist| playlist.id.id().to_string())
+  }
+
+  fn apply_seek(&mut self, seek_ms: u32) {
+    if let Some(CurrentPlaybackContext {
+      item: Some(item), ..
+    }) = &self.current_playback_context
+    {
+      let duration_ms = match item {
+        PlayableItem::Track(track) => track.duration.num_milliseconds() as u32,
+        PlayableItem::Episode(episode) => episode.duration.num_milliseconds() as u32,
+      };
+
+      let event = if seek_ms < duration_ms {
+        IoEvent::Seek(seek_ms)
       } else {
         IoEvent::NextTrack
       };
@@ -1046,7 +1908,12 @@ impl App {
       .elapsed()
       .as_millis();
 
-    if !self.is_fetching_current_playback && elapsed >= poll_interval_ms {
+    let confirm_due = self
+      .playback_confirm_at
+      .is_some_and(|at| Instant::now() >= at);
+
+    if !self.is_fetching_current_playback && (elapsed >= poll_interval_ms || confirm_due) {
+      self.playback_confirm_at = None;
       self.is_fetching_current_playback = true;
       // Trigger the seek if the user has set a new position
       match self.seek_ms {
@@ -1056,6 +1923,184 @@ impl App {
     }
   }
 
+  /// `block` if the search layout shows it, otherwise the first block it does show
+  pub fn visible_search_block(&self, block: SearchResultBlock) -> SearchResultBlock {
+    let blocks = &self.user_config.behavior.search_blocks;
+    if blocks.iter().any(|layout| layout.block == block) {
+      block
+    } else {
+      blocks.first().map_or(block, |layout| layout.block)
+    }
+  }
+
+  /// Search result blocks the user has enabled, laid out two to a row
+  pub fn search_block_rows(&self) -> Vec<Vec<SearchBlockLayout>> {
+    self
+      .user_config
+      .behavior
+      .search_blocks
+      .chunks(2)
+      .map(<[SearchBlockLayout]>::to_vec)
+      .collect()
+  }
+
+  pub fn open_discover(&mut self) {
+    self.push_navigation_stack(RouteId::Discover, ActiveBlock::Discover);
+  }
+
+  pub fn open_recently_played(&mut self) {
+    self.dispatch(IoEvent::GetRecentlyPlayed);
+    self.push_navigation_stack(RouteId::RecentlyPlayed, ActiveBlock::RecentlyPlayed);
+  }
+
+  pub fn open_queue(&mut self) {
+    self.last_queue_poll = None;
+    self.poll_queue();
+    if self.get_current_route().id != RouteId::Queue {
+      self.push_navigation_stack(RouteId::Queue, ActiveBlock::Queue);
+    }
+  }
+
+  /// Store a queue refresh, where `None` means the request failed.
+  ///
+  /// The queue endpoint sometimes answers with nothing at all, so an empty or failed
+  /// refresh keeps the items we have and marks them stale. A second empty answer in
+  /// a row is believed.
+  pub fn apply_queue_update(&mut self, fetched: Option<Vec<QueueItem>>) {
+    self.is_fetching_queue = false;
+    let mut items = match fetched {
+      Some(items) if !items.is_empty() => items,
+      _ if !self.queue.result.is_empty() && !self.queue_stale => {
+        self.queue_stale = true;
+        return;
+      }
+      Some(items) => items,
+      None => {
+        self.queue_length = None;
+        return;
+      }
+    };
+
+    // Forget local additions once they have played
+    self
+      .locally_queued_uris
+      .retain(|uri| items.iter().any(|item| item.uri == *uri));
+    for item in items.iter_mut() {
+      item.added_here = self.locally_queued_uris.contains(&item.uri);
+    }
+    self.queue_length = Some(items.len());
+    self.queue.index = self.queue.index.min(items.len().saturating_sub(1));
+    self.queue.result = items;
+    self.queue_stale = false;
+  }
+
+  fn poll_queue(&mut self) {
+    let queue_visible = self.get_current_route().id == RouteId::Queue;
+    if !(self.user_config.behavior.show_queue_length || queue_visible)
+      || self.is_fetching_queue
+      || self.current_playback_context.is_none()
+    {
+      return;
+    }
+    let due = self
+      .last_queue_poll
+      .is_none_or(|polled_at| polled_at.elapsed() >= QUEUE_POLL_INTERVAL);
+    if due {
+      self.is_fetching_queue = true;
+      self.last_queue_poll = Some(Instant::now());
+      self.dispatch(IoEvent::GetQueue);
+    }
+  }
+
+  /// Refetch the playlists every `behavior.playlist_auto_refresh_secs`, but no more
+  /// often than `MIN_PLAYLIST_AUTO_REFRESH_SECS`, to pick up ones made or deleted
+  /// elsewhere. Waits while a fetch is still running, or while a dialog or prompt
+  /// holds on to a playlist by its place in the list.
+  fn auto_refresh_playlists(&mut self) {
+    let secs = self.user_config.behavior.playlist_auto_refresh_secs;
+    if secs == 0 || !self.playlists_fully_loaded {
+      return;
+    }
+    let Some(refreshed_at) = self.last_playlist_auto_refresh else {
+      // Playlists were just fetched at startup
+      self.last_playlist_auto_refresh = Some(Instant::now());
+      return;
+    };
+    let busy = matches!(
+      self.get_current_route().active_block,
+      ActiveBlock::Dialog(_) | ActiveBlock::SortMenu
+    ) || self.new_playlist.is_some()
+      || self.playlist_details_edit.is_some()
+      || self.library_search.is_some();
+    let interval = Duration::from_secs(secs.max(MIN_PLAYLIST_AUTO_REFRESH_SECS));
+    if busy || refreshed_at.elapsed() < interval {
+      return;
+    }
+    self.last_playlist_auto_refresh = Some(Instant::now());
+    self.dispatch(IoEvent::RefreshPlaylists);
+  }
+
+  fn auto_scroll_home(&mut self) {
+    let behavior = &self.user_config.behavior;
+    if !behavior.home_auto_scroll
+      || self.home_mode != HomeMode::Changelog
+      || self.get_current_route().id != RouteId::Home
+    {
+      return;
+    }
+    let interval = Duration::from_millis(behavior.home_auto_scroll_interval_ms);
+    if self.home_last_auto_scroll.elapsed() >= interval {
+      self.home_last_auto_scroll = Instant::now();
+      if self.home_scroll < self.home_scroll_max.get() {
+        self.home_scroll += 1;
+      }
+    }
+  }
+
+  /// Store the latest captured spectrum and keep track of how long it has been silent
+  #[cfg(any(feature = "audio-viz", feature = "audio-viz-cpal"))]
+  pub fn update_spectrum(&mut self, spectrum: SpectrumData) {
+    if spectrum.peak < SILENT_SPECTRUM_PEAK {
+      self.silent_spectrum_since.get_or_insert_with(Instant::now);
+    } else {
+      self.silent_spectrum_since = None;
+    }
+    self.spectrum_data = Some(spectrum);
+  }
+
+  /// Whether music is playing but capture has heard nothing for a while, which
+  /// usually means no loopback/monitor device is set up
+  pub fn capture_hears_nothing(&self) -> bool {
+    let is_playing = self
+      .native_is_playing
+      .or_else(|| self.current_playback_context.as_ref().map(|c| c.is_playing))
+      .unwrap_or(false);
+    is_playing
+      && self
+        .silent_spectrum_since
+        .is_some_and(|since| since.elapsed() >= Duration::from_secs(SILENT_SPECTRUM_SECS))
+  }
+
+  /// Marks startup as done once the essentials needed to draw the library are in
+  pub fn check_first_load(&mut self) {
+    if !self.first_load_complete {
+      self.first_load_complete = self.user.is_some() && self.playlists.is_some();
+    }
+  }
+
+  /// A startup request failed, so what `check_first_load` waits for isn't coming.
+  /// Stop the loading indicator instead of spinning forever.
+  pub fn abandon_first_load(&mut self) {
+    self.first_load_complete = true;
+  }
+
+  pub fn toggle_home_mode(&mut self) {
+    self.home_mode = match self.home_mode {
+      HomeMode::Changelog => HomeMode::Stats,
+      HomeMode::Stats => HomeMode::Changelog,
+    };
+  }
+
   pub fn update_on_tick(&mut self) {
     // Increment global animation tick (wraps after ~9.4 quintillion ticks, effectively never)
     self.animation_tick = self.animation_tick.wrapping_add(1);
@@ -1075,67 +2120,93 @@ impl App {
       }
     }
 
+    #[cfg(feature = "preview")]
+    if self
+      .preview
+      .as_ref()
+      .is_some_and(|preview| preview.progress_ms() >= crate::infra::preview::PREVIEW_DURATION_MS)
+    {
+      self.preview = None;
+    }
+
+    self.auto_scroll_home();
+
+    let is_playing = self
+      .native_is_playing
+      .or_else(|| self.current_playback_context.as_ref().map(|c| c.is_playing))
+      .unwrap_or(false);
+    self
+      .keep_awake
+      .set(is_playing && self.user_config.behavior.prevent_sleep_while_playing);
+
     self.poll_current_playback();
+    self.poll_queue();
+    self.auto_refresh_playlists();
+
+    #[cfg(feature = "streaming")]
+    self.check_ab_loop();
 
     if let Some(CurrentPlaybackContext {
       item: Some(item),
-      progress,
       is_playing,
       ..
     }) = &self.current_playback_context
     {
-      // When native streaming is active, skip API-based progress calculation
-      // The native player's PositionChanged events update song_progress_ms directly
-      if self.is_streaming_active {
-        let ms_since_poll = self
-          .instant_since_last_current_playback_poll
-          .elapsed()
-          .as_millis();
-        if ms_since_poll < 2000 {
-          return; // Recent native update - don't overwrite
-        }
-        // No recent native update - fall through to API-based calculation as fallback
-      }
-
-      let ms_since_poll = self
-        .instant_since_last_current_playback_poll
-        .elapsed()
-        .as_millis();
-
-      // Skip position updates if we recently seeked (let UI show our target position)
-      let recently_seeked = self
-        .last_api_seek
-        .is_some_and(|t| t.elapsed().as_millis() < SEEK_POSITION_IGNORE_MS);
-
-      if recently_seeked {
-        return; // Don't overwrite our seek target
-      }
-
-      // Resync from fresh API data (within 300ms of poll) to correct drift
-      if ms_since_poll < 300 {
-        self.song_progress_ms = progress
-          .as_ref()
-          .map(|p| p.num_milliseconds() as u128)
-          .unwrap_or(0);
-      } else if *is_playing {
-        // Smooth incremental updates between API polls
-        let tick_rate_ms = self.user_config.behavior.tick_rate_milliseconds as u128;
+      if *is_playing {
+        // Extrapolate from the last report rather than stepping a tick at a time,
+        // so progress stays accurate whatever the tick rate
         let duration_ms = match item {
           PlayableItem::Track(track) => track.duration.num_milliseconds() as u128,
           PlayableItem::Episode(episode) => episode.duration.num_milliseconds() as u128,
         };
-
-        self.song_progress_ms = (self.song_progress_ms + tick_rate_ms).min(duration_ms);
+        let (anchor_ms, anchored_at) = self.song_progress_anchor;
+        // Running far past the last report means the player or the poll has stalled,
+        // so wait at the cap for the next report instead of racing ahead
+        let ahead_ms = anchored_at.elapsed().as_millis().min(u128::from(
+          self.user_config.behavior.max_progress_extrapolation_ms,
+        ));
+        self.song_progress_ms = (anchor_ms + ahead_ms).min(duration_ms);
+      } else {
+        // Freeze where we are, and carry on from here on resume
+        self.song_progress_anchor = (self.song_progress_ms, Instant::now());
       }
-      // When paused, keep song_progress_ms unchanged
     }
   }
 
+  /// The seek step for what's playing now
+  fn seek_step_ms(&self) -> u32 {
+    let item = self
+      .current_playback_context
+      .as_ref()
+      .and_then(|context| context.item.as_ref());
+    seek_step_for(item, &self.user_config.behavior)
+  }
+
+  /// The seek step for what's playing, saying what it is when it differs from
+  /// the last seek's, e.g. going from a song to an episode
+  fn seek_step_announced(&mut self) -> u32 {
+    let step = self.seek_step_ms();
+    if self.last_seek_step_ms.is_some_and(|last| last != step) {
+      let label = seek_step_label(step);
+      self.set_status_message(locale::tf("status.seek_step", &[("step", &label)]), 2);
+    }
+    self.last_seek_step_ms = Some(step);
+    step
+  }
+
   pub fn seek_forwards(&mut self) {
-    info!(
-      "seeking forwards by {} ms",
-      self.user_config.behavior.seek_milliseconds
-    );
+    let step = self.seek_step_announced();
+    self.seek_forwards_by(step);
+  }
+
+  /// Seek forwards a few seek steps at once, for getting around long episodes
+  pub fn seek_forwards_large(&mut self) {
+    let step = self.seek_step_announced();
+    self.seek_forwards_by(step.saturating_mul(LARGE_SEEK_MULTIPLIER));
+  }
+
+  fn seek_forwards_by(&mut self, step_ms: u32) {
+    info!("seeking forwards by {} ms", step_ms);
     if let Some(CurrentPlaybackContext {
       item: Some(item), ..
     }) = &self.current_playback_context
@@ -1150,10 +2221,7 @@ impl App {
         None => self.song_progress_ms,
       };
 
-      let new_progress = min(
-        old_progress as u32 + self.user_config.behavior.seek_milliseconds,
-        duration_ms,
-      );
+      let new_progress = min(old_progress as u32 + step_ms, duration_ms);
 
       self.seek_ms = Some(new_progress as u128);
 
@@ -1161,7 +2229,7 @@ impl App {
       #[cfg(feature = "streaming")]
       if self.is_native_streaming_active_for_playback() && self.streaming_player.is_some() {
         // Always update UI immediately
-        self.song_progress_ms = new_progress as u128;
+        self.set_song_progress(new_progress as u128);
         self.seek_ms = None;
 
         // Throttle actual seeks to avoid overwhelming librespot (max ~20/sec)
@@ -1185,23 +2253,29 @@ impl App {
   }
 
   pub fn seek_backwards(&mut self) {
-    info!(
-      "seeking backwards by {} ms",
-      self.user_config.behavior.seek_milliseconds
-    );
+    let step = self.seek_step_announced();
+    self.seek_backwards_by(step);
+  }
+
+  pub fn seek_backwards_large(&mut self) {
+    let step = self.seek_step_announced();
+    self.seek_backwards_by(step.saturating_mul(LARGE_SEEK_MULTIPLIER));
+  }
+
+  fn seek_backwards_by(&mut self, step_ms: u32) {
+    info!("seeking backwards by {} ms", step_ms);
     let old_progress = match self.seek_ms {
       Some(seek_ms) => seek_ms,
       None => self.song_progress_ms,
     };
-    let new_progress =
-      (old_progress as u32).saturating_sub(self.user_config.behavior.seek_milliseconds);
+    let new_progress = (old_progress as u32).saturating_sub(step_ms);
     self.seek_ms = Some(new_progress as u128);
 
     // Use native streaming player for instant control (bypasses event channel latency)
     #[cfg(feature = "streaming")]
     if self.is_native_streaming_active_for_playback() && self.streaming_player.is_some() {
       // Always update UI immediately
-      self.song_progress_ms = new_progress as u128;
+      self.set_song_progress(new_progress as u128);
       self.seek_ms = None;
 
       // Throttle actual seeks to avoid overwhelming librespot (max ~20/sec)
@@ -1226,7 +2300,7 @@ impl App {
   /// Queue an API-based seek with throttling (for external device control)
   fn queue_api_seek(&mut self, position_ms: u32) {
     // Always update UI immediately
-    self.song_progress_ms = position_ms as u128;
+    self.set_song_progress(position_ms as u128);
     self.seek_ms = None;
 
     // Start the ignore window immediately when the user requests a seek
@@ -1290,6 +2364,84 @@ impl App {
     }
   }
 
+  /// Sets A, then B, then clears the A-B repeat. Only the native player can loop
+  /// tightly enough, so other devices just get a status message.
+  pub fn cycle_ab_loop(&mut self) {
+    #[cfg(feature = "streaming")]
+    let native = self.is_native_streaming_active_for_playback();
+    #[cfg(not(feature = "streaming"))]
+    let native = false;
+    if !native {
+      self.set_status_message(locale::t("status.ab_loop_native_only"), 4);
+      return;
+    }
+    let position = self.song_progress_ms as u32;
+    let message = self.next_ab_loop_point(position);
+    self.set_status_message(message, 3);
+  }
+
+  pub fn next_ab_loop_point(&mut self, position: u32) -> String {
+    if self.ab_loop.is_some() {
+      self.clear_ab_loop();
+      return locale::t("status.ab_loop_off");
+    }
+    match self.ab_loop_start.take() {
+      None => {
+        self.ab_loop_start = Some(position);
+        self.ab_loop_track = self.last_track_id.clone();
+        locale::tf(
+          "status.ab_loop_start",
+          &[("a", &millis_to_minutes(position as u128))],
+        )
+      }
+      Some(start) if start == position => {
+        self.ab_loop_start = Some(start);
+        locale::t("status.ab_loop_too_short")
+      }
+      Some(start) => {
+        let (a, b) = (start.min(position), start.max(position));
+        self.ab_loop = Some((a, b));
+        locale::tf(
+          "status.ab_loop_on",
+          &[
+            ("a", &millis_to_minutes(a as u128)),
+            ("b", &millis_to_minutes(b as u128)),
+          ],
+        )
+      }
+    }
+  }
+
+  pub fn clear_ab_loop(&mut self) {
+    self.ab_loop = None;
+    self.ab_loop_start = None;
+    self.ab_loop_track = None;
+  }
+
+  /// Where to jump back to once playback passes point B. Drops the loop when the
+  /// track has changed since it was set.
+  #[cfg(feature = "streaming")]
+  pub fn ab_loop_seek_target(&mut self) -> Option<u32> {
+    if self.ab_loop.is_none() && self.ab_loop_start.is_none() {
+      return None;
+    }
+    if self.ab_loop_track != self.last_track_id {
+      self.clear_ab_loop();
+      return None;
+    }
+    let (a, b) = self.ab_loop?;
+    (self.song_progress_ms >= b as u128).then_some(a)
+  }
+
+  /// Loops the native player between the A-B points (called from tick loop)
+  #[cfg(feature = "streaming")]
+  fn check_ab_loop(&mut self) {
+    if let Some(a) = self.ab_loop_seek_target() {
+      self.set_song_progress(a as u128);
+      self.execute_native_seek(a);
+    }
+  }
+
   /// Flush any pending native seek (called from tick loop)
   #[cfg(feature = "streaming")]
   pub fn flush_pending_native_seek(&mut self) {
@@ -1306,6 +2458,75 @@ impl App {
     }
   }
 
+  /// Grow or shrink the seek step in use, music's or podcasts', by five seconds,
+  /// within one second and two minutes
+  pub fn adjust_seek_step(&mut self, increase: bool) {
+    const SEEK_STEP_MS: u32 = 5_000;
+    const MIN_SEEK_MS: u32 = 1_000;
+    const MAX_SEEK_MS: u32 = 120_000;
+
+    let current = self.seek_step_ms();
+    let next = if increase {
+      current.saturating_add(SEEK_STEP_MS)
+    } else {
+      current.saturating_sub(SEEK_STEP_MS)
+    }
+    .clamp(MIN_SEEK_MS, MAX_SEEK_MS);
+
+    let is_episode = matches!(
+      self
+        .current_playback_context
+        .as_ref()
+        .and_then(|context| context.item.as_ref()),
+      Some(PlayableItem::Episode(_))
+    );
+    if is_episode {
+      self.user_config.behavior.podcast_seek_milliseconds = next;
+    } else {
+      self.user_config.behavior.seek_milliseconds = next;
+    }
+    self.last_seek_step_ms = Some(next);
+    let _ = self.user_config.save_config();
+    let step = seek_step_label(next);
+    self.set_status_message(locale::tf("status.seek_step", &[("step", &step)]), 3);
+  }
+
+  pub fn toggle_prevent_sleep(&mut self) {
+    let enabled = !self.user_config.behavior.prevent_sleep_while_playing;
+    self.user_config.behavior.prevent_sleep_while_playing = enabled;
+    let _ = self.user_config.save_config();
+    let message = if enabled {
+      "status.keep_awake_on"
+    } else {
+      "status.keep_awake_off"
+    };
+    self.set_status_message(locale::t(message), 3);
+  }
+
+  /// Switches the Discover time range, which is saved on exit. Cached top tracks
+  /// are dropped, and refetched straight away if they're on screen.
+  pub fn set_discover_time_range(&mut self, range: DiscoverTimeRange) {
+    self.discover_time_range = range;
+    self.discover_top_tracks.clear();
+    let showing_top_tracks = self.get_current_route().id == RouteId::TrackTable
+      && self.track_table.context == Some(TrackTableContext::DiscoverPlaylist)
+      && self.discover_selected_index == 1;
+    if showing_top_tracks {
+      self.dispatch(IoEvent::GetUserTopTracks(range));
+    }
+  }
+
+  pub fn cycle_discover_time_range(&mut self) {
+    self.set_discover_time_range(self.discover_time_range.next());
+    self.set_status_message(
+      locale::tf(
+        "status.discover_time_range",
+        &[("range", self.discover_time_range.label())],
+      ),
+      3,
+    );
+  }
+
   pub fn get_recommendations_for_seed(
     &mut self,
     seed_artists: Option<Vec<String>>,
@@ -1333,6 +2554,25 @@ impl App {
     ));
   }
 
+  /// Recommendations seeded from the playing track, with Liked Songs and tracks from
+  /// saved albums moved to the top
+  pub fn get_library_recommendations_for_playing(&mut self) {
+    let Some(CurrentPlaybackContext {
+      item: Some(PlayableItem::Track(track)),
+      ..
+    }) = &self.current_playback_context
+    else {
+      self.set_status_message(locale::t("status.library_recommendations_need_track"), 4);
+      return;
+    };
+    let track = track.clone();
+    let seed_tracks = track.id.as_ref().map(|id| vec![id.to_string()]);
+
+    self.recommendations_context = Some(RecommendationsContext::Library);
+    self.recommendations_seed = track.name.clone();
+    self.get_recommendations_for_seed(None, seed_tracks, Some(track));
+  }
+
   pub fn get_recommendations_for_track_id(&mut self, id: String) {
     let user_country = self.get_user_country();
     if let Ok(track_id) = TrackId::from_id(id) {
@@ -1344,70 +2584,214 @@ impl App {
   }
 
   pub fn increase_volume(&mut self) {
-    if let Some(context) = self.current_playback_context.clone() {
-      let current_volume = context.device.volume_percent.unwrap_or(0) as u8;
-      let next_volume = min(
-        current_volume + self.user_config.behavior.volume_increment,
-        100,
-      );
+    if self.current_playback_context.is_none() {
+      let volume = self.user_config.behavior.volume_percent;
+      let next_volume = min(volume + self.user_config.behavior.volume_increment, 100);
+      self.cold_start_volume(next_volume);
+      return;
+    }
 
-      if next_volume != current_volume {
-        info!("increasing volume: {} -> {}", current_volume, next_volume);
-        // Use native streaming player for instant control (bypasses event channel latency)
-        #[cfg(feature = "streaming")]
-        if self.is_native_streaming_active_for_playback() {
-          if let Some(ref player) = self.streaming_player {
-            player.set_volume(next_volume);
-
-            // Update UI state immediately
-            if let Some(ctx) = &mut self.current_playback_context {
-              ctx.device.volume_percent = Some(next_volume.into());
-            }
-            self.user_config.behavior.volume_percent = next_volume;
-            let _ = self.user_config.save_config();
-            return;
+    let current_volume = self.current_volume();
+    let next_volume = min(
+      current_volume.saturating_add(self.user_config.behavior.volume_increment),
+      self.max_volume(),
+    );
+
+    if next_volume != current_volume {
+      info!("increasing volume: {} -> {}", current_volume, next_volume);
+      // Use native streaming player for instant control (bypasses event channel latency)
+      #[cfg(feature = "streaming")]
+      if self.is_native_streaming_active_for_playback() {
+        if let Some(ref player) = self.streaming_player {
+          player.set_volume(next_volume);
+
+          // Update UI state immediately
+          if let Some(ctx) = &mut self.current_playback_context {
+            ctx.device.volume_percent = Some(next_volume.into());
+          }
+          if next_volume > 100 && current_volume <= 100 {
+            self.set_status_message(locale::t("status.volume_boost_on"), 4);
           }
+          // A boost is never the volume the next launch starts at
+          self.user_config.behavior.volume_percent = next_volume.min(100);
+          let _ = self.user_config.save_config();
+          return;
         }
-
-        // Fallback to API-based volume control for external devices
-        self.dispatch(IoEvent::ChangeVolume(next_volume));
       }
+
+      // Fallback to API-based volume control for external devices
+      self.queue_api_volume(next_volume);
     }
   }
 
   pub fn decrease_volume(&mut self) {
-    if let Some(context) = self.current_playback_context.clone() {
-      let current_volume = context.device.volume_percent.unwrap_or(0) as i8;
-      let next_volume = max(
-        current_volume - self.user_config.behavior.volume_increment as i8,
-        0,
-      );
+    if self.current_playback_context.is_none() {
+      let volume = self.user_config.behavior.volume_percent;
+      let next_volume = volume.saturating_sub(self.user_config.behavior.volume_increment);
+      self.cold_start_volume(next_volume);
+      return;
+    }
 
-      if next_volume != current_volume {
-        let next_volume_u8 = next_volume as u8;
-        info!(
-          "decreasing volume: {} -> {}",
-          current_volume, next_volume_u8
-        );
+    let current_volume = self.current_volume();
+    let next_volume = current_volume.saturating_sub(self.user_config.behavior.volume_increment);
 
-        // Use native streaming player for instant control (bypasses event channel latency)
-        #[cfg(feature = "streaming")]
-        if self.is_native_streaming_active_for_playback() {
-          if let Some(ref player) = self.streaming_player {
-            player.set_volume(next_volume_u8);
+    if next_volume != current_volume {
+      info!("decreasing volume: {} -> {}", current_volume, next_volume);
 
-            // Update UI state immediately
-            if let Some(ctx) = &mut self.current_playback_context {
-              ctx.device.volume_percent = Some(next_volume_u8.into());
-            }
-            self.user_config.behavior.volume_percent = next_volume_u8;
-            let _ = self.user_config.save_config();
-            return;
+      // Use native streaming player for instant control (bypasses event channel latency)
+      #[cfg(feature = "streaming")]
+      if self.is_native_streaming_active_for_playback() {
+        if let Some(ref player) = self.streaming_player {
+          player.set_volume(next_volume);
+
+          // Update UI state immediately
+          if let Some(ctx) = &mut self.current_playback_context {
+            ctx.device.volume_percent = Some(next_volume.into());
           }
+          self.user_config.behavior.volume_percent = next_volume.min(100);
+          let _ = self.user_config.save_config();
+          return;
         }
+      }
+
+      // Fallback to API-based volume control for external devices
+      self.queue_api_volume(next_volume);
+    }
+  }
+
+  /// The volume as of the latest change, including one still waiting to be sent
+  fn current_volume(&self) -> u8 {
+    self
+      .pending_volume
+      .or_else(|| {
+        self
+          .current_playback_context
+          .as_ref()
+          .and_then(|context| context.device.volume_percent)
+          .map(|volume| volume as u8)
+      })
+      .unwrap_or(0)
+  }
+
+  /// Take the device volume from a playback poll, so a change made on another
+  /// device shows at once and the volume keys carry on from it. Right after a
+  /// change of ours the poll may not have seen it yet, so the local volume stays.
+  pub fn sync_polled_volume(&mut self, context: &mut CurrentPlaybackContext) {
+    let local = self
+      .current_playback_context
+      .as_ref()
+      .filter(|current| current.device.id == context.device.id)
+      .and_then(|current| current.device.volume_percent);
+    if let (Some(local), true) = (
+      local,
+      self
+        .volume_dirty_until
+        .is_some_and(|until| Instant::now() < until),
+    ) {
+      context.device.volume_percent = Some(local);
+      return;
+    }
 
-        // Fallback to API-based volume control for external devices
-        self.dispatch(IoEvent::ChangeVolume(next_volume_u8));
+    self.volume_dirty_until = None;
+    if let Some(volume) = context.device.volume_percent {
+      self.user_config.behavior.volume_percent = volume.min(100) as u8;
+    }
+  }
+
+  /// Loudest the volume keys go. Past 100% needs the boost allowed and the native
+  /// player, started with it, playing.
+  fn max_volume(&self) -> u8 {
+    #[cfg(feature = "streaming")]
+    if self.user_config.behavior.allow_volume_boost
+      && self.is_native_streaming_active_for_playback()
+      && self
+        .streaming_player
+        .as_ref()
+        .is_some_and(|player| player.supports_volume_boost())
+    {
+      return crate::player::MAX_BOOSTED_VOLUME;
+    }
+    100
+  }
+
+  /// Volume keys before anything has played: set the native player directly, as
+  /// there is no device to send the change to otherwise
+  fn cold_start_volume(&mut self, volume: u8) {
+    #[cfg(feature = "streaming")]
+    let native = match self.cold_start_player() {
+      Some(player) => {
+        player.set_volume(volume);
+        self.user_config.behavior.volume_percent = volume;
+        let _ = self.user_config.save_config();
+        true
+      }
+      None => false,
+    };
+    #[cfg(not(feature = "streaming"))]
+    let native = false;
+    if native {
+      self.set_status_message(format!("Volume {}%, nothing is playing yet", volume), 3);
+      return;
+    }
+
+    self.set_status_message(
+      format!(
+        "Nothing is playing, press {} to pick a device",
+        self.user_config.keys.manage_devices
+      ),
+      4,
+    );
+  }
+
+  /// The native player, when it is up but nothing is playing anywhere yet
+  #[cfg(feature = "streaming")]
+  fn cold_start_player(&self) -> Option<Arc<crate::player::StreamingPlayer>> {
+    if self.current_playback_context.is_some() {
+      return None;
+    }
+    self
+      .streaming_player
+      .as_ref()
+      .filter(|player| player.is_connected())
+      .cloned()
+  }
+
+  /// Show the new volume right away and send at most one API call per throttle window
+  fn queue_api_volume(&mut self, volume: u8) {
+    if let Some(ctx) = &mut self.current_playback_context {
+      ctx.device.volume_percent = Some(volume.into());
+    }
+
+    const API_VOLUME_THROTTLE_MS: u128 = 200;
+    let should_send_now = self
+      .last_api_volume
+      .is_none_or(|t| t.elapsed().as_millis() >= API_VOLUME_THROTTLE_MS);
+
+    if should_send_now {
+      self.execute_api_volume(volume);
+    } else {
+      // Queue the volume - will be flushed by tick loop
+      self.pending_volume = Some(volume);
+    }
+  }
+
+  fn execute_api_volume(&mut self, volume: u8) {
+    self.pending_volume = None;
+    self.last_api_volume = Some(Instant::now());
+    self.volume_dirty_until = Some(Instant::now() + VOLUME_DIRTY_WINDOW);
+    self.dispatch(IoEvent::ChangeVolume(volume));
+  }
+
+  /// Flush any pending API volume change (called from tick loop)
+  pub fn flush_pending_volume(&mut self) {
+    if let Some(volume) = self.pending_volume {
+      const API_VOLUME_THROTTLE_MS: u128 = 200;
+      let should_flush = self
+        .last_api_volume
+        .is_none_or(|t| t.elapsed().as_millis() >= API_VOLUME_THROTTLE_MS);
+
+      if should_flush {
+        self.execute_api_volume(volume);
       }
     }
   }
@@ -1418,6 +2802,27 @@ impl App {
     self.api_error = e.to_string();
   }
 
+  /// Whether the signed-in account has Premium, once the profile has loaded
+  pub fn account_is_premium(&self) -> Option<bool> {
+    self
+      .user
+      .as_ref()
+      .and_then(|user| user.product)
+      .map(|product| product == SubscriptionLevel::Premium)
+  }
+
+  /// Like `handle_error`, but a Premium restriction only shows a status message.
+  /// `api_error` is still set so the CLI reports it and exits with an error.
+  pub fn handle_playback_error(&mut self, e: anyhow::Error) {
+    if !is_premium_required_error(&e, self.account_is_premium()) {
+      self.handle_error(e);
+      return;
+    }
+    info!("playback refused, premium required: {}", e);
+    self.api_error = PREMIUM_REQUIRED_MESSAGE.to_string();
+    self.set_status_message(PREMIUM_REQUIRED_MESSAGE, 6);
+  }
+
   /// Check if native streaming is the active playback device
   /// Returns true only if the player is connected AND it's the currently active device
   #[cfg(feature = "streaming")]
@@ -1466,6 +2871,22 @@ impl App {
   }
 
   pub fn toggle_playback(&mut self) {
+    // A preview clip cannot be paused, only stopped
+    #[cfg(feature = "preview")]
+    if self.preview.is_some() {
+      self.dispatch(IoEvent::StopPreview);
+      return;
+    }
+
+    // Nothing has played yet, so there is nothing to resume: start something on
+    // the native player instead
+    #[cfg(feature = "streaming")]
+    if self.cold_start_player().is_some() {
+      self.set_status_message("Nothing was playing, starting your last context", 4);
+      self.dispatch(IoEvent::StartColdPlayback);
+      return;
+    }
+
     // Use native streaming player for instant control (bypasses event channel latency)
     #[cfg(feature = "streaming")]
     if self.is_native_streaming_active_for_playback() {
@@ -1478,15 +2899,37 @@ impl App {
           "toggling playback: {}",
           if is_playing { "paused" } else { "playing" }
         );
-        if is_playing {
+        let fade_ms = self.user_config.behavior.fade_ms;
+        if fade_ms > 0 {
+          let fade = Duration::from_millis(fade_ms);
+          let max_volume = self.max_volume();
+          let volume = self
+            .current_playback_context
+            .as_ref()
+            .and_then(|c| c.device.volume_percent)
+            .map(|v| v.min(u32::from(max_volume)) as u8)
+            .unwrap_or_else(|| player.get_volume());
+          let player = Arc::clone(player);
+          tokio::spawn(async move {
+            if is_playing {
+              player.pause_with_fade(volume, fade).await;
+            } else {
+              player.play_with_fade(volume, fade).await;
+            }
+          });
+        } else if is_playing {
           player.pause();
+        } else {
+          player.play();
+        }
+
+        if is_playing {
           // Update UI state immediately
           if let Some(ctx) = &mut self.current_playback_context {
             ctx.is_playing = false;
           }
           self.native_is_playing = Some(false);
         } else {
-          player.play();
           // Update UI state immediately
           if let Some(ctx) = &mut self.current_playback_context {
             ctx.is_playing = true;
@@ -1521,20 +2964,27 @@ impl App {
 
   pub fn previous_track(&mut self) {
     info!("playing previous track or restarting current track");
-    if self.song_progress_ms >= 3_000 {
+    const DOUBLE_PRESS_WINDOW: Duration = Duration::from_secs(2);
+    let now = Instant::now();
+    let double_press = self
+      .last_previous_press
+      .is_some_and(|t| now.duration_since(t) < DOUBLE_PRESS_WINDOW);
+    self.last_previous_press = Some(now);
+
+    if self.song_progress_ms >= 3_000 && !double_press {
       // If more than 3 seconds into the song, restart from beginning
       #[cfg(feature = "streaming")]
       if self.is_native_streaming_active_for_playback() {
         if let Some(ref player) = self.streaming_player {
           player.seek(0);
-          self.song_progress_ms = 0;
+          self.set_song_progress(0);
           self.seek_ms = None;
           return;
         }
       }
 
-      // Fallback for external devices
-      self.dispatch(IoEvent::Seek(0));
+      // Fallback for external devices, throttled so the next poll can't undo the restart
+      self.queue_api_seek(0);
     } else {
       // If less than 3 seconds in, go to previous track
       #[cfg(feature = "streaming")]
@@ -1542,8 +2992,6 @@ impl App {
         if let Some(ref player) = self.streaming_player {
           player.activate();
           player.prev();
-          // Reset progress immediately for UI feedback
-          self.song_progress_ms = 0;
           // librespot can occasionally land in a paused state after a skip.
           // Schedule a short delayed resume to avoid racing the track transition.
           let player = std::sync::Arc::clone(player);
@@ -1552,6 +3000,8 @@ impl App {
             player.activate();
             player.play();
           });
+          // Reset progress immediately for UI feedback
+          self.set_song_progress(0);
           return;
         }
       }
@@ -1563,14 +3013,13 @@ impl App {
 
   pub fn next_track(&mut self) {
     info!("skipping to next track");
+    self.record_skip_stat();
     // Use native streaming player for instant control (bypasses event channel latency)
     #[cfg(feature = "streaming")]
     if self.is_native_streaming_active_for_playback() {
       if let Some(ref player) = self.streaming_player {
         player.activate();
         player.next();
-        // Reset progress immediately for UI feedback
-        self.song_progress_ms = 0;
         // librespot can occasionally land in a paused state after a skip.
         // Schedule a short delayed resume to avoid racing the track transition.
         let player = std::sync::Arc::clone(player);
@@ -1579,6 +3028,8 @@ impl App {
           player.activate();
           player.play();
         });
+        // Reset progress immediately for UI feedback
+        self.set_song_progress(0);
         return;
       }
     }
@@ -1587,6 +3038,36 @@ impl App {
     self.dispatch(IoEvent::NextTrack);
   }
 
+  /// Play `item` now by queueing it and skipping to it, so the rest of the queue
+  /// and the context stay as they are
+  pub fn queue_and_skip(&mut self, item: PlayableId<'static>) {
+    self.record_skip_stat();
+    self.set_status_message(locale::t("status.queue_and_skip_pending"), 3);
+    self.dispatch(IoEvent::QueueAndSkip(item));
+  }
+
+  /// Log the playing track as skipped or finished, when skip stats are on
+  fn record_skip_stat(&self) {
+    let Some(path) = &self.user_config.behavior.log_skip_stats else {
+      return;
+    };
+    let Some(PlayableItem::Track(track)) = self
+      .current_playback_context
+      .as_ref()
+      .and_then(|context| context.item.as_ref())
+    else {
+      return;
+    };
+    if let Some(id) = &track.id {
+      skip_stats::record(
+        path,
+        &id.uri(),
+        skip_stats::Outcome::for_skip_at(self.song_progress_ms),
+        self.song_progress_ms,
+      );
+    }
+  }
+
   // The navigation_stack actually only controls the large block to the right of `library` and
   // `playlists`
   pub fn push_navigation_stack(&mut self, next_route_id: RouteId, next_active_block: ActiveBlock) {
@@ -1603,6 +3084,7 @@ impl App {
         hovered_block: next_active_block,
       });
     }
+    self.close_playlist_filter_if_unfocused();
   }
 
   pub fn pop_navigation_stack(&mut self) -> Option<Route> {
@@ -1610,7 +3092,9 @@ impl App {
     if self.navigation_stack.len() == 1 {
       None
     } else {
-      self.navigation_stack.pop()
+      let route = self.navigation_stack.pop();
+      self.close_playlist_filter_if_unfocused();
+      route
     }
   }
 
@@ -1635,6 +3119,7 @@ impl App {
     if let Some(hovered_block) = hovered_block {
       current_route.hovered_block = hovered_block;
     }
+    self.close_playlist_filter_if_unfocused();
   }
 
   pub fn copy_song_url(&mut self) {
@@ -1675,6 +3160,94 @@ impl App {
     }
   }
 
+  /// Move playback between the native spotatui device and the last external one
+  pub fn toggle_native_playback(&mut self) {
+    #[cfg(feature = "streaming")]
+    {
+      let Some(native_name) = self
+        .streaming_player
+        .as_ref()
+        .map(|player| player.device_name().to_string())
+      else {
+        self.set_status_message("Native playback is not running", 4);
+        return;
+      };
+
+      if !self.is_streaming_active {
+        match self.native_device_id.clone() {
+          Some(id) => self.dispatch(IoEvent::TransferPlaybackToDevice(id, true)),
+          None => self.dispatch(IoEvent::AutoSelectStreamingDevice(
+            native_name.clone(),
+            true,
+          )),
+        }
+        self.set_status_message(format!("Switching playback to {}", native_name), 4);
+        return;
+      }
+    }
+
+    match self.last_external_device.clone() {
+      Some((id, name)) => {
+        self.dispatch(IoEvent::TransferPlaybackToDevice(id, true));
+        self.set_status_message(format!("Switching playback to {}", name), 4);
+      }
+      None => self.set_status_message(
+        "No other device used yet, pick one from the device list first",
+        5,
+      ),
+    }
+  }
+
+  /// The link to what's playing with an "@m:ss" note for the playback position
+  pub fn playing_url_at_position(&self) -> Option<String> {
+    let url = match &self.current_playback_context {
+      Some(CurrentPlaybackContext {
+        item: Some(PlayableItem::Track(track)),
+        ..
+      }) => track
+        .id
+        .as_ref()
+        .map(|id| format!("https://open.spotify.com/track/{}", id.id())),
+      Some(CurrentPlaybackContext {
+        item: Some(PlayableItem::Episode(episode)),
+        ..
+      }) => Some(format!(
+        "https://open.spotify.com/episode/{}",
+        episode.id.id()
+      )),
+      _ => None,
+    }?;
+    Some(timestamped_link(&url, self.song_progress_ms))
+  }
+
+  /// Copy the current song/episode URL with an "@m:ss" note for the playback position.
+  pub fn copy_song_url_at_position(&mut self) {
+    info!("copying song url with playback position to clipboard");
+    let Some(link) = self.playing_url_at_position() else {
+      self.set_status_message("No track currently playing", 4);
+      return;
+    };
+
+    let position = millis_to_minutes(self.song_progress_ms);
+    let clipboard = match &mut self.clipboard {
+      Some(ctx) => ctx,
+      None => return,
+    };
+    if let Err(e) = clipboard.set_text(link) {
+      self.handle_error(anyhow!("failed to set clipboard content: {}", e));
+      return;
+    }
+    // The position is only a note beside the link, which still opens the item from
+    // the start, so make sure nobody expects otherwise
+    self.set_status_message(
+      format!(
+        "Copied link with an @{} note (the link itself opens from the start)",
+        position
+      ),
+      5,
+    );
+  }
+
   pub fn copy_album_url(&mut self) {
     info!("copying album url to clipboard");
     let clipboard = match &mut self.clipboard {
@@ -1797,7 +3370,25 @@ impl App {
   }
 
   pub fn shuffle(&mut self) {
-    if let Some(context) = &self.current_playback_context.clone() {
+    // With nothing playing, flip the saved preference that the next playback starts with
+    if self.current_playback_context.is_none() {
+      let new_shuffle_state = !self.user_config.behavior.shuffle_enabled;
+      #[cfg(feature = "streaming")]
+      if let Some(player) = self.cold_start_player() {
+        let _ = player.set_shuffle(new_shuffle_state);
+      }
+      self.set_shuffle_state(new_shuffle_state);
+      self.set_status_message(
+        format!(
+          "Shuffle {} for the next playback",
+          if new_shuffle_state { "on" } else { "off" }
+        ),
+        3,
+      );
+      return;
+    }
+
+    if let Some(context) = &self.current_playback_context {
       let new_shuffle_state = !context.shuffle_state;
       info!("toggling shuffle: {}", new_shuffle_state);
 
@@ -1807,28 +3398,59 @@ impl App {
         if let Some(ref player) = self.streaming_player {
           // Try to set shuffle on the native player
           let _ = player.set_shuffle(new_shuffle_state);
-
-          // Update UI state immediately
-          if let Some(ctx) = &mut self.current_playback_context {
-            ctx.shuffle_state = new_shuffle_state;
-          }
-          self.user_config.behavior.shuffle_enabled = new_shuffle_state;
-          let _ = self.user_config.save_config();
-
-          // Notify MPRIS clients of the change
-          #[cfg(all(feature = "mpris", target_os = "linux"))]
-          if let Some(ref mpris) = self.mpris_manager {
-            mpris.set_shuffle(new_shuffle_state);
-          }
+          self.set_shuffle_state(new_shuffle_state);
           return;
         }
       }
 
       // Fallback to API-based shuffle for external devices
+      self.set_shuffle_state(new_shuffle_state);
       self.dispatch(IoEvent::Shuffle(new_shuffle_state));
+      self.confirm_playback_soon();
     };
   }
 
+  /// Single place that records the shuffle state: updates the playbar, the saved
+  /// preference and MPRIS clients
+  pub fn set_shuffle_state(&mut self, shuffle_state: bool) {
+    if let Some(ctx) = &mut self.current_playback_context {
+      ctx.shuffle_state = shuffle_state;
+    }
+    if self.user_config.behavior.shuffle_enabled != shuffle_state {
+      self.user_config.behavior.shuffle_enabled = shuffle_state;
+      let _ = self.user_config.save_config();
+    }
+
+    #[cfg(all(feature = "mpris", target_os = "linux"))]
+    if let Some(ref mpris) = self.mpris_manager {
+      mpris.set_shuffle(shuffle_state);
+    }
+  }
+
+  /// Single place that records the repeat state: updates the playbar and MPRIS clients
+  pub fn set_repeat_state(&mut self, repeat_state: RepeatState) {
+    if let Some(ctx) = &mut self.current_playback_context {
+      ctx.repeat_state = repeat_state;
+    }
+
+    #[cfg(all(feature = "mpris", target_os = "linux"))]
+    if let Some(ref mpris) = self.mpris_manager {
+      use crate::mpris::LoopStatusEvent;
+      let loop_status = match repeat_state {
+        RepeatState::Off => LoopStatusEvent::None,
+        RepeatState::Context => LoopStatusEvent::Playlist,
+        RepeatState::Track => LoopStatusEvent::Track,
+      };
+      mpris.set_loop_status(loop_status);
+    }
+  }
+
+  /// Re-fetch playback about a second from now, to confirm what an external device
+  /// actually did with a shuffle/repeat request
+  pub fn confirm_playback_soon(&mut self) {
+    self.playback_confirm_at = Some(Instant::now() + Duration::from_secs(1));
+  }
+
   pub fn get_current_user_saved_albums_next(&mut self) {
     match self
       .library
@@ -1954,6 +3576,23 @@ impl App {
     }
   }
 
+  /// Episodes after `finished_id` (a bare ID or URI) in the open show's episode list.
+  /// `None` if it isn't one of them; empty if it was the last one.
+  pub fn show_episodes_after(&self, finished_id: &str) -> Option<Vec<PlayableId<'static>>> {
+    let finished_id = finished_id.rsplit(':').next().unwrap_or(finished_id);
+    let episodes = self.library.show_episodes.get_results(None)?;
+    let position = episodes
+      .items
+      .iter()
+      .position(|episode| episode.id.id() == finished_id)?;
+    Some(
+      episodes.items[position + 1..]
+        .iter()
+        .map(|episode| PlayableId::Episode(episode.id.clone().into_static()))
+        .collect(),
+    )
+  }
+
   pub fn get_episode_table_previous(&mut self) {
     if self.library.show_episodes.index > 0 {
       self.library.show_episodes.index -= 1;
@@ -2062,6 +3701,91 @@ impl App {
     }
   }
 
+  fn selected_owned_playlist(&mut self) -> Option<SimplifiedPlaylist> {
+    let selected_index = self.selected_playlist_index?;
+    let playlist = match self.get_playlist_display_item_at(selected_index) {
+      Some(PlaylistFolderItem::Playlist { index, .. }) => self.all_playlists.get(*index).cloned(),
+      _ => None,
+    }?;
+    let owned = self
+      .user
+      .as_ref()
+      .is_some_and(|user| user.id == playlist.owner.id);
+    if !owned {
+      self.set_status_message("You can only change playlists you own", 4);
+      return None;
+    }
+    Some(playlist)
+  }
+
+  pub fn toggle_selected_playlist_public(&mut self) {
+    if let Some(playlist) = self.selected_owned_playlist() {
+      let (public, collaborative) =
+        toggled_playlist_public(playlist.public, playlist.collaborative);
+      self.dispatch(IoEvent::ChangePlaylistVisibility(
+        playlist.id.into_static(),
+        public,
+        collaborative,
+      ));
+    }
+  }
+
+  pub fn view_selected_playlist_owner(&mut self) {
+    let owner = self
+      .selected_playlist_index
+      .and_then(
+        |selected_index| match self.get_playlist_display_item_at(selected_index) {
+          Some(PlaylistFolderItem::Playlist { index, .. }) => self.all_playlists.get(*index),
+          _ => None,
+        },
+      )
+      .map(|playlist| playlist.owner.clone());
+    if let Some(owner) = owner {
+      self.view_playlist_owner(owner);
+    }
+  }
+
+  /// List the public playlists of a playlist owner in the search results view
+  pub fn view_playlist_owner(&mut self, owner: PublicUser) {
+    let user_id = owner.id.id().to_string();
+    let name = owner.display_name.unwrap_or_else(|| user_id.clone());
+
+    // Spotify's editorial account owns thousands of playlists and the API won't list
+    // them, so point at the profile instead
+    if user_id == SPOTIFY_USER_ID {
+      let url = user_profile_url(&user_id);
+      let copied = self
+        .clipboard
+        .as_mut()
+        .is_some_and(|clipboard| clipboard.set_text(url.clone()).is_ok());
+      let message = if copied {
+        format!("Curated by Spotify, copied {}", url)
+      } else {
+        format!("Curated by Spotify: {}", url)
+      };
+      self.set_status_message(message, 6);
+      return;
+    }
+
+    self.dispatch(IoEvent::GetUserPlaylists(
+      owner.id.into_static(),
+      name.clone(),
+    ));
+    self.set_status_message(format!("Loading playlists by {}", name), 4);
+  }
+
+  pub fn toggle_selected_playlist_collaborative(&mut self) {
+    if let Some(playlist) = self.selected_owned_playlist() {
+      let (public, collaborative) =
+        toggled_playlist_collaborative(playlist.public, playlist.collaborative);
+      self.dispatch(IoEvent::ChangePlaylistVisibility(
+        playlist.id.into_static(),
+        public,
+        collaborative,
+      ));
+    }
+  }
+
   pub fn user_unfollow_playlist_search_result(&mut self) {
     info!("unfollowing playlist from search results");
     if let (Some(playlists), Some(selected_index), Some(user)) = (
@@ -2158,48 +3882,69 @@ impl App {
   }
 
   pub fn repeat(&mut self) {
-    if let Some(context) = &self.current_playback_context.clone() {
-      let current_repeat_state = context.repeat_state;
-      info!("toggling repeat mode: {:?}", current_repeat_state);
-
-      // Use native streaming player for instant control (bypasses event channel latency)
-      #[cfg(feature = "streaming")]
-      if self.is_native_streaming_active_for_playback() {
-        if let Some(ref player) = self.streaming_player {
-          use rspotify::model::enums::RepeatState;
+    if let Some(context) = &self.current_playback_context {
+      let next_repeat_state = next_repeat_state(context.repeat_state);
+      info!("toggling repeat mode: {:?}", next_repeat_state);
+      self.request_repeat_state(next_repeat_state);
+    }
+  }
 
-          // Try to set repeat on the native player (pass current state, not next)
-          let _ = player.set_repeat(current_repeat_state);
+  /// Switch between repeating the track and the context, never turning repeat off.
+  /// The mode picked is remembered for when repeat is off.
+  pub fn toggle_repeat_one_or_all(&mut self) {
+    if let Some(context) = &self.current_playback_context {
+      let target = toggled_repeat_state(
+        context.repeat_state,
+        self.user_config.behavior.preferred_repeat,
+      );
+      info!("switching repeat mode to {:?}", target);
+      self.request_repeat_state(target);
+      if self.user_config.behavior.preferred_repeat != target {
+        self.user_config.behavior.preferred_repeat = target;
+        let _ = self.user_config.save_config();
+      }
+    }
+  }
 
-          // Calculate next state for UI update
-          let next_repeat_state = match current_repeat_state {
-            RepeatState::Off => RepeatState::Context,
-            RepeatState::Context => RepeatState::Track,
-            RepeatState::Track => RepeatState::Off,
-          };
+  fn request_repeat_state(&mut self, repeat_state: RepeatState) {
+    // Use native streaming player for instant control (bypasses event channel latency)
+    #[cfg(feature = "streaming")]
+    if self.is_native_streaming_active_for_playback() {
+      if let Some(ref player) = self.streaming_player {
+        let _ = player.set_repeat_mode(repeat_state);
+        self.set_repeat_state(repeat_state);
+        return;
+      }
+    }
 
-          // Update UI state immediately
-          if let Some(ctx) = &mut self.current_playback_context {
-            ctx.repeat_state = next_repeat_state;
-          }
+    // Fallback to API-based repeat for external devices
+    self.set_repeat_state(repeat_state);
+    self.dispatch(IoEvent::Repeat(repeat_state));
+    self.confirm_playback_soon();
+  }
 
-          // Notify MPRIS clients of the change
-          #[cfg(all(feature = "mpris", target_os = "linux"))]
-          if let Some(ref mpris) = self.mpris_manager {
-            use crate::mpris::LoopStatusEvent;
-            let loop_status = match next_repeat_state {
-              RepeatState::Off => LoopStatusEvent::None,
-              RepeatState::Context => LoopStatusEvent::Playlist,
-              RepeatState::Track => LoopStatusEvent::Track,
-            };
-            mpris.set_loop_status(loop_status);
-          }
-          return;
+  /// Open the artist of a track, asking which one first when there are several
+  pub fn open_track_artist(&mut self, artists: &[SimplifiedArtist]) {
+    let artists: Vec<SimplifiedArtist> = artists
+      .iter()
+      .filter(|artist| artist.id.is_some())
+      .cloned()
+      .collect();
+    match artists.as_slice() {
+      [] => self.set_status_message("This track has no artist to open", 3),
+      [artist] => {
+        if let Some(artist_id) = &artist.id {
+          self.get_artist(artist_id.clone().into_static(), artist.name.clone());
         }
       }
-
-      // Fallback to API-based repeat for external devices
-      self.dispatch(IoEvent::Repeat(current_repeat_state));
+      _ => {
+        self.artist_picker = artists;
+        self.artist_picker_selected_index = 0;
+        self.push_navigation_stack(
+          RouteId::Dialog,
+          ActiveBlock::Dialog(DialogContext::ArtistPicker),
+        );
+      }
     }
   }
 
@@ -2230,6 +3975,84 @@ impl App {
 
   /// Load settings for the current category into settings_items
   pub fn load_settings_for_category(&mut self) {
+    self.settings_items = Self::settings_items_for(&self.user_config, self.settings_category);
+    // The English text in `settings_items_for` is the fallback for each translated
+    // name and description
+    for item in &mut self.settings_items {
+      if let Some(name) = locale::translation(&format!("settings.{}.name", item.id)) {
+        item.name = name;
+      }
+      if let Some(description) = locale::translation(&format!("settings.{}.description", item.id)) {
+        item.description = description;
+      }
+    }
+    self.settings_selected_index = 0;
+    self.settings_filter = None;
+    self.settings_saved_items = self.settings_items.clone();
+    self.settings_default_items =
+      Self::settings_items_for(&UserConfig::new(), self.settings_category);
+    self.settings_unsaved_prompt_visible = false;
+    self.settings_unsaved_prompt_save_selected = true;
+    self.settings_reset_prompt_visible = false;
+  }
+
+  /// The compiled-in value of the setting `id`
+  pub fn default_setting_value(&self, id: &str) -> Option<&SettingValue> {
+    self
+      .settings_default_items
+      .iter()
+      .find(|setting| setting.id == id)
+      .map(|setting| &setting.value)
+  }
+
+  /// Restores the setting at `index` to its default and applies it right away.
+  /// Saving writes it to the config file. Returns whether anything changed.
+  pub fn reset_setting(&mut self, index: usize) -> bool {
+    let Some(setting) = self.settings_items.get(index) else {
+      return false;
+    };
+    let Some(default) = self.default_setting_value(&setting.id).cloned() else {
+      return false;
+    };
+    if setting.value == default {
+      return false;
+    }
+    self.settings_items[index].value = default;
+    self.apply_settings_changes();
+    true
+  }
+
+  /// Restores every setting in the current category to its default
+  pub fn reset_settings_category(&mut self) -> usize {
+    let reset = (0..self.settings_items.len())
+      .filter(|&i| self.reset_setting(i))
+      .count();
+    self.settings_reset_prompt_visible = false;
+    reset
+  }
+
+  /// Indices into `settings_items` that match the settings filter, in order
+  pub fn visible_settings_indices(&self) -> Vec<usize> {
+    let query = self
+      .settings_filter
+      .as_ref()
+      .map(|query| query.trim().to_lowercase())
+      .unwrap_or_default();
+    self
+      .settings_items
+      .iter()
+      .enumerate()
+      .filter(|(_, setting)| {
+        query.is_empty()
+          || [&setting.name, &setting.id, &setting.description]
+            .iter()
+            .any(|text| text.to_lowercase().contains(&query))
+      })
+      .map(|(i, _)| i)
+      .collect()
+  }
+
+  fn settings_items_for(config: &UserConfig, category: SettingsCategory) -> Vec<SettingItem> {
     use crate::event::Key;
 
     // Helper to convert Key to displayable string
@@ -2252,266 +4075,309 @@ impl App {
       }
     }
 
-    self.settings_items = match self.settings_category {
+    match category {
+      SettingsCategory::All => SettingsCategory::all()
+        .iter()
+        .filter(|category| **category != SettingsCategory::All)
+        .flat_map(|category| Self::settings_items_for(config, *category))
+        .collect(),
       SettingsCategory::Behavior => vec![
         SettingItem {
           id: "behavior.seek_milliseconds".to_string(),
           name: "Seek Duration (ms)".to_string(),
-          description: "Milliseconds to skip when seeking".to_string(),
-          value: SettingValue::Number(self.user_config.behavior.seek_milliseconds as i64),
+          description: "Milliseconds to skip when seeking in a song".to_string(),
+          value: SettingValue::Number(config.behavior.seek_milliseconds as i64),
+        },
+        SettingItem {
+          id: "behavior.podcast_seek_milliseconds".to_string(),
+          name: "Podcast Seek Duration (ms)".to_string(),
+          description: "Milliseconds to skip when seeking in an episode".to_string(),
+          value: SettingValue::Number(config.behavior.podcast_seek_milliseconds as i64),
         },
         SettingItem {
           id: "behavior.volume_increment".to_string(),
           name: "Volume Increment".to_string(),
           description: "Volume change per keypress (0-100)".to_string(),
-          value: SettingValue::Number(self.user_config.behavior.volume_increment as i64),
+          value: SettingValue::Number(config.behavior.volume_increment as i64),
         },
         SettingItem {
           id: "behavior.tick_rate_milliseconds".to_string(),
           name: "Tick Rate (ms)".to_string(),
           description: "UI refresh rate in milliseconds".to_string(),
-          value: SettingValue::Number(self.user_config.behavior.tick_rate_milliseconds as i64),
+          value: SettingValue::Number(config.behavior.tick_rate_milliseconds as i64),
         },
         SettingItem {
           id: "behavior.enable_text_emphasis".to_string(),
           name: "Text Emphasis".to_string(),
           description: "Enable bold/italic text styling".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.enable_text_emphasis),
+          value: SettingValue::Bool(config.behavior.enable_text_emphasis),
         },
         SettingItem {
           id: "behavior.show_loading_indicator".to_string(),
           name: "Loading Indicator".to_string(),
           description: "Show loading status in UI".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.show_loading_indicator),
+          value: SettingValue::Bool(config.behavior.show_loading_indicator),
+        },
+        SettingItem {
+          id: "behavior.enforce_wide_search_bar".to_string(),
+          name: "Wide Search Bar".to_string(),
+          description: "Force search bar to take full width".to_string(),
+          value: SettingValue::Bool(config.behavior.enforce_wide_search_bar),
+        },
+        SettingItem {
+          id: "behavior.wide_layout_min_width".to_string(),
+          name: "Wide Layout Min Width".to_string(),
+          description: "Terminal width from which search and library move into the sidebar"
+            .to_string(),
+          value: SettingValue::Number(config.behavior.wide_layout_min_width as i64),
         },
         SettingItem {
-          id: "behavior.enforce_wide_search_bar".to_string(),
-          name: "Wide Search Bar".to_string(),
-          description: "Force search bar to take full width".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.enforce_wide_search_bar),
+          id: "behavior.enable_mouse".to_string(),
+          name: "Mouse Support".to_string(),
+          description: "Clicks and scrolling, off for terminal text selection (restart to apply)"
+            .to_string(),
+          value: SettingValue::Bool(config.behavior.enable_mouse),
         },
         SettingItem {
-          id: "behavior.set_window_title".to_string(),
-          name: "Set Window Title".to_string(),
-          description: "Update terminal window title with track info".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.set_window_title),
+          id: "behavior.home_scroll_step".to_string(),
+          name: "Home Scroll Step".to_string(),
+          description: "Changelog lines moved per up/down press".to_string(),
+          value: SettingValue::Number(config.behavior.home_scroll_step as i64),
         },
         SettingItem {
-          id: "behavior.enable_discord_rpc".to_string(),
-          name: "Discord Rich Presence".to_string(),
-          description: "Show your current track in Discord".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.enable_discord_rpc),
+          id: "behavior.mouse_scroll_lines".to_string(),
+          name: "Mouse Scroll Lines".to_string(),
+          description: "Rows moved per mouse wheel step in lists and tables".to_string(),
+          value: SettingValue::Number(config.behavior.mouse_scroll_lines as i64),
         },
         SettingItem {
-          id: "behavior.enable_announcements".to_string(),
-          name: "Remote Announcements".to_string(),
-          description: "Show one-time announcements from remote JSON feed".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.enable_announcements),
+          id: "behavior.home_auto_scroll".to_string(),
+          name: "Home Auto-Scroll".to_string(),
+          description: "Slowly scroll the changelog on the Home screen".to_string(),
+          value: SettingValue::Bool(config.behavior.home_auto_scroll),
         },
         SettingItem {
-          id: "behavior.announcement_feed_url".to_string(),
-          name: "Announcements Feed URL".to_string(),
-          description: "Remote JSON feed URL (HTTPS)".to_string(),
-          value: SettingValue::String(
-            self
-              .user_config
-              .behavior
-              .announcement_feed_url
-              .clone()
-              .unwrap_or_default(),
-          ),
+          id: "behavior.home_auto_scroll_interval_ms".to_string(),
+          name: "Home Auto-Scroll Interval (ms)".to_string(),
+          description: "Milliseconds between changelog auto-scroll steps".to_string(),
+          value: SettingValue::Number(config.behavior.home_auto_scroll_interval_ms as i64),
         },
         SettingItem {
-          id: "behavior.liked_icon".to_string(),
-          name: "Liked Icon".to_string(),
-          description: "Icon for liked songs".to_string(),
-          value: SettingValue::String(self.user_config.behavior.liked_icon.clone()),
+          id: "behavior.liked_play_order".to_string(),
+          name: "Liked Songs Play Order".to_string(),
+          description: "added_newest, added_oldest, alphabetical or shuffle".to_string(),
+          value: SettingValue::String(config.behavior.liked_play_order.name().to_string()),
         },
         SettingItem {
-          id: "behavior.shuffle_icon".to_string(),
-          name: "Shuffle Icon".to_string(),
-          description: "Icon for shuffle mode".to_string(),
-          value: SettingValue::String(self.user_config.behavior.shuffle_icon.clone()),
+          id: "behavior.fair_shuffle".to_string(),
+          name: "Fair Shuffle".to_string(),
+          description: "Avoid back-to-back artists when shuffling (native streaming)".to_string(),
+          value: SettingValue::Bool(config.behavior.fair_shuffle),
         },
         SettingItem {
-          id: "behavior.playing_icon".to_string(),
-          name: "Playing Icon".to_string(),
-          description: "Icon for playing state".to_string(),
-          value: SettingValue::String(self.user_config.behavior.playing_icon.clone()),
+          id: "behavior.fade_ms".to_string(),
+          name: "Pause/Play Fade (ms)".to_string(),
+          description: "Fade the volume when pausing or resuming (native streaming, 0 = off)"
+            .to_string(),
+          value: SettingValue::Number(config.behavior.fade_ms as i64),
         },
         SettingItem {
-          id: "behavior.paused_icon".to_string(),
-          name: "Paused Icon".to_string(),
-          description: "Icon for paused state".to_string(),
-          value: SettingValue::String(self.user_config.behavior.paused_icon.clone()),
+          id: "behavior.show_queue_length".to_string(),
+          name: "Show Queue Length".to_string(),
+          description: "Show how many items are coming up in the playbar".to_string(),
+          value: SettingValue::Bool(config.behavior.show_queue_length),
         },
-        #[cfg(feature = "cover-art")]
         SettingItem {
-          id: "behavior.draw_cover_art".to_string(),
-          name: "Draw Cover Art".to_string(),
-          description: "Enable rendering song/episode cover art".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.draw_cover_art),
+          id: "behavior.show_key_hints".to_string(),
+          name: "Show Key Hints".to_string(),
+          description: "Show the main keys for the focused view below the playbar".to_string(),
+          value: SettingValue::Bool(config.behavior.show_key_hints),
         },
-        #[cfg(feature = "cover-art")]
         SettingItem {
-          id: "behavior.draw_cover_art_forced".to_string(),
-          name: "Force Draw Cover Art".to_string(),
-          description: "Force rendering of cover art despite terminal support".to_string(),
-          value: SettingValue::Bool(self.user_config.behavior.draw_cover_art_forced),
+          id: "behavior.default_playlist_public".to_string(),
+          name: "New Playlists Public".to_string(),
+          description: "Start the new playlist prompt on public instead of private".to_string(),
+          value: SettingValue::Bool(config.behavior.default_playlist_public),
         },
-      ],
-      SettingsCategory::Keybindings => vec![
         SettingItem {
-          id: "keys.back".to_string(),
-          name: "Back".to_string(),
-          description: "Go back / quit".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.back)),
+          id: "behavior.playlist_auto_refresh_secs".to_string(),
+          name: "Playlist Auto-Refresh".to_string(),
+          description: format!(
+            "Seconds between refetching playlists edited elsewhere, at least {} (0 = off)",
+            MIN_PLAYLIST_AUTO_REFRESH_SECS
+          ),
+          value: SettingValue::Number(config.behavior.playlist_auto_refresh_secs as i64),
         },
         SettingItem {
-          id: "keys.next_page".to_string(),
-          name: "Next Page".to_string(),
-          description: "Navigate to next page".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.next_page)),
+          id: "behavior.pause_on_exit".to_string(),
+          name: "Pause On Exit".to_string(),
+          description: "Pause playback when you quit spotatui".to_string(),
+          value: SettingValue::Bool(config.behavior.pause_on_exit),
         },
         SettingItem {
-          id: "keys.previous_page".to_string(),
-          name: "Previous Page".to_string(),
-          description: "Navigate to previous page".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.previous_page)),
+          id: "behavior.warn_duplicate_add".to_string(),
+          name: "Warn On Duplicate Add".to_string(),
+          description: "Ask before adding a track that is already in the playlist".to_string(),
+          value: SettingValue::Bool(config.behavior.warn_duplicate_add),
         },
         SettingItem {
-          id: "keys.toggle_playback".to_string(),
-          name: "Toggle Playback".to_string(),
-          description: "Play/pause".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.toggle_playback)),
+          id: "behavior.show_track_badges".to_string(),
+          name: "Show Track Badges".to_string(),
+          description: "Mark explicit tracks and local files in track lists".to_string(),
+          value: SettingValue::Bool(config.behavior.show_track_badges),
         },
         SettingItem {
-          id: "keys.seek_backwards".to_string(),
-          name: "Seek Backwards".to_string(),
-          description: "Seek backwards in track".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.seek_backwards)),
+          id: "behavior.podcast_autoplay_next".to_string(),
+          name: "Podcast Autoplay Next".to_string(),
+          description: "Continue with the next episode in the open show's list when one ends"
+            .to_string(),
+          value: SettingValue::Bool(config.behavior.podcast_autoplay_next),
         },
         SettingItem {
-          id: "keys.seek_forwards".to_string(),
-          name: "Seek Forwards".to_string(),
-          description: "Seek forwards in track".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.seek_forwards)),
+          id: "behavior.preview_mode".to_string(),
+          name: "Preview Mode".to_string(),
+          description: "Play 30-second previews instead of full tracks".to_string(),
+          value: SettingValue::Bool(config.behavior.preview_mode),
         },
         SettingItem {
-          id: "keys.next_track".to_string(),
-          name: "Next Track".to_string(),
-          description: "Skip to next track".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.next_track)),
+          id: "behavior.prevent_sleep_while_playing".to_string(),
+          name: "Prevent Sleep While Playing".to_string(),
+          description: "Keep the system awake while music plays".to_string(),
+          value: SettingValue::Bool(config.behavior.prevent_sleep_while_playing),
         },
         SettingItem {
-          id: "keys.previous_track".to_string(),
-          name: "Previous Track".to_string(),
-          description: "Go to previous track".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.previous_track)),
+          id: "behavior.monochrome".to_string(),
+          name: "Monochrome".to_string(),
+          description: "Draw without colors (also enabled by NO_COLOR)".to_string(),
+          value: SettingValue::Bool(config.behavior.monochrome),
         },
         SettingItem {
-          id: "keys.shuffle".to_string(),
-          name: "Shuffle".to_string(),
-          description: "Toggle shuffle mode".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.shuffle)),
+          id: "behavior.global_song_count_native_only".to_string(),
+          name: "Count Native Plays Only".to_string(),
+          description: "Only count songs played by spotatui itself towards the global counter"
+            .to_string(),
+          value: SettingValue::Bool(config.behavior.global_song_count_native_only),
         },
         SettingItem {
-          id: "keys.repeat".to_string(),
-          name: "Repeat".to_string(),
-          description: "Cycle repeat mode".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.repeat)),
+          id: "behavior.resume_episodes".to_string(),
+          name: "Resume Episodes".to_string(),
+          description: "Start unfinished podcast episodes where they were left off".to_string(),
+          value: SettingValue::Bool(config.behavior.resume_episodes),
         },
         SettingItem {
-          id: "keys.search".to_string(),
-          name: "Search".to_string(),
-          description: "Open search".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.search)),
+          id: "behavior.allow_volume_boost".to_string(),
+          name: "Volume Boost".to_string(),
+          description: "Native player volume up to 150% (restart to apply; may clip)".to_string(),
+          value: SettingValue::Bool(config.behavior.allow_volume_boost),
         },
         SettingItem {
-          id: "keys.help".to_string(),
-          name: "Help".to_string(),
-          description: "Show help menu".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.help)),
+          id: "behavior.set_window_title".to_string(),
+          name: "Set Window Title".to_string(),
+          description: "Update terminal window title with track info".to_string(),
+          value: SettingValue::Bool(config.behavior.set_window_title),
         },
         SettingItem {
-          id: "keys.open_settings".to_string(),
-          name: "Open Settings".to_string(),
-          description: "Open settings menu".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.open_settings)),
+          id: "behavior.window_title_format".to_string(),
+          name: "Window Title Format".to_string(),
+          description: "{artist} {title} {album} {progress} {duration} {state}".to_string(),
+          value: SettingValue::String(config.behavior.window_title_format.clone()),
         },
         SettingItem {
-          id: "keys.save_settings".to_string(),
-          name: "Save Settings".to_string(),
-          description: "Save settings to file".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.save_settings)),
+          id: "behavior.enable_discord_rpc".to_string(),
+          name: "Discord Rich Presence".to_string(),
+          description: "Show your current track in Discord".to_string(),
+          value: SettingValue::Bool(config.behavior.enable_discord_rpc),
         },
         SettingItem {
-          id: "keys.jump_to_album".to_string(),
-          name: "Jump to Album".to_string(),
-          description: "Jump to currently playing album".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.jump_to_album)),
+          id: "behavior.enable_announcements".to_string(),
+          name: "Remote Announcements".to_string(),
+          description: "Show one-time announcements from remote JSON feed".to_string(),
+          value: SettingValue::Bool(config.behavior.enable_announcements),
         },
         SettingItem {
-          id: "keys.jump_to_artist_album".to_string(),
-          name: "Jump to Artist".to_string(),
-          description: "Jump to artist's albums".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.jump_to_artist_album)),
+          id: "behavior.announcement_feed_url".to_string(),
+          name: "Announcements Feed URL".to_string(),
+          description: "Remote JSON feed URL (HTTPS)".to_string(),
+          value: SettingValue::String(
+            config
+              .behavior
+              .announcement_feed_url
+              .clone()
+              .unwrap_or_default(),
+          ),
         },
         SettingItem {
-          id: "keys.jump_to_context".to_string(),
-          name: "Jump to Context".to_string(),
-          description: "Jump to current playback context".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.jump_to_context)),
+          id: "behavior.liked_icon".to_string(),
+          name: "Liked Icon".to_string(),
+          description: "Icon for liked songs".to_string(),
+          value: SettingValue::String(config.behavior.liked_icon.clone()),
         },
         SettingItem {
-          id: "keys.manage_devices".to_string(),
-          name: "Manage Devices".to_string(),
-          description: "Open device selection".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.manage_devices)),
+          id: "behavior.pinned_icon".to_string(),
+          name: "Pinned Icon".to_string(),
+          description: "Marks pinned playlists in the sidebar (empty for none)".to_string(),
+          value: SettingValue::String(config.behavior.pinned_icon.clone()),
         },
         SettingItem {
-          id: "keys.decrease_volume".to_string(),
-          name: "Decrease Volume".to_string(),
-          description: "Decrease playback volume".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.decrease_volume)),
+          id: "behavior.shuffle_icon".to_string(),
+          name: "Shuffle Icon".to_string(),
+          description: "Shown in the playbar when shuffle is on (empty shows \"On\")".to_string(),
+          value: SettingValue::String(config.behavior.shuffle_icon.clone()),
         },
         SettingItem {
-          id: "keys.increase_volume".to_string(),
-          name: "Increase Volume".to_string(),
-          description: "Increase playback volume".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.increase_volume)),
+          id: "behavior.playing_icon".to_string(),
+          name: "Playing Icon".to_string(),
+          description: "Shown in the playbar while playing (empty shows \"Playing\")".to_string(),
+          value: SettingValue::String(config.behavior.playing_icon.clone()),
         },
         SettingItem {
-          id: "keys.add_item_to_queue".to_string(),
-          name: "Add to Queue".to_string(),
-          description: "Add selected item to queue".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.add_item_to_queue)),
+          id: "behavior.paused_icon".to_string(),
+          name: "Paused Icon".to_string(),
+          description: "Shown in the playbar while paused (empty shows \"Paused\")".to_string(),
+          value: SettingValue::String(config.behavior.paused_icon.clone()),
         },
         SettingItem {
-          id: "keys.copy_song_url".to_string(),
-          name: "Copy Song URL".to_string(),
-          description: "Copy current song URL to clipboard".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.copy_song_url)),
+          id: "behavior.repeat_track_icon".to_string(),
+          name: "Repeat Track Icon".to_string(),
+          description: "Shown in the playbar when repeating one track (empty shows \"Track\")"
+            .to_string(),
+          value: SettingValue::String(config.behavior.repeat_track_icon.clone()),
         },
         SettingItem {
-          id: "keys.copy_album_url".to_string(),
-          name: "Copy Album URL".to_string(),
-          description: "Copy current album URL to clipboard".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.copy_album_url)),
+          id: "behavior.repeat_context_icon".to_string(),
+          name: "Repeat All Icon".to_string(),
+          description: "Shown in the playbar when repeating the context (empty shows \"All\")"
+            .to_string(),
+          value: SettingValue::String(config.behavior.repeat_context_icon.clone()),
         },
+        #[cfg(feature = "cover-art")]
         SettingItem {
-          id: "keys.audio_analysis".to_string(),
-          name: "Audio Analysis".to_string(),
-          description: "Open audio analysis view".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.audio_analysis)),
+          id: "behavior.draw_cover_art".to_string(),
+          name: "Draw Cover Art".to_string(),
+          description: "Enable rendering song/episode cover art".to_string(),
+          value: SettingValue::Bool(config.behavior.draw_cover_art),
         },
+        #[cfg(feature = "cover-art")]
         SettingItem {
-          id: "keys.basic_view".to_string(),
-          name: "Basic View".to_string(),
-          description: "Open lyrics/basic view".to_string(),
-          value: SettingValue::Key(key_to_string(&self.user_config.keys.basic_view)),
+          id: "behavior.draw_cover_art_forced".to_string(),
+          name: "Force Draw Cover Art".to_string(),
+          description: "Force rendering of cover art despite terminal support".to_string(),
+          value: SettingValue::Bool(config.behavior.draw_cover_art_forced),
         },
       ],
+      SettingsCategory::Keybindings => KEY_BINDINGS
+        .iter()
+        .filter_map(|binding| {
+          let keys = config.keys.get(binding.name)?;
+          let keys = keys.keys().iter().map(key_to_string).collect::<Vec<_>>();
+          Some(SettingItem {
+            id: format!("keys.{}", binding.name),
+            name: binding.label.to_string(),
+            description: locale::t(&format!("help.{}", binding.name)),
+            value: SettingValue::Key(keys.join(KEY_LIST_SEPARATOR)),
+          })
+        })
+        .collect(),
       SettingsCategory::Theme => {
         fn color_to_string(color: ratatui::style::Color) -> String {
           match color {
@@ -2548,75 +4414,83 @@ impl App {
             id: "theme.active".to_string(),
             name: "Active Color".to_string(),
             description: "Color for active elements".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.active)),
+            value: SettingValue::Color(color_to_string(config.theme.active)),
           },
           SettingItem {
             id: "theme.banner".to_string(),
             name: "Banner Color".to_string(),
             description: "Color for banner text".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.banner)),
+            value: SettingValue::Color(color_to_string(config.theme.banner)),
           },
           SettingItem {
             id: "theme.hint".to_string(),
             name: "Hint Color".to_string(),
             description: "Color for hints".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.hint)),
+            value: SettingValue::Color(color_to_string(config.theme.hint)),
           },
           SettingItem {
             id: "theme.hovered".to_string(),
             name: "Hovered Color".to_string(),
             description: "Color for hovered elements".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.hovered)),
+            value: SettingValue::Color(color_to_string(config.theme.hovered)),
           },
           SettingItem {
             id: "theme.selected".to_string(),
             name: "Selected Color".to_string(),
             description: "Color for selected items".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.selected)),
+            value: SettingValue::Color(color_to_string(config.theme.selected)),
           },
           SettingItem {
             id: "theme.inactive".to_string(),
             name: "Inactive Color".to_string(),
             description: "Color for inactive elements".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.inactive)),
+            value: SettingValue::Color(color_to_string(config.theme.inactive)),
           },
           SettingItem {
             id: "theme.text".to_string(),
             name: "Text Color".to_string(),
             description: "Default text color".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.text)),
+            value: SettingValue::Color(color_to_string(config.theme.text)),
           },
           SettingItem {
             id: "theme.error_text".to_string(),
             name: "Error Text Color".to_string(),
             description: "Color for error messages".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.error_text)),
+            value: SettingValue::Color(color_to_string(config.theme.error_text)),
           },
           SettingItem {
             id: "theme.playbar_background".to_string(),
             name: "Playbar Background".to_string(),
             description: "Background color for playbar".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.playbar_background)),
+            value: SettingValue::Color(color_to_string(config.theme.playbar_background)),
           },
           SettingItem {
             id: "theme.playbar_progress".to_string(),
             name: "Playbar Progress".to_string(),
             description: "Color for playbar progress".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.playbar_progress)),
+            value: SettingValue::Color(color_to_string(config.theme.playbar_progress)),
           },
           SettingItem {
             id: "theme.highlighted_lyrics".to_string(),
             name: "Lyrics Highlight".to_string(),
             description: "Color for current lyrics line".to_string(),
-            value: SettingValue::Color(color_to_string(self.user_config.theme.highlighted_lyrics)),
+            value: SettingValue::Color(color_to_string(config.theme.highlighted_lyrics)),
+          },
+          SettingItem {
+            id: "behavior.selection_symbol".to_string(),
+            name: "Selection Symbol".to_string(),
+            description: "Marks the selected row (empty for none)".to_string(),
+            value: SettingValue::String(config.behavior.selection_symbol.clone()),
+          },
+          SettingItem {
+            id: "behavior.selection_reverse".to_string(),
+            name: "Reverse Video Selection".to_string(),
+            description: "Swap text and background colors on the selected row".to_string(),
+            value: SettingValue::Bool(config.behavior.selection_reverse),
           },
         ]
       }
-    };
-    self.settings_selected_index = 0;
-    self.settings_saved_items = self.settings_items.clone();
-    self.settings_unsaved_prompt_visible = false;
-    self.settings_unsaved_prompt_save_selected = true;
+    }
   }
 
   /// Apply changes from settings_items back to user_config
@@ -2629,6 +4503,11 @@ impl App {
             self.user_config.behavior.seek_milliseconds = *v as u32;
           }
         }
+        "behavior.podcast_seek_milliseconds" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.podcast_seek_milliseconds = *v as u32;
+          }
+        }
         "behavior.volume_increment" => {
           if let SettingValue::Number(v) = &setting.value {
             self.user_config.behavior.volume_increment = (*v).clamp(0, 100) as u8;
@@ -2654,236 +4533,221 @@ impl App {
             self.user_config.behavior.enforce_wide_search_bar = *v;
           }
         }
-        "behavior.set_window_title" => {
+        "behavior.enable_mouse" => {
           if let SettingValue::Bool(v) = &setting.value {
-            self.user_config.behavior.set_window_title = *v;
+            self.user_config.behavior.enable_mouse = *v;
           }
         }
-        "behavior.enable_discord_rpc" => {
-          if let SettingValue::Bool(v) = &setting.value {
-            self.user_config.behavior.enable_discord_rpc = *v;
+        "behavior.wide_layout_min_width" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.wide_layout_min_width =
+              (*v).clamp(MIN_WIDE_LAYOUT_WIDTH as i64, u16::MAX as i64) as u16;
           }
         }
-        "behavior.enable_announcements" => {
+        "behavior.mouse_scroll_lines" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.mouse_scroll_lines = (*v).clamp(1, u16::MAX as i64) as u16;
+          }
+        }
+        "behavior.home_scroll_step" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.home_scroll_step = (*v).clamp(1, u16::MAX as i64) as u16;
+          }
+        }
+        "behavior.home_auto_scroll" => {
           if let SettingValue::Bool(v) = &setting.value {
-            self.user_config.behavior.enable_announcements = *v;
+            self.user_config.behavior.home_auto_scroll = *v;
           }
         }
-        "behavior.announcement_feed_url" => {
-          if let SettingValue::String(v) = &setting.value {
-            let trimmed = v.trim();
-            self.user_config.behavior.announcement_feed_url = if trimmed.is_empty() {
-              None
-            } else {
-              Some(trimmed.to_string())
-            };
+        "behavior.home_auto_scroll_interval_ms" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.home_auto_scroll_interval_ms = (*v).max(100) as u64;
           }
         }
-        "behavior.liked_icon" => {
+        "behavior.liked_play_order" => {
           if let SettingValue::String(v) = &setting.value {
-            self.user_config.behavior.liked_icon = v.clone();
+            if let Ok(order) = v.parse() {
+              self.user_config.behavior.liked_play_order = order;
+            }
           }
         }
-        "behavior.shuffle_icon" => {
-          if let SettingValue::String(v) = &setting.value {
-            self.user_config.behavior.shuffle_icon = v.clone();
+        "behavior.fair_shuffle" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.fair_shuffle = *v;
           }
         }
-        "behavior.playing_icon" => {
-          if let SettingValue::String(v) = &setting.value {
-            self.user_config.behavior.playing_icon = v.clone();
+        "behavior.fade_ms" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.fade_ms = (*v).clamp(0, MAX_FADE_MS as i64) as u64;
           }
         }
-        "behavior.paused_icon" => {
-          if let SettingValue::String(v) = &setting.value {
-            self.user_config.behavior.paused_icon = v.clone();
+        "behavior.show_queue_length" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.show_queue_length = *v;
+            if !*v {
+              self.queue_length = None;
+            }
           }
         }
-        #[cfg(feature = "cover-art")]
-        "behavior.draw_cover_art" => {
-          if let SettingValue::Bool(v) = setting.value {
-            self.user_config.behavior.draw_cover_art = v;
+        "behavior.show_key_hints" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.show_key_hints = *v;
           }
         }
-        #[cfg(feature = "cover-art")]
-        "behavior.draw_cover_art_forced" => {
-          if let SettingValue::Bool(v) = setting.value {
-            self.user_config.behavior.draw_cover_art_forced = v;
+        "behavior.default_playlist_public" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.default_playlist_public = *v;
           }
         }
-        // Keybindings
-        "keys.back" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.back = key;
-            }
+        "behavior.playlist_auto_refresh_secs" => {
+          if let SettingValue::Number(v) = &setting.value {
+            self.user_config.behavior.playlist_auto_refresh_secs = (*v).max(0) as u64;
           }
         }
-        "keys.next_page" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.next_page = key;
-            }
+        "behavior.pause_on_exit" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.pause_on_exit = *v;
           }
         }
-        "keys.previous_page" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.previous_page = key;
-            }
+        "behavior.warn_duplicate_add" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.warn_duplicate_add = *v;
           }
         }
-        "keys.toggle_playback" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.toggle_playback = key;
-            }
+        "behavior.show_track_badges" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.show_track_badges = *v;
           }
         }
-        "keys.seek_backwards" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.seek_backwards = key;
-            }
+        "behavior.podcast_autoplay_next" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.podcast_autoplay_next = *v;
           }
         }
-        "keys.seek_forwards" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.seek_forwards = key;
-            }
+        "behavior.preview_mode" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.preview_mode = *v;
           }
         }
-        "keys.next_track" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.next_track = key;
-            }
+        "behavior.prevent_sleep_while_playing" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.prevent_sleep_while_playing = *v;
           }
         }
-        "keys.previous_track" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.previous_track = key;
-            }
+        "behavior.monochrome" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.monochrome = *v;
           }
         }
-        "keys.shuffle" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.shuffle = key;
-            }
+        "behavior.window_title_format" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.window_title_format = v.clone();
           }
         }
-        "keys.repeat" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.repeat = key;
-            }
+        "behavior.global_song_count_native_only" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.global_song_count_native_only = *v;
           }
         }
-        "keys.search" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.search = key;
-            }
+        "behavior.resume_episodes" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.resume_episodes = *v;
           }
         }
-        "keys.help" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.help = key;
-            }
+        "behavior.allow_volume_boost" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.allow_volume_boost = *v;
           }
         }
-        "keys.open_settings" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.open_settings = key;
-            }
+        "behavior.set_window_title" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.set_window_title = *v;
           }
         }
-        "keys.save_settings" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.save_settings = key;
-            }
+        "behavior.enable_discord_rpc" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.enable_discord_rpc = *v;
           }
         }
-        "keys.jump_to_album" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.jump_to_album = key;
-            }
+        "behavior.enable_announcements" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.enable_announcements = *v;
           }
         }
-        "keys.jump_to_artist_album" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.jump_to_artist_album = key;
-            }
+        "behavior.announcement_feed_url" => {
+          if let SettingValue::String(v) = &setting.value {
+            let trimmed = v.trim();
+            self.user_config.behavior.announcement_feed_url = if trimmed.is_empty() {
+              None
+            } else {
+              Some(trimmed.to_string())
+            };
           }
         }
-        "keys.jump_to_context" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.jump_to_context = key;
-            }
+        "behavior.liked_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.liked_icon = v.clone();
           }
         }
-        "keys.manage_devices" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.manage_devices = key;
-            }
+        "behavior.pinned_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.pinned_icon = v.clone();
           }
         }
-        "keys.decrease_volume" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.decrease_volume = key;
-            }
+        "behavior.selection_symbol" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.selection_symbol = v.clone();
           }
         }
-        "keys.increase_volume" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.increase_volume = key;
-            }
+        "behavior.selection_reverse" => {
+          if let SettingValue::Bool(v) = &setting.value {
+            self.user_config.behavior.selection_reverse = *v;
           }
         }
-        "keys.add_item_to_queue" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.add_item_to_queue = key;
-            }
+        "behavior.shuffle_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.shuffle_icon = v.clone();
           }
         }
-        "keys.copy_song_url" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.copy_song_url = key;
-            }
+        "behavior.playing_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.playing_icon = v.clone();
           }
         }
-        "keys.copy_album_url" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.copy_album_url = key;
-            }
+        "behavior.paused_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.paused_icon = v.clone();
           }
         }
-        "keys.audio_analysis" => {
-          if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.audio_analysis = key;
-            }
+        "behavior.repeat_track_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.repeat_track_icon = v.clone();
+          }
+        }
+        "behavior.repeat_context_icon" => {
+          if let SettingValue::String(v) = &setting.value {
+            self.user_config.behavior.repeat_context_icon = v.clone();
+          }
+        }
+        #[cfg(feature = "cover-art")]
+        "behavior.draw_cover_art" => {
+          if let SettingValue::Bool(v) = setting.value {
+            self.user_config.behavior.draw_cover_art = v;
           }
         }
-        "keys.basic_view" => {
+        #[cfg(feature = "cover-art")]
+        "behavior.draw_cover_art_forced" => {
+          if let SettingValue::Bool(v) = setting.value {
+            self.user_config.behavior.draw_cover_art_forced = v;
+          }
+        }
+        // Keybindings
+        id if id.starts_with("keys.") => {
           if let SettingValue::Key(v) = &setting.value {
-            if let Ok(key) = crate::core::user_config::parse_key_public(v.clone()) {
-              self.user_config.keys.basic_view = key;
+            if let Ok(keys) = crate::core::user_config::parse_key_list_public(v) {
+              if let Some(bound) = self.user_config.keys.get_mut(&id["keys.".len()..]) {
+                *bound = keys;
+              }
             }
           }
         }
@@ -2905,3 +4769,34 @@ impl App {
     }
   }
 }
+
+/// Label for a playlist's sharing state, as shown next to owned playlists.
+pub fn playlist_visibility_label(public: Option<bool>, collaborative: bool) -> &'static str {
+  if collaborative {
+    "Collaborative"
+  } else if public == Some(true) {
+    "Public"
+  } else {
+    "Private"
+  }
+}
+
+/// New `(public, collaborative)` values after flipping public/private.
+///
+/// Spotify rejects collaborative playlists that are public, so making a playlist
+/// public also turns collaboration off.
+pub fn toggled_playlist_public(public: Option<bool>, collaborative: bool) -> (bool, bool) {
+  let make_public = public != Some(true);
+  (make_public, collaborative && !make_public)
+}
+
+/// New `(public, collaborative)` values after flipping collaborative mode.
+///
+/// Enabling collaboration makes the playlist private, as Spotify requires.
+pub fn toggled_playlist_collaborative(public: Option<bool>, collaborative: bool) -> (bool, bool) {
+  if collaborative {
+    (public == Some(true), false)
+  } else {
+    (false, true)
+  }
+}
@@ -0,0 +1,133 @@
+//! Whether tracks can be played in the user's market
+//!
+//! Spotify only sends `is_playable` and `restrictions` for requests made with a
+//! market, and only sends `available_markets` for requests made without one, so
+//! both are looked at.
+
+use crate::core::locale;
+use rspotify::model::{
+  album::Restriction,
+  enums::{Country, RestrictionReason},
+  FullTrack, SimplifiedTrack,
+};
+
+/// The availability fields full and simplified tracks have in common
+pub trait Playability {
+  fn is_playable(&self) -> Option<bool>;
+  fn available_markets(&self) -> &[String];
+  fn restriction(&self) -> Option<&Restriction>;
+}
+
+impl Playability for FullTrack {
+  fn is_playable(&self) -> Option<bool> {
+    self.is_playable
+  }
+
+  fn available_markets(&self) -> &[String] {
+    &self.available_markets
+  }
+
+  fn restriction(&self) -> Option<&Restriction> {
+    self.restrictions.as_ref()
+  }
+}
+
+impl Playability for SimplifiedTrack {
+  fn is_playable(&self) -> Option<bool> {
+    self.is_playable
+  }
+
+  fn available_markets(&self) -> &[String] {
+    self.available_markets.as_deref().unwrap_or_default()
+  }
+
+  fn restriction(&self) -> Option<&Restriction> {
+    self.restrictions.as_ref()
+  }
+}
+
+/// Whether `track` can be played in the user's market, or `None` when that isn't
+/// known. `is_playable` wins, otherwise the country is looked up in `available_markets`.
+pub fn market_availability(track: &impl Playability, country: Option<Country>) -> Option<bool> {
+  if let Some(playable) = track.is_playable() {
+    return Some(playable);
+  }
+  let country: &'static str = country?.into();
+  let markets = track.available_markets();
+  if markets.is_empty() {
+    return None;
+  }
+  Some(markets.iter().any(|market| market == country))
+}
+
+/// Known to be unplayable for the user, rather than just not known to be playable
+pub fn is_unavailable(track: &impl Playability, country: Option<Country>) -> bool {
+  market_availability(track, country) == Some(false)
+}
+
+/// Id of the message saying why `track` can't be played
+fn unavailable_reason(track: &impl Playability) -> &'static str {
+  match track.restriction().map(|restriction| &restriction.reason) {
+    Some(RestrictionReason::Product) => "status.unavailable_premium",
+    Some(RestrictionReason::Explicit) => "status.unavailable_explicit",
+    Some(RestrictionReason::Market) | None => "status.unavailable_market",
+  }
+}
+
+/// The status message shown instead of playing `track`, if it can't be played
+pub fn unavailable_message(track: &impl Playability, country: Option<Country>) -> Option<String> {
+  is_unavailable(track, country).then(|| locale::t(unavailable_reason(track)))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::fixtures;
+
+  #[test]
+  fn market_availability_prefers_is_playable_then_markets() {
+    let mut track = fixtures::track("Song", "Artist", "Album", 1000);
+    track.available_markets = vec!["US".to_string(), "GB".to_string()];
+
+    assert_eq!(market_availability(&track, None), None);
+    assert_eq!(
+      market_availability(&track, Some(Country::UnitedStates)),
+      Some(true)
+    );
+    assert_eq!(
+      market_availability(&track, Some(Country::Japan)),
+      Some(false)
+    );
+
+    track.is_playable = Some(true);
+    assert_eq!(
+      market_availability(&track, Some(Country::Japan)),
+      Some(true)
+    );
+
+    track.is_playable = None;
+    track.available_markets.clear();
+    assert_eq!(market_availability(&track, Some(Country::Japan)), None);
+  }
+
+  #[test]
+  fn restrictions_explain_why_a_track_is_unavailable() {
+    let mut track = crate::core::fixtures::track("Song", "Artist", "Album", 1000);
+    track.is_playable = Some(false);
+    assert_eq!(
+      unavailable_message(&track, None).as_deref(),
+      Some("This song isn't available in your country")
+    );
+
+    track.restrictions = Some(Restriction {
+      reason: RestrictionReason::Product,
+    });
+    assert_eq!(
+      unavailable_message(&track, None).as_deref(),
+      Some("This song needs Spotify Premium")
+    );
+
+    track.is_playable = Some(true);
+    assert_eq!(unavailable_message(&track, Some(Country::Japan)), None);
+  }
+}
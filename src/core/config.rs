@@ -1,3 +1,4 @@
+use crate::infra::secret_store;
 use crate::tui::banner::BANNER;
 use anyhow::{anyhow, Error, Result};
 use serde::{Deserialize, Serialize};
@@ -28,6 +29,10 @@ pub struct ClientConfig {
   pub device_id: Option<String>,
   // FIXME: port should be defined in `user_config` not in here
   pub port: Option<u16>,
+  /// Address the auth callback server listens on. `0.0.0.0` lets a browser outside
+  /// WSL2 or a container reach it
+  #[serde(default = "default_bind_address")]
+  pub bind_address: String,
   // Streaming configuration
   #[serde(default = "default_streaming_enabled")]
   pub enable_streaming: bool,
@@ -35,14 +40,26 @@ pub struct ClientConfig {
   pub streaming_device_name: String,
   #[serde(default = "default_bitrate")]
   pub streaming_bitrate: u16,
+  /// Keep downloaded audio on disk. Caching a playlist for offline playback downloads
+  /// into this cache, on a best-effort basis and within librespot's cache limits
   #[serde(default)]
   pub streaming_audio_cache: bool,
+  /// Take playback on the native device at startup even when another device is saved
+  #[serde(default)]
+  pub streaming_prefer_native: bool,
+  /// Keep the client secret and auth token in the OS keyring rather than in files
+  #[serde(default)]
+  pub use_keyring: bool,
 }
 
 fn default_streaming_enabled() -> bool {
   cfg!(feature = "streaming")
 }
 
+fn default_bind_address() -> String {
+  "127.0.0.1".to_string()
+}
+
 fn default_device_name() -> String {
   "spotatui".to_string()
 }
@@ -65,10 +82,13 @@ impl ClientConfig {
       setup_version: 0,
       device_id: None,
       port: None,
+      bind_address: default_bind_address(),
       enable_streaming: default_streaming_enabled(),
       streaming_device_name: default_device_name(),
       streaming_bitrate: default_bitrate(),
       streaming_audio_cache: false,
+      streaming_prefer_native: false,
+      use_keyring: false,
     }
   }
 
@@ -154,10 +174,17 @@ impl ClientConfig {
       self.setup_version = config_yml.setup_version;
       self.device_id = config_yml.device_id;
       self.port = config_yml.port;
+      self.bind_address = config_yml.bind_address;
       self.enable_streaming = config_yml.enable_streaming;
       self.streaming_device_name = config_yml.streaming_device_name;
       self.streaming_bitrate = config_yml.streaming_bitrate;
       self.streaming_audio_cache = config_yml.streaming_audio_cache;
+      self.streaming_prefer_native = config_yml.streaming_prefer_native;
+      self.use_keyring = config_yml.use_keyring;
+
+      if self.use_keyring {
+        self.load_keyring_secret()?;
+      }
 
       Ok(())
     } else {
@@ -241,9 +268,31 @@ impl ClientConfig {
     self.save_config_file()
   }
 
+  /// Reads the client secret from the keyring, first moving a plaintext one from
+  /// client.yml there
+  fn load_keyring_secret(&mut self) -> Result<()> {
+    if self.client_secret.is_empty() {
+      if let Some(secret) = secret_store::get(secret_store::CLIENT_SECRET) {
+        self.client_secret = secret;
+      }
+      Ok(())
+    } else {
+      log::info!("moving the client secret from client.yml to the OS keyring");
+      self.save_config_file()
+    }
+  }
+
   fn save_config_file(&self) -> Result<()> {
     let paths = self.get_or_build_paths()?;
-    let content_yml = serde_yaml::to_string(self)?;
+    // With the secret safely in the keyring, client.yml keeps a blank one
+    let mut on_disk = self.clone();
+    if self.use_keyring
+      && !self.client_secret.is_empty()
+      && secret_store::set(secret_store::CLIENT_SECRET, &self.client_secret)
+    {
+      on_disk.client_secret.clear();
+    }
+    let content_yml = serde_yaml::to_string(&on_disk)?;
 
     let mut config_file = fs::File::create(&paths.config_file_path)?;
     write!(config_file, "{}", content_yml)?;
@@ -0,0 +1,121 @@
+//! Client-side "Enhance" for playlists
+//!
+//! Spotify's own Enhance/Smart Shuffle isn't in the Web API, so suggestions are
+//! fetched as recommendations seeded from the playlist and mixed in between its
+//! tracks on screen. They aren't part of the playlist, so they're left out of
+//! anything that edits it.
+
+use rspotify::model::{idtypes::PlaylistId, track::FullTrack};
+use rspotify::prelude::Id;
+
+/// A suggestion follows every this many playlist tracks
+pub const SUGGESTION_EVERY: usize = 3;
+
+/// Playlist tracks used to seed the suggestions, the most Spotify takes
+pub const SEED_TRACKS: usize = 5;
+
+fn track_id(track: &FullTrack) -> Option<&str> {
+  track.id.as_ref().map(|id| id.id())
+}
+
+/// Enhance switched on for a playlist, with the suggestions once they've arrived
+pub struct PlaylistEnhance {
+  pub playlist_id: PlaylistId<'static>,
+  pub suggestions: Vec<FullTrack>,
+}
+
+impl PlaylistEnhance {
+  pub fn new(playlist_id: PlaylistId<'static>) -> Self {
+    PlaylistEnhance {
+      playlist_id,
+      suggestions: Vec::new(),
+    }
+  }
+
+  pub fn is_suggestion(&self, id: &str) -> bool {
+    self
+      .suggestions
+      .iter()
+      .any(|suggestion| track_id(suggestion) == Some(id))
+  }
+
+  /// `tracks` with a suggestion after every `SUGGESTION_EVERY` of them. Suggestions
+  /// the playlist already has are dropped for good, so a suggestion's ID never
+  /// matches one of the playlist's own rows.
+  pub fn interleave(&mut self, tracks: Vec<FullTrack>) -> Vec<FullTrack> {
+    self.suggestions.retain(|suggestion| {
+      track_id(suggestion).is_some_and(|id| !tracks.iter().any(|track| track_id(track) == Some(id)))
+    });
+    let mut suggestions = self.suggestions.iter();
+    let mut mixed = Vec::with_capacity(tracks.len() + tracks.len() / SUGGESTION_EVERY);
+    for (i, track) in tracks.into_iter().enumerate() {
+      mixed.push(track);
+      if (i + 1) % SUGGESTION_EVERY == 0 {
+        mixed.extend(suggestions.next().cloned());
+      }
+    }
+    mixed
+  }
+
+  /// `tracks` with the suggestions taken out again
+  pub fn strip(&self, tracks: Vec<FullTrack>) -> Vec<FullTrack> {
+    tracks
+      .into_iter()
+      .filter(|track| !track_id(track).is_some_and(|id| self.is_suggestion(id)))
+      .collect()
+  }
+
+  /// The playlist row shown at `index` of the mixed `tracks`, `None` for a suggestion
+  pub fn playlist_row(&self, tracks: &[FullTrack], index: usize) -> Option<usize> {
+    let is_suggestion =
+      |track: &FullTrack| track_id(track).is_some_and(|id| self.is_suggestion(id));
+    if is_suggestion(tracks.get(index)?) {
+      return None;
+    }
+    Some(
+      tracks[..index]
+        .iter()
+        .filter(|track| !is_suggestion(track))
+        .count(),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::fixtures;
+
+  fn tracks(names: &[&str]) -> Vec<FullTrack> {
+    names
+      .iter()
+      .map(|name| fixtures::track(name, "Artist", "Album", 1000))
+      .collect()
+  }
+
+  fn names(tracks: &[FullTrack]) -> Vec<&str> {
+    tracks.iter().map(|track| track.name.as_str()).collect()
+  }
+
+  #[test]
+  fn suggestions_are_mixed_in_and_taken_out_again() {
+    let playlist = PlaylistId::from_id(fixtures::id("Road Trip")).unwrap();
+    let mut enhance = PlaylistEnhance::new(playlist);
+    // "d" is already in the playlist, so it isn't suggested
+    enhance.suggestions = tracks(&["x", "d", "y", "z"]);
+
+    let mixed = enhance.interleave(tracks(&["a", "b", "c", "d", "e", "f", "g"]));
+    assert_eq!(names(&mixed), ["a", "b", "c", "x", "d", "e", "f", "y", "g"]);
+    assert!(!enhance.is_suggestion(&fixtures::id("d")));
+
+    assert_eq!(enhance.playlist_row(&mixed, 2), Some(2));
+    assert_eq!(enhance.playlist_row(&mixed, 3), None);
+    assert_eq!(enhance.playlist_row(&mixed, 4), Some(3));
+    assert_eq!(enhance.playlist_row(&mixed, 9), None);
+
+    assert_eq!(
+      names(&enhance.strip(mixed)),
+      ["a", "b", "c", "d", "e", "f", "g"]
+    );
+  }
+}
@@ -0,0 +1,233 @@
+//! Synthetic Spotify objects for tests
+//!
+//! Each helper fills in everything the API would send with plain defaults, so a
+//! test only spells out what it checks. The results are ordinary rspotify models
+//! with public fields, so tweak them afterwards when a default doesn't fit.
+
+use rspotify::model::{
+  album::FullAlbum, artist::FullArtist, context::CurrentPlaybackContext, device::Device,
+  page::Page, playlist::SimplifiedPlaylist, show::FullEpisode, track::FullTrack, user::PrivateUser,
+};
+use serde_json::{json, Value};
+
+/// A stable 22 character base62 id derived from `seed`
+pub fn id(seed: &str) -> String {
+  let alphabet: Vec<char> = seed.chars().filter(char::is_ascii_alphanumeric).collect();
+  if alphabet.is_empty() {
+    return "0".repeat(22);
+  }
+  alphabet.iter().cycle().take(22).collect()
+}
+
+fn simplified_artist(name: &str) -> Value {
+  json!({
+    "external_urls": {},
+    "href": null,
+    "id": id(name),
+    "name": name,
+  })
+}
+
+/// A track by `artist` on `album`
+pub fn track(name: &str, artist: &str, album: &str, duration_ms: u32) -> FullTrack {
+  serde_json::from_value(json!({
+    "album": {
+      "album_type": "album",
+      "artists": [simplified_artist(artist)],
+      "available_markets": [],
+      "external_urls": {},
+      "href": null,
+      "id": id(album),
+      "images": [],
+      "name": album,
+      "release_date": "2020-01-01",
+      "release_date_precision": "day",
+    },
+    "artists": [simplified_artist(artist)],
+    "available_markets": [],
+    "disc_number": 1,
+    "duration_ms": duration_ms,
+    "explicit": false,
+    "external_ids": {},
+    "external_urls": {},
+    "href": null,
+    "id": id(name),
+    "is_local": false,
+    "name": name,
+    "popularity": 50,
+    "preview_url": null,
+    "track_number": 1,
+  }))
+  .unwrap()
+}
+
+/// An album by `artist` holding a track for each of `track_names`
+pub fn album(name: &str, artist: &str, track_names: &[&str]) -> FullAlbum {
+  let tracks: Vec<Value> = track_names
+    .iter()
+    .enumerate()
+    .map(|(i, track)| {
+      json!({
+        "artists": [simplified_artist(artist)],
+        "available_markets": [],
+        "disc_number": 1,
+        "duration_ms": 180_000,
+        "explicit": false,
+        "external_urls": {},
+        "href": null,
+        "id": id(track),
+        "is_local": false,
+        "name": track,
+        "preview_url": null,
+        "track_number": i + 1,
+      })
+    })
+    .collect();
+  serde_json::from_value(json!({
+    "album_type": "album",
+    "artists": [simplified_artist(artist)],
+    "copyrights": [],
+    "external_ids": {},
+    "external_urls": {},
+    "genres": [],
+    "href": "",
+    "id": id(name),
+    "images": [],
+    "label": null,
+    "name": name,
+    "popularity": 50,
+    "release_date": "2020-01-01",
+    "release_date_precision": "day",
+    "tracks": page(tracks),
+  }))
+  .unwrap()
+}
+
+/// An episode of the podcast `show`
+pub fn episode(name: &str, show: &str, duration_ms: u32) -> FullEpisode {
+  serde_json::from_value(json!({
+    "audio_preview_url": null,
+    "description": "",
+    "duration_ms": duration_ms,
+    "explicit": false,
+    "external_urls": {},
+    "href": "",
+    "id": id(name),
+    "images": [],
+    "is_externally_hosted": false,
+    "is_playable": true,
+    "language": "en",
+    "languages": ["en"],
+    "name": name,
+    "release_date": "2020-01-01",
+    "release_date_precision": "day",
+    "resume_point": null,
+    "show": {
+      "available_markets": [],
+      "copyrights": [],
+      "description": "",
+      "explicit": false,
+      "external_urls": {},
+      "href": "",
+      "id": id(show),
+      "images": [],
+      "languages": ["en"],
+      "media_type": "audio",
+      "name": show,
+      "publisher": show,
+    },
+  }))
+  .unwrap()
+}
+
+pub fn artist(name: &str) -> FullArtist {
+  serde_json::from_value(json!({
+    "external_urls": {},
+    "followers": { "href": null, "total": 0 },
+    "genres": [],
+    "href": "",
+    "id": id(name),
+    "images": [],
+    "name": name,
+    "popularity": 0,
+  }))
+  .unwrap()
+}
+
+/// A public playlist owned by `owner` holding `total` tracks
+pub fn playlist(name: &str, owner: &str, total: u32) -> SimplifiedPlaylist {
+  serde_json::from_value(json!({
+    "collaborative": false,
+    "external_urls": {},
+    "href": "",
+    "id": id(name),
+    "images": [],
+    "name": name,
+    "owner": { "display_name": owner, "external_urls": {}, "href": "", "id": id(owner) },
+    "public": true,
+    "snapshot_id": "",
+    "tracks": { "href": "", "total": total },
+  }))
+  .unwrap()
+}
+
+/// A Premium account in the US
+pub fn user(display_name: &str) -> PrivateUser {
+  serde_json::from_value(json!({
+    "country": "US",
+    "display_name": display_name,
+    "external_urls": {},
+    "href": "",
+    "id": id(display_name),
+    "product": "premium",
+  }))
+  .unwrap()
+}
+
+pub fn device(name: &str, is_active: bool) -> Device {
+  serde_json::from_value(json!({
+    "id": id(name),
+    "is_active": is_active,
+    "is_private_session": false,
+    "is_restricted": false,
+    "name": name,
+    "type": "Computer",
+    "volume_percent": 50,
+  }))
+  .unwrap()
+}
+
+/// `track` playing (or paused) on `device`, `progress_ms` in
+pub fn playback(
+  device: &Device,
+  track: &FullTrack,
+  is_playing: bool,
+  progress_ms: u32,
+) -> CurrentPlaybackContext {
+  serde_json::from_value(json!({
+    "device": device,
+    "repeat_state": "off",
+    "shuffle_state": false,
+    "context": null,
+    "timestamp": 0,
+    "progress_ms": progress_ms,
+    "is_playing": is_playing,
+    "item": track,
+    "currently_playing_type": "track",
+    "actions": { "disallows": {} },
+  }))
+  .unwrap()
+}
+
+/// A single page holding all of `items`
+pub fn page<T>(items: Vec<T>) -> Page<T> {
+  Page {
+    href: String::new(),
+    limit: items.len().max(1) as u32,
+    next: None,
+    offset: 0,
+    previous: None,
+    total: items.len() as u32,
+    items,
+  }
+}
@@ -0,0 +1,123 @@
+//! Searching the parts of the user's library that are already loaded
+//!
+//! Matches names case-insensitively across liked songs, saved albums, followed
+//! artists and playlists without asking the API for anything.
+
+use super::app::App;
+use super::locale::t;
+
+/// Where a match lives, so it can be opened
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LibraryItem {
+  /// Liked song at `index` on saved tracks page `page`
+  Track { page: usize, index: usize },
+  /// Saved album at `index` on saved albums page `page`
+  Album { page: usize, index: usize },
+  /// Followed artist at `index` on saved artists page `page`
+  Artist { page: usize, index: usize },
+  /// Index into `all_playlists`
+  Playlist(usize),
+}
+
+impl LibraryItem {
+  pub fn kind(&self) -> String {
+    t(match self {
+      LibraryItem::Track { .. } => "popup.library_search_song",
+      LibraryItem::Album { .. } => "popup.library_search_album",
+      LibraryItem::Artist { .. } => "popup.library_search_artist",
+      LibraryItem::Playlist(_) => "popup.library_search_playlist",
+    })
+  }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LibraryMatch {
+  pub item: LibraryItem,
+  pub name: String,
+  /// Artists for songs and albums, the owner for playlists
+  pub detail: String,
+}
+
+fn artist_names<'a>(names: impl Iterator<Item = &'a str>) -> String {
+  names.collect::<Vec<_>>().join(", ")
+}
+
+/// Everything loaded whose name or detail contains `query`, songs first.
+/// An empty query matches nothing.
+pub fn search(app: &App, query: &str) -> Vec<LibraryMatch> {
+  let query = query.trim().to_lowercase();
+  if query.is_empty() {
+    return Vec::new();
+  }
+  let is_match = |m: &LibraryMatch| {
+    m.name.to_lowercase().contains(&query) || m.detail.to_lowercase().contains(&query)
+  };
+
+  let tracks = app
+    .library
+    .saved_tracks
+    .pages
+    .iter()
+    .enumerate()
+    .flat_map(|(page, saved)| {
+      saved
+        .items
+        .iter()
+        .enumerate()
+        .map(move |(index, item)| LibraryMatch {
+          item: LibraryItem::Track { page, index },
+          name: item.track.name.clone(),
+          detail: artist_names(item.track.artists.iter().map(|a| a.name.as_str())),
+        })
+    });
+  let albums = app
+    .library
+    .saved_albums
+    .pages
+    .iter()
+    .enumerate()
+    .flat_map(|(page, saved)| {
+      saved
+        .items
+        .iter()
+        .enumerate()
+        .map(move |(index, item)| LibraryMatch {
+          item: LibraryItem::Album { page, index },
+          name: item.album.name.clone(),
+          detail: artist_names(item.album.artists.iter().map(|a| a.name.as_str())),
+        })
+    });
+  let artists = app
+    .library
+    .saved_artists
+    .pages
+    .iter()
+    .enumerate()
+    .flat_map(|(page, saved)| {
+      saved
+        .items
+        .iter()
+        .enumerate()
+        .map(move |(index, artist)| LibraryMatch {
+          item: LibraryItem::Artist { page, index },
+          name: artist.name.clone(),
+          detail: String::new(),
+        })
+    });
+  let playlists = app
+    .all_playlists
+    .iter()
+    .enumerate()
+    .map(|(index, playlist)| LibraryMatch {
+      item: LibraryItem::Playlist(index),
+      name: playlist.name.clone(),
+      detail: playlist.owner.display_name.clone().unwrap_or_default(),
+    });
+
+  tracks
+    .chain(albums)
+    .chain(artists)
+    .chain(playlists)
+    .filter(is_match)
+    .collect()
+}
@@ -0,0 +1,228 @@
+//! Translated UI strings.
+//!
+//! Strings are looked up by id (`help.next_track`, `help.section.general`, ...) in
+//! TOML tables embedded from `locale/`. The active language comes from
+//! `behavior.language`, or `$LANG` when that is unset, and any id a translation
+//! leaves out falls back to English.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const ENGLISH: &str = "en";
+
+/// Language code and embedded strings for every shipped locale
+const LOCALES: &[(&str, &str)] = &[
+  (ENGLISH, include_str!("locale/en.toml")),
+  ("es", include_str!("locale/es.toml")),
+];
+
+struct Locale {
+  language: &'static str,
+  strings: HashMap<String, String>,
+}
+
+static ACTIVE: OnceLock<Locale> = OnceLock::new();
+static FALLBACK: OnceLock<Locale> = OnceLock::new();
+
+/// Picks the UI language once at startup. Later calls are ignored.
+pub fn init(configured: Option<&str>) {
+  let env = ["LC_ALL", "LC_MESSAGES", "LANG"]
+    .iter()
+    .find_map(|var| std::env::var(var).ok().filter(|value| !value.is_empty()));
+  let language = resolve_language(configured, env.as_deref());
+  if ACTIVE.set(load(language)).is_err() {
+    log::info!("UI language already set, ignoring {}", language);
+  }
+}
+
+/// The string for `id` in the active language, falling back to English and then
+/// to the id itself so a missing string is obvious rather than blank
+pub fn t(id: &str) -> String {
+  translation(id)
+    .or_else(|| fallback().strings.get(id).cloned())
+    .unwrap_or_else(|| id.to_string())
+}
+
+/// Like [`t`], with `{name}` placeholders filled from `args`
+pub fn tf(id: &str, args: &[(&str, &str)]) -> String {
+  interpolate(&t(id), args)
+}
+
+/// The string for `id` only if the active language is not English and translates it.
+/// For text whose English lives next to the code that uses it.
+pub fn translation(id: &str) -> Option<String> {
+  let active = ACTIVE.get()?;
+  if active.language == ENGLISH {
+    return None;
+  }
+  active.strings.get(id).cloned()
+}
+
+fn fallback() -> &'static Locale {
+  FALLBACK.get_or_init(|| load(ENGLISH))
+}
+
+fn load(language: &'static str) -> Locale {
+  let source = LOCALES
+    .iter()
+    .find(|(code, _)| *code == language)
+    .map_or("", |(_, source)| source);
+  let mut strings = HashMap::new();
+  match source.parse::<toml::Table>() {
+    Ok(table) => flatten("", &table, &mut strings),
+    Err(e) => log::warn!("could not parse the {} locale: {}", language, e),
+  }
+  Locale { language, strings }
+}
+
+/// `[help] next_track = "..."` becomes `help.next_track`
+fn flatten(prefix: &str, table: &toml::Table, strings: &mut HashMap<String, String>) {
+  for (key, value) in table {
+    let id = if prefix.is_empty() {
+      key.clone()
+    } else {
+      format!("{}.{}", prefix, key)
+    };
+    match value {
+      toml::Value::String(text) => {
+        strings.insert(id, text.clone());
+      }
+      toml::Value::Table(table) => flatten(&id, table, strings),
+      _ => {}
+    }
+  }
+}
+
+/// Matches a configured language, or a locale like `es_ES.UTF-8`, to a shipped
+/// locale. The configured value wins; anything unknown means English.
+pub fn resolve_language(configured: Option<&str>, env: Option<&str>) -> &'static str {
+  let code = |value: &str| {
+    let language = value
+      .split(['_', '-', '.', '@'])
+      .next()
+      .unwrap_or_default()
+      .to_lowercase();
+    LOCALES
+      .iter()
+      .map(|(code, _)| *code)
+      .find(|code| *code == language)
+  };
+  configured
+    .map(str::trim)
+    .filter(|value| !value.is_empty())
+    .or(env)
+    .and_then(code)
+    .unwrap_or(ENGLISH)
+}
+
+/// Replaces `{name}` with the matching value from `args`. Unknown placeholders are
+/// left as they are, and `{{`/`}}` give literal braces.
+pub fn interpolate(template: &str, args: &[(&str, &str)]) -> String {
+  let mut out = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some(start) = rest.find(['{', '}']) {
+    out.push_str(&rest[..start]);
+    let tail = &rest[start..];
+    if tail.starts_with("{{") || tail.starts_with("}}") {
+      out.push_str(&tail[..1]);
+      rest = &tail[2..];
+      continue;
+    }
+    let placeholder = tail
+      .strip_prefix('{')
+      .and_then(|inner| inner.find('}').map(|end| &inner[..end]));
+    match placeholder.and_then(|name| args.iter().find(|(key, _)| *key == name)) {
+      Some((name, value)) => {
+        out.push_str(value);
+        rest = &tail[name.len() + 2..];
+      }
+      None => {
+        out.push_str(&tail[..1]);
+        rest = &tail[1..];
+      }
+    }
+  }
+  out.push_str(rest);
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::user_config::{UserConfig, FIXED_KEYS, KEY_BINDINGS};
+  use crate::tui::ui::help::get_help_docs;
+
+  #[test]
+  fn interpolate_fills_placeholders() {
+    assert_eq!(
+      interpolate("Seek step: {step}", &[("step", "10s")]),
+      "Seek step: 10s"
+    );
+    assert_eq!(
+      interpolate("{a} and {b}, {a}", &[("a", "x"), ("b", "y")]),
+      "x and y, x"
+    );
+    // Unknown or unclosed placeholders are left alone
+    assert_eq!(interpolate("{missing} {", &[("a", "x")]), "{missing} {");
+    assert_eq!(interpolate("{{a}} {a}", &[("a", "x")]), "{a} x");
+    assert_eq!(interpolate("niño {a}", &[("a", "ñ")]), "niño ñ");
+  }
+
+  #[test]
+  fn language_comes_from_config_then_environment() {
+    assert_eq!(resolve_language(Some("es"), Some("en_US.UTF-8")), "es");
+    assert_eq!(resolve_language(None, Some("es_ES.UTF-8")), "es");
+    assert_eq!(resolve_language(Some(" "), Some("es-MX")), "es");
+    assert_eq!(resolve_language(Some("ES"), None), "es");
+    assert_eq!(resolve_language(Some("tlh"), Some("es_ES")), "en");
+    assert_eq!(resolve_language(None, Some("C")), "en");
+    assert_eq!(resolve_language(None, None), "en");
+  }
+
+  #[test]
+  fn translations_only_use_ids_english_knows() {
+    let english = load(ENGLISH);
+    for (code, _) in LOCALES {
+      let locale = load(code);
+      assert!(!locale.strings.is_empty(), "{} locale is empty", code);
+      for id in locale.strings.keys() {
+        // Settings and key hints keep their English next to their definitions
+        if !id.starts_with("settings.") && !id.starts_with("hints.") {
+          assert!(
+            english.strings.contains_key(id),
+            "{} has unknown id {}",
+            code,
+            id
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn every_help_row_has_english_text() {
+    let help_docs = get_help_docs(&UserConfig::new().keys);
+    for row in help_docs {
+      assert!(!row[0].starts_with("help."), "missing string {}", row[0]);
+      assert!(!row[2].starts_with("help."), "missing string {}", row[2]);
+    }
+  }
+
+  #[test]
+  fn every_key_hint_has_a_spanish_label() {
+    let spanish = load("es");
+    let labels = FIXED_KEYS
+      .iter()
+      .flat_map(|(_, _, _, hints)| hints.iter())
+      .chain(KEY_BINDINGS.iter().flat_map(|binding| binding.hints.iter()))
+      .map(|(_, label)| *label)
+      .chain(["Search", "Help"]);
+    for label in labels {
+      assert!(
+        spanish.strings.contains_key(&format!("hints.{}", label)),
+        "no Spanish hint for {}",
+        label
+      );
+    }
+  }
+}
@@ -1,4 +1,14 @@
 pub mod app;
+pub mod availability;
 pub mod config;
+pub mod enhance;
+#[cfg(test)]
+pub mod fixtures;
+pub mod library_search;
+pub mod locale;
+#[cfg(feature = "streaming")]
+pub mod shuffle;
+pub mod skip_stats;
 pub mod sort;
+pub mod startup_queue;
 pub mod user_config;
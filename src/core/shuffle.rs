@@ -0,0 +1,124 @@
+//! Client-side "fair" shuffle for track lists
+//!
+//! Spreads artists evenly so the same artist never plays back to back when the
+//! list allows it. Only used for native streaming, where we hand librespot the
+//! exact play order instead of relying on Spotify's shuffle.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Shuffle `items` so that consecutive items have different keys where possible.
+///
+/// When `start` is given, that item is kept first and the rest are arranged to
+/// follow it. Each step takes a random item from whichever key has the most
+/// items left, skipping the key that was just played.
+pub fn fair_shuffle<T, K, R>(
+  items: Vec<T>,
+  start: Option<usize>,
+  key_of: impl Fn(&T) -> K,
+  rng: &mut R,
+) -> Vec<T>
+where
+  K: Eq + Hash + Clone,
+  R: Rng + ?Sized,
+{
+  let mut result = Vec::with_capacity(items.len());
+  let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+  let mut key_order = Vec::new();
+
+  for (index, item) in items.into_iter().enumerate() {
+    if Some(index) == start {
+      result.push(item);
+      continue;
+    }
+    let key = key_of(&item);
+    groups
+      .entry(key.clone())
+      .or_insert_with(|| {
+        key_order.push(key);
+        Vec::new()
+      })
+      .push(item);
+  }
+
+  for group in groups.values_mut() {
+    group.shuffle(rng);
+  }
+  // Randomise tie-breaking between equally sized groups
+  key_order.shuffle(rng);
+
+  let mut previous = result.first().map(&key_of);
+  loop {
+    let next_key = key_order
+      .iter()
+      .filter(|key| groups.get(*key).is_some_and(|group| !group.is_empty()))
+      .max_by_key(|key| (Some(*key) != previous.as_ref(), groups[*key].len()))
+      .cloned();
+
+    let Some(key) = next_key else {
+      break;
+    };
+    if let Some(item) = groups.get_mut(&key).and_then(Vec::pop) {
+      result.push(item);
+    }
+    previous = Some(key);
+  }
+
+  result
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::rngs::StdRng;
+  use rand::SeedableRng;
+
+  fn tracks() -> Vec<(&'static str, u32)> {
+    let mut tracks = Vec::new();
+    for i in 0..6 {
+      tracks.push(("a", i));
+    }
+    for i in 0..3 {
+      tracks.push(("b", i));
+    }
+    for i in 0..3 {
+      tracks.push(("c", i));
+    }
+    tracks
+  }
+
+  #[test]
+  fn no_back_to_back_artists_when_avoidable() {
+    for seed in 0..20 {
+      let mut rng = StdRng::seed_from_u64(seed);
+      let shuffled = fair_shuffle(tracks(), None, |t| t.0, &mut rng);
+      assert_eq!(shuffled.len(), 12);
+      for pair in shuffled.windows(2) {
+        assert_ne!(pair[0].0, pair[1].0, "seed {} gave {:?}", seed, shuffled);
+      }
+    }
+  }
+
+  #[test]
+  fn start_item_plays_first() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let shuffled = fair_shuffle(tracks(), Some(7), |t| t.0, &mut rng);
+    assert_eq!(shuffled[0], ("b", 1));
+    assert_ne!(shuffled[1].0, "b");
+
+    let mut sorted = shuffled.clone();
+    sorted.sort();
+    let mut expected = tracks();
+    expected.sort();
+    assert_eq!(sorted, expected);
+  }
+
+  #[test]
+  fn single_artist_keeps_every_track() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let items = vec![("a", 0), ("a", 1), ("a", 2)];
+    assert_eq!(fair_shuffle(items, None, |t| t.0, &mut rng).len(), 3);
+  }
+}
@@ -0,0 +1,97 @@
+//! A local log of skipped and finished tracks
+//!
+//! When `behavior.log_skip_stats` names a file, every skip and every track that
+//! plays to the end is appended to it as a JSON line. Nothing is sent anywhere,
+//! and failing to write only loses that line.
+
+use serde::Serialize;
+use std::{fs, io::Write, path::Path};
+
+/// Skipping before this far into a track counts as skipping it
+pub const SKIP_THRESHOLD_MS: u128 = 30_000;
+
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+  Skipped,
+  Completed,
+}
+
+impl Outcome {
+  /// How a track left by the user at `position_ms` ended
+  pub fn for_skip_at(position_ms: u128) -> Self {
+    if position_ms < SKIP_THRESHOLD_MS {
+      Outcome::Skipped
+    } else {
+      Outcome::Completed
+    }
+  }
+}
+
+#[derive(Serialize)]
+struct Entry<'a> {
+  /// Seconds since the Unix epoch
+  timestamp: i64,
+  track_id: &'a str,
+  outcome: Outcome,
+  position_ms: u128,
+}
+
+fn line(track_id: &str, outcome: Outcome, position_ms: u128, timestamp: i64) -> String {
+  let entry = Entry {
+    timestamp,
+    track_id,
+    outcome,
+    position_ms,
+  };
+  serde_json::to_string(&entry).unwrap_or_default()
+}
+
+/// Append an entry for `track_id` to the log at `path`
+pub fn record(path: &Path, track_id: &str, outcome: Outcome, position_ms: u128) {
+  let line = line(
+    track_id,
+    outcome,
+    position_ms,
+    chrono::Utc::now().timestamp(),
+  );
+  if let Some(parent) = path.parent() {
+    let _ = fs::create_dir_all(parent);
+  }
+  if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(path) {
+    let _ = writeln!(file, "{}", line);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn early_skips_and_finished_tracks_are_told_apart() {
+    assert_eq!(Outcome::for_skip_at(12_000), Outcome::Skipped);
+    assert_eq!(Outcome::for_skip_at(SKIP_THRESHOLD_MS), Outcome::Completed);
+    assert_eq!(
+      line("spotify:track:abc", Outcome::Skipped, 12_000, 1_700_000_000),
+      r#"{"timestamp":1700000000,"track_id":"spotify:track:abc","outcome":"skipped","position_ms":12000}"#
+    );
+  }
+
+  #[test]
+  fn entries_are_appended_one_per_line() {
+    let path = std::env::temp_dir()
+      .join(format!("spotatui-skip-stats-{}", std::process::id()))
+      .join("skips.jsonl");
+    let _ = fs::remove_file(&path);
+
+    record(&path, "spotify:track:a", Outcome::Skipped, 1_000);
+    record(&path, "spotify:track:b", Outcome::Completed, 200_000);
+
+    let written = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = written.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains(r#""track_id":"spotify:track:a","outcome":"skipped""#));
+    assert!(lines[1].contains(r#""outcome":"completed""#));
+    let _ = fs::remove_dir_all(path.parent().unwrap());
+  }
+}
@@ -0,0 +1,151 @@
+use crate::infra::network::IoEvent;
+use crate::tui::handlers::canonical_spotify_uri;
+use rspotify::model::idtypes::{
+  AlbumId, ArtistId, EpisodeId, PlayContextId, PlayableId, PlaylistId, ShowId, TrackId,
+};
+use rspotify::prelude::Id;
+
+/// Something handed to spotatui on launch (`--play-on-start` or `--stdin-queue`)
+#[derive(Clone, Debug, PartialEq)]
+pub enum StartupItem {
+  Context(PlayContextId<'static>),
+  Playable(PlayableId<'static>),
+}
+
+/// Parse a Spotify URI or open.spotify.com link into something playable
+pub fn parse_startup_item(input: &str) -> Option<StartupItem> {
+  let uri = canonical_spotify_uri(input)?;
+  let item = match uri.split(':').nth(1)? {
+    "track" => StartupItem::Playable(PlayableId::Track(
+      TrackId::from_uri(&uri).ok()?.into_static(),
+    )),
+    "episode" => StartupItem::Playable(PlayableId::Episode(
+      EpisodeId::from_uri(&uri).ok()?.into_static(),
+    )),
+    "album" => StartupItem::Context(PlayContextId::Album(
+      AlbumId::from_uri(&uri).ok()?.into_static(),
+    )),
+    "artist" => StartupItem::Context(PlayContextId::Artist(
+      ArtistId::from_uri(&uri).ok()?.into_static(),
+    )),
+    "playlist" => StartupItem::Context(PlayContextId::Playlist(
+      PlaylistId::from_uri(&uri).ok()?.into_static(),
+    )),
+    "show" => StartupItem::Context(PlayContextId::Show(
+      ShowId::from_uri(&uri).ok()?.into_static(),
+    )),
+    _ => return None,
+  };
+  Some(item)
+}
+
+/// Parse launch inputs in order, returning the valid items and the skipped inputs.
+/// Blank lines are ignored.
+pub fn parse_startup_items<I, S>(inputs: I) -> (Vec<StartupItem>, Vec<String>)
+where
+  I: IntoIterator<Item = S>,
+  S: AsRef<str>,
+{
+  let mut items = Vec::new();
+  let mut skipped = Vec::new();
+  for input in inputs {
+    let input = input.as_ref().trim();
+    if input.is_empty() {
+      continue;
+    }
+    match parse_startup_item(input) {
+      Some(item) => items.push(item),
+      None => skipped.push(input.to_string()),
+    }
+  }
+  (items, skipped)
+}
+
+/// Events that start the first item and queue the rest.
+///
+/// Only tracks and episodes can be queued, so later albums, artists, playlists
+/// and shows are returned as skipped instead.
+pub fn startup_events(items: Vec<StartupItem>) -> (Vec<IoEvent>, Vec<String>) {
+  let mut items = items.into_iter();
+  let mut events = Vec::new();
+  let mut skipped = Vec::new();
+
+  match items.next() {
+    Some(StartupItem::Context(context)) => {
+      events.push(IoEvent::StartPlayback(Some(context), None, None));
+    }
+    Some(StartupItem::Playable(playable)) => {
+      events.push(IoEvent::StartPlayback(None, Some(vec![playable]), None));
+    }
+    None => return (events, skipped),
+  }
+
+  for item in items {
+    match item {
+      StartupItem::Playable(playable) => events.push(IoEvent::AddItemToQueue(playable)),
+      StartupItem::Context(context) => {
+        skipped.push(format!("{} (cannot be queued)", context.uri()))
+      }
+    }
+  }
+  (events, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TRACK: &str = "spotify:track:4uLU6hMCjMI75M1A2tKUQC";
+  const EPISODE_URL: &str = "https://open.spotify.com/episode/512ojhOuo1ktJprKbVcKyQ?si=abc";
+  const PLAYLIST: &str = "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M";
+
+  #[test]
+  fn parses_uris_and_links_and_skips_the_rest() {
+    let (items, skipped) = parse_startup_items([
+      PLAYLIST,
+      "",
+      TRACK,
+      "not a uri",
+      EPISODE_URL,
+      "spotify:track:",
+    ]);
+
+    assert_eq!(items.len(), 3);
+    assert!(matches!(
+      items[0],
+      StartupItem::Context(PlayContextId::Playlist(_))
+    ));
+    assert!(matches!(
+      items[1],
+      StartupItem::Playable(PlayableId::Track(_))
+    ));
+    assert!(matches!(
+      items[2],
+      StartupItem::Playable(PlayableId::Episode(_))
+    ));
+    assert_eq!(skipped, vec!["not a uri", "spotify:track:"]);
+  }
+
+  #[test]
+  fn first_item_plays_and_the_rest_are_queued() {
+    let (items, _) = parse_startup_items([PLAYLIST, TRACK, PLAYLIST, EPISODE_URL]);
+    let (events, skipped) = startup_events(items);
+
+    assert_eq!(events.len(), 3);
+    assert!(matches!(
+      &events[0],
+      IoEvent::StartPlayback(Some(PlayContextId::Playlist(_)), None, None)
+    ));
+    assert!(matches!(
+      &events[1],
+      IoEvent::AddItemToQueue(PlayableId::Track(_))
+    ));
+    assert!(matches!(
+      &events[2],
+      IoEvent::AddItemToQueue(PlayableId::Episode(_))
+    ));
+    assert_eq!(skipped, vec![format!("{} (cannot be queued)", PLAYLIST)]);
+
+    assert!(startup_events(Vec::new()).0.is_empty());
+  }
+}
@@ -1,15 +1,23 @@
+use crate::core::app::{ActiveBlock, DiscoverTimeRange, HomeMode, SearchResultBlock};
 use crate::event::Key;
 use anyhow::{anyhow, Result};
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
+use rspotify::model::enums::RepeatState;
 use serde::{Deserialize, Serialize};
 use std::{
-  fs,
+  fmt, fs,
   path::{Path, PathBuf},
 };
 
 const FILE_NAME: &str = "config.yml";
 const CONFIG_DIR: &str = ".config";
 const APP_CONFIG_DIR: &str = "spotatui";
+/// Longest allowed pause/play fade
+pub const MAX_FADE_MS: u64 = 2000;
+/// Most results Spotify returns per type in one search request
+pub const MAX_SEARCH_LIMIT: u32 = 50;
+/// Narrowest terminal the wide layout can be set to start at
+pub const MIN_WIDE_LAYOUT_WIDTH: u16 = 80;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UserTheme {
@@ -105,6 +113,7 @@ pub enum ThemePreset {
   Gruvbox,
   GruvboxLight,
   CatppuccinMocha,
+  HighContrast,
   Custom, // When user has manually customized colors
 }
 
@@ -122,6 +131,7 @@ impl ThemePreset {
       ThemePreset::Gruvbox,
       ThemePreset::GruvboxLight,
       ThemePreset::CatppuccinMocha,
+      ThemePreset::HighContrast,
     ]
   }
 
@@ -138,6 +148,7 @@ impl ThemePreset {
       ThemePreset::Gruvbox => "Gruvbox",
       ThemePreset::GruvboxLight => "Gruvbox Light",
       ThemePreset::CatppuccinMocha => "Catppuccin Mocha",
+      ThemePreset::HighContrast => "High Contrast",
       ThemePreset::Custom => "Custom",
     }
   }
@@ -155,6 +166,7 @@ impl ThemePreset {
       "Gruvbox" => ThemePreset::Gruvbox,
       "Gruvbox Light" => ThemePreset::GruvboxLight,
       "Catppuccin Mocha" => ThemePreset::CatppuccinMocha,
+      "High Contrast" => ThemePreset::HighContrast,
       _ => ThemePreset::Custom,
     }
   }
@@ -381,6 +393,27 @@ impl ThemePreset {
         header: Color::Rgb(29, 185, 84),             // Spotify Green
         highlighted_lyrics: Color::Rgb(29, 185, 84), // Spotify Green
       },
+      // Pure black and white with saturated accents, for low vision
+      ThemePreset::HighContrast => Theme {
+        analysis_bar: Color::Rgb(255, 255, 0),
+        analysis_bar_text: Color::Rgb(255, 255, 255),
+        active: Color::Rgb(255, 255, 0),   // Yellow
+        banner: Color::Rgb(255, 255, 255), // White
+        error_border: Color::Rgb(255, 64, 64),
+        error_text: Color::Rgb(255, 64, 64),
+        hint: Color::Rgb(0, 255, 255),    // Cyan
+        hovered: Color::Rgb(0, 255, 255), // Cyan
+        inactive: Color::Rgb(192, 192, 192),
+        playbar_background: Color::Rgb(0, 0, 0),
+        playbar_progress: Color::Rgb(255, 255, 0),
+        playbar_progress_text: Color::Rgb(0, 0, 0),
+        playbar_text: Color::Rgb(255, 255, 255),
+        selected: Color::Rgb(255, 255, 0), // Yellow
+        text: Color::Rgb(255, 255, 255),
+        background: Color::Rgb(0, 0, 0),
+        header: Color::Rgb(255, 255, 255),
+        highlighted_lyrics: Color::Rgb(255, 255, 0),
+      },
       ThemePreset::Custom => Theme::default(), // Won't be used directly
     }
   }
@@ -419,6 +452,238 @@ impl VisualizerStyle {
   }
 }
 
+/// A block shown in the search results view and its share of the screen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SearchBlockLayout {
+  pub block: SearchResultBlock,
+  pub weight: u16,
+}
+
+/// Config names of the search result blocks, in their default order
+const SEARCH_BLOCK_NAMES: [(&str, SearchResultBlock); 5] = [
+  ("songs", SearchResultBlock::SongSearch),
+  ("artists", SearchResultBlock::ArtistSearch),
+  ("albums", SearchResultBlock::AlbumSearch),
+  ("playlists", SearchResultBlock::PlaylistSearch),
+  ("podcasts", SearchResultBlock::ShowSearch),
+];
+
+pub fn default_search_blocks() -> Vec<SearchBlockLayout> {
+  SEARCH_BLOCK_NAMES
+    .iter()
+    .map(|(_, block)| SearchBlockLayout {
+      block: *block,
+      weight: 1,
+    })
+    .collect()
+}
+
+/// Parse `search_blocks` entries such as `songs` or `artists:2`
+fn parse_search_blocks(entries: &[String]) -> Result<Vec<SearchBlockLayout>> {
+  let mut blocks: Vec<SearchBlockLayout> = Vec::new();
+  for entry in entries {
+    let (name, weight) = match entry.split_once(':') {
+      Some((name, weight)) => {
+        let weight = weight
+          .trim()
+          .parse::<u16>()
+          .ok()
+          .filter(|weight| (1..=10).contains(weight))
+          .ok_or_else(|| {
+            anyhow!(
+              "Search block weight in \"{}\" must be between 1 and 10",
+              entry
+            )
+          })?;
+        (name.trim(), weight)
+      }
+      None => (entry.trim(), 1),
+    };
+    let block = SEARCH_BLOCK_NAMES
+      .iter()
+      .find(|(block_name, _)| block_name.eq_ignore_ascii_case(name))
+      .map(|(_, block)| *block)
+      .ok_or_else(|| {
+        anyhow!(
+          "Unknown search block \"{}\", expected one of songs, artists, albums, playlists, podcasts",
+          name
+        )
+      })?;
+    if blocks.iter().any(|existing| existing.block == block) {
+      return Err(anyhow!(
+        "Search block \"{}\" is listed more than once",
+        name
+      ));
+    }
+    blocks.push(SearchBlockLayout { block, weight });
+  }
+  if blocks.is_empty() {
+    return Err(anyhow!("search_blocks must list at least one block"));
+  }
+  Ok(blocks)
+}
+
+fn search_block_entry(layout: &SearchBlockLayout) -> String {
+  let name = SEARCH_BLOCK_NAMES
+    .iter()
+    .find(|(_, block)| *block == layout.block)
+    .map(|(name, _)| *name)
+    .unwrap_or_default();
+  if layout.weight == 1 {
+    name.to_string()
+  } else {
+    format!("{}:{}", name, layout.weight)
+  }
+}
+
+/// How many colors the terminal can show
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ColorMode {
+  /// Detect from `$COLORTERM` and `$TERM` at startup
+  #[default]
+  Auto,
+  TrueColor,
+  Ansi256,
+  Ansi16,
+  /// No colors at all, emphasis comes from bold, dim and reverse instead
+  Monochrome,
+}
+
+impl ColorMode {
+  pub fn name(&self) -> &'static str {
+    match self {
+      ColorMode::Auto => "auto",
+      ColorMode::TrueColor => "truecolor",
+      ColorMode::Ansi256 => "256",
+      ColorMode::Ansi16 => "16",
+      ColorMode::Monochrome => "monochrome",
+    }
+  }
+}
+
+impl std::str::FromStr for ColorMode {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s.trim().to_ascii_lowercase().as_str() {
+      "auto" => Ok(ColorMode::Auto),
+      "truecolor" | "24bit" => Ok(ColorMode::TrueColor),
+      "256" => Ok(ColorMode::Ansi256),
+      "16" => Ok(ColorMode::Ansi16),
+      "monochrome" | "none" => Ok(ColorMode::Monochrome),
+      other => Err(anyhow!(
+        "Unknown color mode \"{}\", expected auto, truecolor, 256, 16 or monochrome",
+        other
+      )),
+    }
+  }
+}
+
+impl Serialize for ColorMode {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(self.name())
+  }
+}
+
+impl<'de> Deserialize<'de> for ColorMode {
+  fn deserialize<D: serde::Deserializer<'de>>(
+    deserializer: D,
+  ) -> std::result::Result<Self, D::Error> {
+    // `256` and `16` are usually written unquoted, which YAML reads as numbers
+    let name = match serde_yaml::Value::deserialize(deserializer)? {
+      serde_yaml::Value::String(name) => name,
+      serde_yaml::Value::Number(number) => number.to_string(),
+      _ => {
+        return Err(serde::de::Error::custom(
+          "color_mode must be auto, truecolor, 256, 16 or monochrome",
+        ))
+      }
+    };
+    name.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+/// Order Liked Songs play in when started from the library
+#[derive(Clone, Copy, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LikedPlayOrder {
+  /// The library's own order, most recently liked first
+  #[default]
+  AddedNewest,
+  AddedOldest,
+  Alphabetical,
+  Shuffle,
+}
+
+impl LikedPlayOrder {
+  pub fn all() -> &'static [LikedPlayOrder] {
+    &[
+      LikedPlayOrder::AddedNewest,
+      LikedPlayOrder::AddedOldest,
+      LikedPlayOrder::Alphabetical,
+      LikedPlayOrder::Shuffle,
+    ]
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      LikedPlayOrder::AddedNewest => "added_newest",
+      LikedPlayOrder::AddedOldest => "added_oldest",
+      LikedPlayOrder::Alphabetical => "alphabetical",
+      LikedPlayOrder::Shuffle => "shuffle",
+    }
+  }
+}
+
+impl std::str::FromStr for LikedPlayOrder {
+  type Err = anyhow::Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    let name = s.trim().to_ascii_lowercase();
+    LikedPlayOrder::all()
+      .iter()
+      .find(|order| order.name() == name)
+      .copied()
+      .ok_or_else(|| {
+        anyhow!(
+          "Unknown liked_play_order \"{}\", expected added_newest, added_oldest, alphabetical or shuffle",
+          s
+        )
+      })
+  }
+}
+
+/// What the spacebar does outside of text input
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum SpaceAction {
+  #[default]
+  TogglePlayback,
+  LikeTrack,
+  AddToQueue,
+}
+
+impl SpaceAction {
+  pub fn as_config_str(&self) -> &'static str {
+    match self {
+      SpaceAction::TogglePlayback => "toggle_playback",
+      SpaceAction::LikeTrack => "like_track",
+      SpaceAction::AddToQueue => "add_to_queue",
+    }
+  }
+
+  pub fn from_config_str(value: &str) -> Result<Self> {
+    match value.trim() {
+      "toggle_playback" => Ok(SpaceAction::TogglePlayback),
+      "like_track" => Ok(SpaceAction::LikeTrack),
+      "add_to_queue" => Ok(SpaceAction::AddToQueue),
+      other => Err(anyhow!(
+        "Invalid space_action '{}', expected one of: toggle_playback, like_track, add_to_queue",
+        other
+      )),
+    }
+  }
+}
+
 fn parse_key(key: String) -> Result<Key> {
   fn get_single_char(string: &str) -> char {
     match string.chars().next() {
@@ -429,6 +694,18 @@ fn parse_key(key: String) -> Result<Key> {
 
   match key.len() {
     1 => Ok(Key::Char(get_single_char(key.as_str()))),
+    _ if key.contains(' ') => {
+      let chord = key
+        .split_once(' ')
+        .map(|(first, second)| (parse_key(first.to_string()), parse_key(second.to_string())));
+      match chord {
+        Some((Ok(Key::Char(first)), Ok(Key::Char(second)))) => Ok(Key::Chord(first, second)),
+        _ => Err(anyhow!(
+          "Chords can only combine two plain keys, \"{}\" is not one",
+          key
+        )),
+      }
+    }
     _ => {
       let sections: Vec<&str> = key.split('-').collect();
 
@@ -477,9 +754,130 @@ fn parse_key(key: String) -> Result<Key> {
   }
 }
 
-/// Public version of parse_key for use in app.rs
-pub fn parse_key_public(key: String) -> Result<Key> {
-  parse_key(key)
+/// The config file spelling of `key`, the inverse of [`parse_key`]
+fn key_to_config_string(key: Key) -> String {
+  match key {
+    Key::Char(' ') => "space".to_string(),
+    Key::Char(c) => c.to_string(),
+    Key::Ctrl(c) => format!("ctrl-{}", c),
+    Key::Alt(c) => format!("alt-{}", c),
+    Key::Chord(first, second) => [first, second]
+      .map(|c| match c {
+        ' ' => "space".to_string(),
+        c => c.to_string(),
+      })
+      .join(" "),
+    Key::Enter => "enter".to_string(),
+    Key::Tab => "tab".to_string(),
+    Key::Esc => "esc".to_string(),
+    Key::Backspace => "backspace".to_string(),
+    Key::Delete => "del".to_string(),
+    Key::Left => "left".to_string(),
+    Key::Right => "right".to_string(),
+    Key::Up => "up".to_string(),
+    Key::Down => "down".to_string(),
+    Key::Home => "home".to_string(),
+    Key::End => "end".to_string(),
+    Key::Ins => "ins".to_string(),
+    Key::PageUp => "pageup".to_string(),
+    Key::PageDown => "pagedown".to_string(),
+    Key::F0 => "f0".to_string(),
+    Key::F1 => "f1".to_string(),
+    Key::F2 => "f2".to_string(),
+    Key::F3 => "f3".to_string(),
+    Key::F4 => "f4".to_string(),
+    Key::F5 => "f5".to_string(),
+    Key::F6 => "f6".to_string(),
+    Key::F7 => "f7".to_string(),
+    Key::F8 => "f8".to_string(),
+    Key::F9 => "f9".to_string(),
+    Key::F10 => "f10".to_string(),
+    Key::F11 => "f11".to_string(),
+    Key::F12 => "f12".to_string(),
+    _ => "unknown".to_string(),
+  }
+}
+
+/// Separates the keys of one action on the settings page
+pub const KEY_LIST_SEPARATOR: &str = " | ";
+
+/// Keys as shown on the settings page, like `space | p`
+pub fn parse_key_list_public(keys: &str) -> Result<KeyList> {
+  parse_keys(KeyConfig::Many(
+    keys.split(KEY_LIST_SEPARATOR).map(str::to_string).collect(),
+  ))
+}
+
+fn parse_keys(config: KeyConfig) -> Result<KeyList> {
+  let keys = match config {
+    KeyConfig::One(key) => vec![parse_key(key)?],
+    KeyConfig::Many(keys) => keys.into_iter().map(parse_key).collect::<Result<_>>()?,
+  };
+  if keys.is_empty() {
+    return Err(anyhow!("A key binding needs at least one key"));
+  }
+  Ok(KeyList(keys))
+}
+
+/// A binding in the config file, either `p` or `[space, p]`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyConfig {
+  One(String),
+  Many(Vec<String>),
+}
+
+impl From<&str> for KeyConfig {
+  fn from(key: &str) -> Self {
+    KeyConfig::One(key.to_string())
+  }
+}
+
+/// Every key that triggers one action. Never empty; the first key is the one
+/// shown where there's only room for one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyList(Vec<Key>);
+
+impl KeyList {
+  pub fn primary(&self) -> Key {
+    self.0[0]
+  }
+
+  pub fn keys(&self) -> &[Key] {
+    &self.0
+  }
+
+  fn to_config(&self) -> KeyConfig {
+    match self.0.as_slice() {
+      [key] => KeyConfig::One(key_to_config_string(*key)),
+      keys => KeyConfig::Many(keys.iter().map(|key| key_to_config_string(*key)).collect()),
+    }
+  }
+}
+
+impl From<Key> for KeyList {
+  fn from(key: Key) -> Self {
+    KeyList(vec![key])
+  }
+}
+
+/// Lets handlers keep matching with `key == app.user_config.keys.back`
+impl PartialEq<KeyList> for Key {
+  fn eq(&self, keys: &KeyList) -> bool {
+    keys.0.contains(self)
+  }
+}
+
+impl fmt::Display for KeyList {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, key) in self.0.iter().enumerate() {
+      if i > 0 {
+        f.write_str(KEY_LIST_SEPARATOR)?;
+      }
+      write!(f, "{}", key)?;
+    }
+    Ok(())
+  }
 }
 
 fn check_reserved_keys(key: Key) -> Result<()> {
@@ -520,94 +918,517 @@ pub struct UserConfigPaths {
   pub config_file_path: PathBuf,
 }
 
-#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct KeyBindingsString {
-  back: Option<String>,
-  next_page: Option<String>,
-  previous_page: Option<String>,
-  jump_to_start: Option<String>,
-  jump_to_end: Option<String>,
-  jump_to_album: Option<String>,
-  jump_to_artist_album: Option<String>,
-  jump_to_context: Option<String>,
-  manage_devices: Option<String>,
-  decrease_volume: Option<String>,
-  increase_volume: Option<String>,
-  toggle_playback: Option<String>,
-  seek_backwards: Option<String>,
-  seek_forwards: Option<String>,
-  next_track: Option<String>,
-  previous_track: Option<String>,
-  help: Option<String>,
-  shuffle: Option<String>,
-  repeat: Option<String>,
-  search: Option<String>,
-  submit: Option<String>,
-  copy_song_url: Option<String>,
-  copy_album_url: Option<String>,
-  audio_analysis: Option<String>,
-  basic_view: Option<String>,
-  add_item_to_queue: Option<String>,
-  open_settings: Option<String>,
-  save_settings: Option<String>,
+/// One configurable action, as listed in the help menu and on the settings page
+pub struct KeyBindingInfo {
+  /// Field name in [`KeyBindings`] and in the `keybindings` config section
+  pub name: &'static str,
+  /// Name shown on the settings page
+  pub label: &'static str,
+  /// Help section id, looked up as `help.section.<section>`
+  pub section: &'static str,
+  /// Blocks whose key hints footer lists this action, with the label shown there
+  pub hints: &'static [(ActiveBlock, &'static str)],
 }
 
-#[derive(Clone)]
-pub struct KeyBindings {
-  pub back: Key,
-  pub next_page: Key,
-  pub previous_page: Key,
-  pub jump_to_start: Key,
-  pub jump_to_end: Key,
-  pub jump_to_album: Key,
-  pub jump_to_artist_album: Key,
-  pub jump_to_context: Key,
-  pub manage_devices: Key,
-  pub decrease_volume: Key,
-  pub increase_volume: Key,
-  pub toggle_playback: Key,
-  pub seek_backwards: Key,
-  pub seek_forwards: Key,
-  pub next_track: Key,
-  pub previous_track: Key,
-  pub help: Key,
-  pub shuffle: Key,
-  pub repeat: Key,
-  pub search: Key,
-  pub submit: Key,
-  pub copy_song_url: Key,
-  pub copy_album_url: Key,
-  pub audio_analysis: Key,
-  pub basic_view: Key,
-  pub add_item_to_queue: Key,
-  pub open_settings: Key,
-  pub save_settings: Key,
+/// Declares every configurable binding once. The config struct, the bindings
+/// themselves and [`KEY_BINDINGS`] are all generated from the same list, and the
+/// help menu, settings page and key hints footer walk [`KEY_BINDINGS`], so a new
+/// action is added here and nowhere else (apart from its `help.<name>` string and
+/// its handler).
+macro_rules! key_bindings {
+  ($(
+    $name:ident: $default:expr, $label:literal, $section:literal
+    $(, hints: [$($block:ident => $hint:literal),* $(,)?])?;
+  )*) => {
+    #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct KeyBindingsString {
+      $($name: Option<KeyConfig>,)*
+      space_action: Option<String>,
+    }
+
+    #[derive(Clone)]
+    pub struct KeyBindings {
+      $(pub $name: KeyList,)*
+      pub space_action: SpaceAction,
+    }
+
+    /// Every configurable binding, in help and settings order
+    pub const KEY_BINDINGS: &[KeyBindingInfo] = &[
+      $(KeyBindingInfo {
+        name: stringify!($name),
+        label: $label,
+        section: $section,
+        hints: &[$($((ActiveBlock::$block, $hint),)*)?],
+      },)*
+    ];
+
+    impl Default for KeyBindings {
+      fn default() -> Self {
+        KeyBindings {
+          $($name: KeyList::from($default),)*
+          space_action: SpaceAction::default(),
+        }
+      }
+    }
+
+    impl KeyBindings {
+      /// The keys bound to the action `name`, as named in the config
+      pub fn get(&self, name: &str) -> Option<&KeyList> {
+        match name {
+          $(stringify!($name) => Some(&self.$name),)*
+          _ => None,
+        }
+      }
+
+      pub fn get_mut(&mut self, name: &str) -> Option<&mut KeyList> {
+        match name {
+          $(stringify!($name) => Some(&mut self.$name),)*
+          _ => None,
+        }
+      }
+
+      fn load(&mut self, config: KeyBindingsString) -> Result<()> {
+        $(if let Some(keys) = config.$name {
+          self.$name = parse_keys(keys)?;
+        })*
+        if let Some(space_action) = config.space_action {
+          self.space_action = SpaceAction::from_config_str(&space_action)?;
+        }
+        Ok(())
+      }
+
+      fn to_config(&self) -> KeyBindingsString {
+        KeyBindingsString {
+          $($name: Some(self.$name.to_config()),)*
+          space_action: Some(self.space_action.as_config_str().to_string()),
+        }
+      }
+    }
+  };
+}
+
+key_bindings! {
+  next_page: Key::Ctrl('d'), "Next Page", "pagination";
+  previous_page: Key::Ctrl('u'), "Previous Page", "pagination";
+  jump_to_start: Key::Ctrl('a'), "Jump to Start", "pagination";
+  jump_to_end: Key::Ctrl('e'), "Jump to End", "pagination";
+  jump_to_playing: Key::Ctrl('o'), "Jump to Playing Row", "pagination";
+  jump_to_album: Key::Char('a'), "Jump to Album", "general";
+  jump_to_artist_album: Key::Char('A'), "Jump to Artist", "general";
+  jump_to_context: Key::Char('o'), "Jump to Context", "general";
+  increase_volume: Key::Char('+'), "Increase Volume", "general", hints: [NowPlaying => "Vol+"];
+  decrease_volume: Key::Char('-'), "Decrease Volume", "general", hints: [NowPlaying => "Vol-"];
+  next_track: Key::Char('n'), "Next Track", "general", hints: [NowPlaying => "Next"];
+  previous_track: Key::Char('p'), "Previous Track", "general", hints: [NowPlaying => "Previous"];
+  seek_backwards: Key::Char('<'), "Seek Backwards", "general", hints: [NowPlaying => "Back"];
+  seek_backwards_large: Key::Alt('<'), "Seek Backwards (Large)", "general";
+  seek_forwards: Key::Char('>'), "Seek Forwards", "general", hints: [NowPlaying => "Forward"];
+  seek_forwards_large: Key::Alt('>'), "Seek Forwards (Large)", "general";
+  shuffle: Key::Ctrl('s'), "Shuffle", "general", hints: [NowPlaying => "Shuffle"];
+  copy_song_url: Key::Char('c'), "Copy Song URL", "general";
+  copy_album_url: Key::Char('C'), "Copy Album URL", "general";
+  copy_song_url_at_position: Key::Alt('c'), "Copy Song URL at Position", "general";
+  repeat: Key::Ctrl('r'), "Repeat", "general", hints: [NowPlaying => "Repeat"];
+  repeat_one_or_all: Key::Alt('l'), "Repeat One/All", "general";
+  search: Key::Char('/'), "Search", "general", hints: [MyPlaylists => "Filter"];
+  toggle_playback: Key::Char(' '), "Toggle Playback", "general", hints: [NowPlaying => "Play/Pause"];
+  audio_analysis: Key::Char('v'), "Audio Analysis", "general";
+  basic_view: Key::Char('B'), "Basic View", "general";
+  now_playing: Key::Alt('n'), "Now Playing", "general";
+  tv_view: Key::Alt('v'), "TV View", "general";
+  open_queue: Key::Char('Q'), "Open Queue", "general";
+  open_discover: Key::Char('E'), "Open Discover", "general";
+  open_recently_played: Key::Char('R'), "Open Recently Played", "general";
+  decrease_seek_step: Key::Char('{'), "Decrease Seek Step", "general";
+  increase_seek_step: Key::Char('}'), "Increase Seek Step", "general";
+  toggle_prevent_sleep: Key::Alt('w'), "Toggle Keep Awake", "general";
+  library_recommendations: Key::Alt('r'), "Library Recommendations", "general";
+  ab_loop: Key::Alt('b'), "A-B Repeat", "general";
+  cycle_discover_time_range: Key::Alt('t'), "Cycle Discover Time Range", "general";
+  search_library: Key::Alt('f'), "Search Library", "general";
+  cache_playlist_offline: Key::Alt('o'), "Cache Playlist Offline", "playlist_track_table";
+  toggle_enhance: Key::Alt('e'), "Toggle Enhance", "playlist_track_table";
+  back: Key::Char('q'), "Back", "general";
+  manage_devices: Key::Char('d'), "Manage Devices", "general";
+  toggle_native_playback: Key::Alt('d'), "Toggle Native Playback", "general";
+  help: Key::Char('?'), "Help", "general";
+  // On macOS, use Ctrl+, for settings since Alt+, produces ≤ on most keyboard layouts
+  // On other platforms, keep Alt+, for consistency with many apps
+  open_settings: if cfg!(target_os = "macos") {
+    Key::Ctrl(',')
+  } else {
+    Key::Alt(',')
+  }, "Open Settings", "general";
+  save_settings: Key::Alt('s'), "Save Settings", "settings";
+  submit: Key::Enter, "Submit", "selected_block";
+  add_item_to_queue: Key::Char('z'), "Add to Queue", "hovered_over_track", hints: [
+    TrackTable => "Queue",
+    AlbumTracks => "Queue",
+    ArtistBlock => "Queue",
+    RecentlyPlayed => "Queue",
+    SearchResultBlock => "Queue",
+  ];
+  queue_and_skip: Key::Alt('q'), "Queue and Skip", "hovered_over_track";
+}
+
+/// The binding for `name`, if it is a configurable action
+pub fn key_binding_info(name: &str) -> Option<&'static KeyBindingInfo> {
+  KEY_BINDINGS.iter().find(|binding| binding.name == name)
+}
+
+/// Keys that can't be rebound, as (help id, keys, help section id, key hints).
+/// The hints are the blocks whose footer lists the key, like [`KeyBindingInfo::hints`].
+#[allow(clippy::type_complexity)]
+pub const FIXED_KEYS: &[(&str, &str, &str, &[(ActiveBlock, &str)])] = &[
+  (
+    "home_toggle_stats",
+    "m",
+    "home",
+    &[(ActiveBlock::Home, "Changelog/Stats")],
+  ),
+  (
+    "move_selection_left",
+    "h | <Left Arrow Key> | <Ctrl+b>",
+    "general",
+    &[],
+  ),
+  (
+    "move_selection_down",
+    "j | <Down Arrow Key> | <Ctrl+n>",
+    "general",
+    &[],
+  ),
+  (
+    "move_selection_up",
+    "k | <Up Arrow Key> | <Ctrl+p>",
+    "general",
+    &[],
+  ),
+  (
+    "move_selection_right",
+    "l | <Right Arrow Key> | <Ctrl+f>",
+    "general",
+    &[],
+  ),
+  ("move_selection_top", "H", "general", &[]),
+  ("move_selection_middle", "M", "general", &[]),
+  ("move_selection_bottom", "L", "general", &[]),
+  (
+    "enter_active_mode",
+    "<Enter>",
+    "general",
+    &[
+      (ActiveBlock::Empty, "Focus"),
+      (ActiveBlock::Library, "Open"),
+      (ActiveBlock::Discover, "Open"),
+      (ActiveBlock::MyPlaylists, "Open"),
+      (ActiveBlock::TrackTable, "Play"),
+      (ActiveBlock::AlbumTracks, "Play"),
+      (ActiveBlock::AlbumList, "Open"),
+      (ActiveBlock::Artists, "Open"),
+      (ActiveBlock::ArtistBlock, "Open"),
+      (ActiveBlock::Podcasts, "Open"),
+      (ActiveBlock::EpisodeTable, "Play"),
+      (ActiveBlock::RecentlyPlayed, "Play"),
+      (ActiveBlock::SearchResultBlock, "Open"),
+      (ActiveBlock::SelectDevice, "Play here"),
+    ],
+  ),
+  ("lyrics_only", "f", "lyrics_view", &[]),
+  ("enter_hover_mode", "<Esc>", "selected_block", &[]),
+  (
+    "save_track",
+    "s",
+    "selected_block",
+    &[
+      (ActiveBlock::TrackTable, "Like"),
+      (ActiveBlock::AlbumTracks, "Like"),
+      (ActiveBlock::RecentlyPlayed, "Like"),
+      (ActiveBlock::EpisodeTable, "Follow"),
+    ],
+  ),
+  (
+    "add_to_playlist",
+    "w",
+    "track_table",
+    &[(ActiveBlock::TrackTable, "Add to playlist")],
+  ),
+  ("add_playing_to_playlist", "w", "playbar", &[]),
+  ("quick_add_to_playlist", "W", "global", &[]),
+  ("remove_from_playlist", "x", "playlist_track_table", &[]),
+  ("play_recommendations", "r", "selected_block", &[]),
+  (
+    "jump_to_search_block",
+    "1-5",
+    "search_result",
+    &[(ActiveBlock::SearchResultBlock, "Jump to block")],
+  ),
+  (
+    "play_artist",
+    "e",
+    "library_artists",
+    &[(ActiveBlock::Artists, "Play")],
+  ),
+  (
+    "unfollow_artist",
+    "D",
+    "library_artists",
+    &[(ActiveBlock::Artists, "Unfollow")],
+  ),
+  (
+    "play_top_tracks",
+    "e",
+    "artist_top_tracks",
+    &[(ActiveBlock::ArtistBlock, "Play all")],
+  ),
+  (
+    "run_search",
+    "<Enter>",
+    "search_input",
+    &[(ActiveBlock::Input, "Search")],
+  ),
+  ("cursor_left", "<Left Arrow Key>", "search_input", &[]),
+  ("cursor_right", "<Right Arrow Key>", "search_input", &[]),
+  ("delete_entire_input", "<Ctrl+l>", "search_input", &[]),
+  ("delete_to_start", "<Ctrl+u>", "search_input", &[]),
+  ("delete_to_end", "<Ctrl+k>", "search_input", &[]),
+  ("delete_previous_word", "<Ctrl+w>", "search_input", &[]),
+  ("input_start", "<Ctrl+a>", "search_input", &[]),
+  ("input_end", "<Ctrl+e>", "search_input", &[]),
+  (
+    "leave_input",
+    "<Esc>",
+    "search_input",
+    &[(ActiveBlock::Input, "Leave input")],
+  ),
+  (
+    "delete_saved_album",
+    "D",
+    "library_albums",
+    &[
+      (ActiveBlock::AlbumList, "Remove"),
+      (ActiveBlock::ArtistBlock, "Remove"),
+    ],
+  ),
+  (
+    "mark_for_removal",
+    "V",
+    "liked_songs_or_albums",
+    &[(ActiveBlock::AlbumList, "Mark")],
+  ),
+  ("remove_marked", "D", "liked_songs_or_albums", &[]),
+  (
+    "delete_saved_playlist",
+    "D",
+    "playlist",
+    &[(ActiveBlock::MyPlaylists, "Delete")],
+  ),
+  ("filter_playlists", "/", "playlist", &[]),
+  (
+    "toggle_playlist_public",
+    "P",
+    "playlist",
+    &[(ActiveBlock::MyPlaylists, "Public")],
+  ),
+  (
+    "toggle_playlist_collaborative",
+    "O",
+    "playlist",
+    &[(ActiveBlock::MyPlaylists, "Collaborative")],
+  ),
+  (
+    "create_playlist",
+    "N",
+    "playlist",
+    &[(ActiveBlock::MyPlaylists, "New")],
+  ),
+  (
+    "rename_playlist",
+    "r",
+    "playlist",
+    &[(ActiveBlock::MyPlaylists, "Rename")],
+  ),
+  (
+    "pin_playlist",
+    "f",
+    "playlist",
+    &[(ActiveBlock::MyPlaylists, "Pin")],
+  ),
+  (
+    "show_owner_playlists",
+    "U",
+    "playlist_search_result",
+    &[
+      (ActiveBlock::MyPlaylists, "Owner"),
+      (ActiveBlock::SearchResultBlock, "Owner"),
+    ],
+  ),
+  (
+    "follow_an_artist_playlist",
+    "w",
+    "search_result",
+    &[
+      (ActiveBlock::ArtistBlock, "Save/Follow"),
+      (ActiveBlock::SearchResultBlock, "Follow/Save"),
+    ],
+  ),
+  (
+    "save_album",
+    "w",
+    "search_result",
+    &[(ActiveBlock::AlbumTracks, "Save album")],
+  ),
+  (
+    "play_random_song",
+    "S",
+    "selected_playlist",
+    &[(ActiveBlock::TrackTable, "Play random")],
+  ),
+  (
+    "unfollow_show",
+    "D",
+    "library_podcasts",
+    &[(ActiveBlock::Podcasts, "Unfollow")],
+  ),
+  (
+    "toggle_episode_order",
+    "S",
+    "selected_show",
+    &[(ActiveBlock::EpisodeTable, "Sort by date")],
+  ),
+  (
+    "open_sort_menu",
+    ",",
+    "track_album_artist_list",
+    &[(ActiveBlock::TrackTable, "Sort")],
+  ),
+  (
+    "refresh_queue",
+    "r",
+    "queue",
+    &[(ActiveBlock::Queue, "Refresh")],
+  ),
+  (
+    "close_now_playing",
+    "<Esc>",
+    "now_playing",
+    &[(ActiveBlock::NowPlaying, "Close")],
+  ),
+  ("reset_setting", "d", "settings", &[]),
+  ("reset_settings_category", "D", "settings", &[]),
+];
+
+impl KeyBindings {
+  fn bound_keys(&self) -> Vec<Key> {
+    KEY_BINDINGS
+      .iter()
+      .filter_map(|binding| self.get(binding.name))
+      .flat_map(|keys| keys.keys().iter().copied())
+      .collect()
+  }
+
+  /// Whether `key` is the first half of a configured chord
+  pub fn starts_chord(&self, key: char) -> bool {
+    self
+      .bound_keys()
+      .iter()
+      .any(|bound| matches!(bound, Key::Chord(first, _) if *first == key))
+  }
+
+  /// Whether `chord` is bound to any action
+  pub fn has_chord(&self, chord: Key) -> bool {
+    matches!(chord, Key::Chord(..)) && self.bound_keys().contains(&chord)
+  }
+
+  /// The first key of a chord is held back until the second one arrives, so it
+  /// can't also trigger an action by itself or replace a navigation key.
+  fn check_chord_collisions(&self) -> Result<()> {
+    let bound = self.bound_keys();
+    for key in bound.iter() {
+      if let Key::Chord(first, _) = *key {
+        check_reserved_keys(Key::Char(first))?;
+        if bound.contains(&Key::Char(first)) {
+          return Err(anyhow!(
+            "The key {} starts the chord \"{}\" and cannot also be bound on its own",
+            Key::Char(first),
+            key
+          ));
+        }
+      }
+    }
+    Ok(())
+  }
 }
 
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BehaviorConfigString {
   pub seek_milliseconds: Option<u32>,
+  pub podcast_seek_milliseconds: Option<u32>,
   pub volume_increment: Option<u8>,
   pub volume_percent: Option<u8>,
   pub tick_rate_milliseconds: Option<u64>,
   pub enable_text_emphasis: Option<bool>,
   pub show_loading_indicator: Option<bool>,
   pub enforce_wide_search_bar: Option<bool>,
+  pub wide_layout_min_width: Option<u16>,
+  pub enable_mouse: Option<bool>,
   pub enable_global_song_count: Option<bool>,
   pub enable_discord_rpc: Option<bool>,
   pub discord_rpc_client_id: Option<String>,
   pub enable_announcements: Option<bool>,
   pub announcement_feed_url: Option<String>,
   pub seen_announcement_ids: Option<Vec<String>>,
+  pub pinned_playlists: Option<Vec<String>>,
   pub shuffle_enabled: Option<bool>,
+  pub fair_shuffle: Option<bool>,
+  pub fade_ms: Option<u64>,
+  pub max_progress_extrapolation_ms: Option<u64>,
+  pub show_queue_length: Option<bool>,
+  pub show_key_hints: Option<bool>,
+  pub default_playlist_public: Option<bool>,
+  pub playlist_auto_refresh_secs: Option<u64>,
+  pub pause_on_exit: Option<bool>,
+  pub warn_duplicate_add: Option<bool>,
+  pub show_track_badges: Option<bool>,
+  pub search_limit_songs: Option<u32>,
+  pub search_limit_albums: Option<u32>,
+  pub search_limit_artists: Option<u32>,
+  pub search_limit_playlists: Option<u32>,
+  pub search_limit_podcasts: Option<u32>,
+  pub search_blocks: Option<Vec<String>>,
+  pub handoff_device: Option<String>,
+  pub log_skip_stats: Option<PathBuf>,
+  pub language: Option<String>,
+  pub podcast_autoplay_next: Option<bool>,
+  pub preview_mode: Option<bool>,
+  pub prevent_sleep_while_playing: Option<bool>,
+  pub monochrome: Option<bool>,
+  pub global_song_count_native_only: Option<bool>,
+  pub liked_play_order: Option<LikedPlayOrder>,
+  pub discover_time_range: Option<DiscoverTimeRange>,
+  pub home_mode: Option<HomeMode>,
+  pub preferred_repeat: Option<RepeatState>,
+  pub resume_episodes: Option<bool>,
+  pub allow_volume_boost: Option<bool>,
+  pub mouse_scroll_lines: Option<u16>,
+  pub home_scroll_step: Option<u16>,
+  pub home_auto_scroll: Option<bool>,
+  pub home_auto_scroll_interval_ms: Option<u64>,
+  pub home_scroll_position: Option<u16>,
+  pub home_scroll_version: Option<String>,
   pub liked_icon: Option<String>,
+  pub pinned_icon: Option<String>,
+  pub selection_symbol: Option<String>,
+  pub selection_reverse: Option<bool>,
   pub shuffle_icon: Option<String>,
   pub repeat_track_icon: Option<String>,
   pub repeat_context_icon: Option<String>,
   pub playing_icon: Option<String>,
   pub paused_icon: Option<String>,
   pub set_window_title: Option<bool>,
+  pub window_title_format: Option<String>,
   pub visualizer_style: Option<VisualizerStyle>,
+  pub color_mode: Option<ColorMode>,
   pub dismissed_announcements: Option<Vec<String>>,
   #[cfg(feature = "cover-art")]
   pub draw_cover_art: Option<bool>,
@@ -618,27 +1439,116 @@ pub struct BehaviorConfigString {
 #[derive(Clone)]
 pub struct BehaviorConfig {
   pub seek_milliseconds: u32,
+  /// Seek step used instead of `seek_milliseconds` while an episode is playing
+  pub podcast_seek_milliseconds: u32,
   pub volume_increment: u8,
   pub volume_percent: u8,
   pub tick_rate_milliseconds: u64,
   pub enable_text_emphasis: bool,
   pub show_loading_indicator: bool,
   pub enforce_wide_search_bar: bool,
+  /// Terminal width from which the search bar and library move into the sidebar
+  pub wide_layout_min_width: u16,
+  /// Capture the mouse for clicks and scrolling. Off leaves selecting text to the
+  /// terminal. Read at startup.
+  pub enable_mouse: bool,
   pub enable_global_song_count: bool,
   pub enable_discord_rpc: bool,
   pub discord_rpc_client_id: Option<String>,
   pub enable_announcements: bool,
   pub announcement_feed_url: Option<String>,
   pub seen_announcement_ids: Vec<String>,
+  /// IDs of the playlists listed first in the sidebar, in the order they were pinned
+  pub pinned_playlists: Vec<String>,
   pub shuffle_enabled: bool,
+  /// Spread artists evenly when shuffling a track list (native streaming only)
+  pub fair_shuffle: bool,
+  /// Volume fade when pausing or resuming native playback, 0 to disable
+  pub fade_ms: u64,
+  /// How far the playbar may run ahead of the last reported position, 0 to only
+  /// move on reports
+  pub max_progress_extrapolation_ms: u64,
+  /// Show how many items are queued up in the playbar title
+  pub show_queue_length: bool,
+  /// One-line footer with the keys that matter in the focused view
+  pub show_key_hints: bool,
+  /// Whether the new playlist prompt starts out public
+  pub default_playlist_public: bool,
+  /// Seconds between refetching the playlist panel in the background, 0 for never.
+  /// Shorter intervals than `app::MIN_PLAYLIST_AUTO_REFRESH_SECS` are raised to it.
+  pub playlist_auto_refresh_secs: u64,
+  /// Pause playback when quitting the app
+  pub pause_on_exit: bool,
+  /// Ask before adding a track to a playlist that already has it
+  pub warn_duplicate_add: bool,
+  /// Narrow column marking explicit tracks and local files
+  pub show_track_badges: bool,
+  /// Search results fetched per category, `None` sizes them to the terminal
+  pub search_limit_songs: Option<u32>,
+  pub search_limit_albums: Option<u32>,
+  pub search_limit_artists: Option<u32>,
+  pub search_limit_playlists: Option<u32>,
+  pub search_limit_podcasts: Option<u32>,
+  /// Search result blocks to show, in order, with their share of the screen
+  pub search_blocks: Vec<SearchBlockLayout>,
+  /// Device (name or ID) that takes over native playback when quitting
+  pub handoff_device: Option<String>,
+  /// File that skipped and finished tracks are appended to as JSON lines
+  pub log_skip_stats: Option<PathBuf>,
+  /// UI language code such as `es`, `None` to follow `$LANG`
+  pub language: Option<String>,
+  /// Start the next episode of the open show when an episode ends
+  pub podcast_autoplay_next: bool,
+  /// Play 30-second previews instead of full tracks (needs the `preview` feature)
+  pub preview_mode: bool,
+  /// Keep the system from sleeping while music plays
+  pub prevent_sleep_while_playing: bool,
+  /// Draw without colors, using bold, dim and reverse for emphasis
+  pub monochrome: bool,
+  /// Only count songs the native player starts towards the global song counter
+  pub global_song_count_native_only: bool,
+  /// Order Liked Songs play in when started from the library
+  pub liked_play_order: LikedPlayOrder,
+  /// Time range Discover's Top Tracks start with, kept as it's cycled
+  pub discover_time_range: DiscoverTimeRange,
+  /// Whether Home shows the changelog or listening stats, kept as it's toggled
+  pub home_mode: HomeMode,
+  /// Repeat mode the one/all toggle switches on from Off, the last one it picked
+  pub preferred_repeat: RepeatState,
+  /// Start unfinished podcast episodes where they were left off
+  pub resume_episodes: bool,
+  /// Let the native player go past 100% volume with software gain (read at startup)
+  pub allow_volume_boost: bool,
+  /// Rows moved per mouse wheel step in lists and tables
+  pub mouse_scroll_lines: u16,
+  /// Lines moved per up/down press on the Home changelog
+  pub home_scroll_step: u16,
+  /// Slowly scroll the Home changelog while it is on screen
+  pub home_auto_scroll: bool,
+  /// Milliseconds between auto-scroll steps
+  pub home_auto_scroll_interval_ms: u64,
+  /// Changelog scroll offset when spotatui last exited
+  pub home_scroll_position: u16,
+  /// Version whose changelog `home_scroll_position` belongs to
+  pub home_scroll_version: Option<String>,
   pub liked_icon: String,
+  /// Marks pinned playlists in the sidebar, empty for none
+  pub pinned_icon: String,
+  /// Marks the selected row in lists, and in tables when `selection_reverse` is off
+  pub selection_symbol: String,
+  /// Draw the selected row in reverse video
+  pub selection_reverse: bool,
   pub shuffle_icon: String,
   pub repeat_track_icon: String,
   pub repeat_context_icon: String,
   pub playing_icon: String,
   pub paused_icon: String,
   pub set_window_title: bool,
+  /// Window title while something plays, see `tui::window_title` for placeholders
+  pub window_title_format: String,
   pub visualizer_style: VisualizerStyle,
+  /// Colors the terminal supports; RGB colors are mapped down to fit
+  pub color_mode: ColorMode,
   pub dismissed_announcements: Vec<String>,
   #[cfg(feature = "cover-art")]
   pub draw_cover_art: bool,
@@ -659,77 +1569,83 @@ pub struct UserConfig {
   pub theme: Theme,
   pub behavior: BehaviorConfig,
   pub path_to_config: Option<UserConfigPaths>,
+  /// The config file doesn't say yet whether to join the global song counter
+  pub song_count_choice_pending: bool,
 }
 
 impl UserConfig {
   pub fn new() -> UserConfig {
-    // Detect platform for platform-specific defaults
-    #[cfg(target_os = "macos")]
-    let is_macos = true;
-    #[cfg(not(target_os = "macos"))]
-    let is_macos = false;
-
     UserConfig {
       theme: Default::default(),
-      keys: KeyBindings {
-        back: Key::Char('q'),
-        next_page: Key::Ctrl('d'),
-        previous_page: Key::Ctrl('u'),
-        jump_to_start: Key::Ctrl('a'),
-        jump_to_end: Key::Ctrl('e'),
-        jump_to_album: Key::Char('a'),
-        jump_to_artist_album: Key::Char('A'),
-        jump_to_context: Key::Char('o'),
-        manage_devices: Key::Char('d'),
-        decrease_volume: Key::Char('-'),
-        increase_volume: Key::Char('+'),
-        toggle_playback: Key::Char(' '),
-        seek_backwards: Key::Char('<'),
-        seek_forwards: Key::Char('>'),
-        next_track: Key::Char('n'),
-        previous_track: Key::Char('p'),
-        help: Key::Char('?'),
-        shuffle: Key::Ctrl('s'),
-        repeat: Key::Ctrl('r'),
-        search: Key::Char('/'),
-        submit: Key::Enter,
-        copy_song_url: Key::Char('c'),
-        copy_album_url: Key::Char('C'),
-        audio_analysis: Key::Char('v'),
-        basic_view: Key::Char('B'),
-        add_item_to_queue: Key::Char('z'),
-        // On macOS, use Ctrl+, for settings since Alt+, produces ≤ on most keyboard layouts
-        // On other platforms, keep Alt+, for consistency with many apps
-        open_settings: if is_macos {
-          Key::Ctrl(',')
-        } else {
-          Key::Alt(',')
-        },
-        save_settings: Key::Alt('s'),
-      },
+      keys: KeyBindings::default(),
       behavior: BehaviorConfig {
         seek_milliseconds: 5 * 1000,
+        podcast_seek_milliseconds: 30 * 1000,
         volume_increment: 10,
         volume_percent: 100,
         tick_rate_milliseconds: 16,
         enable_text_emphasis: true,
         show_loading_indicator: true,
         enforce_wide_search_bar: false,
+        wide_layout_min_width: 150,
+        enable_mouse: true,
         enable_global_song_count: true,
         enable_discord_rpc: true,
         discord_rpc_client_id: None,
         enable_announcements: true,
         announcement_feed_url: None,
         seen_announcement_ids: Vec::new(),
+        pinned_playlists: Vec::new(),
         shuffle_enabled: false,
+        fair_shuffle: false,
+        fade_ms: 0,
+        max_progress_extrapolation_ms: 2_000,
+        show_queue_length: false,
+        show_key_hints: false,
+        default_playlist_public: true,
+        playlist_auto_refresh_secs: 0,
+        pause_on_exit: false,
+        warn_duplicate_add: true,
+        show_track_badges: true,
+        search_limit_songs: None,
+        search_limit_albums: None,
+        search_limit_artists: None,
+        search_limit_playlists: None,
+        search_limit_podcasts: None,
+        search_blocks: default_search_blocks(),
+        handoff_device: None,
+        log_skip_stats: None,
+        language: None,
+        podcast_autoplay_next: false,
+        preview_mode: false,
+        prevent_sleep_while_playing: false,
+        monochrome: false,
+        global_song_count_native_only: false,
+        liked_play_order: LikedPlayOrder::default(),
+        discover_time_range: DiscoverTimeRange::default(),
+        home_mode: HomeMode::default(),
+        preferred_repeat: RepeatState::Context,
+        resume_episodes: true,
+        allow_volume_boost: false,
+        mouse_scroll_lines: 1,
+        home_scroll_step: 1,
+        home_auto_scroll: false,
+        home_auto_scroll_interval_ms: 1500,
+        home_scroll_position: 0,
+        home_scroll_version: None,
         liked_icon: "♥".to_string(),
+        pinned_icon: "\u{1F4CC}".to_string(),
+        selection_symbol: "▶".to_string(),
+        selection_reverse: true,
         shuffle_icon: "🔀".to_string(),
         repeat_track_icon: "🔂".to_string(),
         repeat_context_icon: "🔁".to_string(),
         playing_icon: "▶".to_string(),
         paused_icon: "⏸".to_string(),
         set_window_title: true,
+        window_title_format: "{artist} - {title}".to_string(),
         visualizer_style: VisualizerStyle::default(),
+        color_mode: ColorMode::default(),
         dismissed_announcements: Vec::new(),
         #[cfg(feature = "cover-art")]
         draw_cover_art: true,
@@ -737,6 +1653,7 @@ impl UserConfig {
         draw_cover_art_forced: false,
       },
       path_to_config: None,
+      song_count_choice_pending: true,
     }
   }
 
@@ -768,44 +1685,8 @@ impl UserConfig {
   }
 
   pub fn load_keybindings(&mut self, keybindings: KeyBindingsString) -> Result<()> {
-    macro_rules! to_keys {
-      ($name: ident) => {
-        if let Some(key_string) = keybindings.$name {
-          self.keys.$name = parse_key(key_string)?;
-        }
-      };
-    }
-
-    to_keys!(back);
-    to_keys!(next_page);
-    to_keys!(previous_page);
-    to_keys!(jump_to_start);
-    to_keys!(jump_to_end);
-    to_keys!(jump_to_album);
-    to_keys!(jump_to_artist_album);
-    to_keys!(jump_to_context);
-    to_keys!(manage_devices);
-    to_keys!(decrease_volume);
-    to_keys!(increase_volume);
-    to_keys!(toggle_playback);
-    to_keys!(seek_backwards);
-    to_keys!(seek_forwards);
-    to_keys!(next_track);
-    to_keys!(previous_track);
-    to_keys!(help);
-    to_keys!(shuffle);
-    to_keys!(repeat);
-    to_keys!(search);
-    to_keys!(submit);
-    to_keys!(copy_song_url);
-    to_keys!(copy_album_url);
-    to_keys!(audio_analysis);
-    to_keys!(basic_view);
-    to_keys!(add_item_to_queue);
-    to_keys!(open_settings);
-    to_keys!(save_settings);
-
-    Ok(())
+    self.keys.load(keybindings)?;
+    self.keys.check_chord_collisions()
   }
 
   pub fn load_theme(&mut self, theme: UserTheme) -> Result<()> {
@@ -841,6 +1722,10 @@ impl UserConfig {
       self.behavior.seek_milliseconds = behavior_string;
     }
 
+    if let Some(behavior_string) = behavior_config.podcast_seek_milliseconds {
+      self.behavior.podcast_seek_milliseconds = behavior_string;
+    }
+
     if let Some(behavior_string) = behavior_config.volume_increment {
       if behavior_string > 100 {
         return Err(anyhow!(
@@ -875,10 +1760,37 @@ impl UserConfig {
       self.behavior.enforce_wide_search_bar = wide_search_bar;
     }
 
+    if let Some(width) = behavior_config.wide_layout_min_width {
+      if width < MIN_WIDE_LAYOUT_WIDTH {
+        return Err(anyhow!(
+          "Wide layout min width must be at least {}, is {}",
+          MIN_WIDE_LAYOUT_WIDTH,
+          width
+        ));
+      }
+      self.behavior.wide_layout_min_width = width;
+    }
+
+    if let Some(enable_mouse) = behavior_config.enable_mouse {
+      self.behavior.enable_mouse = enable_mouse;
+    }
+
     if let Some(liked_icon) = behavior_config.liked_icon {
       self.behavior.liked_icon = liked_icon;
     }
 
+    if let Some(pinned_icon) = behavior_config.pinned_icon {
+      self.behavior.pinned_icon = pinned_icon;
+    }
+
+    if let Some(selection_symbol) = behavior_config.selection_symbol {
+      self.behavior.selection_symbol = selection_symbol;
+    }
+
+    if let Some(selection_reverse) = behavior_config.selection_reverse {
+      self.behavior.selection_reverse = selection_reverse;
+    }
+
     if let Some(paused_icon) = behavior_config.paused_icon {
       self.behavior.paused_icon = paused_icon;
     }
@@ -903,8 +1815,13 @@ impl UserConfig {
       self.behavior.set_window_title = set_window_title;
     }
 
+    if let Some(window_title_format) = behavior_config.window_title_format {
+      self.behavior.window_title_format = window_title_format;
+    }
+
     if let Some(enable_global_song_count) = behavior_config.enable_global_song_count {
       self.behavior.enable_global_song_count = enable_global_song_count;
+      self.song_count_choice_pending = false;
     }
 
     if let Some(enable_discord_rpc) = behavior_config.enable_discord_rpc {
@@ -932,6 +1849,14 @@ impl UserConfig {
         .collect();
     }
 
+    if let Some(pinned_playlists) = behavior_config.pinned_playlists {
+      self.behavior.pinned_playlists = pinned_playlists
+        .into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect();
+    }
+
     if let Some(discord_rpc_client_id) = behavior_config.discord_rpc_client_id {
       self.behavior.discord_rpc_client_id = Some(discord_rpc_client_id);
     }
@@ -940,10 +1865,198 @@ impl UserConfig {
       self.behavior.shuffle_enabled = shuffle_enabled;
     }
 
+    if let Some(fair_shuffle) = behavior_config.fair_shuffle {
+      self.behavior.fair_shuffle = fair_shuffle;
+    }
+
+    if let Some(fade_ms) = behavior_config.fade_ms {
+      if fade_ms > MAX_FADE_MS {
+        return Err(anyhow!("Fade must be at most {} milliseconds", MAX_FADE_MS));
+      }
+      self.behavior.fade_ms = fade_ms;
+    }
+
+    if let Some(max_ms) = behavior_config.max_progress_extrapolation_ms {
+      self.behavior.max_progress_extrapolation_ms = max_ms;
+    }
+
+    if let Some(show_queue_length) = behavior_config.show_queue_length {
+      self.behavior.show_queue_length = show_queue_length;
+    }
+
+    if let Some(show_key_hints) = behavior_config.show_key_hints {
+      self.behavior.show_key_hints = show_key_hints;
+    }
+
+    if let Some(default_playlist_public) = behavior_config.default_playlist_public {
+      self.behavior.default_playlist_public = default_playlist_public;
+    }
+
+    if let Some(secs) = behavior_config.playlist_auto_refresh_secs {
+      self.behavior.playlist_auto_refresh_secs = secs;
+    }
+
+    if let Some(pause_on_exit) = behavior_config.pause_on_exit {
+      self.behavior.pause_on_exit = pause_on_exit;
+    }
+
+    if let Some(warn_duplicate_add) = behavior_config.warn_duplicate_add {
+      self.behavior.warn_duplicate_add = warn_duplicate_add;
+    }
+
+    if let Some(show_track_badges) = behavior_config.show_track_badges {
+      self.behavior.show_track_badges = show_track_badges;
+    }
+
+    for (name, limit, target) in [
+      (
+        "songs",
+        behavior_config.search_limit_songs,
+        &mut self.behavior.search_limit_songs,
+      ),
+      (
+        "albums",
+        behavior_config.search_limit_albums,
+        &mut self.behavior.search_limit_albums,
+      ),
+      (
+        "artists",
+        behavior_config.search_limit_artists,
+        &mut self.behavior.search_limit_artists,
+      ),
+      (
+        "playlists",
+        behavior_config.search_limit_playlists,
+        &mut self.behavior.search_limit_playlists,
+      ),
+      (
+        "podcasts",
+        behavior_config.search_limit_podcasts,
+        &mut self.behavior.search_limit_podcasts,
+      ),
+    ] {
+      if let Some(limit) = limit {
+        if !(1..=MAX_SEARCH_LIMIT).contains(&limit) {
+          return Err(anyhow!(
+            "Search limit for {} must be between 1 and {}",
+            name,
+            MAX_SEARCH_LIMIT
+          ));
+        }
+        *target = Some(limit);
+      }
+    }
+
+    if let Some(search_blocks) = behavior_config.search_blocks {
+      self.behavior.search_blocks = parse_search_blocks(&search_blocks)?;
+    }
+
+    if let Some(handoff_device) = behavior_config.handoff_device {
+      let trimmed = handoff_device.trim();
+      self.behavior.handoff_device = (!trimmed.is_empty()).then(|| trimmed.to_string());
+    }
+
+    if let Some(log_skip_stats) = behavior_config.log_skip_stats {
+      self.behavior.log_skip_stats =
+        (!log_skip_stats.as_os_str().is_empty()).then_some(log_skip_stats);
+    }
+
+    if let Some(language) = behavior_config.language {
+      let trimmed = language.trim();
+      self.behavior.language = (!trimmed.is_empty()).then(|| trimmed.to_string());
+    }
+
+    if let Some(podcast_autoplay_next) = behavior_config.podcast_autoplay_next {
+      self.behavior.podcast_autoplay_next = podcast_autoplay_next;
+    }
+
+    if let Some(preview_mode) = behavior_config.preview_mode {
+      self.behavior.preview_mode = preview_mode;
+    }
+
+    if let Some(prevent_sleep_while_playing) = behavior_config.prevent_sleep_while_playing {
+      self.behavior.prevent_sleep_while_playing = prevent_sleep_while_playing;
+    }
+
+    if let Some(monochrome) = behavior_config.monochrome {
+      self.behavior.monochrome = monochrome;
+    }
+
+    if let Some(global_song_count_native_only) = behavior_config.global_song_count_native_only {
+      self.behavior.global_song_count_native_only = global_song_count_native_only;
+    }
+
+    if let Some(liked_play_order) = behavior_config.liked_play_order {
+      self.behavior.liked_play_order = liked_play_order;
+    }
+
+    if let Some(discover_time_range) = behavior_config.discover_time_range {
+      self.behavior.discover_time_range = discover_time_range;
+    }
+
+    if let Some(home_mode) = behavior_config.home_mode {
+      self.behavior.home_mode = home_mode;
+    }
+
+    if let Some(preferred_repeat) = behavior_config.preferred_repeat {
+      // Off would make the toggle skip nothing, so only one or all are kept
+      if preferred_repeat != RepeatState::Off {
+        self.behavior.preferred_repeat = preferred_repeat;
+      }
+    }
+
+    if let Some(resume_episodes) = behavior_config.resume_episodes {
+      self.behavior.resume_episodes = resume_episodes;
+    }
+
+    if let Some(allow_volume_boost) = behavior_config.allow_volume_boost {
+      self.behavior.allow_volume_boost = allow_volume_boost;
+    }
+
+    if let Some(lines) = behavior_config.mouse_scroll_lines {
+      if lines == 0 {
+        return Err(anyhow!("Mouse scroll lines must be at least 1"));
+      }
+      self.behavior.mouse_scroll_lines = lines;
+    }
+
+    if let Some(step) = behavior_config.home_scroll_step {
+      if step == 0 {
+        return Err(anyhow!("Home scroll step must be at least 1"));
+      }
+      self.behavior.home_scroll_step = step;
+    }
+
+    if let Some(position) = behavior_config.home_scroll_position {
+      self.behavior.home_scroll_position = position;
+    }
+
+    if let Some(version) = behavior_config.home_scroll_version {
+      self.behavior.home_scroll_version = Some(version);
+    }
+
+    if let Some(home_auto_scroll) = behavior_config.home_auto_scroll {
+      self.behavior.home_auto_scroll = home_auto_scroll;
+    }
+
+    if let Some(interval) = behavior_config.home_auto_scroll_interval_ms {
+      if interval < 100 {
+        return Err(anyhow!(
+          "Home auto-scroll interval must be at least 100ms, is {}",
+          interval,
+        ));
+      }
+      self.behavior.home_auto_scroll_interval_ms = interval;
+    }
+
     if let Some(visualizer_style) = behavior_config.visualizer_style {
       self.behavior.visualizer_style = visualizer_style;
     }
 
+    if let Some(color_mode) = behavior_config.color_mode {
+      self.behavior.color_mode = color_mode;
+    }
+
     if let Some(dismissed_announcements) = behavior_config.dismissed_announcements {
       self.behavior.dismissed_announcements = dismissed_announcements
         .into_iter()
@@ -1009,27 +2122,79 @@ impl UserConfig {
     // Helper to build behavior config from current values
     let build_behavior = || BehaviorConfigString {
       seek_milliseconds: Some(self.behavior.seek_milliseconds),
+      podcast_seek_milliseconds: Some(self.behavior.podcast_seek_milliseconds),
       volume_increment: Some(self.behavior.volume_increment),
       volume_percent: Some(self.behavior.volume_percent),
       tick_rate_milliseconds: Some(self.behavior.tick_rate_milliseconds),
       enable_text_emphasis: Some(self.behavior.enable_text_emphasis),
       show_loading_indicator: Some(self.behavior.show_loading_indicator),
       enforce_wide_search_bar: Some(self.behavior.enforce_wide_search_bar),
+      wide_layout_min_width: Some(self.behavior.wide_layout_min_width),
+      enable_mouse: Some(self.behavior.enable_mouse),
       enable_global_song_count: Some(self.behavior.enable_global_song_count),
       enable_discord_rpc: Some(self.behavior.enable_discord_rpc),
       discord_rpc_client_id: self.behavior.discord_rpc_client_id.clone(),
       enable_announcements: Some(self.behavior.enable_announcements),
       announcement_feed_url: self.behavior.announcement_feed_url.clone(),
       seen_announcement_ids: Some(self.behavior.seen_announcement_ids.clone()),
+      pinned_playlists: Some(self.behavior.pinned_playlists.clone()),
       shuffle_enabled: Some(self.behavior.shuffle_enabled),
+      fair_shuffle: Some(self.behavior.fair_shuffle),
+      fade_ms: Some(self.behavior.fade_ms),
+      max_progress_extrapolation_ms: Some(self.behavior.max_progress_extrapolation_ms),
+      show_queue_length: Some(self.behavior.show_queue_length),
+      show_key_hints: Some(self.behavior.show_key_hints),
+      default_playlist_public: Some(self.behavior.default_playlist_public),
+      playlist_auto_refresh_secs: Some(self.behavior.playlist_auto_refresh_secs),
+      pause_on_exit: Some(self.behavior.pause_on_exit),
+      warn_duplicate_add: Some(self.behavior.warn_duplicate_add),
+      show_track_badges: Some(self.behavior.show_track_badges),
+      search_limit_songs: self.behavior.search_limit_songs,
+      search_limit_albums: self.behavior.search_limit_albums,
+      search_limit_artists: self.behavior.search_limit_artists,
+      search_limit_playlists: self.behavior.search_limit_playlists,
+      search_limit_podcasts: self.behavior.search_limit_podcasts,
+      search_blocks: Some(
+        self
+          .behavior
+          .search_blocks
+          .iter()
+          .map(search_block_entry)
+          .collect(),
+      ),
+      handoff_device: self.behavior.handoff_device.clone(),
+      log_skip_stats: self.behavior.log_skip_stats.clone(),
+      language: self.behavior.language.clone(),
+      podcast_autoplay_next: Some(self.behavior.podcast_autoplay_next),
+      preview_mode: Some(self.behavior.preview_mode),
+      prevent_sleep_while_playing: Some(self.behavior.prevent_sleep_while_playing),
+      monochrome: Some(self.behavior.monochrome),
+      global_song_count_native_only: Some(self.behavior.global_song_count_native_only),
+      liked_play_order: Some(self.behavior.liked_play_order),
+      discover_time_range: Some(self.behavior.discover_time_range),
+      home_mode: Some(self.behavior.home_mode),
+      preferred_repeat: Some(self.behavior.preferred_repeat),
+      resume_episodes: Some(self.behavior.resume_episodes),
+      allow_volume_boost: Some(self.behavior.allow_volume_boost),
+      mouse_scroll_lines: Some(self.behavior.mouse_scroll_lines),
+      home_scroll_step: Some(self.behavior.home_scroll_step),
+      home_auto_scroll: Some(self.behavior.home_auto_scroll),
+      home_auto_scroll_interval_ms: Some(self.behavior.home_auto_scroll_interval_ms),
+      home_scroll_position: Some(self.behavior.home_scroll_position),
+      home_scroll_version: self.behavior.home_scroll_version.clone(),
       liked_icon: Some(self.behavior.liked_icon.clone()),
+      pinned_icon: Some(self.behavior.pinned_icon.clone()),
+      selection_symbol: Some(self.behavior.selection_symbol.clone()),
+      selection_reverse: Some(self.behavior.selection_reverse),
       shuffle_icon: Some(self.behavior.shuffle_icon.clone()),
       repeat_track_icon: Some(self.behavior.repeat_track_icon.clone()),
       repeat_context_icon: Some(self.behavior.repeat_context_icon.clone()),
       playing_icon: Some(self.behavior.playing_icon.clone()),
       paused_icon: Some(self.behavior.paused_icon.clone()),
       set_window_title: Some(self.behavior.set_window_title),
+      window_title_format: Some(self.behavior.window_title_format.clone()),
       visualizer_style: Some(self.behavior.visualizer_style),
+      color_mode: Some(self.behavior.color_mode),
       dismissed_announcements: Some(self.behavior.dismissed_announcements.clone()),
       #[cfg(feature = "cover-art")]
       draw_cover_art: Some(self.behavior.draw_cover_art),
@@ -1037,76 +2202,6 @@ impl UserConfig {
       draw_cover_art_forced: Some(self.behavior.draw_cover_art_forced),
     };
 
-    // Helper to convert Key to config string
-    let key_to_config_string = |key: Key| -> String {
-      match key {
-        Key::Char(' ') => "space".to_string(),
-        Key::Char(c) => c.to_string(),
-        Key::Ctrl(c) => format!("ctrl-{}", c),
-        Key::Alt(c) => format!("alt-{}", c),
-        Key::Enter => "enter".to_string(),
-        Key::Tab => "tab".to_string(),
-        Key::Esc => "esc".to_string(),
-        Key::Backspace => "backspace".to_string(),
-        Key::Delete => "del".to_string(),
-        Key::Left => "left".to_string(),
-        Key::Right => "right".to_string(),
-        Key::Up => "up".to_string(),
-        Key::Down => "down".to_string(),
-        Key::Home => "home".to_string(),
-        Key::End => "end".to_string(),
-        Key::Ins => "ins".to_string(),
-        Key::PageUp => "pageup".to_string(),
-        Key::PageDown => "pagedown".to_string(),
-        Key::F0 => "f0".to_string(),
-        Key::F1 => "f1".to_string(),
-        Key::F2 => "f2".to_string(),
-        Key::F3 => "f3".to_string(),
-        Key::F4 => "f4".to_string(),
-        Key::F5 => "f5".to_string(),
-        Key::F6 => "f6".to_string(),
-        Key::F7 => "f7".to_string(),
-        Key::F8 => "f8".to_string(),
-        Key::F9 => "f9".to_string(),
-        Key::F10 => "f10".to_string(),
-        Key::F11 => "f11".to_string(),
-        Key::F12 => "f12".to_string(),
-        _ => "unknown".to_string(),
-      }
-    };
-
-    // Helper to build keybindings config from current values
-    let build_keybindings = || KeyBindingsString {
-      back: Some(key_to_config_string(self.keys.back)),
-      next_page: Some(key_to_config_string(self.keys.next_page)),
-      previous_page: Some(key_to_config_string(self.keys.previous_page)),
-      jump_to_start: Some(key_to_config_string(self.keys.jump_to_start)),
-      jump_to_end: Some(key_to_config_string(self.keys.jump_to_end)),
-      jump_to_album: Some(key_to_config_string(self.keys.jump_to_album)),
-      jump_to_artist_album: Some(key_to_config_string(self.keys.jump_to_artist_album)),
-      jump_to_context: Some(key_to_config_string(self.keys.jump_to_context)),
-      manage_devices: Some(key_to_config_string(self.keys.manage_devices)),
-      decrease_volume: Some(key_to_config_string(self.keys.decrease_volume)),
-      increase_volume: Some(key_to_config_string(self.keys.increase_volume)),
-      toggle_playback: Some(key_to_config_string(self.keys.toggle_playback)),
-      seek_backwards: Some(key_to_config_string(self.keys.seek_backwards)),
-      seek_forwards: Some(key_to_config_string(self.keys.seek_forwards)),
-      next_track: Some(key_to_config_string(self.keys.next_track)),
-      previous_track: Some(key_to_config_string(self.keys.previous_track)),
-      help: Some(key_to_config_string(self.keys.help)),
-      shuffle: Some(key_to_config_string(self.keys.shuffle)),
-      repeat: Some(key_to_config_string(self.keys.repeat)),
-      search: Some(key_to_config_string(self.keys.search)),
-      submit: Some(key_to_config_string(self.keys.submit)),
-      copy_song_url: Some(key_to_config_string(self.keys.copy_song_url)),
-      copy_album_url: Some(key_to_config_string(self.keys.copy_album_url)),
-      audio_analysis: Some(key_to_config_string(self.keys.audio_analysis)),
-      basic_view: Some(key_to_config_string(self.keys.basic_view)),
-      add_item_to_queue: Some(key_to_config_string(self.keys.add_item_to_queue)),
-      open_settings: Some(key_to_config_string(self.keys.open_settings)),
-      save_settings: Some(key_to_config_string(self.keys.save_settings)),
-    };
-
     // Helper to build theme config from current values
     let build_theme = || UserTheme {
       active: Some(color_to_string(self.theme.active)),
@@ -1135,18 +2230,18 @@ impl UserConfig {
         // Update behavior, theme, and keybindings
         existing.behavior = Some(build_behavior());
         existing.theme = Some(build_theme());
-        existing.keybindings = Some(build_keybindings());
+        existing.keybindings = Some(self.keys.to_config());
         existing
       } else {
         UserConfigString {
-          keybindings: Some(build_keybindings()),
+          keybindings: Some(self.keys.to_config()),
           behavior: Some(build_behavior()),
           theme: Some(build_theme()),
         }
       }
     } else {
       UserConfigString {
-        keybindings: Some(build_keybindings()),
+        keybindings: Some(self.keys.to_config()),
         behavior: Some(build_behavior()),
         theme: Some(build_theme()),
       }
@@ -1163,6 +2258,111 @@ impl UserConfig {
     format!("{} ", &self.behavior.liked_icon)
   }
 
+  /// Prefix for pinned playlists, empty when no icon is configured
+  pub fn padded_pinned_icon(&self) -> String {
+    if self.behavior.pinned_icon.is_empty() {
+      String::new()
+    } else {
+      format!("{} ", &self.behavior.pinned_icon)
+    }
+  }
+
+  /// Prefix for the selected row, empty when no symbol is configured
+  pub fn padded_selection_symbol(&self) -> String {
+    match self.behavior.selection_symbol.as_str() {
+      "" => String::new(),
+      symbol => format!("{} ", symbol),
+    }
+  }
+
+  /// Style for the selected row of a list or table
+  pub fn selection_style(&self, base: Style) -> Style {
+    if self.behavior.selection_reverse {
+      base.add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+      base.add_modifier(Modifier::BOLD)
+    }
+  }
+
+  /// Changelog offset to resume at. After an update the newest release notes are
+  /// at the top, so a position saved by another version starts from 0 instead.
+  pub fn saved_home_scroll(&self) -> u16 {
+    match &self.behavior.home_scroll_version {
+      Some(version) if version == env!("CARGO_PKG_VERSION") => self.behavior.home_scroll_position,
+      _ => 0,
+    }
+  }
+
+  /// Store the changelog offset for the next launch. Returns whether anything changed.
+  pub fn remember_home_scroll(&mut self, scroll: u16) -> bool {
+    let version = env!("CARGO_PKG_VERSION");
+    if self.behavior.home_scroll_position == scroll
+      && self.behavior.home_scroll_version.as_deref() == Some(version)
+    {
+      return false;
+    }
+    self.behavior.home_scroll_position = scroll;
+    self.behavior.home_scroll_version = Some(version.to_string());
+    true
+  }
+
+  /// Store the Discover time range for the next launch. Returns whether anything changed.
+  pub fn remember_discover_time_range(&mut self, range: DiscoverTimeRange) -> bool {
+    if self.behavior.discover_time_range == range {
+      return false;
+    }
+    self.behavior.discover_time_range = range;
+    true
+  }
+
+  /// Store what Home shows for the next launch. Returns whether anything changed.
+  pub fn remember_home_mode(&mut self, mode: HomeMode) -> bool {
+    if self.behavior.home_mode == mode {
+      return false;
+    }
+    self.behavior.home_mode = mode;
+    true
+  }
+
+  /// Write only what is remembered between launches, the changelog offset, what
+  /// Home shows and the Discover time range, leaving everything else in the config
+  /// file as it was
+  pub fn save_session_state(&self) -> Result<()> {
+    let paths = match &self.path_to_config {
+      Some(path) => path,
+      None => return Err(anyhow!("Config path not initialized")),
+    };
+
+    let mut config: UserConfigString = if paths.config_file_path.exists() {
+      let config_string = fs::read_to_string(&paths.config_file_path)?;
+      if config_string.trim().is_empty() {
+        UserConfigString::default()
+      } else {
+        serde_yaml::from_str(&config_string)?
+      }
+    } else {
+      UserConfigString::default()
+    };
+    let behavior = config.behavior.get_or_insert_with(Default::default);
+    behavior.home_scroll_position = Some(self.behavior.home_scroll_position);
+    behavior.home_scroll_version = self.behavior.home_scroll_version.clone();
+    behavior.discover_time_range = Some(self.behavior.discover_time_range);
+    behavior.home_mode = Some(self.behavior.home_mode);
+
+    let content_yml = serde_yaml::to_string(&config)?;
+    let mut config_file = fs::File::create(&paths.config_file_path)?;
+    std::io::Write::write_all(&mut config_file, content_yml.as_bytes())?;
+
+    Ok(())
+  }
+
+  /// Record whether to take part in the global song counter and save it
+  pub fn set_global_song_count(&mut self, enable: bool) -> Result<()> {
+    self.behavior.enable_global_song_count = enable;
+    self.song_count_choice_pending = false;
+    self.save_config()
+  }
+
   pub fn mark_announcement_seen(&mut self, announcement_id: impl Into<String>) {
     let id = announcement_id.into();
     if id.is_empty() {
@@ -1279,6 +2479,87 @@ mod tests {
     assert_eq!(parse_key(String::from("f10")).unwrap(), Key::F10);
     assert_eq!(parse_key(String::from("f11")).unwrap(), Key::F11);
     assert_eq!(parse_key(String::from("f12")).unwrap(), Key::F12);
+    assert_eq!(
+      parse_key(String::from("g a")).unwrap(),
+      Key::Chord('g', 'a')
+    );
+    assert_eq!(
+      parse_key(String::from("space p")).unwrap(),
+      Key::Chord(' ', 'p')
+    );
+    assert!(parse_key(String::from("g ctrl-a")).is_err());
+    assert!(parse_key(String::from("g a b")).is_err());
+  }
+
+  #[test]
+  fn test_chord_collisions() {
+    use super::{KeyBindingsString, UserConfig};
+    use crate::event::Key;
+
+    let mut config = UserConfig::new();
+    config
+      .load_keybindings(KeyBindingsString {
+        jump_to_album: Some("g a".into()),
+        jump_to_artist_album: Some("g r".into()),
+        ..Default::default()
+      })
+      .unwrap();
+    assert_eq!(config.keys.jump_to_album.primary(), Key::Chord('g', 'a'));
+    assert!(config.keys.starts_chord('g'));
+    assert!(config.keys.has_chord(Key::Chord('g', 'r')));
+    assert!(!config.keys.has_chord(Key::Chord('g', 'x')));
+
+    // A chord can't start with a key that already does something on its own
+    let mut config = UserConfig::new();
+    assert!(config
+      .load_keybindings(KeyBindingsString {
+        jump_to_album: Some("g a".into()),
+        help: Some("g".into()),
+        ..Default::default()
+      })
+      .is_err());
+
+    // ...or with a navigation key
+    assert!(config
+      .load_keybindings(KeyBindingsString {
+        jump_to_album: Some("j a".into()),
+        ..Default::default()
+      })
+      .is_err());
+  }
+
+  #[test]
+  fn test_multiple_keys_per_action() {
+    use super::{parse_key_list_public, KeyBindingsString, KeyConfig, UserConfig};
+    use crate::event::Key;
+
+    let yaml = "toggle_playback: [space, p]\nback: esc\n";
+    let keybindings: KeyBindingsString = serde_yaml::from_str(yaml).unwrap();
+    let mut config = UserConfig::new();
+    config.load_keybindings(keybindings).unwrap();
+    assert!(Key::Char(' ') == config.keys.toggle_playback);
+    assert!(Key::Char('p') == config.keys.toggle_playback);
+    assert!(Key::Char('q') != config.keys.back);
+    assert_eq!(
+      config.keys.to_config().toggle_playback,
+      Some(KeyConfig::Many(vec!["space".into(), "p".into()]))
+    );
+    assert_eq!(config.keys.to_config().back, Some("esc".into()));
+
+    // Settings show the keys joined, and read them back the same way
+    let shown = config.keys.toggle_playback.to_string();
+    assert_eq!(shown, "<Space> | p");
+    assert_eq!(
+      parse_key_list_public("space | p").unwrap(),
+      config.keys.toggle_playback
+    );
+
+    assert!(config
+      .load_keybindings(KeyBindingsString {
+        toggle_playback: Some(KeyConfig::Many(Vec::new())),
+        ..Default::default()
+      })
+      .is_err());
   }
 
   #[test]
@@ -1311,6 +2592,272 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_space_action() {
+    use super::{KeyBindingsString, SpaceAction, UserConfig};
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.keys.space_action, SpaceAction::TogglePlayback);
+
+    config
+      .load_keybindings(KeyBindingsString {
+        space_action: Some("add_to_queue".to_string()),
+        ..Default::default()
+      })
+      .unwrap();
+    assert_eq!(config.keys.space_action, SpaceAction::AddToQueue);
+
+    assert!(config
+      .load_keybindings(KeyBindingsString {
+        space_action: Some("shuffle".to_string()),
+        ..Default::default()
+      })
+      .is_err());
+  }
+
+  #[test]
+  fn test_every_binding_is_registered() {
+    use super::{parse_key, KeyBindings, KEY_BINDINGS};
+    use std::collections::HashSet;
+
+    let keys = KeyBindings::default();
+    let config = serde_json::to_value(keys.to_config()).unwrap();
+    let fields: HashSet<&str> = config
+      .as_object()
+      .unwrap()
+      .keys()
+      .map(String::as_str)
+      .filter(|field| *field != "space_action")
+      .collect();
+    let registered: HashSet<&str> = KEY_BINDINGS.iter().map(|binding| binding.name).collect();
+    assert_eq!(fields, registered);
+    assert_eq!(registered.len(), KEY_BINDINGS.len());
+
+    let mut defaults = HashSet::new();
+    for binding in KEY_BINDINGS {
+      let key = keys.get(binding.name).unwrap().primary();
+      assert!(defaults.insert(key), "{} shares its default", binding.name);
+      // What gets saved must load back as the same key
+      let saved = config[binding.name].as_str().unwrap();
+      assert_eq!(parse_key(saved.to_string()).unwrap(), key);
+    }
+  }
+
+  #[test]
+  fn test_selection_indicator() {
+    use super::{ThemePreset, UserConfig};
+    use ratatui::style::{Modifier, Style};
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.padded_selection_symbol(), "▶ ");
+    assert!(config
+      .selection_style(Style::default())
+      .add_modifier
+      .contains(Modifier::REVERSED));
+
+    config.behavior.selection_symbol = String::new();
+    config.behavior.selection_reverse = false;
+    assert_eq!(config.padded_selection_symbol(), "");
+    assert!(!config
+      .selection_style(Style::default())
+      .add_modifier
+      .contains(Modifier::REVERSED));
+
+    let preset = ThemePreset::HighContrast;
+    assert_eq!(ThemePreset::from_name(preset.name()), preset);
+    assert!(ThemePreset::all().contains(&preset));
+  }
+
+  #[test]
+  fn test_fade_ms_limit() {
+    use super::{BehaviorConfigString, UserConfig, MAX_FADE_MS};
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.behavior.fade_ms, 0);
+
+    config
+      .load_behaviorconfig(BehaviorConfigString {
+        fade_ms: Some(300),
+        ..Default::default()
+      })
+      .unwrap();
+    assert_eq!(config.behavior.fade_ms, 300);
+
+    assert!(config
+      .load_behaviorconfig(BehaviorConfigString {
+        fade_ms: Some(MAX_FADE_MS + 1),
+        ..Default::default()
+      })
+      .is_err());
+  }
+
+  #[test]
+  fn test_color_mode_accepts_unquoted_numbers() {
+    use super::{BehaviorConfigString, ColorMode};
+
+    for (yaml, mode) in [
+      ("color_mode: 256", ColorMode::Ansi256),
+      ("color_mode: \"16\"", ColorMode::Ansi16),
+      ("color_mode: truecolor", ColorMode::TrueColor),
+    ] {
+      let config: BehaviorConfigString = serde_yaml::from_str(yaml).unwrap();
+      assert_eq!(config.color_mode, Some(mode));
+    }
+    assert!(serde_yaml::from_str::<BehaviorConfigString>("color_mode: 88").is_err());
+
+    let saved = serde_yaml::to_string(&BehaviorConfigString {
+      color_mode: Some(ColorMode::Ansi256),
+      ..Default::default()
+    })
+    .unwrap();
+    let reloaded: BehaviorConfigString = serde_yaml::from_str(&saved).unwrap();
+    assert_eq!(reloaded.color_mode, Some(ColorMode::Ansi256));
+  }
+
+  #[test]
+  fn test_search_blocks() {
+    use super::{search_block_entry, BehaviorConfigString, SearchBlockLayout, UserConfig};
+    use crate::core::app::SearchResultBlock;
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.behavior.search_blocks.len(), 5);
+
+    config
+      .load_behaviorconfig(BehaviorConfigString {
+        search_blocks: Some(vec!["Artists".to_string(), "songs: 3".to_string()]),
+        ..Default::default()
+      })
+      .unwrap();
+    assert_eq!(
+      config.behavior.search_blocks,
+      vec![
+        SearchBlockLayout {
+          block: SearchResultBlock::ArtistSearch,
+          weight: 1
+        },
+        SearchBlockLayout {
+          block: SearchResultBlock::SongSearch,
+          weight: 3
+        },
+      ]
+    );
+    let entries: Vec<String> = config
+      .behavior
+      .search_blocks
+      .iter()
+      .map(search_block_entry)
+      .collect();
+    assert_eq!(entries, vec!["artists", "songs:3"]);
+
+    for bad in [
+      vec![],
+      vec!["videos".to_string()],
+      vec!["songs".to_string(), "songs:2".to_string()],
+      vec!["albums:0".to_string()],
+    ] {
+      assert!(config
+        .load_behaviorconfig(BehaviorConfigString {
+          search_blocks: Some(bad),
+          ..Default::default()
+        })
+        .is_err());
+    }
+  }
+
+  #[test]
+  fn test_search_limits() {
+    use super::{BehaviorConfigString, UserConfig, MAX_SEARCH_LIMIT};
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.behavior.search_limit_songs, None);
+
+    config
+      .load_behaviorconfig(BehaviorConfigString {
+        search_limit_songs: Some(MAX_SEARCH_LIMIT),
+        ..Default::default()
+      })
+      .unwrap();
+    assert_eq!(config.behavior.search_limit_songs, Some(MAX_SEARCH_LIMIT));
+    assert_eq!(config.behavior.search_limit_albums, None);
+
+    for limit in [0, MAX_SEARCH_LIMIT + 1] {
+      assert!(config
+        .load_behaviorconfig(BehaviorConfigString {
+          search_limit_playlists: Some(limit),
+          ..Default::default()
+        })
+        .is_err());
+    }
+  }
+
+  #[test]
+  fn test_wide_layout_min_width() {
+    use super::{BehaviorConfigString, UserConfig, MIN_WIDE_LAYOUT_WIDTH};
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.behavior.wide_layout_min_width, 150);
+    config
+      .load_behaviorconfig(BehaviorConfigString {
+        wide_layout_min_width: Some(120),
+        ..Default::default()
+      })
+      .unwrap();
+    assert_eq!(config.behavior.wide_layout_min_width, 120);
+
+    assert!(config
+      .load_behaviorconfig(BehaviorConfigString {
+        wide_layout_min_width: Some(MIN_WIDE_LAYOUT_WIDTH - 1),
+        ..Default::default()
+      })
+      .is_err());
+    assert_eq!(config.behavior.wide_layout_min_width, 120);
+  }
+
+  #[test]
+  fn test_home_scroll_resume() {
+    use super::UserConfig;
+
+    let mut config = UserConfig::new();
+    assert_eq!(config.saved_home_scroll(), 0);
+
+    assert!(config.remember_home_scroll(42));
+    assert!(!config.remember_home_scroll(42));
+    assert_eq!(config.saved_home_scroll(), 42);
+
+    // A position saved by another version starts at the newest release notes
+    config.behavior.home_scroll_version = Some("0.0.1".to_string());
+    assert_eq!(config.saved_home_scroll(), 0);
+  }
+
+  #[test]
+  fn test_save_session_state_leaves_other_settings() {
+    use super::{DiscoverTimeRange, HomeMode, UserConfig, UserConfigPaths};
+
+    let path = std::env::temp_dir().join("spotatui-home-scroll.yml");
+    std::fs::write(&path, "behavior:\n  seek_milliseconds: 1234\n").unwrap();
+    let mut config = UserConfig::new();
+    config.path_to_config = Some(UserConfigPaths {
+      config_file_path: path.clone(),
+    });
+    // Changed in memory but never saved, so it stays out of the file
+    config.behavior.volume_increment = 3;
+    config.remember_home_scroll(42);
+    assert!(config.remember_discover_time_range(DiscoverTimeRange::Long));
+    assert!(!config.remember_discover_time_range(DiscoverTimeRange::Long));
+    assert!(config.remember_home_mode(HomeMode::Stats));
+    config.save_session_state().unwrap();
+
+    let mut saved = UserConfig::new();
+    saved.path_to_config = config.path_to_config.clone();
+    saved.load_config().unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(saved.saved_home_scroll(), 42);
+    assert_eq!(saved.behavior.discover_time_range, DiscoverTimeRange::Long);
+    assert_eq!(saved.behavior.home_mode, HomeMode::Stats);
+    assert_eq!(saved.behavior.seek_milliseconds, 1234);
+    assert_eq!(saved.behavior.volume_increment, 10);
+  }
+
   #[test]
   fn test_reserved_key() {
     use super::check_reserved_keys;
@@ -0,0 +1,92 @@
+//! Keeps the machine from idling to sleep while music plays.
+//!
+//! The inhibit is held by a helper process that lives until we release it, or
+//! until spotatui exits, since it watches our PID:
+//! - Linux: `systemd-inhibit`, which takes a logind idle/sleep lock over D-Bus
+//! - macOS: `caffeinate`
+//!
+//! Anywhere else, or when the helper is missing, this quietly does nothing.
+
+use std::process::{Child, Command};
+
+#[derive(Default)]
+pub struct KeepAwake {
+  inhibitor: Option<Child>,
+  /// Set once spawning the helper fails, so we don't retry on every tick
+  unsupported: bool,
+}
+
+impl KeepAwake {
+  pub fn is_active(&self) -> bool {
+    self.inhibitor.is_some()
+  }
+
+  /// Takes or releases the inhibit; calling it with the current state is a no-op
+  pub fn set(&mut self, active: bool) {
+    if active == self.is_active() {
+      return;
+    }
+    if active {
+      if self.unsupported {
+        return;
+      }
+      match inhibit_command().map(|mut command| command.spawn()) {
+        Some(Ok(child)) => self.inhibitor = Some(child),
+        Some(Err(e)) => {
+          log::info!("keep awake unavailable: {}", e);
+          self.unsupported = true;
+        }
+        None => self.unsupported = true,
+      }
+    } else if let Some(mut child) = self.inhibitor.take() {
+      let _ = child.kill();
+      let _ = child.wait();
+    }
+  }
+}
+
+impl Drop for KeepAwake {
+  fn drop(&mut self) {
+    self.set(false);
+  }
+}
+
+#[cfg(target_os = "linux")]
+fn inhibit_command() -> Option<Command> {
+  let mut command = Command::new("systemd-inhibit");
+  command
+    .args([
+      "--what=idle:sleep",
+      "--who=spotatui",
+      "--why=Playing music",
+      "--mode=block",
+      "tail",
+      "-f",
+      "/dev/null",
+    ])
+    .arg(format!("--pid={}", std::process::id()));
+  Some(quiet(command))
+}
+
+#[cfg(target_os = "macos")]
+fn inhibit_command() -> Option<Command> {
+  let mut command = Command::new("caffeinate");
+  command
+    .args(["-d", "-i", "-w"])
+    .arg(std::process::id().to_string());
+  Some(quiet(command))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn inhibit_command() -> Option<Command> {
+  None
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn quiet(mut command: Command) -> Command {
+  command
+    .stdin(std::process::Stdio::null())
+    .stdout(std::process::Stdio::null())
+    .stderr(std::process::Stdio::null());
+  command
+}
@@ -1,6 +1,7 @@
 pub mod audio;
 #[cfg(feature = "discord-rpc")]
 pub mod discord_rpc;
+pub mod keep_awake;
 #[cfg(all(feature = "macos-media", target_os = "macos"))]
 pub mod macos_media;
 #[cfg(all(feature = "mpris", target_os = "linux"))]
@@ -8,4 +9,7 @@ pub mod mpris;
 pub mod network;
 #[cfg(feature = "streaming")]
 pub mod player;
+#[cfg(feature = "preview")]
+pub mod preview;
 pub mod redirect_uri;
+pub mod secret_store;
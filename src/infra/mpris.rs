@@ -8,10 +8,30 @@
 //! This module is only available on Linux with the `mpris` feature enabled.
 
 use anyhow::Result;
-use mpris_server::{Metadata, PlaybackStatus, Player, Time};
+use mpris_server::{Metadata, PlaybackStatus, Player, Time, TrackId};
+use std::sync::{
+  atomic::{AtomicU64, Ordering},
+  Arc,
+};
 use std::thread;
+use std::time::Duration;
 use tokio::sync::mpsc;
 
+/// How often the `Position` property is refreshed from the player's position
+const POSITION_SYNC_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The D-Bus object path MPRIS knows a track by, built from its Spotify ID or URI
+fn track_object_path(id: &str) -> Option<TrackId> {
+  let id: String = id
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+    .collect();
+  if id.is_empty() {
+    return None;
+  }
+  TrackId::try_from(format!("/org/spotatui/track/{}", id)).ok()
+}
+
 /// Events that can be received from external MPRIS clients (e.g., media keys, playerctl)
 #[derive(Debug, Clone)]
 pub enum MprisEvent {
@@ -39,6 +59,7 @@ pub enum LoopStatusEvent {
 #[derive(Debug, Clone)]
 pub enum MprisCommand {
   Metadata {
+    track_id: Option<String>,
     title: String,
     artists: Vec<String>,
     album: String,
@@ -46,11 +67,11 @@ pub enum MprisCommand {
     art_url: Option<String>,
   },
   PlaybackStatus(bool),        // true = playing, false = paused
-  Position(u64),               // position in milliseconds (silent update)
   Seeked(u64),                 // position in milliseconds (emits Seeked signal to notify clients)
   Volume(u8),                  // 0-100
   Shuffle(bool),               // shuffle state
   LoopStatus(LoopStatusEvent), // loop/repeat state
+  Controllable(bool),          // whether play/pause/next/previous/seek can be carried out
   Stopped,
 }
 
@@ -58,6 +79,8 @@ pub enum MprisCommand {
 pub struct MprisManager {
   event_rx: std::sync::Mutex<Option<mpsc::UnboundedReceiver<MprisEvent>>>,
   command_tx: mpsc::UnboundedSender<MprisCommand>,
+  /// The native player's position in milliseconds, shared with the player events
+  position: Arc<AtomicU64>,
 }
 
 impl MprisManager {
@@ -66,9 +89,13 @@ impl MprisManager {
   /// Registers spotatui as `org.mpris.MediaPlayer2.spotatui` on D-Bus
   /// The MPRIS server runs in a dedicated thread with its own runtime
   /// because player.run() returns a !Send future that requires LocalSet
-  pub fn new() -> Result<Self> {
+  ///
+  /// `Position` is served from `position`, the same atomic the player events
+  /// write to, so clients never read a value older than the sync interval
+  pub fn new(position: Arc<AtomicU64>) -> Result<Self> {
     let (event_tx, event_rx) = mpsc::unbounded_channel();
     let (command_tx, mut command_rx) = mpsc::unbounded_channel::<MprisCommand>();
+    let server_position = Arc::clone(&position);
 
     // Spawn MPRIS server in a dedicated thread with its own LocalSet runtime
     // This is required because mpris_server::Player uses Rc internally (not Send)
@@ -84,11 +111,14 @@ impl MprisManager {
         let player = match Player::builder("spotatui")
           .identity("spotatui")
           .desktop_entry("spotatui")
-          .can_play(true)
-          .can_pause(true)
-          .can_go_next(true)
-          .can_go_previous(true)
-          .can_seek(true)
+          // Nothing is loaded until the native player becomes the active device.
+          // CanControl is fixed once built, and stays true since this interface
+          // only exists alongside the native player; the rest follow the device.
+          .can_play(false)
+          .can_pause(false)
+          .can_go_next(false)
+          .can_go_previous(false)
+          .can_seek(false)
           .can_control(true)
           .can_quit(false)
           .can_raise(false)
@@ -166,10 +196,24 @@ impl MprisManager {
         // Spawn the player event loop
         tokio::task::spawn_local(player.run());
 
-        // Handle commands from the main application
-        while let Some(cmd) = command_rx.recv().await {
+        // Handle commands from the main application, keeping the position in sync
+        // in between. mpris_server answers `Position` from what it was last given.
+        let mut position_sync = tokio::time::interval(POSITION_SYNC_INTERVAL);
+        loop {
+          let cmd = tokio::select! {
+            cmd = command_rx.recv() => match cmd {
+              Some(cmd) => cmd,
+              None => break,
+            },
+            _ = position_sync.tick() => {
+              let position_ms = server_position.load(Ordering::Relaxed);
+              player.set_position(Time::from_millis(position_ms as i64));
+              continue;
+            }
+          };
           match cmd {
             MprisCommand::Metadata {
+              track_id,
               title,
               artists,
               album,
@@ -182,6 +226,9 @@ impl MprisManager {
                 .album(&album)
                 .length(Time::from_millis(duration_ms as i64));
 
+              if let Some(track_id) = track_id.as_deref().and_then(track_object_path) {
+                builder = builder.trackid(track_id);
+              }
               if let Some(url) = &art_url {
                 builder = builder.art_url(url);
               }
@@ -203,10 +250,6 @@ impl MprisManager {
                 eprintln!("MPRIS: Failed to set playback status: {}", e);
               }
             }
-            MprisCommand::Position(position_ms) => {
-              // Silent position update (for regular playback progress)
-              player.set_position(Time::from_millis(position_ms as i64));
-            }
             MprisCommand::Seeked(position_ms) => {
               // Update position AND emit Seeked signal so clients know to refresh
               let time = Time::from_millis(position_ms as i64);
@@ -237,6 +280,18 @@ impl MprisManager {
                 eprintln!("MPRIS: Failed to set loop status: {}", e);
               }
             }
+            MprisCommand::Controllable(controllable) => {
+              let results = [
+                player.set_can_play(controllable).await,
+                player.set_can_pause(controllable).await,
+                player.set_can_go_next(controllable).await,
+                player.set_can_go_previous(controllable).await,
+                player.set_can_seek(controllable).await,
+              ];
+              if let Some(Err(e)) = results.into_iter().find(Result::is_err) {
+                eprintln!("MPRIS: Failed to set capabilities: {}", e);
+              }
+            }
             MprisCommand::Stopped => {
               if let Err(e) = player.set_playback_status(PlaybackStatus::Stopped).await {
                 eprintln!("MPRIS: Failed to set stopped status: {}", e);
@@ -250,6 +305,7 @@ impl MprisManager {
     Ok(Self {
       event_rx: std::sync::Mutex::new(Some(event_rx)),
       command_tx,
+      position,
     })
   }
 
@@ -260,9 +316,10 @@ impl MprisManager {
     self.event_rx.lock().ok()?.take()
   }
 
-  /// Update track metadata
+  /// Update track metadata. `track_id` is the Spotify ID or URI, if known.
   pub fn set_metadata(
     &self,
+    track_id: Option<&str>,
     title: &str,
     artists: &[String],
     album: &str,
@@ -270,6 +327,7 @@ impl MprisManager {
     art_url: Option<String>,
   ) {
     let _ = self.command_tx.send(MprisCommand::Metadata {
+      track_id: track_id.map(str::to_string),
       title: title.to_string(),
       artists: artists.to_vec(),
       album: album.to_string(),
@@ -287,11 +345,12 @@ impl MprisManager {
 
   /// Update playback position (silent, no signal emitted)
   pub fn set_position(&self, position_ms: u64) {
-    let _ = self.command_tx.send(MprisCommand::Position(position_ms));
+    self.position.store(position_ms, Ordering::Relaxed);
   }
 
   /// Update position AND emit Seeked signal (use when position jumps due to seeking)
   pub fn emit_seeked(&self, position_ms: u64) {
+    self.set_position(position_ms);
     let _ = self.command_tx.send(MprisCommand::Seeked(position_ms));
   }
 
@@ -310,8 +369,31 @@ impl MprisManager {
     let _ = self.command_tx.send(MprisCommand::Shuffle(shuffle));
   }
 
+  /// Say whether playback requests can be carried out: only while the native
+  /// player is the active device and has a track loaded
+  pub fn set_controllable(&self, controllable: bool) {
+    let _ = self
+      .command_tx
+      .send(MprisCommand::Controllable(controllable));
+  }
+
   /// Update loop/repeat status
   pub fn set_loop_status(&self, status: LoopStatusEvent) {
     let _ = self.command_tx.send(MprisCommand::LoopStatus(status));
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn track_ids_become_object_paths() {
+    let path = |id| track_object_path(id).map(|id| id.as_str().to_string());
+    assert_eq!(
+      path("spotify:track:4uLU6hMCjMI75M1A2tKUQC").as_deref(),
+      Some("/org/spotatui/track/spotify_track_4uLU6hMCjMI75M1A2tKUQC")
+    );
+    assert_eq!(path(""), None);
+  }
+}
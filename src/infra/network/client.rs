@@ -0,0 +1,486 @@
+use super::requests::spotify_api_request_json_for;
+use chrono::Duration as ChronoDuration;
+use reqwest::Method;
+use rspotify::model::enums::RepeatState;
+use rspotify::model::{
+  CursorBasedPage, Device, Offset, PlayContextId, PlayHistory, PlayableId, PlaylistId,
+  PlaylistResult, SearchResult, SearchType,
+};
+use rspotify::prelude::OAuthClient;
+use rspotify::{AuthCodePkceSpotify, ClientResult};
+use serde_json::Value;
+use std::future::Future;
+
+/// The Spotify Web API calls the network layer makes.
+///
+/// `Network` is generic over this so the handlers can run against a fake client in
+/// tests; the real client just forwards to rspotify. Endpoints rspotify can't parse
+/// go through `request_json`, which everything built on
+/// `spotify_get_typed_compat_for` uses.
+///
+/// The futures are `Send` so calls can be made from spawned tasks.
+pub trait SpotifyApi {
+  /// A raw Web API request to `path` under `https://api.spotify.com/v1/`
+  fn request_json(
+    &self,
+    method: Method,
+    path: &str,
+    query: &[(&str, String)],
+    body: Option<Value>,
+  ) -> impl Future<Output = anyhow::Result<Value>> + Send;
+  fn pause_playback(
+    &self,
+    device_id: Option<&str>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn next_track(&self, device_id: Option<&str>) -> impl Future<Output = ClientResult<()>> + Send;
+  fn previous_track(
+    &self,
+    device_id: Option<&str>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn seek_track(
+    &self,
+    position: ChronoDuration,
+    device_id: Option<&str>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn shuffle(
+    &self,
+    state: bool,
+    device_id: Option<&str>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn repeat(
+    &self,
+    state: RepeatState,
+    device_id: Option<&str>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn volume(
+    &self,
+    volume_percent: u8,
+    device_id: Option<&str>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn start_context_playback(
+    &self,
+    context_id: PlayContextId<'static>,
+    offset: Option<Offset>,
+    position: Option<ChronoDuration>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn start_uris_playback(
+    &self,
+    uris: Vec<PlayableId<'static>>,
+    offset: Option<Offset>,
+    position: Option<ChronoDuration>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn resume_playback(&self) -> impl Future<Output = ClientResult<()>> + Send;
+  fn transfer_playback(
+    &self,
+    device_id: &str,
+    play: Option<bool>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn add_item_to_queue(
+    &self,
+    item: PlayableId<'static>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  // Only the native player's handoff and cold start use these two
+  #[cfg_attr(not(feature = "streaming"), allow(dead_code))]
+  fn device(&self) -> impl Future<Output = ClientResult<Vec<Device>>> + Send;
+  #[cfg_attr(not(feature = "streaming"), allow(dead_code))]
+  fn current_user_recently_played(
+    &self,
+    limit: u32,
+  ) -> impl Future<Output = ClientResult<CursorBasedPage<PlayHistory>>> + Send;
+  fn search(
+    &self,
+    query: &str,
+    search_type: SearchType,
+    limit: u32,
+  ) -> impl Future<Output = ClientResult<SearchResult>> + Send;
+  fn playlist_follow(
+    &self,
+    playlist_id: PlaylistId<'static>,
+    public: bool,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn playlist_unfollow(
+    &self,
+    playlist_id: PlaylistId<'static>,
+  ) -> impl Future<Output = ClientResult<()>> + Send;
+  fn playlist_add_items(
+    &self,
+    playlist_id: PlaylistId<'static>,
+    items: Vec<PlayableId<'static>>,
+  ) -> impl Future<Output = ClientResult<PlaylistResult>> + Send;
+}
+
+impl SpotifyApi for AuthCodePkceSpotify {
+  async fn request_json(
+    &self,
+    method: Method,
+    path: &str,
+    query: &[(&str, String)],
+    body: Option<Value>,
+  ) -> anyhow::Result<Value> {
+    spotify_api_request_json_for(self, method, path, query, body).await
+  }
+
+  async fn pause_playback(&self, device_id: Option<&str>) -> ClientResult<()> {
+    OAuthClient::pause_playback(self, device_id).await
+  }
+
+  async fn next_track(&self, device_id: Option<&str>) -> ClientResult<()> {
+    OAuthClient::next_track(self, device_id).await
+  }
+
+  async fn previous_track(&self, device_id: Option<&str>) -> ClientResult<()> {
+    OAuthClient::previous_track(self, device_id).await
+  }
+
+  async fn seek_track(
+    &self,
+    position: ChronoDuration,
+    device_id: Option<&str>,
+  ) -> ClientResult<()> {
+    OAuthClient::seek_track(self, position, device_id).await
+  }
+
+  async fn shuffle(&self, state: bool, device_id: Option<&str>) -> ClientResult<()> {
+    OAuthClient::shuffle(self, state, device_id).await
+  }
+
+  async fn repeat(&self, state: RepeatState, device_id: Option<&str>) -> ClientResult<()> {
+    OAuthClient::repeat(self, state, device_id).await
+  }
+
+  async fn volume(&self, volume_percent: u8, device_id: Option<&str>) -> ClientResult<()> {
+    OAuthClient::volume(self, volume_percent, device_id).await
+  }
+
+  async fn start_context_playback(
+    &self,
+    context_id: PlayContextId<'static>,
+    offset: Option<Offset>,
+    position: Option<ChronoDuration>,
+  ) -> ClientResult<()> {
+    OAuthClient::start_context_playback(self, context_id, None, offset, position).await
+  }
+
+  async fn start_uris_playback(
+    &self,
+    uris: Vec<PlayableId<'static>>,
+    offset: Option<Offset>,
+    position: Option<ChronoDuration>,
+  ) -> ClientResult<()> {
+    OAuthClient::start_uris_playback(self, uris, None, offset, position).await
+  }
+
+  async fn resume_playback(&self) -> ClientResult<()> {
+    OAuthClient::resume_playback(self, None, None).await
+  }
+
+  async fn transfer_playback(&self, device_id: &str, play: Option<bool>) -> ClientResult<()> {
+    OAuthClient::transfer_playback(self, device_id, play).await
+  }
+
+  async fn add_item_to_queue(&self, item: PlayableId<'static>) -> ClientResult<()> {
+    OAuthClient::add_item_to_queue(self, item, None).await
+  }
+
+  async fn device(&self) -> ClientResult<Vec<Device>> {
+    OAuthClient::device(self).await
+  }
+
+  async fn current_user_recently_played(
+    &self,
+    limit: u32,
+  ) -> ClientResult<CursorBasedPage<PlayHistory>> {
+    OAuthClient::current_user_recently_played(self, Some(limit), None).await
+  }
+
+  async fn search(
+    &self,
+    query: &str,
+    search_type: SearchType,
+    limit: u32,
+  ) -> ClientResult<SearchResult> {
+    rspotify::prelude::BaseClient::search(
+      self,
+      query,
+      search_type,
+      None,
+      None, // include_external
+      Some(limit),
+      Some(0),
+    )
+    .await
+  }
+
+  async fn playlist_follow(
+    &self,
+    playlist_id: PlaylistId<'static>,
+    public: bool,
+  ) -> ClientResult<()> {
+    OAuthClient::playlist_follow(self, playlist_id, Some(public)).await
+  }
+
+  async fn playlist_unfollow(&self, playlist_id: PlaylistId<'static>) -> ClientResult<()> {
+    OAuthClient::playlist_unfollow(self, playlist_id).await
+  }
+
+  async fn playlist_add_items(
+    &self,
+    playlist_id: PlaylistId<'static>,
+    items: Vec<PlayableId<'static>>,
+  ) -> ClientResult<PlaylistResult> {
+    OAuthClient::playlist_add_items(self, playlist_id, items, None).await
+  }
+}
+
+/// Records every call instead of talking to Spotify, optionally failing them all.
+///
+/// `request_json` answers from `responses`, keyed by method and path and handed
+/// out in order, so pagination can be scripted page by page. Paths with nothing
+/// queued answer `null`. Clones share their calls and responses, like clones of
+/// the real client share its token.
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub struct MockSpotify {
+  pub calls: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+  pub fail: bool,
+  /// Fail with this message instead of an invalid token
+  pub fail_with: Option<String>,
+  #[allow(clippy::type_complexity)]
+  pub responses: std::sync::Arc<
+    std::sync::Mutex<
+      std::collections::HashMap<String, std::collections::VecDeque<Result<Value, String>>>,
+    >,
+  >,
+}
+
+#[cfg(test)]
+impl MockSpotify {
+  pub fn calls(&self) -> Vec<String> {
+    self.calls.lock().unwrap().clone()
+  }
+
+  /// Queues the answer to the next `method path` request, e.g. `"GET me/playlists"`
+  pub fn respond(&self, request: &str, response: Result<Value, String>) {
+    self
+      .responses
+      .lock()
+      .unwrap()
+      .entry(request.to_string())
+      .or_default()
+      .push_back(response);
+  }
+
+  fn failure(&self) -> Option<rspotify::ClientError> {
+    match &self.fail_with {
+      Some(message) => Some(rspotify::ClientError::Io(std::io::Error::other(
+        message.clone(),
+      ))),
+      None if self.fail => Some(rspotify::ClientError::InvalidToken),
+      None => None,
+    }
+  }
+
+  fn record(&self, call: String) -> ClientResult<()> {
+    self.calls.lock().unwrap().push(call);
+    match self.failure() {
+      Some(e) => Err(e),
+      None => Ok(()),
+    }
+  }
+}
+
+/// ` at <offset>` and ` from <ms>` for the parts of a playback start that are set
+#[cfg(test)]
+fn describe_start(offset: Option<Offset>, position: Option<ChronoDuration>) -> String {
+  let offset = match offset {
+    Some(Offset::Position(index)) => format!(" at {}", index.num_milliseconds()),
+    Some(Offset::Uri(uri)) => format!(" at {}", uri),
+    None => String::new(),
+  };
+  let position = position
+    .map(|position| format!(" from {}", position.num_milliseconds()))
+    .unwrap_or_default();
+  offset + &position
+}
+
+#[cfg(test)]
+impl SpotifyApi for MockSpotify {
+  async fn request_json(
+    &self,
+    method: Method,
+    path: &str,
+    query: &[(&str, String)],
+    _body: Option<Value>,
+  ) -> anyhow::Result<Value> {
+    let request = format!("{} {}", method, path);
+    let query: Vec<String> = query.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    self.record(
+      format!("{} {}", request, query.join("&"))
+        .trim_end()
+        .to_string(),
+    )?;
+    let response = self
+      .responses
+      .lock()
+      .unwrap()
+      .get_mut(&request)
+      .and_then(|queue| queue.pop_front());
+    match response {
+      Some(Ok(value)) => Ok(value),
+      Some(Err(message)) => Err(anyhow::anyhow!(message)),
+      None => Ok(Value::Null),
+    }
+  }
+
+  async fn pause_playback(&self, _device_id: Option<&str>) -> ClientResult<()> {
+    self.record("pause_playback".to_string())
+  }
+
+  async fn next_track(&self, _device_id: Option<&str>) -> ClientResult<()> {
+    self.record("next_track".to_string())
+  }
+
+  async fn previous_track(&self, _device_id: Option<&str>) -> ClientResult<()> {
+    self.record("previous_track".to_string())
+  }
+
+  async fn seek_track(
+    &self,
+    position: ChronoDuration,
+    device_id: Option<&str>,
+  ) -> ClientResult<()> {
+    match device_id {
+      Some(device_id) => self.record(format!(
+        "seek_track {} on {}",
+        position.num_milliseconds(),
+        device_id
+      )),
+      None => self.record(format!("seek_track {}", position.num_milliseconds())),
+    }
+  }
+
+  async fn shuffle(&self, state: bool, _device_id: Option<&str>) -> ClientResult<()> {
+    self.record(format!("shuffle {}", state))
+  }
+
+  async fn repeat(&self, state: RepeatState, _device_id: Option<&str>) -> ClientResult<()> {
+    self.record(format!("repeat {:?}", state))
+  }
+
+  async fn volume(&self, volume_percent: u8, _device_id: Option<&str>) -> ClientResult<()> {
+    self.record(format!("volume {}", volume_percent))
+  }
+
+  async fn start_context_playback(
+    &self,
+    context_id: PlayContextId<'static>,
+    offset: Option<Offset>,
+    position: Option<ChronoDuration>,
+  ) -> ClientResult<()> {
+    use rspotify::prelude::Id;
+    self.record(format!(
+      "start_context_playback {}{}",
+      context_id.uri(),
+      describe_start(offset, position)
+    ))
+  }
+
+  async fn start_uris_playback(
+    &self,
+    uris: Vec<PlayableId<'static>>,
+    offset: Option<Offset>,
+    position: Option<ChronoDuration>,
+  ) -> ClientResult<()> {
+    use rspotify::prelude::Id;
+    let uris: Vec<String> = uris.iter().map(|uri| uri.uri()).collect();
+    self.record(format!(
+      "start_uris_playback {}{}",
+      uris.join(","),
+      describe_start(offset, position)
+    ))
+  }
+
+  async fn resume_playback(&self) -> ClientResult<()> {
+    self.record("resume_playback".to_string())
+  }
+
+  async fn transfer_playback(&self, device_id: &str, _play: Option<bool>) -> ClientResult<()> {
+    self.record(format!("transfer_playback {}", device_id))
+  }
+
+  async fn add_item_to_queue(&self, item: PlayableId<'static>) -> ClientResult<()> {
+    use rspotify::prelude::Id;
+    self.record(format!("add_item_to_queue {}", item.uri()))
+  }
+
+  async fn device(&self) -> ClientResult<Vec<Device>> {
+    self.record("device".to_string()).map(|_| Vec::new())
+  }
+
+  async fn current_user_recently_played(
+    &self,
+    limit: u32,
+  ) -> ClientResult<CursorBasedPage<PlayHistory>> {
+    self.record(format!("current_user_recently_played {}", limit))?;
+    Ok(
+      serde_json::from_value(serde_json::json!({
+        "href": "", "items": [], "limit": limit, "next": null,
+        "cursors": null, "total": 0
+      }))
+      .expect("empty history page"),
+    )
+  }
+
+  async fn search(
+    &self,
+    query: &str,
+    search_type: SearchType,
+    limit: u32,
+  ) -> ClientResult<SearchResult> {
+    self.record(format!("search {:?} {:?} {}", query, search_type, limit))?;
+    let empty = serde_json::json!({
+      "href": "", "items": [], "limit": limit, "next": null,
+      "offset": 0, "previous": null, "total": 0
+    });
+    let result = match search_type {
+      SearchType::Track => SearchResult::Tracks(serde_json::from_value(empty)?),
+      SearchType::Album => SearchResult::Albums(serde_json::from_value(empty)?),
+      SearchType::Playlist => SearchResult::Playlists(serde_json::from_value(empty)?),
+      SearchType::Show => SearchResult::Shows(serde_json::from_value(empty)?),
+      SearchType::Artist => SearchResult::Artists(serde_json::from_value(empty)?),
+      SearchType::Episode => SearchResult::Episodes(serde_json::from_value(empty)?),
+    };
+    Ok(result)
+  }
+
+  async fn playlist_follow(
+    &self,
+    playlist_id: PlaylistId<'static>,
+    public: bool,
+  ) -> ClientResult<()> {
+    use rspotify::prelude::Id;
+    self.record(format!("playlist_follow {} {}", playlist_id.id(), public))
+  }
+
+  async fn playlist_unfollow(&self, playlist_id: PlaylistId<'static>) -> ClientResult<()> {
+    use rspotify::prelude::Id;
+    self.record(format!("playlist_unfollow {}", playlist_id.id()))
+  }
+
+  async fn playlist_add_items(
+    &self,
+    playlist_id: PlaylistId<'static>,
+    items: Vec<PlayableId<'static>>,
+  ) -> ClientResult<PlaylistResult> {
+    use rspotify::prelude::Id;
+    let items: Vec<String> = items.iter().map(|item| item.uri()).collect();
+    self.record(format!(
+      "playlist_add_items {} {}",
+      playlist_id.id(),
+      items.join(",")
+    ))?;
+    Ok(PlaylistResult {
+      snapshot_id: "snapshot".to_string(),
+    })
+  }
+}
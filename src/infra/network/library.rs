@@ -1,20 +1,25 @@
-use super::requests::{spotify_api_request_json_for, spotify_get_typed_compat_for};
-use super::Network;
+use super::client::SpotifyApi;
+use super::liked_cache::{LikedStateCache, LIKED_STATE_COALESCE_WINDOW};
+use super::requests::spotify_get_typed_compat_for;
+use super::{IoEvent, Network};
 use crate::core::app::{
-  ActiveBlock, App, PlaylistFolder, PlaylistFolderItem, PlaylistFolderNode, PlaylistFolderNodeType,
-  RouteId, TrackTableContext,
+  playlist_visibility_label, ActiveBlock, App, DialogContext, PlaylistFolder, PlaylistFolderItem,
+  PlaylistFolderNode, PlaylistFolderNodeType, PlaylistTrackAdd, RouteId, SearchResultBlock,
+  TrackTableContext,
 };
+use crate::core::locale::{t, tf};
 use anyhow::anyhow;
 use reqwest::Method;
 use rspotify::model::{
-  idtypes::{AlbumId, PlaylistId, ShowId, TrackId, UserId},
+  idtypes::{AlbumId, EpisodeId, PlaylistId, ShowId, TrackId, UserId},
   page::Page,
-  playlist::PlaylistItem,
+  playlist::{PlaylistItem, SimplifiedPlaylist},
   track::FullTrack,
   PlayableItem,
 };
 use rspotify::{prelude::*, AuthCodePkceSpotify};
 use serde_json::json;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
@@ -127,14 +132,144 @@ pub async fn prefetch_all_playlist_tracks_task(
   }
 }
 
+/// Download every song on a playlist into the native player's audio cache one at
+/// a time, keeping `offline_cache_progress` up to date for the playbar
+#[cfg(feature = "streaming")]
+pub async fn cache_playlist_offline_task(
+  spotify: AuthCodePkceSpotify,
+  app: Arc<Mutex<App>>,
+  player: Arc<StreamingPlayer>,
+  playlist_id: PlaylistId<'static>,
+  name: String,
+) {
+  let mut uris = Vec::new();
+  let mut offset = 0u32;
+  let limit = 50u32;
+  let path = format!("playlists/{}/items", playlist_id.id());
+  loop {
+    let query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
+    match spotify_get_typed_compat_for::<Page<PlaylistItem>>(&spotify, &path, &query).await {
+      Ok(page) => {
+        // Local files have no ID and aren't Spotify's to download
+        uris.extend(page.items.iter().filter_map(|item| match &item.track {
+          Some(PlayableItem::Track(track)) => track.id.as_ref().map(|id| id.uri()),
+          _ => None,
+        }));
+        if page.next.is_none() || page.items.is_empty() {
+          break;
+        }
+        offset += limit;
+      }
+      Err(e) => {
+        let mut app = app.lock().await;
+        app.offline_cache_progress = None;
+        app.handle_error(anyhow!(e));
+        return;
+      }
+    }
+  }
+
+  let total = uris.len();
+  app.lock().await.offline_cache_progress = Some((name.clone(), 0, total));
+  let mut failed = 0;
+  for (done, uri) in uris.iter().enumerate() {
+    if let Err(e) = player.cache_track(uri).await {
+      log::warn!("couldn't cache {} for offline playback: {}", uri, e);
+      failed += 1;
+    }
+    app.lock().await.offline_cache_progress = Some((name.clone(), done + 1, total));
+  }
+
+  let mut app = app.lock().await;
+  app.offline_cache_progress = None;
+  let message = if failed == 0 {
+    tf(
+      "status.offline_cache_done",
+      &[("total", &total.to_string()), ("name", &name)],
+    )
+  } else {
+    tf(
+      "status.offline_cache_partial",
+      &[
+        ("cached", &(total - failed).to_string()),
+        ("total", &total.to_string()),
+        ("name", &name),
+      ],
+    )
+  };
+  app.set_status_message(message, 6);
+}
+
+/// Maximum number of URIs sent in a single library request
+const LIBRARY_BATCH_SIZE: usize = 50;
+
+/// Resolve every queued liked-state miss in batched `me/library/contains` requests.
+pub async fn flush_liked_state_task(
+  spotify: impl SpotifyApi,
+  app: Arc<Mutex<App>>,
+  liked_state: Arc<Mutex<LikedStateCache>>,
+) {
+  loop {
+    let batch = liked_state.lock().await.take_batch();
+    if batch.is_empty() {
+      break;
+    }
+
+    let uris: Vec<String> = batch
+      .iter()
+      .map(|id| format!("spotify:track:{}", id))
+      .collect();
+    match spotify_get_typed_compat_for::<Vec<bool>>(
+      &spotify,
+      "me/library/contains",
+      &[("uris", uris.join(","))],
+    )
+    .await
+    {
+      Ok(is_saved_vec) => {
+        let states: Vec<(String, bool)> = batch.into_iter().zip(is_saved_vec).collect();
+        {
+          let mut cache = liked_state.lock().await;
+          let now = Instant::now();
+          for (id, liked) in &states {
+            cache.record(id, *liked, now);
+          }
+        }
+        let mut app = app.lock().await;
+        apply_liked_states(&mut app, &states);
+      }
+      Err(e) => {
+        // Leave the batch uncached so the next check retries it
+        let mut app = app.lock().await;
+        app.status_message = Some(format!("Could not check liked track state: {}", e));
+        app.status_message_expires_at = Some(Instant::now() + Duration::from_secs(5));
+      }
+    }
+  }
+}
+
+fn apply_liked_states(app: &mut App, states: &[(String, bool)]) {
+  for (id, liked) in states {
+    if *liked {
+      app.liked_song_ids_set.insert(id.clone());
+    } else {
+      app.liked_song_ids_set.remove(id);
+    }
+  }
+}
+
 pub trait LibraryNetwork {
-  async fn get_current_user_playlists(&mut self);
+  /// Fetch every page of the user's playlists. A `background` refresh follows the
+  /// sidebar as the user moves it meanwhile and only logs failures.
+  async fn get_current_user_playlists(&mut self, background: bool);
   async fn get_playlist_tracks(&mut self, playlist_id: PlaylistId<'static>, playlist_offset: u32);
   async fn get_current_user_saved_tracks(&mut self, offset: Option<u32>);
   async fn get_current_user_saved_albums(&mut self, offset: Option<u32>);
   async fn current_user_saved_albums_contains(&mut self, album_ids: Vec<AlbumId<'static>>);
   async fn current_user_saved_album_delete(&mut self, album_id: AlbumId<'static>);
   async fn current_user_saved_album_add(&mut self, album_id: AlbumId<'static>);
+  async fn current_user_saved_albums_delete(&mut self, album_ids: Vec<AlbumId<'static>>);
+  async fn current_user_saved_tracks_delete(&mut self, track_ids: Vec<TrackId<'static>>);
   async fn current_user_saved_shows_contains(&mut self, show_ids: Vec<ShowId<'static>>);
   async fn current_user_saved_shows_delete(&mut self, show_id: ShowId<'static>);
   async fn current_user_saved_shows_add(&mut self, show_id: ShowId<'static>);
@@ -155,14 +290,31 @@ pub trait LibraryNetwork {
     playlist_id: PlaylistId<'static>,
     track_id: TrackId<'static>,
   );
+  async fn add_track_to_playlist_checked(&mut self, add: PlaylistTrackAdd);
   async fn remove_track_from_playlist_at_position(
     &mut self,
     playlist_id: PlaylistId<'static>,
     track_id: TrackId<'static>,
     position: usize,
   );
+  async fn change_playlist_visibility(
+    &mut self,
+    playlist_id: PlaylistId<'static>,
+    public: bool,
+    collaborative: bool,
+  );
+  async fn get_playlist_description(&mut self, playlist_id: PlaylistId<'static>);
+  async fn change_playlist_details(
+    &mut self,
+    playlist_id: PlaylistId<'static>,
+    name: Option<String>,
+    description: Option<String>,
+  );
+  async fn get_user_playlists(&mut self, user_id: UserId<'static>, name: String);
+  async fn create_playlist(&mut self, name: String, public: bool);
   async fn toggle_save_track(&mut self, track_id: rspotify::model::idtypes::PlayableId<'static>);
   async fn current_user_saved_tracks_contains(&mut self, ids: Vec<TrackId<'static>>);
+  async fn current_user_saved_episodes_contains(&mut self, episode_ids: Vec<EpisodeId<'static>>);
   async fn fetch_all_playlist_tracks_and_sort(&mut self, playlist_id: PlaylistId<'static>);
 
   // Helpers exposed via trait if needed, or kept private if only used internally
@@ -170,8 +322,39 @@ pub trait LibraryNetwork {
 }
 
 // Private helper methods
-impl Network {
-  async fn library_contains_uris(&self, uris: &[String]) -> anyhow::Result<Vec<bool>> {
+impl<C: SpotifyApi + Clone + Send + Sync + 'static> Network<C> {
+  async fn playlist_contains_track(
+    &self,
+    playlist_id: &PlaylistId<'static>,
+    track_id: &TrackId<'static>,
+  ) -> anyhow::Result<bool> {
+    let path = format!("playlists/{}/items", playlist_id.id());
+    let limit = 100u32;
+    let mut offset = 0u32;
+
+    loop {
+      let query = vec![
+        ("fields", "items(track(id)),next".to_string()),
+        ("limit", limit.to_string()),
+        ("offset", offset.to_string()),
+      ];
+      let page: serde_json::Value =
+        spotify_get_typed_compat_for(&self.spotify, &path, &query).await?;
+      let items = page["items"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+      if items
+        .iter()
+        .any(|item| item["track"]["id"].as_str() == Some(track_id.id()))
+      {
+        return Ok(true);
+      }
+      if items.is_empty() || page["next"].is_null() {
+        return Ok(false);
+      }
+      offset += limit;
+    }
+  }
+
+  pub(super) async fn library_contains_uris(&self, uris: &[String]) -> anyhow::Result<Vec<bool>> {
     if uris.is_empty() {
       return Ok(Vec::new());
     }
@@ -190,14 +373,15 @@ impl Network {
     }
 
     let query = vec![("uris", uris.join(","))];
-    spotify_api_request_json_for(
-      &self.spotify,
-      Method::PUT,
-      "me/library",
-      &query,
-      Some(json!({ "uris": uris })),
-    )
-    .await?;
+    self
+      .spotify
+      .request_json(
+        Method::PUT,
+        "me/library",
+        &query,
+        Some(json!({ "uris": uris })),
+      )
+      .await?;
     Ok(())
   }
 
@@ -207,17 +391,37 @@ impl Network {
     }
 
     let query = vec![("uris", uris.join(","))];
-    spotify_api_request_json_for(
-      &self.spotify,
-      Method::DELETE,
-      "me/library",
-      &query,
-      Some(json!({ "uris": uris })),
-    )
-    .await?;
+    self
+      .spotify
+      .request_json(
+        Method::DELETE,
+        "me/library",
+        &query,
+        Some(json!({ "uris": uris })),
+      )
+      .await?;
     Ok(())
   }
 
+  /// Remove URIs from the library in batches, reporting progress in the status bar.
+  /// Returns how many URIs were removed before the first failure, if any.
+  async fn library_remove_uris_batched(&self, uris: &[String]) -> (usize, Option<anyhow::Error>) {
+    let total = uris.len();
+    let mut removed = 0;
+    for chunk in uris.chunks(LIBRARY_BATCH_SIZE) {
+      if let Err(e) = self.library_remove_uris(chunk).await {
+        return (removed, Some(e));
+      }
+      removed += chunk.len();
+      if removed < total {
+        self
+          .show_status_message(format!("Removing from library... {}/{}", removed, total), 5)
+          .await;
+      }
+    }
+    (removed, None)
+  }
+
   async fn set_playlist_tracks_to_table(&mut self, playlist_track_page: &Page<PlaylistItem>) {
     let mut tracks: Vec<FullTrack> = Vec::new();
     let mut positions: Vec<usize> = Vec::new();
@@ -236,15 +440,22 @@ impl Network {
   }
 }
 
-impl LibraryNetwork for Network {
-  async fn get_current_user_playlists(&mut self) {
-    let (preferred_playlist_id, preferred_folder_id, preferred_selected_index) = {
-      let app = self.app.lock().await;
-      (
-        app.get_selected_playlist_id(),
-        app.current_playlist_folder_id,
-        app.selected_playlist_index,
-      )
+impl<C: SpotifyApi + Clone + Send + Sync + 'static> LibraryNetwork for Network<C> {
+  async fn get_current_user_playlists(&mut self, background: bool) {
+    let (started_selection, generation) = {
+      let mut app = self.app.lock().await;
+      // Bump the generation so a fetch still in flight stops writing its pages
+      app.playlist_refresh_generation += 1;
+      app.playlists_fully_loaded = false;
+      (PlaylistSelection::of(&app), app.playlist_refresh_generation)
+    };
+    // Read before the playlists are replaced, while the selection still points into them
+    let selection = |app: &App| {
+      if background {
+        PlaylistSelection::of(app)
+      } else {
+        started_selection.clone()
+      }
     };
 
     let limit = 50u32;
@@ -253,10 +464,13 @@ impl LibraryNetwork for Network {
     let mut first_page = None;
 
     loop {
-      match self
-        .spotify
-        .current_user_playlists_manual(Some(limit), Some(offset))
-        .await
+      // Goes through the paced request helper so large libraries back off on 429s
+      match spotify_get_typed_compat_for::<Page<SimplifiedPlaylist>>(
+        &self.spotify,
+        "me/playlists",
+        &[("limit", limit.to_string()), ("offset", offset.to_string())],
+      )
+      .await
       {
         Ok(page) => {
           if offset == 0 {
@@ -267,14 +481,39 @@ impl LibraryNetwork for Network {
             break;
           }
 
+          let has_next = page.next.is_some();
           all_playlists.extend(page.items);
 
-          if page.next.is_none() {
+          let mut app = self.app.lock().await;
+          if app.playlist_refresh_generation != generation {
+            return;
+          }
+
+          // Render pages as they arrive, unless a folder tree is showing: its indices
+          // point into the previous `all_playlists` until the refresh completes
+          if app._playlist_folder_nodes.is_none() {
+            let selection = selection(&app);
+            app.playlists = first_page.clone();
+            app.all_playlists = all_playlists.clone();
+            app.playlist_folder_items = build_flat_playlist_items(&all_playlists);
+            reconcile_playlist_selection(&mut app, &selection);
+          }
+
+          if !has_next {
             break;
           }
           offset += limit;
         }
+        Err(e) if background => {
+          log::warn!("background playlist refresh failed: {}", e);
+          let mut app = self.app.lock().await;
+          if app.playlist_refresh_generation == generation {
+            app.playlists_fully_loaded = true;
+          }
+          return;
+        }
         Err(e) => {
+          self.app.lock().await.abandon_first_load();
           self.handle_error(anyhow!(e)).await;
           return;
         }
@@ -293,17 +532,18 @@ impl LibraryNetwork for Network {
     };
 
     let mut app = self.app.lock().await;
+    if app.playlist_refresh_generation != generation {
+      return;
+    }
+    let selection = selection(&app);
     app.playlists = first_page;
     app.all_playlists = all_playlists;
     app._playlist_folder_nodes = folder_nodes;
     app.playlist_folder_items = folder_items;
+    app.playlists_fully_loaded = true;
+    app.check_first_load();
 
-    reconcile_playlist_selection(
-      &mut app,
-      preferred_playlist_id.as_deref(),
-      preferred_folder_id,
-      preferred_selected_index,
-    );
+    reconcile_playlist_selection(&mut app, &selection);
   }
 
   async fn get_playlist_tracks(&mut self, playlist_id: PlaylistId<'static>, playlist_offset: u32) {
@@ -314,6 +554,8 @@ impl LibraryNetwork for Network {
       &[
         ("limit", self.large_search_limit.to_string()),
         ("offset", playlist_offset.to_string()),
+        // Adds `is_playable` and `restrictions` to each track
+        ("market", "from_token".to_string()),
       ],
     )
     .await
@@ -322,6 +564,20 @@ impl LibraryNetwork for Network {
         self.set_playlist_tracks_to_table(&playlist_tracks).await;
 
         let mut app = self.app.lock().await;
+        let app = &mut *app;
+        // Enhance carries over to other pages of its playlist only
+        match app.playlist_enhance.as_mut() {
+          Some(enhance) if enhance.playlist_id == playlist_id => {
+            let tracks = std::mem::take(&mut app.track_table.tracks);
+            let on_last = app.track_table.selected_index + 1 == tracks.len();
+            app.track_table.tracks = enhance.interleave(tracks);
+            if on_last {
+              app.track_table.selected_index = app.track_table.tracks.len() - 1;
+            }
+          }
+          Some(_) => app.playlist_enhance = None,
+          None => {}
+        }
         app.playlist_tracks = Some(playlist_tracks);
         app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
       }
@@ -332,7 +588,10 @@ impl LibraryNetwork for Network {
   }
 
   async fn get_current_user_saved_tracks(&mut self, offset: Option<u32>) {
-    let mut query = vec![("limit", self.large_search_limit.to_string())];
+    let mut query = vec![
+      ("limit", self.large_search_limit.to_string()),
+      ("market", "from_token".to_string()),
+    ];
     if let Some(offset) = offset {
       query.push(("offset", offset.to_string()));
     }
@@ -345,6 +604,19 @@ impl LibraryNetwork for Network {
     .await
     {
       Ok(saved_tracks) => {
+        {
+          // Everything in Liked Songs is liked; no need to ask again this session
+          let mut liked_state = self.liked_state.lock().await;
+          let now = Instant::now();
+          for track_id in saved_tracks
+            .items
+            .iter()
+            .filter_map(|item| item.track.id.as_ref())
+          {
+            liked_state.record(track_id.id(), true, now);
+          }
+        }
+
         let mut app = self.app.lock().await;
         app.track_table.tracks = saved_tracks
           .items
@@ -454,6 +726,103 @@ impl LibraryNetwork for Network {
     }
   }
 
+  async fn current_user_saved_albums_delete(&mut self, album_ids: Vec<AlbumId<'static>>) {
+    let uris: Vec<String> = album_ids
+      .iter()
+      .map(|id| format!("spotify:album:{}", id.id()))
+      .collect();
+
+    let (removed, error) = self.library_remove_uris_batched(&uris).await;
+    let removed_ids: HashSet<String> = album_ids[..removed]
+      .iter()
+      .map(|id| id.id().to_string())
+      .collect();
+
+    {
+      let mut app = self.app.lock().await;
+      for id in &removed_ids {
+        app.saved_album_ids_set.remove(id);
+        app.marked_album_ids.remove(id);
+      }
+      for page in app.library.saved_albums.pages.iter_mut() {
+        page
+          .items
+          .retain(|item| !removed_ids.contains(item.album.id.id()));
+      }
+      let remaining = app
+        .library
+        .saved_albums
+        .get_results(None)
+        .map(|page| page.items.len())
+        .unwrap_or(0);
+      app.album_list_index = app.album_list_index.min(remaining.saturating_sub(1));
+    }
+
+    match error {
+      Some(e) => self.handle_error(anyhow!(e)).await,
+      None => {
+        self
+          .show_status_message(format!("Removed {} albums from library", removed), 4)
+          .await
+      }
+    }
+  }
+
+  async fn current_user_saved_tracks_delete(&mut self, track_ids: Vec<TrackId<'static>>) {
+    let uris: Vec<String> = track_ids
+      .iter()
+      .map(|id| format!("spotify:track:{}", id.id()))
+      .collect();
+
+    let (removed, error) = self.library_remove_uris_batched(&uris).await;
+    let removed_ids: HashSet<String> = track_ids[..removed]
+      .iter()
+      .map(|id| id.id().to_string())
+      .collect();
+
+    {
+      let mut liked_state = self.liked_state.lock().await;
+      let now = Instant::now();
+      for id in &removed_ids {
+        liked_state.record(id, false, now);
+      }
+    }
+
+    {
+      let mut app = self.app.lock().await;
+      for id in &removed_ids {
+        app.liked_song_ids_set.remove(id);
+        app.marked_track_ids.remove(id);
+      }
+      let is_removed = |track: &FullTrack| {
+        track
+          .id
+          .as_ref()
+          .is_some_and(|id| removed_ids.contains(id.id()))
+      };
+      for page in app.library.saved_tracks.pages.iter_mut() {
+        page.items.retain(|item| !is_removed(&item.track));
+      }
+      if app.track_table.context == Some(TrackTableContext::SavedTracks) {
+        app.track_table.tracks.retain(|track| !is_removed(track));
+        let remaining = app.track_table.tracks.len();
+        app.track_table.selected_index = app
+          .track_table
+          .selected_index
+          .min(remaining.saturating_sub(1));
+      }
+    }
+
+    match error {
+      Some(e) => self.handle_error(anyhow!(e)).await,
+      None => {
+        self
+          .show_status_message(format!("Removed {} tracks from Liked Songs", removed), 4)
+          .await
+      }
+    }
+  }
+
   async fn current_user_saved_shows_contains(&mut self, show_ids: Vec<ShowId<'static>>) {
     let uris: Vec<String> = show_ids
       .iter()
@@ -533,7 +902,7 @@ impl LibraryNetwork for Network {
   ) {
     match self
       .spotify
-      .playlist_follow(playlist_id, Some(is_public.unwrap_or(false)))
+      .playlist_follow(playlist_id, is_public.unwrap_or(false))
       .await
     {
       Ok(_) => {
@@ -563,7 +932,7 @@ impl LibraryNetwork for Network {
   ) {
     match self
       .spotify
-      .playlist_add_items(playlist_id, vec![PlayableId::Track(track_id)], None)
+      .playlist_add_items(playlist_id, vec![PlayableId::Track(track_id)])
       .await
     {
       Ok(_) => {
@@ -575,6 +944,33 @@ impl LibraryNetwork for Network {
     }
   }
 
+  async fn add_track_to_playlist_checked(&mut self, add: PlaylistTrackAdd) {
+    match self
+      .playlist_contains_track(&add.playlist_id, &add.track_id)
+      .await
+    {
+      Ok(true) => {
+        let mut app = self.app.lock().await;
+        app.set_status_message(
+          format!("{} is already in {}", add.track_name, add.playlist_name),
+          4,
+        );
+        app.confirm = false;
+        app.pending_duplicate_track_add = Some(add);
+        app.push_navigation_stack(
+          RouteId::Dialog,
+          ActiveBlock::Dialog(DialogContext::DuplicateTrackAddConfirm),
+        );
+      }
+      // A failed check shouldn't block the add, Spotify accepts it either way
+      Ok(false) | Err(_) => {
+        self
+          .add_track_to_playlist(add.playlist_id, add.track_id)
+          .await
+      }
+    }
+  }
+
   async fn remove_track_from_playlist_at_position(
     &mut self,
     playlist_id: PlaylistId<'static>,
@@ -588,14 +984,15 @@ impl LibraryNetwork for Network {
         }]
     });
 
-    match spotify_api_request_json_for(
-      &self.spotify,
-      Method::DELETE,
-      &format!("playlists/{}/tracks", playlist_id.id()),
-      &[],
-      Some(body),
-    )
-    .await
+    match self
+      .spotify
+      .request_json(
+        Method::DELETE,
+        &format!("playlists/{}/tracks", playlist_id.id()),
+        &[],
+        Some(body),
+      )
+      .await
     {
       Ok(_) => {
         self
@@ -606,63 +1003,255 @@ impl LibraryNetwork for Network {
     }
   }
 
-  async fn toggle_save_track(&mut self, track_id: rspotify::model::idtypes::PlayableId<'static>) {
-    let id_str = match &track_id {
-      PlayableId::Track(id) => id.id(),
-      PlayableId::Episode(id) => id.id(),
-    };
-    let uri = match &track_id {
-      PlayableId::Track(id) => format!("spotify:track:{}", id.id()),
-      PlayableId::Episode(id) => format!("spotify:episode:{}", id.id()),
-    };
+  async fn change_playlist_visibility(
+    &mut self,
+    playlist_id: PlaylistId<'static>,
+    public: bool,
+    collaborative: bool,
+  ) {
+    match self
+      .spotify
+      .request_json(
+        Method::PUT,
+        &format!("playlists/{}", playlist_id.id()),
+        &[],
+        Some(json!({ "public": public, "collaborative": collaborative })),
+      )
+      .await
+    {
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        let app = &mut *app;
+        let first_page = app
+          .playlists
+          .iter_mut()
+          .flat_map(|page| page.items.iter_mut());
+        for playlist in app.all_playlists.iter_mut().chain(first_page) {
+          if playlist.id == playlist_id {
+            playlist.public = Some(public);
+            playlist.collaborative = collaborative;
+          }
+        }
+        app.set_status_message(
+          format!(
+            "Playlist is now {}",
+            playlist_visibility_label(Some(public), collaborative).to_lowercase()
+          ),
+          3,
+        );
+      }
+      Err(e) => self.handle_error(anyhow!(e)).await,
+    }
+  }
 
-    let is_liked = {
-      let app = self.app.lock().await;
-      app.liked_song_ids_set.contains(id_str)
-    };
+  async fn get_playlist_description(&mut self, playlist_id: PlaylistId<'static>) {
+    let path = format!("playlists/{}", playlist_id.id());
+    let query = [("fields", "description".to_string())];
+    match self
+      .spotify
+      .request_json(Method::GET, &path, &query, None)
+      .await
+    {
+      Ok(value) => {
+        let description = value["description"]
+          .as_str()
+          .unwrap_or_default()
+          .to_string();
+        let mut app = self.app.lock().await;
+        // The prompt may have been closed or moved to another playlist meanwhile
+        if let Some(edit) = app
+          .playlist_details_edit
+          .as_mut()
+          .filter(|edit| edit.playlist_id == playlist_id && edit.original_description.is_none())
+        {
+          if edit.description.is_empty() {
+            edit.description = description.clone();
+          }
+          edit.original_description = Some(description);
+        }
+      }
+      Err(e) => self.handle_error(anyhow!(e)).await,
+    }
+  }
 
-    if is_liked {
-      if let Err(e) = self.library_remove_uris(&[uri]).await {
-        self.handle_error(anyhow!(e)).await;
-      } else {
+  async fn change_playlist_details(
+    &mut self,
+    playlist_id: PlaylistId<'static>,
+    name: Option<String>,
+    description: Option<String>,
+  ) {
+    let mut body = serde_json::Map::new();
+    if let Some(name) = &name {
+      body.insert("name".to_string(), json!(name));
+    }
+    if let Some(description) = &description {
+      body.insert("description".to_string(), json!(description));
+    }
+    match self
+      .spotify
+      .request_json(
+        Method::PUT,
+        &format!("playlists/{}", playlist_id.id()),
+        &[],
+        Some(body.into()),
+      )
+      .await
+    {
+      Ok(_) => {
         let mut app = self.app.lock().await;
-        app.liked_song_ids_set.remove(id_str);
+        let app = &mut *app;
+        if let Some(name) = &name {
+          let first_page = app
+            .playlists
+            .iter_mut()
+            .flat_map(|page| page.items.iter_mut());
+          for playlist in app.all_playlists.iter_mut().chain(first_page) {
+            if playlist.id == playlist_id {
+              playlist.name = name.clone();
+            }
+          }
+        }
+        let message = match name {
+          Some(name) => tf("status.playlist_renamed", &[("name", &name)]),
+          None => t("status.playlist_description_updated"),
+        };
+        app.set_status_message(message, 3);
       }
-    } else if let Err(e) = self.library_save_uris(&[uri]).await {
-      self.handle_error(anyhow!(e)).await;
-    } else {
-      let mut app = self.app.lock().await;
-      app.liked_song_ids_set.insert(id_str.to_string());
+      Err(e) => self.handle_error(anyhow!(e)).await,
     }
   }
 
-  async fn current_user_saved_tracks_contains(&mut self, ids: Vec<TrackId<'static>>) {
-    let uris: Vec<String> = ids
-      .iter()
-      .map(|id| format!("spotify:track:{}", id.id()))
-      .collect();
+  async fn get_user_playlists(&mut self, user_id: UserId<'static>, name: String) {
+    let path = format!("users/{}/playlists", user_id.id());
+    let query = [
+      ("limit", self.large_search_limit.to_string()),
+      ("offset", "0".to_string()),
+    ];
+    match spotify_get_typed_compat_for::<Page<SimplifiedPlaylist>>(&self.spotify, &path, &query)
+      .await
+    {
+      Ok(page) => {
+        let mut app = self.app.lock().await;
+        if page.items.is_empty() {
+          app.set_status_message(format!("{} has no public playlists", name), 4);
+          return;
+        }
+        // Reuse the search results view with only the playlists block filled in
+        app.search_results.tracks = None;
+        app.search_results.artists = None;
+        app.search_results.albums = None;
+        app.search_results.shows = None;
+        app.search_results.playlists = Some(page);
+        app.search_results.selected_playlists_index = Some(0);
+        let block = app.visible_search_block(SearchResultBlock::PlaylistSearch);
+        app.search_results.hovered_block = block;
+        app.search_results.selected_block = block;
+        app.set_status_message(format!("Public playlists by {}", name), 4);
+        app.push_navigation_stack(RouteId::Search, ActiveBlock::SearchResultBlock);
+      }
+      Err(e) => self.handle_error(anyhow!(e)).await,
+    }
+  }
 
+  async fn create_playlist(&mut self, name: String, public: bool) {
+    match self
+      .spotify
+      .request_json(
+        Method::POST,
+        "me/playlists",
+        &[],
+        Some(json!({ "name": name, "public": public })),
+      )
+      .await
+    {
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        app.set_status_message(
+          format!(
+            "Created {} playlist \"{}\"",
+            playlist_visibility_label(Some(public), false).to_lowercase(),
+            name
+          ),
+          4,
+        );
+        app.dispatch(IoEvent::GetPlaylists);
+      }
+      Err(e) => self.handle_error(anyhow!(e)).await,
+    }
+  }
+
+  async fn toggle_save_track(&mut self, track_id: rspotify::model::idtypes::PlayableId<'static>) {
+    // Tracks and episodes share the `me/library` endpoints, keyed by URI
+    let uri = track_id.uri();
+    let was_saved = self.app.lock().await.is_playable_saved(&track_id);
+
+    let result = if was_saved {
+      self.library_remove_uris(&[uri]).await
+    } else {
+      self.library_save_uris(&[uri]).await
+    };
+    if let Err(e) = result {
+      self.handle_error(anyhow!(e)).await;
+      return;
+    }
+
+    if let PlayableId::Track(id) = &track_id {
+      self
+        .liked_state
+        .lock()
+        .await
+        .record(id.id(), !was_saved, Instant::now());
+    }
+    let mut app = self.app.lock().await;
+    app.apply_playable_saved(&track_id, !was_saved);
+  }
+
+  async fn current_user_saved_episodes_contains(&mut self, episode_ids: Vec<EpisodeId<'static>>) {
+    let uris: Vec<String> = episode_ids.iter().map(|id| id.uri()).collect();
     match self.library_contains_uris(&uris).await {
       Ok(is_saved_vec) => {
         let mut app = self.app.lock().await;
-        for (i, id) in ids.iter().enumerate() {
-          if let Some(is_liked) = is_saved_vec.get(i) {
-            if *is_liked {
-              app.liked_song_ids_set.insert(id.id().to_string());
-            } else if app.liked_song_ids_set.contains(id.id()) {
-              app.liked_song_ids_set.remove(id.id());
-            }
-          };
+        for (id, is_saved) in episode_ids.iter().zip(is_saved_vec) {
+          if is_saved {
+            app.saved_episode_ids_set.insert(id.id().to_string());
+          } else {
+            app.saved_episode_ids_set.remove(id.id());
+          }
         }
       }
       Err(e) => {
-        let mut app = self.app.lock().await;
-        app.status_message = Some(format!("Could not check liked track state: {}", e));
-        app.status_message_expires_at = Some(Instant::now() + Duration::from_secs(5));
+        self.handle_error(anyhow!(e)).await;
       }
     }
   }
 
+  async fn current_user_saved_tracks_contains(&mut self, ids: Vec<TrackId<'static>>) {
+    let ids: Vec<String> = ids.iter().map(|id| id.id().to_string()).collect();
+    let (known, schedule_flush) = self.liked_state.lock().await.resolve(&ids, Instant::now());
+
+    if !known.is_empty() {
+      let mut app = self.app.lock().await;
+      apply_liked_states(&mut app, &known);
+    }
+
+    if !schedule_flush {
+      return;
+    }
+
+    let spotify = self.spotify.clone();
+    let app = self.app.clone();
+    let liked_state = self.liked_state.clone();
+    if self.coalesce_liked_checks {
+      // Give other views a moment to queue their ids so they share one request
+      tokio::spawn(async move {
+        tokio::time::sleep(LIKED_STATE_COALESCE_WINDOW).await;
+        flush_liked_state_task(spotify, app, liked_state).await;
+      });
+    } else {
+      flush_liked_state_task(spotify, app, liked_state).await;
+    }
+  }
+
   async fn set_tracks_to_table(&mut self, tracks: Vec<FullTrack>) {
     let track_ids: Vec<TrackId<'static>> = tracks
       .iter()
@@ -703,7 +1292,11 @@ impl LibraryNetwork for Network {
     let path = format!("playlists/{}/items", playlist_id.id());
 
     loop {
-      let query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
+      let query = vec![
+        ("limit", limit.to_string()),
+        ("offset", offset.to_string()),
+        ("market", "from_token".to_string()),
+      ];
       match spotify_get_typed_compat_for::<Page<PlaylistItem>>(&self.spotify, &path, &query).await {
         Ok(page) => {
           if page.items.is_empty() {
@@ -777,12 +1370,28 @@ fn build_flat_playlist_items(
     .collect()
 }
 
-fn reconcile_playlist_selection(
-  app: &mut App,
-  preferred_playlist_id: Option<&str>,
-  preferred_folder_id: usize,
-  preferred_selected_index: Option<usize>,
-) {
+/// Where the sidebar was, to find the same spot again once the playlists change
+#[derive(Clone)]
+struct PlaylistSelection {
+  playlist_id: Option<String>,
+  folder_id: usize,
+  selected_index: Option<usize>,
+}
+
+impl PlaylistSelection {
+  fn of(app: &App) -> Self {
+    PlaylistSelection {
+      playlist_id: app.get_selected_playlist_id(),
+      folder_id: app.current_playlist_folder_id,
+      selected_index: app.selected_playlist_index,
+    }
+  }
+}
+
+fn reconcile_playlist_selection(app: &mut App, selection: &PlaylistSelection) {
+  let preferred_playlist_id = selection.playlist_id.as_deref();
+  let preferred_folder_id = selection.folder_id;
+  let preferred_selected_index = selection.selected_index;
   if app.playlist_folder_items.is_empty() {
     app.current_playlist_folder_id = 0;
     app.selected_playlist_index = None;
@@ -793,6 +1402,7 @@ fn reconcile_playlist_selection(
     app.playlist_folder_items.iter().any(|item| match item {
       PlaylistFolderItem::Folder(folder) => folder.current_id == folder_id,
       PlaylistFolderItem::Playlist { current_id, .. } => *current_id == folder_id,
+      PlaylistFolderItem::PinnedHeader => false,
     })
   };
 
@@ -804,9 +1414,8 @@ fn reconcile_playlist_selection(
 
   if let Some(playlist_id) = preferred_playlist_id {
     let visible_playlist_index = app
-      .playlist_folder_items
-      .iter()
-      .filter(|item| app.is_playlist_item_visible_in_current_folder(item))
+      .get_playlist_display_items()
+      .into_iter()
       .enumerate()
       .find_map(|(display_idx, item)| match item {
         PlaylistFolderItem::Playlist { index, .. } => app
@@ -814,7 +1423,7 @@ fn reconcile_playlist_selection(
           .get(*index)
           .filter(|playlist| playlist.id.id() == playlist_id)
           .map(|_| display_idx),
-        PlaylistFolderItem::Folder(_) => None,
+        PlaylistFolderItem::Folder(_) | PlaylistFolderItem::PinnedHeader => None,
       });
 
     if let Some(display_idx) = visible_playlist_index {
@@ -837,9 +1446,8 @@ fn reconcile_playlist_selection(
     if let Some(folder_id) = target_folder {
       app.current_playlist_folder_id = folder_id;
       let display_idx = app
-        .playlist_folder_items
-        .iter()
-        .filter(|item| app.is_playlist_item_visible_in_current_folder(item))
+        .get_playlist_display_items()
+        .into_iter()
         .enumerate()
         .find_map(|(idx, item)| match item {
           PlaylistFolderItem::Playlist { index, .. } => app
@@ -847,7 +1455,7 @@ fn reconcile_playlist_selection(
             .get(*index)
             .filter(|playlist| playlist.id.id() == playlist_id)
             .map(|_| idx),
-          PlaylistFolderItem::Folder(_) => None,
+          PlaylistFolderItem::Folder(_) | PlaylistFolderItem::PinnedHeader => None,
         });
       if let Some(idx) = display_idx {
         app.selected_playlist_index = Some(idx);
@@ -1016,3 +1624,184 @@ fn structurize_playlist_folders(
 
   items
 }
+
+#[cfg(test)]
+mod tests {
+  use super::super::client::MockSpotify;
+  use super::*;
+
+  fn playlist(id: &str) -> serde_json::Value {
+    json!({
+      "collaborative": false,
+      "external_urls": {},
+      "href": "",
+      "id": id,
+      "images": [],
+      "name": id,
+      "owner": {
+        "display_name": "me",
+        "external_urls": {},
+        "href": "",
+        "id": "me",
+        "type": "user",
+        "uri": "spotify:user:me"
+      },
+      "public": true,
+      "snapshot_id": "snapshot",
+      "tracks": { "href": "", "total": 0 }
+    })
+  }
+
+  fn page(items: Vec<serde_json::Value>, offset: u32, has_next: bool) -> serde_json::Value {
+    json!({
+      "href": "",
+      "items": items,
+      "limit": 50,
+      "next": has_next.then_some("next"),
+      "offset": offset,
+      "previous": null,
+      "total": 3
+    })
+  }
+
+  #[tokio::test]
+  async fn playlists_are_fetched_page_by_page() {
+    let client = MockSpotify::default();
+    let ids = [
+      "37i9dQZF1DXcBWIGoYBM5M",
+      "37i9dQZF1DX0XUsuxWHRQd",
+      "37i9dQZF1DX4JAvHpjipBk",
+    ];
+    client.respond(
+      "GET me/playlists",
+      Ok(page(vec![playlist(ids[0]), playlist(ids[1])], 0, true)),
+    );
+    client.respond(
+      "GET me/playlists",
+      Ok(page(vec![playlist(ids[2])], 50, false)),
+    );
+    let mut network = Network::mock(App::default(), client);
+
+    network.get_current_user_playlists(false).await;
+
+    assert_eq!(
+      network.spotify.calls(),
+      [
+        "GET me/playlists limit=50&offset=0",
+        "GET me/playlists limit=50&offset=50",
+      ]
+    );
+    let app = network.app.lock().await;
+    let loaded: Vec<&str> = app.all_playlists.iter().map(|p| p.id.id()).collect();
+    assert_eq!(loaded, ids);
+    assert!(app.playlists_fully_loaded);
+  }
+
+  #[tokio::test]
+  async fn a_failed_page_stops_the_playlist_fetch() {
+    let client = MockSpotify::default();
+    client.respond(
+      "GET me/playlists",
+      Err("Spotify API 500 Internal Server Error failed: ".to_string()),
+    );
+    let mut network = Network::mock(App::default(), client);
+
+    network.get_current_user_playlists(false).await;
+
+    assert_eq!(network.spotify.calls().len(), 1);
+    let app = network.app.lock().await;
+    assert!(!app.playlists_fully_loaded);
+    assert!(app.api_error.contains("500 Internal Server Error"));
+    // The loading indicator gives up rather than spinning on
+    assert!(app.first_load_complete);
+  }
+
+  #[tokio::test]
+  async fn a_failed_background_refresh_stays_quiet() {
+    let client = MockSpotify::default();
+    client.respond(
+      "GET me/playlists",
+      Err("Spotify API 500 Internal Server Error failed: ".to_string()),
+    );
+    let mut network = Network::mock(App::default(), client);
+
+    network.get_current_user_playlists(true).await;
+
+    let app = network.app.lock().await;
+    assert!(app.api_error.is_empty());
+    assert_ne!(app.get_current_route().id, RouteId::Error);
+    // Free to try again on the next interval
+    assert!(app.playlists_fully_loaded);
+  }
+
+  #[tokio::test]
+  async fn playlist_details_prompt_starts_on_the_current_description() {
+    use crate::core::app::PlaylistDetailsEdit;
+
+    let client = MockSpotify::default();
+    client.respond(
+      "GET playlists/37i9dQZF1DXcBWIGoYBM5M",
+      Ok(json!({ "description": "Songs for the car" })),
+    );
+    let playlist_id = PlaylistId::from_id("37i9dQZF1DXcBWIGoYBM5M").unwrap();
+    let mut app = App::default();
+    app.playlist_details_edit = Some(PlaylistDetailsEdit {
+      playlist_id: playlist_id.clone(),
+      name: "Road Trip".to_string(),
+      description: String::new(),
+      original_description: None,
+      editing_description: false,
+    });
+    let mut network = Network::mock(app, client);
+
+    network.get_playlist_description(playlist_id).await;
+
+    assert_eq!(
+      network.spotify.calls(),
+      ["GET playlists/37i9dQZF1DXcBWIGoYBM5M fields=description"]
+    );
+    let app = network.app.lock().await;
+    let edit = app.playlist_details_edit.as_ref().unwrap();
+    assert_eq!(edit.description, "Songs for the car");
+    assert_eq!(
+      edit.original_description.as_deref(),
+      Some("Songs for the car")
+    );
+  }
+
+  #[tokio::test]
+  async fn playlist_search_reads_pages_until_the_track_turns_up() {
+    let client = MockSpotify::default();
+    let path = "GET playlists/37i9dQZF1DXcBWIGoYBM5M/items";
+    let item = |id: &str| json!({ "track": { "id": id } });
+    client.respond(path, Ok(json!({ "items": [item("a")], "next": "more" })));
+    client.respond(
+      path,
+      Ok(json!({ "items": [item("4uLU6hMCjMI75M1A2tKUQC")], "next": "more" })),
+    );
+    let network = Network::mock(App::default(), client);
+    let playlist_id = PlaylistId::from_id("37i9dQZF1DXcBWIGoYBM5M").unwrap();
+    let track_id = TrackId::from_id("4uLU6hMCjMI75M1A2tKUQC").unwrap();
+
+    assert!(network
+      .playlist_contains_track(&playlist_id, &track_id)
+      .await
+      .unwrap());
+    let offsets: Vec<String> = network
+      .spotify
+      .calls()
+      .iter()
+      .map(|call| call.rsplit('&').next().unwrap().to_string())
+      .collect();
+    assert_eq!(offsets, ["offset=0", "offset=100"]);
+
+    // The last page ends the search without a match
+    network
+      .spotify
+      .respond(path, Ok(json!({ "items": [item("b")], "next": null })));
+    assert!(!network
+      .playlist_contains_track(&playlist_id, &track_id)
+      .await
+      .unwrap());
+  }
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// Spotify accepts at most this many ids per `me/library/contains` request
+pub const LIKED_STATE_BATCH_SIZE: usize = 50;
+/// How long misses are collected before being sent as a single request
+pub const LIKED_STATE_COALESCE_WINDOW: Duration = Duration::from_millis(150);
+/// How long a "not liked" answer is trusted before it is checked again
+pub const LIKED_STATE_NEGATIVE_TTL: Duration = Duration::from_secs(300);
+
+/// Session cache of liked-track state keyed by base62 track id.
+///
+/// Positive results live for the whole session, negative results expire after
+/// `negative_ttl` so likes made from other clients eventually show up. Misses are
+/// queued in `pending` and drained in batches by whoever scheduled the flush.
+pub struct LikedStateCache {
+  liked: HashSet<String>,
+  not_liked: HashMap<String, Instant>,
+  pending: Vec<String>,
+  flush_scheduled: bool,
+  negative_ttl: Duration,
+}
+
+impl LikedStateCache {
+  pub fn new(negative_ttl: Duration) -> Self {
+    LikedStateCache {
+      liked: HashSet::new(),
+      not_liked: HashMap::new(),
+      pending: Vec::new(),
+      flush_scheduled: false,
+      negative_ttl,
+    }
+  }
+
+  pub fn lookup(&self, id: &str, now: Instant) -> Option<bool> {
+    if self.liked.contains(id) {
+      return Some(true);
+    }
+    match self.not_liked.get(id) {
+      Some(checked_at) if now.duration_since(*checked_at) < self.negative_ttl => Some(false),
+      _ => None,
+    }
+  }
+
+  /// Resolve `ids` against the cache, queueing every miss for the next batch.
+  ///
+  /// Returns the cached answers and whether the caller is responsible for
+  /// scheduling a flush (only the first caller after the queue empties is).
+  pub fn resolve(&mut self, ids: &[String], now: Instant) -> (Vec<(String, bool)>, bool) {
+    let mut known = Vec::new();
+    for id in ids {
+      match self.lookup(id, now) {
+        Some(liked) => known.push((id.clone(), liked)),
+        None => {
+          if !self.pending.contains(id) {
+            self.pending.push(id.clone());
+          }
+        }
+      }
+    }
+
+    let schedule_flush = !self.pending.is_empty() && !self.flush_scheduled;
+    if schedule_flush {
+      self.flush_scheduled = true;
+    }
+    (known, schedule_flush)
+  }
+
+  /// Take the next batch of queued misses. An empty batch means the flush is done.
+  pub fn take_batch(&mut self) -> Vec<String> {
+    let count = self.pending.len().min(LIKED_STATE_BATCH_SIZE);
+    let batch: Vec<String> = self.pending.drain(..count).collect();
+    if batch.is_empty() {
+      self.flush_scheduled = false;
+    }
+    batch
+  }
+
+  pub fn record(&mut self, id: &str, liked: bool, now: Instant) {
+    if liked {
+      self.not_liked.remove(id);
+      self.liked.insert(id.to_string());
+    } else {
+      self.liked.remove(id);
+      self.not_liked.insert(id.to_string(), now);
+    }
+  }
+}
+
+impl Default for LikedStateCache {
+  fn default() -> Self {
+    Self::new(LIKED_STATE_NEGATIVE_TTL)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn ids(range: std::ops::Range<usize>) -> Vec<String> {
+    range.map(|i| format!("track{}", i)).collect()
+  }
+
+  #[test]
+  fn misses_are_coalesced_into_one_flush() {
+    let mut cache = LikedStateCache::default();
+    let now = Instant::now();
+
+    let (known, schedule) = cache.resolve(&ids(0..3), now);
+    assert!(known.is_empty());
+    assert!(schedule);
+
+    // A second dispatch within the window joins the pending batch without a new flush
+    let (_, schedule) = cache.resolve(&ids(2..5), now);
+    assert!(!schedule);
+
+    assert_eq!(cache.take_batch(), ids(0..5));
+    assert!(cache.take_batch().is_empty());
+
+    // Once drained, the next miss schedules a fresh flush
+    let (_, schedule) = cache.resolve(&ids(5..6), now);
+    assert!(schedule);
+  }
+
+  #[test]
+  fn batches_are_capped() {
+    let mut cache = LikedStateCache::default();
+    cache.resolve(&ids(0..120), Instant::now());
+
+    assert_eq!(cache.take_batch().len(), LIKED_STATE_BATCH_SIZE);
+    assert_eq!(cache.take_batch().len(), LIKED_STATE_BATCH_SIZE);
+    assert_eq!(cache.take_batch().len(), 20);
+    assert!(cache.take_batch().is_empty());
+  }
+
+  #[test]
+  fn cached_ids_skip_the_queue() {
+    let mut cache = LikedStateCache::default();
+    let now = Instant::now();
+    cache.record("track0", true, now);
+    cache.record("track1", false, now);
+
+    let (known, schedule) = cache.resolve(&ids(0..2), now);
+    assert_eq!(
+      known,
+      vec![("track0".to_string(), true), ("track1".to_string(), false)]
+    );
+    assert!(!schedule);
+  }
+
+  #[test]
+  fn negative_results_expire() {
+    let mut cache = LikedStateCache::new(Duration::from_secs(60));
+    let now = Instant::now();
+    cache.record("track0", false, now);
+    cache.record("track1", true, now);
+
+    let later = now + Duration::from_secs(61);
+    assert_eq!(cache.lookup("track0", now), Some(false));
+    assert_eq!(cache.lookup("track0", later), None);
+    assert_eq!(cache.lookup("track1", later), Some(true));
+  }
+
+  #[test]
+  fn toggles_overwrite_previous_state() {
+    let mut cache = LikedStateCache::default();
+    let now = Instant::now();
+    cache.record("track0", true, now);
+    cache.record("track0", false, now);
+    assert_eq!(cache.lookup("track0", now), Some(false));
+
+    cache.record("track0", true, now);
+    assert_eq!(cache.lookup("track0", now), Some(true));
+  }
+}
@@ -1,5 +1,5 @@
 use super::requests::spotify_get_typed_compat_for;
-use super::Network;
+use super::{IoEvent, Network};
 use crate::core::app::{
   ActiveBlock, Artist, ArtistBlock, EpisodeTableContext, RouteId, ScrollableResultPages,
   SelectedFullShow, SelectedShow,
@@ -105,19 +105,30 @@ impl MetadataNetwork for Network {
     if let Some(id) = album_id {
       let path = format!("albums/{}/tracks", id.id());
       // TODO: Handle pagination for albums with > 50 tracks
-      match spotify_get_typed_compat_for::<Page<rspotify::model::track::SimplifiedTrack>>(
-        &self.spotify,
-        &path,
-        &[("limit", "50".to_string()), ("offset", "0".to_string())],
-      )
-      .await
-      {
+      let query = [
+        ("limit", "50".to_string()),
+        ("offset", "0".to_string()),
+        ("market", "from_token".to_string()),
+      ];
+      let (tracks, details) = tokio::join!(
+        spotify_get_typed_compat_for::<Page<rspotify::model::track::SimplifiedTrack>>(
+          &self.spotify,
+          &path,
+          &query,
+        ),
+        self.spotify.album(id.clone(), Some(Market::FromToken))
+      );
+      match tracks {
         Ok(tracks) => {
+          let details = details
+            .map_err(|e| log::info!("failed to fetch details of album {}: {}", id.id(), e))
+            .ok();
           let mut app = self.app.lock().await;
           app.selected_album_simplified = Some(crate::core::app::SelectedAlbum {
             album: *album,
             tracks,
             selected_index: 0,
+            details,
           });
           app.album_table_context = crate::core::app::AlbumTableContext::Simplified;
           app.push_navigation_stack(RouteId::AlbumTracks, ActiveBlock::AlbumTracks);
@@ -128,7 +139,7 @@ impl MetadataNetwork for Network {
   }
 
   async fn get_album(&mut self, album_id: AlbumId<'static>) {
-    match self.spotify.album(album_id, None).await {
+    match self.spotify.album(album_id, Some(Market::FromToken)).await {
       Ok(album) => {
         let mut app = self.app.lock().await;
         app.selected_album_full = Some(crate::core::app::SelectedFullAlbum {
@@ -158,9 +169,15 @@ impl MetadataNetwork for Network {
     {
       Ok(episodes) => {
         if !episodes.items.is_empty() {
+          let episode_ids = episodes
+            .items
+            .iter()
+            .map(|episode| episode.id.clone().into_static())
+            .collect();
           let mut app = self.app.lock().await;
           app.library.show_episodes = ScrollableResultPages::new();
           app.library.show_episodes.add_pages(episodes);
+          app.dispatch(IoEvent::CurrentUserSavedEpisodesContains(episode_ids));
 
           app.selected_show_simplified = Some(SelectedShow { show: *show });
 
@@ -212,8 +229,14 @@ impl MetadataNetwork for Network {
     {
       Ok(episodes) => {
         if !episodes.items.is_empty() {
+          let episode_ids = episodes
+            .items
+            .iter()
+            .map(|episode| episode.id.clone().into_static())
+            .collect();
           let mut app = self.app.lock().await;
           app.library.show_episodes.add_pages(episodes);
+          app.dispatch(IoEvent::CurrentUserSavedEpisodesContains(episode_ids));
         }
       }
       Err(e) => {
@@ -1,4 +1,6 @@
+pub mod client;
 pub mod library;
+pub mod liked_cache;
 pub mod metadata;
 pub mod playback;
 pub mod recommend;
@@ -7,15 +9,18 @@ pub mod search;
 pub mod user;
 pub mod utils;
 
-use crate::core::app::App;
+use crate::core::app::{App, PlaylistTrackAdd};
 use crate::core::config::ClientConfig;
+use crate::infra::network::liked_cache::LikedStateCache;
 use anyhow::anyhow;
 use rspotify::clients::BaseClient;
 use rspotify::model::{
   album::SimplifiedAlbum,
   artist::FullArtist,
   enums::{Country, RepeatState},
-  idtypes::{AlbumId, ArtistId, PlayContextId, PlayableId, PlaylistId, ShowId, TrackId, UserId},
+  idtypes::{
+    AlbumId, ArtistId, EpisodeId, PlayContextId, PlayableId, PlaylistId, ShowId, TrackId, UserId,
+  },
   show::SimplifiedShow,
   track::FullTrack,
 };
@@ -26,11 +31,13 @@ use tokio::sync::Mutex;
 
 #[cfg(feature = "streaming")]
 use crate::infra::player::StreamingPlayer;
+#[cfg(feature = "preview")]
+use crate::infra::preview::PreviewPlayer;
 
 // Re-export traits
 use self::library::LibraryNetwork;
 use self::metadata::MetadataNetwork;
-use self::playback::PlaybackNetwork;
+use self::playback::{PlaybackControlNetwork, PlaybackNetwork};
 use self::recommend::RecommendationNetwork;
 use self::search::SearchNetwork;
 use self::user::UserNetwork;
@@ -38,12 +45,15 @@ use self::utils::UtilsNetwork;
 
 pub enum IoEvent {
   GetCurrentPlayback,
+  GetQueue,
   /// After a track transition (e.g., EndOfTrack), ensure we don't end up paused on the next item.
   /// The payload is the previous track identifier (either base62 id or a `spotify:track:` URI).
   #[allow(dead_code)]
   EnsurePlaybackContinues(String),
   RefreshAuthentication,
   GetPlaylists,
+  /// `GetPlaylists` on the auto-refresh timer, which mustn't get in the user's way
+  RefreshPlaylists,
   GetDevices,
   GetSearchResults(String, Option<Country>),
   SetTracksToTable(Vec<FullTrack>),
@@ -54,12 +64,19 @@ pub enum IoEvent {
     Option<Vec<PlayableId<'static>>>,
     Option<usize>,
   ),
+  /// Like `StartPlayback`, starting the first item this many milliseconds in
+  StartPlaybackAt(
+    Option<PlayContextId<'static>>,
+    Option<Vec<PlayableId<'static>>>,
+    Option<usize>,
+    u32,
+  ),
   UpdateSearchLimits(u32, u32),
   Seek(u32),
   NextTrack,
   PreviousTrack,
-  Shuffle(bool), // desired shuffle state
-  Repeat(RepeatState),
+  Shuffle(bool),       // desired shuffle state
+  Repeat(RepeatState), // desired repeat state
   PausePlayback,
   ChangeVolume(u8),
   GetArtist(ArtistId<'static>, String, Option<Country>),
@@ -74,25 +91,52 @@ pub enum IoEvent {
   CurrentUserSavedAlbumsContains(Vec<AlbumId<'static>>),
   CurrentUserSavedAlbumDelete(AlbumId<'static>),
   CurrentUserSavedAlbumAdd(AlbumId<'static>),
+  /// Remove several saved albums from the library in batched requests
+  CurrentUserSavedAlbumsDelete(Vec<AlbumId<'static>>),
+  /// Remove several tracks from Liked Songs in batched requests
+  CurrentUserSavedTracksDelete(Vec<TrackId<'static>>),
   UserUnfollowArtists(Vec<ArtistId<'static>>),
   UserFollowArtists(Vec<ArtistId<'static>>),
   UserFollowPlaylist(UserId<'static>, PlaylistId<'static>, Option<bool>),
   UserUnfollowPlaylist(UserId<'static>, PlaylistId<'static>),
   AddTrackToPlaylist(PlaylistId<'static>, TrackId<'static>),
+  /// Add a track unless the playlist already has it, in which case ask first
+  AddTrackToPlaylistChecked(PlaylistTrackAdd),
   RemoveTrackFromPlaylistAtPosition(PlaylistId<'static>, TrackId<'static>, usize),
+  /// Set a playlist's `(public, collaborative)` flags
+  ChangePlaylistVisibility(PlaylistId<'static>, bool, bool),
+  /// Fill the open playlist details prompt with the playlist's description
+  GetPlaylistDescription(PlaylistId<'static>),
+  /// Rename a playlist and/or set its description, leaving out the `None`s
+  ChangePlaylistDetails(PlaylistId<'static>, Option<String>, Option<String>),
   GetUser,
   ToggleSaveTrack(PlayableId<'static>),
   GetRecommendationsForTrackId(TrackId<'static>, Option<Country>),
+  /// Fetch suggestions to mix into a playlist, seeded from some of its tracks
+  EnhancePlaylist(PlaylistId<'static>, Vec<TrackId<'static>>),
   GetRecentlyPlayed,
   GetFollowedArtists(Option<ArtistId<'static>>),
   SetArtistsToTable(Vec<FullArtist>),
   UserArtistFollowCheck(Vec<ArtistId<'static>>),
   GetAlbum(AlbumId<'static>),
   TransferPlaybackToDevice(String, bool),
+  /// Move playback to the named (or ID'd) device on quit and wait for it to take over
+  #[cfg(feature = "streaming")]
+  HandoffPlayback(String),
+  /// Start the last played context, or Liked Songs, on the native player when nothing is playing
+  #[cfg(feature = "streaming")]
+  StartColdPlayback,
+  /// Stop the preview clip that is playing locally
+  #[cfg(feature = "preview")]
+  StopPreview,
+  /// Download every song of a playlist into the native player's audio cache
+  #[cfg(feature = "streaming")]
+  CachePlaylistOffline(PlaylistId<'static>, String),
   #[allow(dead_code)]
   AutoSelectStreamingDevice(String, bool), // Auto-select a device by name (used for native streaming)
   GetAlbumForTrack(TrackId<'static>),
   CurrentUserSavedTracksContains(Vec<TrackId<'static>>),
+  CurrentUserSavedEpisodesContains(Vec<EpisodeId<'static>>),
   GetCurrentUserSavedShows(Option<u32>),
   CurrentUserSavedShowsContains(Vec<ShowId<'static>>),
   CurrentUserSavedShowDelete(ShowId<'static>),
@@ -101,6 +145,8 @@ pub enum IoEvent {
   GetShow(ShowId<'static>),
   GetCurrentShowEpisodes(ShowId<'static>, Option<u32>),
   AddItemToQueue(PlayableId<'static>),
+  /// Queue an item and skip to it straight away
+  QueueAndSkip(PlayableId<'static>),
   IncrementGlobalSongCount,
   FetchGlobalSongCount,
   FetchAnnouncements,
@@ -121,16 +167,27 @@ pub enum IoEvent {
   GetTopArtistsMix,
   /// Fetch all playlist tracks and apply sorting
   FetchAllPlaylistTracksAndSort(PlaylistId<'static>),
+  /// List a user's public playlists, with the name to show for them
+  GetUserPlaylists(UserId<'static>, String),
+  /// Create a playlist with this name, public or private
+  CreatePlaylist(String, bool),
 }
 
-pub struct Network {
-  pub spotify: AuthCodePkceSpotify,
+pub struct Network<C = AuthCodePkceSpotify> {
+  pub spotify: C,
   pub large_search_limit: u32,
   pub small_search_limit: u32,
   pub client_config: ClientConfig,
   pub app: Arc<Mutex<App>>,
+  /// Session cache for liked-track checks shared with the background flush task
+  pub liked_state: Arc<Mutex<LikedStateCache>>,
+  /// Whether liked-state misses are batched in the background (the CLI needs answers inline)
+  pub coalesce_liked_checks: bool,
   #[cfg(feature = "streaming")]
   pub streaming_player: Option<Arc<StreamingPlayer>>,
+  /// Opened on the first preview, so users who never need one keep their audio device free
+  #[cfg(feature = "preview")]
+  pub preview_player: Option<PreviewPlayer>,
 }
 
 impl Network {
@@ -147,7 +204,11 @@ impl Network {
       small_search_limit: 4,
       client_config,
       app: Arc::clone(app),
+      liked_state: Arc::new(Mutex::new(LikedStateCache::default())),
+      coalesce_liked_checks: true,
       streaming_player,
+      #[cfg(feature = "preview")]
+      preview_player: None,
     }
   }
 
@@ -163,6 +224,10 @@ impl Network {
       small_search_limit: 4,
       client_config,
       app: Arc::clone(app),
+      liked_state: Arc::new(Mutex::new(LikedStateCache::default())),
+      coalesce_liked_checks: true,
+      #[cfg(feature = "preview")]
+      preview_player: None,
     }
   }
 
@@ -176,7 +241,10 @@ impl Network {
         self.ensure_playback_continues(previous_track_id).await;
       }
       IoEvent::GetPlaylists => {
-        self.get_current_user_playlists().await;
+        self.get_current_user_playlists(false).await;
+      }
+      IoEvent::RefreshPlaylists => {
+        self.get_current_user_playlists(true).await;
       }
       IoEvent::GetUser => {
         self.get_user().await;
@@ -187,6 +255,9 @@ impl Network {
       IoEvent::GetCurrentPlayback => {
         self.get_current_playback().await;
       }
+      IoEvent::GetQueue => {
+        self.get_queue().await;
+      }
       IoEvent::SetTracksToTable(full_tracks) => {
         self.set_tracks_to_table(full_tracks).await;
       }
@@ -203,27 +274,23 @@ impl Network {
       IoEvent::StartPlayback(context_uri, uris, offset) => {
         self.start_playback(context_uri, uris, offset).await;
       }
+      IoEvent::StartPlaybackAt(context_uri, uris, offset, position_ms) => {
+        self
+          .start_playback_at(context_uri, uris, offset, Some(position_ms))
+          .await;
+      }
       IoEvent::UpdateSearchLimits(large_search_limit, small_search_limit) => {
         self.large_search_limit = large_search_limit;
         self.small_search_limit = small_search_limit;
       }
-      IoEvent::Seek(position_ms) => {
-        self.seek(position_ms).await;
-      }
-      IoEvent::NextTrack => {
-        self.next_track().await;
-      }
-      IoEvent::PreviousTrack => {
-        self.previous_track().await;
-      }
-      IoEvent::Repeat(repeat_state) => {
-        self.repeat(repeat_state).await;
-      }
-      IoEvent::PausePlayback => {
-        self.pause_playback().await;
-      }
-      IoEvent::ChangeVolume(volume) => {
-        self.change_volume(volume).await;
+      io_event @ (IoEvent::Seek(_)
+      | IoEvent::NextTrack
+      | IoEvent::PreviousTrack
+      | IoEvent::Shuffle(_)
+      | IoEvent::Repeat(_)
+      | IoEvent::PausePlayback
+      | IoEvent::ChangeVolume(_)) => {
+        self.handle_playback_control(io_event).await;
       }
       IoEvent::GetArtist(artist_id, input_artist_name, country) => {
         self.get_artist(artist_id, input_artist_name, country).await;
@@ -248,6 +315,12 @@ impl Network {
       IoEvent::CurrentUserSavedAlbumAdd(album_id) => {
         self.current_user_saved_album_add(album_id).await;
       }
+      IoEvent::CurrentUserSavedAlbumsDelete(album_ids) => {
+        self.current_user_saved_albums_delete(album_ids).await;
+      }
+      IoEvent::CurrentUserSavedTracksDelete(track_ids) => {
+        self.current_user_saved_tracks_delete(track_ids).await;
+      }
       IoEvent::UserUnfollowArtists(artist_ids) => {
         self.user_unfollow_artists(artist_ids).await;
       }
@@ -265,11 +338,33 @@ impl Network {
       IoEvent::AddTrackToPlaylist(playlist_id, track_id) => {
         self.add_track_to_playlist(playlist_id, track_id).await;
       }
+      IoEvent::AddTrackToPlaylistChecked(add) => {
+        self.add_track_to_playlist_checked(add).await;
+      }
       IoEvent::RemoveTrackFromPlaylistAtPosition(playlist_id, track_id, position) => {
         self
           .remove_track_from_playlist_at_position(playlist_id, track_id, position)
           .await;
       }
+      IoEvent::ChangePlaylistVisibility(playlist_id, public, collaborative) => {
+        self
+          .change_playlist_visibility(playlist_id, public, collaborative)
+          .await;
+      }
+      IoEvent::GetPlaylistDescription(playlist_id) => {
+        self.get_playlist_description(playlist_id).await;
+      }
+      IoEvent::ChangePlaylistDetails(playlist_id, name, description) => {
+        self
+          .change_playlist_details(playlist_id, name, description)
+          .await;
+      }
+      IoEvent::GetUserPlaylists(user_id, name) => {
+        self.get_user_playlists(user_id, name).await;
+      }
+      IoEvent::CreatePlaylist(name, public) => {
+        self.create_playlist(name, public).await;
+      }
 
       IoEvent::ToggleSaveTrack(track_id) => {
         self.toggle_save_track(track_id).await;
@@ -279,6 +374,9 @@ impl Network {
           .get_recommendations_for_track_id(track_id, country)
           .await;
       }
+      IoEvent::EnhancePlaylist(playlist_id, seed_tracks) => {
+        self.enhance_playlist(playlist_id, seed_tracks).await;
+      }
       IoEvent::GetRecentlyPlayed => {
         self.get_recently_played().await;
       }
@@ -294,6 +392,18 @@ impl Network {
       IoEvent::GetAlbum(album_id) => {
         self.get_album(album_id).await;
       }
+      #[cfg(feature = "streaming")]
+      IoEvent::HandoffPlayback(device) => {
+        self.handoff_playback(device).await;
+      }
+      #[cfg(feature = "streaming")]
+      IoEvent::StartColdPlayback => {
+        self.start_cold_playback().await;
+      }
+      #[cfg(feature = "preview")]
+      IoEvent::StopPreview => {
+        self.stop_preview().await;
+      }
       IoEvent::TransferPlaybackToDevice(device_id, persist_device_id) => {
         self
           .transfert_playback_to_device(device_id, persist_device_id)
@@ -310,12 +420,12 @@ impl Network {
       IoEvent::GetAlbumForTrack(track_id) => {
         self.get_album_for_track(track_id).await;
       }
-      IoEvent::Shuffle(shuffle_state) => {
-        self.shuffle(shuffle_state).await;
-      }
       IoEvent::CurrentUserSavedTracksContains(track_ids) => {
         self.current_user_saved_tracks_contains(track_ids).await;
       }
+      IoEvent::CurrentUserSavedEpisodesContains(episode_ids) => {
+        self.current_user_saved_episodes_contains(episode_ids).await;
+      }
       IoEvent::GetCurrentUserSavedShows(offset) => {
         self.get_current_user_saved_shows(offset).await;
       }
@@ -340,6 +450,9 @@ impl Network {
       IoEvent::AddItemToQueue(item) => {
         self.add_item_to_queue(item).await;
       }
+      IoEvent::QueueAndSkip(item) => {
+        self.queue_and_skip(item).await;
+      }
       IoEvent::IncrementGlobalSongCount => {
         self.increment_global_song_count().await;
       }
@@ -383,6 +496,17 @@ impl Network {
       IoEvent::FetchAllPlaylistTracksAndSort(playlist_id) => {
         self.fetch_all_playlist_tracks_and_sort(playlist_id).await;
       }
+      #[cfg(feature = "streaming")]
+      IoEvent::CachePlaylistOffline(playlist_id, name) => {
+        // Downloading a whole playlist takes a while, so don't hold up other requests
+        if let Some(player) = self.streaming_player.clone() {
+          let spotify = self.spotify.clone();
+          let app = self.app.clone();
+          tokio::spawn(async move {
+            library::cache_playlist_offline_task(spotify, app, player, playlist_id, name).await;
+          });
+        }
+      }
     };
 
     {
@@ -391,6 +515,32 @@ impl Network {
     }
   }
 
+  async fn refresh_authentication(&mut self) {
+    // Refresh token if needed
+    // This is implicitly handled by spotify_api_request_json_for which checks 401
+    // But sometimes we might want to force refresh or check validity.
+    // The original code called self.spotify.refresh_token().await.
+    if let Err(e) = self.spotify.refetch_token().await {
+      self.handle_error(anyhow!(e)).await;
+    }
+  }
+}
+
+impl<C: client::SpotifyApi> Network<C> {
+  /// Playback control events, which only go through the `SpotifyApi` calls
+  async fn handle_playback_control(&mut self, io_event: IoEvent) {
+    match io_event {
+      IoEvent::Seek(position_ms) => self.seek(position_ms).await,
+      IoEvent::NextTrack => self.next_track().await,
+      IoEvent::PreviousTrack => self.previous_track().await,
+      IoEvent::Shuffle(shuffle_state) => self.shuffle(shuffle_state).await,
+      IoEvent::Repeat(repeat_state) => self.repeat(repeat_state).await,
+      IoEvent::PausePlayback => self.pause_playback().await,
+      IoEvent::ChangeVolume(volume) => self.change_volume(volume).await,
+      _ => {}
+    }
+  }
+
   async fn handle_error(&mut self, e: anyhow::Error) {
     let mut app = self.app.lock().await;
     app.handle_error(e);
@@ -401,14 +551,132 @@ impl Network {
     app.status_message = Some(message);
     app.status_message_expires_at = Some(Instant::now() + Duration::from_secs(ttl_secs));
   }
+}
 
-  async fn refresh_authentication(&mut self) {
-    // Refresh token if needed
-    // This is implicitly handled by spotify_api_request_json_for which checks 401
-    // But sometimes we might want to force refresh or check validity.
-    // The original code called self.spotify.refresh_token().await.
-    if let Err(e) = self.spotify.refetch_token().await {
-      self.handle_error(anyhow!(e)).await;
+#[cfg(test)]
+impl Network<client::MockSpotify> {
+  /// A network on a fake client with no native player, for handler tests
+  pub fn mock(app: App, client: client::MockSpotify) -> Self {
+    Network {
+      spotify: client,
+      large_search_limit: 50,
+      small_search_limit: 4,
+      client_config: ClientConfig::default(),
+      app: Arc::new(Mutex::new(app)),
+      liked_state: Arc::new(Mutex::new(LikedStateCache::default())),
+      coalesce_liked_checks: false,
+      #[cfg(feature = "streaming")]
+      streaming_player: None,
+      #[cfg(feature = "preview")]
+      preview_player: None,
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::client::MockSpotify;
+  use super::*;
+
+  fn mock_network(app: App, client: MockSpotify) -> Network<MockSpotify> {
+    Network::mock(app, client)
+  }
+
+  fn app_playing_on_speaker() -> App {
+    let mut app = App::default();
+    app.current_playback_context = Some(
+      serde_json::from_value(serde_json::json!({
+        "device": {
+          "id": "speaker-id",
+          "is_active": true,
+          "is_private_session": false,
+          "is_restricted": false,
+          "name": "Kitchen",
+          "type": "Speaker",
+          "volume_percent": 40
+        },
+        "repeat_state": "off",
+        "shuffle_state": false,
+        "context": null,
+        "timestamp": 0,
+        "progress_ms": 0,
+        "is_playing": true,
+        "item": null,
+        "currently_playing_type": "unknown",
+        "actions": { "disallows": {} }
+      }))
+      .unwrap(),
+    );
+    app
+  }
+
+  #[tokio::test]
+  async fn playback_controls_call_the_client_and_update_the_app() {
+    let mut network = mock_network(app_playing_on_speaker(), MockSpotify::default());
+
+    network
+      .handle_playback_control(IoEvent::Shuffle(true))
+      .await;
+    network
+      .handle_playback_control(IoEvent::Repeat(RepeatState::Track))
+      .await;
+    network
+      .handle_playback_control(IoEvent::ChangeVolume(70))
+      .await;
+    network
+      .handle_playback_control(IoEvent::PausePlayback)
+      .await;
+    network.handle_playback_control(IoEvent::Seek(1_500)).await;
+    network.handle_playback_control(IoEvent::NextTrack).await;
+
+    assert_eq!(
+      network.spotify.calls(),
+      [
+        "shuffle true",
+        "repeat Track",
+        "volume 70",
+        "pause_playback",
+        "seek_track 1500",
+        "next_track",
+      ]
+    );
+    let app = network.app.lock().await;
+    let ctx = app.current_playback_context.as_ref().unwrap();
+    assert!(ctx.shuffle_state);
+    assert_eq!(ctx.repeat_state, RepeatState::Track);
+    assert_eq!(ctx.device.volume_percent, Some(70));
+    assert!(!ctx.is_playing);
+  }
+
+  #[tokio::test]
+  async fn failed_playback_controls_leave_the_app_unchanged_and_report() {
+    let client = MockSpotify {
+      fail: true,
+      ..Default::default()
+    };
+    let mut network = mock_network(app_playing_on_speaker(), client);
+
+    network
+      .handle_playback_control(IoEvent::ChangeVolume(70))
+      .await;
+    network
+      .handle_playback_control(IoEvent::PausePlayback)
+      .await;
+
+    assert_eq!(network.spotify.calls(), ["volume 70", "pause_playback"]);
+    let app = network.app.lock().await;
+    let ctx = app.current_playback_context.as_ref().unwrap();
+    assert_eq!(ctx.device.volume_percent, Some(40));
+    assert!(ctx.is_playing);
+    assert_eq!(app.api_error, "Token is not valid");
+  }
+
+  #[tokio::test]
+  async fn other_events_are_not_playback_controls() {
+    let mut network = mock_network(app_playing_on_speaker(), MockSpotify::default());
+
+    network.handle_playback_control(IoEvent::GetUser).await;
+
+    assert!(network.spotify.calls().is_empty());
+  }
+}
@@ -1,5 +1,16 @@
+use super::client::SpotifyApi;
+#[cfg(feature = "preview")]
+use super::requests::is_premium_required_error;
 use super::requests::spotify_get_typed_compat_for;
 use super::{IoEvent, Network};
+#[cfg(feature = "preview")]
+use crate::core::app::PreviewTrack;
+use crate::core::app::QueueItem;
+use crate::core::locale::{t, tf};
+#[cfg(feature = "streaming")]
+use crate::core::{app::App, shuffle::fair_shuffle};
+#[cfg(feature = "preview")]
+use crate::infra::preview::PreviewPlayer;
 use crate::tui::ui::util::create_artist_string;
 use anyhow::anyhow;
 use chrono::Duration as ChronoDuration;
@@ -9,7 +20,11 @@ use rspotify::model::{
   idtypes::{PlayContextId, PlayableId},
   PlayableItem,
 };
+#[cfg(feature = "preview")]
+use rspotify::model::{idtypes::TrackId, track::FullTrack};
 use rspotify::prelude::*;
+#[cfg(feature = "streaming")]
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 #[cfg(feature = "streaming")]
@@ -17,31 +32,166 @@ use librespot_connect::{LoadRequest, LoadRequestOptions, PlayingTrack};
 
 pub trait PlaybackNetwork {
   async fn get_current_playback(&mut self);
+  async fn get_queue(&mut self);
   async fn start_playback(
     &mut self,
     context_id: Option<PlayContextId<'static>>,
     uris: Option<Vec<PlayableId<'static>>>,
     offset: Option<usize>,
   );
-  async fn pause_playback(&mut self);
-  async fn next_track(&mut self);
-  async fn previous_track(&mut self);
-  async fn seek(&mut self, position_ms: u32);
-  async fn shuffle(&mut self, shuffle_state: bool);
-  async fn repeat(&mut self, repeat_state: RepeatState);
-  async fn change_volume(&mut self, volume: u8);
+  async fn start_playback_at(
+    &mut self,
+    context_id: Option<PlayContextId<'static>>,
+    uris: Option<Vec<PlayableId<'static>>>,
+    offset: Option<usize>,
+    position_ms: Option<u32>,
+  );
   async fn transfert_playback_to_device(&mut self, device_id: String, persist_device_id: bool);
   #[cfg(feature = "streaming")]
+  async fn handoff_playback(&mut self, device: String);
+  #[cfg(feature = "streaming")]
+  async fn start_cold_playback(&mut self);
+  #[cfg(feature = "streaming")]
   async fn auto_select_streaming_device(&mut self, device_name: String, persist_device_id: bool);
   async fn ensure_playback_continues(&mut self, previous_track_id: String);
   #[allow(dead_code)]
   async fn add_item_to_queue(&mut self, item: PlayableId<'static>);
+  async fn queue_and_skip(&mut self, item: PlayableId<'static>);
   #[allow(dead_code)]
   async fn start_collection_playback(&mut self, offset: usize);
+  #[cfg(feature = "preview")]
+  async fn play_preview(
+    &mut self,
+    context_id: Option<PlayContextId<'static>>,
+    uris: Option<Vec<PlayableId<'static>>>,
+    offset: Option<usize>,
+  );
+  #[cfg(feature = "preview")]
+  async fn stop_preview(&mut self);
+}
+
+/// The item a playback request names directly, following the same rules the
+/// native player uses to pick the first track. A bare context needs a lookup.
+#[cfg(feature = "preview")]
+fn preview_target(
+  context_id: Option<&PlayContextId<'static>>,
+  uris: Option<&[PlayableId<'static>]>,
+  offset: Option<usize>,
+) -> Option<PlayableId<'static>> {
+  let uris = uris?;
+  let target = if context_id.is_some() {
+    uris.first()
+  } else {
+    uris.get(offset.unwrap_or(0)).or(uris.first())
+  };
+  target.cloned()
+}
+
+/// Arrange a URI list for fair shuffle, keeping the requested track first.
+///
+/// Artists are looked up in the track lists already loaded in the app; tracks we
+/// know nothing about are treated as their own artist.
+#[cfg(feature = "streaming")]
+fn fair_shuffle_uris(
+  app: &App,
+  uris: Vec<PlayableId<'static>>,
+  offset: Option<usize>,
+) -> Vec<PlayableId<'static>> {
+  let saved_tracks = app
+    .library
+    .saved_tracks
+    .pages
+    .iter()
+    .flat_map(|page| page.items.iter().map(|saved| &saved.track));
+  let artists: HashMap<String, String> = app
+    .track_table
+    .tracks
+    .iter()
+    .chain(app.recommended_tracks.iter())
+    .chain(saved_tracks)
+    .filter_map(|track| {
+      let id = track.id.as_ref()?.id().to_string();
+      let artist = track.artists.first()?.name.clone();
+      Some((id, artist))
+    })
+    .collect();
+
+  let start = offset.filter(|index| *index < uris.len());
+  fair_shuffle(
+    uris,
+    start,
+    |uri| match uri {
+      PlayableId::Track(id) => artists
+        .get(id.id())
+        .cloned()
+        .unwrap_or_else(|| id.id().to_string()),
+      PlayableId::Episode(id) => id.id().to_string(),
+    },
+    &mut rand::thread_rng(),
+  )
+}
+
+/// Playable context behind a context URI from the Web API, if it is one we can start
+#[cfg(feature = "streaming")]
+fn play_context_from_uri(uri: &str) -> Option<PlayContextId<'static>> {
+  use rspotify::model::idtypes::{AlbumId, ArtistId, PlaylistId, ShowId};
+
+  let context = match uri.split(':').nth(1)? {
+    "album" => PlayContextId::Album(AlbumId::from_uri(uri).ok()?),
+    "artist" => PlayContextId::Artist(ArtistId::from_uri(uri).ok()?),
+    "playlist" => PlayContextId::Playlist(PlaylistId::from_uri(uri).ok()?),
+    "show" => PlayContextId::Show(ShowId::from_uri(uri).ok()?),
+    _ => return None,
+  };
+  Some(context.into_static())
+}
+
+/// How far behind the expected position a device may report after a transfer
+/// before we treat it as having restarted the track
+const TRANSFER_RESUME_TOLERANCE_MS: u32 = 3_000;
+
+/// Pause between queueing a track and skipping to it
+const QUEUE_AND_SKIP_DELAY: Duration = Duration::from_millis(500);
+
+/// How many queued items play before `uri` in a `me/player/queue` response
+fn queued_position(queue: &serde_json::Value, uri: &str) -> Option<usize> {
+  queue
+    .get("queue")?
+    .as_array()?
+    .iter()
+    .position(|item| item.get("uri").and_then(|value| value.as_str()) == Some(uri))
+}
+
+/// What `queue_and_skip` did, owning up to queued tracks it skipped past
+fn queue_and_skip_message(skipped: usize) -> String {
+  match skipped {
+    0 => t("status.queue_and_skip_done"),
+    1 => t("status.queue_and_skip_skipped_one"),
+    n => tf(
+      "status.queue_and_skip_skipped",
+      &[("count", &n.to_string())],
+    ),
+  }
+}
+
+/// ID of the track or episode a playback context is on
+fn playing_item_id(context: &rspotify::model::CurrentPlaybackContext) -> Option<String> {
+  match context.item.as_ref()? {
+    PlayableItem::Track(track) => track.id.as_ref().map(|id| id.id().to_string()),
+    PlayableItem::Episode(episode) => Some(episode.id.id().to_string()),
+  }
+}
+
+/// Where to seek after a transfer, if the new device started the track over.
+/// `captured_ms` is the position before the transfer and `elapsed_ms` the time
+/// since, so a device that picked up the position itself is left alone.
+fn transfer_resume_position(captured_ms: u32, elapsed_ms: u32, reported_ms: u32) -> Option<u32> {
+  let expected_ms = captured_ms.saturating_add(elapsed_ms);
+  (reported_ms.saturating_add(TRANSFER_RESUME_TOLERANCE_MS) < expected_ms).then_some(expected_ms)
 }
 
 #[cfg(feature = "streaming")]
-async fn is_native_streaming_active_for_playback(network: &Network) -> bool {
+async fn is_native_streaming_active_for_playback<C>(network: &Network<C>) -> bool {
   let player_connected = network
     .streaming_player
     .as_ref()
@@ -88,14 +238,184 @@ async fn is_native_streaming_active_for_playback(network: &Network) -> bool {
 }
 
 #[cfg(feature = "streaming")]
-fn is_native_streaming_active(network: &Network) -> bool {
+fn is_native_streaming_active<C>(network: &Network<C>) -> bool {
   network
     .streaming_player
     .as_ref()
     .is_some_and(|p| p.is_connected())
 }
 
-impl PlaybackNetwork for Network {
+/// Play/pause, skip, seek, shuffle, repeat and volume. These only need the
+/// `SpotifyApi` calls, so they work with any client.
+pub trait PlaybackControlNetwork {
+  async fn pause_playback(&mut self);
+  async fn next_track(&mut self);
+  async fn previous_track(&mut self);
+  async fn seek(&mut self, position_ms: u32);
+  async fn shuffle(&mut self, shuffle_state: bool);
+  async fn repeat(&mut self, repeat_state: RepeatState);
+  async fn change_volume(&mut self, volume: u8);
+}
+
+impl<C: SpotifyApi> PlaybackControlNetwork for Network<C> {
+  async fn pause_playback(&mut self) {
+    // Check if using native streaming
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        player.pause();
+        // Update UI state immediately
+        let mut app = self.app.lock().await;
+        if let Some(ctx) = &mut app.current_playback_context {
+          ctx.is_playing = false;
+        }
+        return;
+      }
+    }
+
+    match self.spotify.pause_playback(None).await {
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        if let Some(ctx) = &mut app.current_playback_context {
+          ctx.is_playing = false;
+        }
+      }
+      Err(e) => {
+        let mut app = self.app.lock().await;
+        app.handle_playback_error(anyhow!(e));
+      }
+    }
+  }
+
+  async fn next_track(&mut self) {
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        player.next();
+        return;
+      }
+    }
+
+    if let Err(e) = self.spotify.next_track(None).await {
+      let mut app = self.app.lock().await;
+      app.handle_playback_error(anyhow!(e));
+    }
+  }
+
+  async fn previous_track(&mut self) {
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        player.prev();
+        return;
+      }
+    }
+
+    if let Err(e) = self.spotify.previous_track(None).await {
+      let mut app = self.app.lock().await;
+      app.handle_playback_error(anyhow!(e));
+    }
+  }
+
+  async fn seek(&mut self, position_ms: u32) {
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        player.seek(position_ms);
+        return;
+      }
+    }
+
+    if let Err(e) = self
+      .spotify
+      .seek_track(ChronoDuration::milliseconds(position_ms as i64), None)
+      .await
+    {
+      let mut app = self.app.lock().await;
+      app.handle_playback_error(anyhow!(e));
+    }
+  }
+
+  async fn shuffle(&mut self, shuffle_state: bool) {
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        let _ = player.set_shuffle(shuffle_state);
+        let mut app = self.app.lock().await;
+        app.set_shuffle_state(shuffle_state);
+        return;
+      }
+    }
+
+    match self.spotify.shuffle(shuffle_state, None).await {
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        app.set_shuffle_state(shuffle_state);
+        app.confirm_playback_soon();
+      }
+      Err(e) => {
+        let mut app = self.app.lock().await;
+        app.handle_playback_error(anyhow!(e));
+      }
+    }
+  }
+
+  async fn repeat(&mut self, repeat_state: RepeatState) {
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        let _ = player.set_repeat_mode(repeat_state);
+        let mut app = self.app.lock().await;
+        app.set_repeat_state(repeat_state);
+        return;
+      }
+    }
+
+    match self.spotify.repeat(repeat_state, None).await {
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        app.set_repeat_state(repeat_state);
+        app.confirm_playback_soon();
+      }
+      Err(e) => {
+        let mut app = self.app.lock().await;
+        app.handle_playback_error(anyhow!(e));
+      }
+    }
+  }
+
+  async fn change_volume(&mut self, volume: u8) {
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        player.set_volume(volume);
+        let mut app = self.app.lock().await;
+        if let Some(ctx) = &mut app.current_playback_context {
+          ctx.device.volume_percent = Some(volume.into());
+        }
+        return;
+      }
+    }
+
+    match self.spotify.volume(volume, None).await {
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        // A newer change may already be showing while it waits to be sent
+        if app.pending_volume.is_none() {
+          if let Some(ctx) = &mut app.current_playback_context {
+            ctx.device.volume_percent = Some(volume.into());
+          }
+        }
+      }
+      Err(e) => {
+        let mut app = self.app.lock().await;
+        app.handle_playback_error(anyhow!(e));
+      }
+    }
+  }
+}
+
+impl<C: SpotifyApi + Clone + Send + Sync + 'static> PlaybackNetwork for Network<C> {
   async fn get_current_playback(&mut self) {
     // When using native streaming, the Spotify API returns stale server-side state
     // that doesn't reflect recent local changes (volume, shuffle, repeat, play/pause).
@@ -147,6 +467,12 @@ impl PlaybackNetwork for Network {
         #[cfg(not(feature = "streaming"))]
         let is_native_device = false;
 
+        if !is_native_device {
+          if let Some(id) = c.device.id.clone() {
+            app.last_external_device = Some((id, c.device.name.clone()));
+          }
+        }
+
         #[cfg(feature = "streaming")]
         if is_native_device && app.native_device_id.is_none() {
           if let Some(id) = c.device.id.clone() {
@@ -163,9 +489,7 @@ impl PlaybackNetwork for Network {
 
                 // Check if this is a new track
                 if app.last_track_id.as_ref() != Some(&track_id_str) {
-                  if app.user_config.behavior.enable_global_song_count {
-                    app.dispatch(IoEvent::IncrementGlobalSongCount);
-                  }
+                  app.count_song_play(&track_id_str, false);
 
                   // Trigger lyrics fetch
                   let duration_secs = track.duration.num_seconds() as f64;
@@ -178,12 +502,23 @@ impl PlaybackNetwork for Network {
                   app.dispatch(IoEvent::CurrentUserSavedTracksContains(vec![track_id
                     .clone()
                     .into_static()]));
+                  app.last_queue_poll = None;
                 }
 
                 app.last_track_id = Some(track_id_str);
               };
             }
-            PlayableItem::Episode(_episode) => { /*should map this to following the podcast show*/ }
+            PlayableItem::Episode(episode) => {
+              let episode_id_str = episode.id.id().to_string();
+              if app.last_track_id.as_ref() != Some(&episode_id_str) {
+                app.dispatch(IoEvent::CurrentUserSavedEpisodesContains(vec![episode
+                  .id
+                  .clone()
+                  .into_static()]));
+                app.last_queue_poll = None;
+                app.last_track_id = Some(episode_id_str);
+              }
+            }
           }
         };
 
@@ -236,6 +571,31 @@ impl PlaybackNetwork for Network {
           }
         }
 
+        // The native player keeps its own volume, see above, and reports its own
+        // position through events
+        if !is_native_device {
+          app.sync_polled_volume(&mut c);
+          let progress_ms = c
+            .progress
+            .map(|progress| progress.num_milliseconds().max(0) as u128)
+            .unwrap_or(0);
+          app.sync_polled_progress(progress_ms);
+        }
+
+        // Shuffle or repeat changed somewhere else, e.g. from a phone
+        let shown = app
+          .current_playback_context
+          .as_ref()
+          .map(|shown| (shown.shuffle_state, shown.repeat_state));
+        if let Some((shuffle_state, repeat_state)) = shown {
+          if shuffle_state != c.shuffle_state {
+            app.set_shuffle_state(c.shuffle_state);
+          }
+          if repeat_state != c.repeat_state {
+            app.set_repeat_state(c.repeat_state);
+          }
+        }
+
         app.current_playback_context = Some(c);
 
         // Update is_streaming_active based on whether the current device matches native streaming
@@ -314,11 +674,36 @@ impl PlaybackNetwork for Network {
     app.is_fetching_current_playback = false;
   }
 
+  async fn get_queue(&mut self) {
+    let queue =
+      spotify_get_typed_compat_for::<serde_json::Value>(&self.spotify, "me/player/queue", &[])
+        .await;
+
+    // This works for any active device, native or not, since it asks the Web API
+    let fetched = queue.ok().and_then(|queue| {
+      let items = queue.get("queue")?.as_array()?;
+      Some(items.iter().filter_map(QueueItem::from_json).collect())
+    });
+
+    let mut app = self.app.lock().await;
+    app.apply_queue_update(fetched);
+  }
+
   async fn start_playback(
     &mut self,
     context_id: Option<PlayContextId<'static>>,
     uris: Option<Vec<PlayableId<'static>>>,
     offset: Option<usize>,
+  ) {
+    self.start_playback_at(context_id, uris, offset, None).await;
+  }
+
+  async fn start_playback_at(
+    &mut self,
+    context_id: Option<PlayContextId<'static>>,
+    uris: Option<Vec<PlayableId<'static>>>,
+    offset: Option<usize>,
+    position_ms: Option<u32>,
   ) {
     let desired_shuffle_state = {
       let app = self.app.lock().await;
@@ -329,9 +714,22 @@ impl PlaybackNetwork for Network {
         .unwrap_or(app.user_config.behavior.shuffle_enabled)
     };
 
+    #[cfg(feature = "preview")]
+    let preview_request = (context_id.clone(), uris.clone(), offset);
+    #[cfg(feature = "preview")]
+    {
+      let preview_mode = self.app.lock().await.user_config.behavior.preview_mode;
+      if preview_mode && (context_id.is_some() || uris.is_some()) {
+        self.play_preview(context_id, uris, offset).await;
+        return;
+      }
+    }
+
     // Check if we should use native streaming for playback
     #[cfg(feature = "streaming")]
     if is_native_streaming_active_for_playback(self).await {
+      #[cfg(feature = "preview")]
+      self.stop_preview().await;
       if let Some(ref player) = self.streaming_player {
         let activation_time = Instant::now();
         let should_transfer = {
@@ -370,10 +768,27 @@ impl PlaybackNetwork for Network {
           return;
         }
 
+        // Fair shuffle hands librespot a pre-arranged track list, so its own
+        // shuffle is turned off and playback simply follows our order
+        let fair_shuffle = {
+          let app = self.app.lock().await;
+          desired_shuffle_state
+            && app.user_config.behavior.fair_shuffle
+            && context_id.is_none()
+            && uris.as_ref().is_some_and(|uris| uris.len() > 1)
+        };
+        let (uris, offset) = match uris {
+          Some(uris) if fair_shuffle => {
+            let app = self.app.lock().await;
+            (Some(fair_shuffle_uris(&app, uris, offset)), Some(0))
+          }
+          uris => (uris, offset),
+        };
+
         // For URI-based or context playback, use Spirc load directly.
         let mut options = LoadRequestOptions {
           start_playing: true,
-          seek_to: 0,
+          seek_to: position_ms.unwrap_or(0),
           context_options: None,
           playing_track: None,
         };
@@ -414,7 +829,7 @@ impl PlaybackNetwork for Network {
           let mut app = self.app.lock().await;
           app.handle_error(anyhow!("Failed to start native playback: {}", e));
         } else {
-          let _ = player.set_shuffle(desired_shuffle_state);
+          let _ = player.set_shuffle(desired_shuffle_state && !fair_shuffle);
           // Optimistic UI update
           let mut app = self.app.lock().await;
           if let Some(ctx) = &mut app.current_playback_context {
@@ -429,36 +844,29 @@ impl PlaybackNetwork for Network {
 
     let offset_struct =
       offset.map(|o| rspotify::model::Offset::Position(ChronoDuration::milliseconds(o as i64)));
+    let position = position_ms.map(|ms| ChronoDuration::milliseconds(ms as i64));
 
     let result = if let Some(context) = context_id {
       self
         .spotify
-        .start_context_playback(
-          context,
-          None, // device_id
-          offset_struct,
-          None, // position
-        )
+        .start_context_playback(context, offset_struct, position)
         .await
     } else if let Some(track_uris) = uris {
       self
         .spotify
-        .start_uris_playback(
-          track_uris,
-          None, // device_id
-          offset_struct,
-          None, // position
-        )
+        .start_uris_playback(track_uris, offset_struct, position)
         .await
     } else {
-      self.spotify.resume_playback(None, None).await
+      self.spotify.resume_playback().await
     };
 
     match result {
       Ok(_) => {
+        #[cfg(feature = "preview")]
+        self.stop_preview().await;
         if let Err(e) = self.spotify.shuffle(desired_shuffle_state, None).await {
           let mut app = self.app.lock().await;
-          app.handle_error(anyhow!(e));
+          app.handle_playback_error(anyhow!(e));
         }
 
         let mut app = self.app.lock().await;
@@ -469,172 +877,112 @@ impl PlaybackNetwork for Network {
         app.user_config.behavior.shuffle_enabled = desired_shuffle_state;
       }
       Err(e) => {
+        let e = anyhow!(e);
+        #[cfg(feature = "preview")]
+        {
+          let account_is_premium = self.app.lock().await.account_is_premium();
+          let (context_id, uris, offset) = preview_request;
+          if (context_id.is_some() || uris.is_some())
+            && is_premium_required_error(&e, account_is_premium)
+          {
+            self.play_preview(context_id, uris, offset).await;
+            return;
+          }
+        }
         let mut app = self.app.lock().await;
-        app.handle_error(anyhow!(e));
+        app.handle_playback_error(e);
       }
     }
   }
 
-  async fn pause_playback(&mut self) {
-    // Check if using native streaming
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        player.pause();
-        // Update UI state immediately
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.is_playing = false;
-        }
+  #[cfg(feature = "preview")]
+  async fn play_preview(
+    &mut self,
+    context_id: Option<PlayContextId<'static>>,
+    uris: Option<Vec<PlayableId<'static>>>,
+    offset: Option<usize>,
+  ) {
+    let track_id = match preview_target(context_id.as_ref(), uris.as_deref(), offset) {
+      Some(PlayableId::Track(track_id)) => Some(track_id),
+      Some(PlayableId::Episode(_)) => {
+        self
+          .show_status_message(t("status.preview_tracks_only"), 4)
+          .await;
         return;
       }
-    }
+      None => match context_id {
+        Some(context_id) => self.preview_track_in_context(context_id, offset).await,
+        None => None,
+      },
+    };
+    let Some(track_id) = track_id else {
+      self
+        .show_status_message(t("status.nothing_to_preview"), 4)
+        .await;
+      return;
+    };
 
-    match self.spotify.pause_playback(None).await {
-      Ok(_) => {
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.is_playing = false;
-        }
-      }
+    let path = format!("tracks/{}", track_id.id());
+    let track = match spotify_get_typed_compat_for::<FullTrack>(&self.spotify, &path, &[]).await {
+      Ok(track) => track,
       Err(e) => {
-        let mut app = self.app.lock().await;
-        app.handle_error(anyhow!(e));
-      }
-    }
-  }
-
-  async fn next_track(&mut self) {
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        player.next();
+        self.handle_error(e).await;
         return;
       }
-    }
-
-    if let Err(e) = self.spotify.next_track(None).await {
-      let mut app = self.app.lock().await;
-      app.handle_error(anyhow!(e));
-    }
-  }
-
-  async fn previous_track(&mut self) {
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        player.prev();
-        return;
-      }
-    }
-
-    if let Err(e) = self.spotify.previous_track(None).await {
-      let mut app = self.app.lock().await;
-      app.handle_error(anyhow!(e));
-    }
-  }
-
-  async fn seek(&mut self, position_ms: u32) {
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        player.seek(position_ms);
-        return;
-      }
-    }
-
-    if let Err(e) = self
-      .spotify
-      .seek_track(ChronoDuration::milliseconds(position_ms as i64), None)
-      .await
-    {
-      let mut app = self.app.lock().await;
-      app.handle_error(anyhow!(e));
-    }
-  }
-
-  async fn shuffle(&mut self, shuffle_state: bool) {
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        let _ = player.set_shuffle(shuffle_state);
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.shuffle_state = shuffle_state;
-        }
-        return;
-      }
-    }
-
-    match self.spotify.shuffle(shuffle_state, None).await {
-      Ok(_) => {
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.shuffle_state = shuffle_state;
-        }
-      }
+    };
+    let Some(preview_url) = track.preview_url.as_deref() else {
+      self
+        .show_status_message(tf("status.no_preview", &[("track", &track.name)]), 4)
+        .await;
+      return;
+    };
+    let clip = match download_preview(preview_url).await {
+      Ok(clip) => clip,
       Err(e) => {
-        let mut app = self.app.lock().await;
-        app.handle_error(anyhow!(e));
-      }
-    }
-  }
-
-  async fn repeat(&mut self, repeat_state: RepeatState) {
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        let _ = player.set_repeat(repeat_state);
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.repeat_state = repeat_state;
-        }
+        self.handle_error(e).await;
         return;
       }
-    }
+    };
 
-    match self.spotify.repeat(repeat_state, None).await {
-      Ok(_) => {
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.repeat_state = repeat_state;
+    if self.preview_player.is_none() {
+      match PreviewPlayer::new() {
+        Ok(player) => self.preview_player = Some(player),
+        Err(e) => {
+          self.handle_error(e).await;
+          return;
         }
       }
-      Err(e) => {
-        let mut app = self.app.lock().await;
-        app.handle_error(anyhow!(e));
-      }
     }
-  }
-
-  async fn change_volume(&mut self, volume: u8) {
-    #[cfg(feature = "streaming")]
-    if is_native_streaming_active_for_playback(self).await {
-      if let Some(ref player) = self.streaming_player {
-        player.set_volume(volume);
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.device.volume_percent = Some(volume.into());
-        }
-        return;
-      }
+    let mut app = self.app.lock().await;
+    if let Some(player) = &self.preview_player {
+      player.play(clip, app.user_config.behavior.volume_percent);
     }
+    app.preview = Some(PreviewTrack {
+      name: track.name.clone(),
+      artists: create_artist_string(&track.artists),
+      started_at: Instant::now(),
+    });
+  }
 
-    match self.spotify.volume(volume, None).await {
-      Ok(_) => {
-        let mut app = self.app.lock().await;
-        if let Some(ctx) = &mut app.current_playback_context {
-          ctx.device.volume_percent = Some(volume.into());
-        }
-      }
-      Err(e) => {
-        let mut app = self.app.lock().await;
-        app.handle_error(anyhow!(e));
-      }
+  #[cfg(feature = "preview")]
+  async fn stop_preview(&mut self) {
+    if let Some(player) = &self.preview_player {
+      player.stop();
     }
+    self.app.lock().await.preview = None;
   }
 
   async fn transfert_playback_to_device(&mut self, device_id: String, persist_device_id: bool) {
+    // Remember what was playing and where, in case the new device starts it over
+    let resume = {
+      let app = self.app.lock().await;
+      app
+        .current_playback_context
+        .as_ref()
+        .and_then(playing_item_id)
+        .map(|item_id| (item_id, app.song_progress_ms as u32, Instant::now()))
+    };
+
     #[cfg(feature = "streaming")]
     {
       let is_native_transfer = if let Some(ref player) = self.streaming_player {
@@ -655,11 +1003,36 @@ impl PlaybackNetwork for Network {
         if let Some(ref player) = self.streaming_player {
           let _ = player.transfer(None);
           player.activate();
-          let mut app = self.app.lock().await;
-          app.is_streaming_active = true;
-          app.native_activation_pending = true;
-          app.last_device_activation = Some(Instant::now());
-          app.instant_since_last_current_playback_poll = Instant::now() - Duration::from_secs(6);
+          {
+            let mut app = self.app.lock().await;
+            app.is_streaming_active = true;
+            app.native_activation_pending = true;
+            app.last_device_activation = Some(Instant::now());
+            app.instant_since_last_current_playback_poll = Instant::now() - Duration::from_secs(6);
+          }
+
+          // librespot reports its position through player events, so give it a
+          // moment to load the track and then check where it landed
+          if let Some((item_id, captured_ms, captured_at)) = resume {
+            let player = std::sync::Arc::clone(player);
+            let app = std::sync::Arc::clone(&self.app);
+            tokio::spawn(async move {
+              tokio::time::sleep(Duration::from_millis(1500)).await;
+              let app = app.lock().await;
+              let same_item = app
+                .current_playback_context
+                .as_ref()
+                .and_then(playing_item_id)
+                .is_some_and(|id| id == item_id);
+              let elapsed_ms = captured_at.elapsed().as_millis() as u32;
+              if let Some(position_ms) =
+                transfer_resume_position(captured_ms, elapsed_ms, app.song_progress_ms as u32)
+                  .filter(|_| same_item)
+              {
+                player.seek(position_ms);
+              }
+            });
+          }
           return;
         }
       }
@@ -667,12 +1040,12 @@ impl PlaybackNetwork for Network {
 
     if let Err(e) = self.spotify.transfer_playback(&device_id, Some(true)).await {
       let mut app = self.app.lock().await;
-      app.handle_error(anyhow!(e));
+      app.handle_playback_error(anyhow!(e));
     } else {
       let mut app = self.app.lock().await;
       if persist_device_id {
         // Update via client_config helper to save to file
-        if let Err(e) = self.client_config.set_device_id(device_id) {
+        if let Err(e) = self.client_config.set_device_id(device_id.clone()) {
           app.handle_error(anyhow!(e));
         }
       }
@@ -683,9 +1056,149 @@ impl PlaybackNetwork for Network {
         // If transferring away from native, update flag
         app.is_streaming_active = false;
       }
+      drop(app);
+
+      // Some devices (spotifyd in particular) start the track from the top, so once
+      // the new device is active put it back where it was
+      if let Some((item_id, captured_ms, captured_at)) = resume {
+        let spotify = self.spotify.clone();
+        tokio::spawn(async move {
+          for _ in 0..8 {
+            tokio::time::sleep(Duration::from_millis(250)).await;
+            let context = spotify_get_typed_compat_for::<
+              Option<rspotify::model::CurrentPlaybackContext>,
+            >(&spotify, "me/player", &[])
+            .await;
+            let Ok(Some(context)) = context else {
+              continue;
+            };
+            if context.device.id.as_deref() != Some(device_id.as_str()) {
+              continue;
+            }
+            if playing_item_id(&context).as_deref() != Some(item_id.as_str()) {
+              return;
+            }
+            let reported_ms = context
+              .progress
+              .map(|progress| progress.num_milliseconds() as u32)
+              .unwrap_or(0);
+            let elapsed_ms = captured_at.elapsed().as_millis() as u32;
+            if let Some(position_ms) =
+              transfer_resume_position(captured_ms, elapsed_ms, reported_ms)
+            {
+              let _ = spotify
+                .seek_track(
+                  ChronoDuration::milliseconds(position_ms as i64),
+                  Some(&device_id),
+                )
+                .await;
+            }
+            return;
+          }
+        });
+      }
     }
   }
 
+  #[cfg(feature = "streaming")]
+  async fn start_cold_playback(&mut self) {
+    // Nothing reports the native player as active yet, claim playback for it
+    {
+      let mut app = self.app.lock().await;
+      app.is_streaming_active = true;
+      app.native_activation_pending = true;
+    }
+
+    let last_context = match self.spotify.current_user_recently_played(1).await {
+      Ok(recent) => recent
+        .items
+        .first()
+        .and_then(|item| item.context.as_ref())
+        .and_then(|context| play_context_from_uri(&context.uri)),
+      Err(_) => None,
+    };
+    if let Some(context) = last_context {
+      self.start_playback(Some(context), None, None).await;
+      return;
+    }
+
+    let liked = spotify_get_typed_compat_for::<rspotify::model::Page<rspotify::model::SavedTrack>>(
+      &self.spotify,
+      "me/tracks",
+      &[("limit", self.large_search_limit.to_string())],
+    )
+    .await;
+    let uris: Vec<PlayableId<'static>> = match liked {
+      Ok(page) => page
+        .items
+        .into_iter()
+        .filter_map(|saved| saved.track.id)
+        .map(PlayableId::Track)
+        .collect(),
+      Err(e) => {
+        self.handle_error(anyhow!(e)).await;
+        return;
+      }
+    };
+
+    let mut app = self.app.lock().await;
+    if uris.is_empty() {
+      app.set_status_message("Nothing to play yet, pick something from the library", 4);
+      return;
+    }
+    app.set_status_message("Playing Liked Songs", 3);
+    drop(app);
+    self.start_playback(None, Some(uris), Some(0)).await;
+  }
+
+  #[cfg(feature = "streaming")]
+  async fn handoff_playback(&mut self, device: String) {
+    let message = match self.spotify.device().await {
+      Err(e) => format!("Couldn't hand playback off to {}: {}", device, e),
+      Ok(devices) => match devices
+        .iter()
+        .find(|d| d.name.eq_ignore_ascii_case(&device) || d.id.as_deref() == Some(&device))
+        .and_then(|d| d.id.clone())
+      {
+        None => format!(
+          "Handoff device {} is not available, playback stopped",
+          device
+        ),
+        Some(device_id) => match self.spotify.transfer_playback(&device_id, Some(true)).await {
+          Err(e) => format!("Couldn't hand playback off to {}: {}", device, e),
+          Ok(_) => {
+            // The transfer call returns before the device has taken over, so wait
+            // until the player reports it as the active device
+            let mut confirmed = false;
+            for _ in 0..8 {
+              tokio::time::sleep(Duration::from_millis(250)).await;
+              let context = spotify_get_typed_compat_for::<
+                Option<rspotify::model::CurrentPlaybackContext>,
+              >(&self.spotify, "me/player", &[])
+              .await;
+              if let Ok(Some(context)) = context {
+                if context.device.id.as_deref() == Some(&device_id) {
+                  confirmed = true;
+                  break;
+                }
+              }
+            }
+            if confirmed {
+              format!("Playback handed off to {}", device)
+            } else {
+              format!(
+                "Asked {} to take over playback but it did not confirm",
+                device
+              )
+            }
+          }
+        },
+      },
+    };
+
+    self.app.lock().await.exit_message = Some(message);
+  }
+
   #[cfg(feature = "streaming")]
   async fn auto_select_streaming_device(&mut self, device_name: String, persist_device_id: bool) {
     tokio::time::sleep(Duration::from_millis(200)).await;
@@ -749,6 +1262,30 @@ impl PlaybackNetwork for Network {
   }
 
   async fn ensure_playback_continues(&mut self, previous_track_id: String) {
+    // Episodes played one at a time don't form a context, so carry on through the open show
+    let (next_episodes, nothing_started) = {
+      let app = self.app.lock().await;
+      let next_episodes = if app.user_config.behavior.podcast_autoplay_next {
+        app.show_episodes_after(&previous_track_id)
+      } else {
+        None
+      };
+      (next_episodes, app.last_track_id.is_none())
+    };
+    if let Some(next_episodes) = next_episodes {
+      if nothing_started {
+        if next_episodes.is_empty() {
+          let mut app = self.app.lock().await;
+          app.set_status_message("Finished the last episode in the list", 5);
+        } else {
+          self
+            .start_playback(None, Some(next_episodes), Some(0))
+            .await;
+        }
+      }
+      return;
+    }
+
     #[cfg(feature = "streaming")]
     if is_native_streaming_active_for_playback(self).await {
       // Native player handles queue automatically
@@ -789,19 +1326,84 @@ impl PlaybackNetwork for Network {
   }
 
   async fn add_item_to_queue(&mut self, item: PlayableId<'static>) {
-    match self.spotify.add_item_to_queue(item, None).await {
+    let item_uri = item.uri();
+    match self.spotify.add_item_to_queue(item).await {
       Ok(_) => {
         let mut app = self.app.lock().await;
+        app.last_queue_poll = None;
+        app.locally_queued_uris.push(item_uri);
         app.status_message = Some("Added to queue".to_string());
         app.status_message_expires_at = Some(Instant::now() + Duration::from_secs(3));
       }
       Err(e) => {
         let mut app = self.app.lock().await;
-        app.handle_error(anyhow!(e));
+        app.handle_playback_error(anyhow!(e));
       }
     }
   }
 
+  async fn queue_and_skip(&mut self, item: PlayableId<'static>) {
+    let item_uri = item.uri();
+    if let Err(e) = self.spotify.add_item_to_queue(item).await {
+      let mut app = self.app.lock().await;
+      app.handle_playback_error(anyhow!(e));
+      return;
+    }
+    {
+      let mut app = self.app.lock().await;
+      app.last_queue_poll = None;
+      app.locally_queued_uris.push(item_uri.clone());
+    }
+
+    // The queue change reaches the player a moment after the request returns, and
+    // skipping any sooner lands on whatever was next before
+    tokio::time::sleep(QUEUE_AND_SKIP_DELAY).await;
+
+    // Tracks queued earlier play first, so skip past them to reach the new one
+    let queue =
+      spotify_get_typed_compat_for::<serde_json::Value>(&self.spotify, "me/player/queue", &[])
+        .await;
+    let Some(ahead) = queue
+      .ok()
+      .and_then(|queue| queued_position(&queue, &item_uri))
+    else {
+      self
+        .app
+        .lock()
+        .await
+        .set_status_message(t("status.queue_and_skip_not_found"), 4);
+      return;
+    };
+    let skips = ahead + 1;
+
+    #[cfg(feature = "streaming")]
+    if is_native_streaming_active_for_playback(self).await {
+      if let Some(ref player) = self.streaming_player {
+        player.activate();
+        for _ in 0..skips {
+          player.next();
+        }
+        player.play();
+        let mut app = self.app.lock().await;
+        app.set_song_progress(0);
+        app.set_status_message(queue_and_skip_message(ahead), 3);
+        return;
+      }
+    }
+
+    for _ in 0..skips {
+      if let Err(e) = self.spotify.next_track(None).await {
+        let mut app = self.app.lock().await;
+        app.handle_playback_error(anyhow!(e));
+        return;
+      }
+    }
+    let mut app = self.app.lock().await;
+    app.set_song_progress(0);
+    app.set_status_message(queue_and_skip_message(ahead), 3);
+    app.dispatch(IoEvent::GetCurrentPlayback);
+  }
+
   async fn start_collection_playback(&mut self, _offset: usize) {
     // Placeholder - Spotify API doesn't support "My Music" as context
     let mut app = self.app.lock().await;
@@ -810,3 +1412,361 @@ impl PlaybackNetwork for Network {
     app.status_message_expires_at = Some(Instant::now() + Duration::from_secs(5));
   }
 }
+
+#[cfg(feature = "preview")]
+async fn download_preview(url: &str) -> anyhow::Result<Vec<u8>> {
+  let response = reqwest::get(url).await?.error_for_status()?;
+  Ok(response.bytes().await?.to_vec())
+}
+
+#[cfg(feature = "preview")]
+impl<C: SpotifyApi> Network<C> {
+  /// The track at `offset` in an album or playlist, for contexts started without URIs
+  async fn preview_track_in_context(
+    &self,
+    context_id: PlayContextId<'static>,
+    offset: Option<usize>,
+  ) -> Option<TrackId<'static>> {
+    let query = vec![
+      ("limit", "1".to_string()),
+      ("offset", offset.unwrap_or(0).to_string()),
+    ];
+    let (path, id_pointer) = match &context_id {
+      PlayContextId::Album(id) => (format!("albums/{}/tracks", id.id()), "/items/0/id"),
+      PlayContextId::Playlist(id) => (format!("playlists/{}/items", id.id()), "/items/0/track/id"),
+      _ => return None,
+    };
+    let page: serde_json::Value = spotify_get_typed_compat_for(&self.spotify, &path, &query)
+      .await
+      .ok()?;
+    let id = page.pointer(id_pointer)?.as_str()?;
+    TrackId::from_id(id.to_string()).ok()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::super::client::MockSpotify;
+  use super::super::requests::{normalize_spotify_payload, PREMIUM_REQUIRED_MESSAGE};
+  use super::*;
+  use crate::core::app::{ActiveBlock, App};
+  use rspotify::model::idtypes::TrackId;
+
+  #[cfg(feature = "preview")]
+  #[test]
+  fn preview_plays_the_track_the_request_would_start_on() {
+    use rspotify::model::idtypes::{AlbumId, EpisodeId};
+
+    let track = |id: &str| PlayableId::Track(TrackId::from_id(id.to_string()).unwrap());
+    let uris = vec![
+      track("4uLU6hMCjMI75M1A2tKUQC"),
+      track("7ouMYWpwJ422jRcDASZB7P"),
+    ];
+    let album = PlayContextId::Album(AlbumId::from_id("6akEvsycLGftJxYudPjmqK").unwrap());
+
+    assert_eq!(
+      preview_target(None, Some(&uris), Some(1)),
+      Some(uris[1].clone())
+    );
+    // Out-of-range offsets fall back to the first track, like the player does
+    assert_eq!(
+      preview_target(None, Some(&uris), Some(9)),
+      Some(uris[0].clone())
+    );
+    // With a context, the URIs name the track to start on
+    assert_eq!(
+      preview_target(Some(&album), Some(&uris), Some(1)),
+      Some(uris[0].clone())
+    );
+    // A bare context has to be looked up
+    assert_eq!(preview_target(Some(&album), None, Some(3)), None);
+
+    let episode = PlayableId::Episode(EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap());
+    assert_eq!(
+      preview_target(None, Some(std::slice::from_ref(&episode)), None),
+      Some(episode)
+    );
+  }
+
+  #[test]
+  fn transfer_resumes_only_when_the_device_restarted_the_track() {
+    // Picked up where it left off, give or take the transfer delay
+    assert_eq!(transfer_resume_position(60_000, 1_000, 60_500), None);
+    // Started over from the top
+    assert_eq!(transfer_resume_position(60_000, 1_000, 200), Some(61_000));
+    // Too early in the track to tell a restart apart from a slow handover
+    assert_eq!(transfer_resume_position(1_500, 500, 0), None);
+  }
+
+  const TRACK: &str = "4uLU6hMCjMI75M1A2tKUQC";
+
+  fn playback_json(device_id: &str, progress_ms: u32) -> serde_json::Value {
+    let mut context = serde_json::json!({
+      "device": {
+        "id": device_id,
+        "is_active": true,
+        "is_private_session": false,
+        "is_restricted": false,
+        "name": device_id,
+        "type": "Speaker",
+        "volume_percent": 40
+      },
+      "repeat_state": "off",
+      "shuffle_state": false,
+      "context": null,
+      "timestamp": 0,
+      "progress_ms": progress_ms,
+      "is_playing": true,
+      "item": {
+        "album": {
+          "album_type": "album",
+          "artists": [],
+          "external_urls": {},
+          "href": null,
+          "id": null,
+          "images": [],
+          "name": "Album"
+        },
+        "artists": [],
+        "disc_number": 1,
+        "duration_ms": 200_000,
+        "explicit": false,
+        "external_urls": {},
+        "href": null,
+        "id": TRACK,
+        "is_local": false,
+        "name": "Song",
+        "preview_url": null,
+        "track_number": 1
+      },
+      "currently_playing_type": "track",
+      "actions": { "disallows": {} }
+    });
+    normalize_spotify_payload(&mut context);
+    context
+  }
+
+  fn app_playing_on(device_id: &str, progress_ms: u32) -> App {
+    let mut app = App::default();
+    app.current_playback_context =
+      Some(serde_json::from_value(playback_json(device_id, progress_ms)).unwrap());
+    app.song_progress_ms = progress_ms as u128;
+    app
+  }
+
+  /// Lets the task a transfer spawns poll the new device, on paused time
+  async fn wait_for_call(client: &MockSpotify, prefix: &str) {
+    for _ in 0..60 {
+      if client.calls().iter().any(|call| call.starts_with(prefix)) {
+        return;
+      }
+      tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    panic!("no {} call in {:?}", prefix, client.calls());
+  }
+
+  /// A `me/player/queue` answer listing `uris` as up next
+  fn queue_json(uris: &[String]) -> serde_json::Value {
+    serde_json::json!({
+      "currently_playing": null,
+      "queue": uris.iter().map(|uri| serde_json::json!({ "uri": uri })).collect::<Vec<_>>(),
+    })
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn queue_and_skip_queues_before_skipping() {
+    let mut network = Network::mock(App::default(), MockSpotify::default());
+    let track = PlayableId::Track(TrackId::from_id(TRACK).unwrap());
+    network.spotify.respond(
+      "GET me/player/queue",
+      Ok(queue_json(&[format!("spotify:track:{}", TRACK)])),
+    );
+
+    network.queue_and_skip(track).await;
+
+    assert_eq!(
+      network.spotify.calls(),
+      [
+        format!("add_item_to_queue spotify:track:{}", TRACK),
+        "GET me/player/queue".to_string(),
+        "next_track".to_string(),
+      ]
+    );
+    let app = network.app.lock().await;
+    assert_eq!(
+      app.locally_queued_uris,
+      [format!("spotify:track:{}", TRACK)]
+    );
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Playing the queued track now")
+    );
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn queue_and_skip_skips_past_tracks_queued_earlier() {
+    let mut network = Network::mock(App::default(), MockSpotify::default());
+    let track = PlayableId::Track(TrackId::from_id(TRACK).unwrap());
+    network.spotify.respond(
+      "GET me/player/queue",
+      Ok(queue_json(&[
+        "spotify:track:queuedEarlierOne".to_string(),
+        "spotify:track:queuedEarlierTwo".to_string(),
+        format!("spotify:track:{}", TRACK),
+        "spotify:track:fromTheContext".to_string(),
+      ])),
+    );
+
+    network.queue_and_skip(track).await;
+
+    let skips = network
+      .spotify
+      .calls()
+      .iter()
+      .filter(|call| *call == "next_track")
+      .count();
+    assert_eq!(skips, 3);
+    assert_eq!(
+      network.app.lock().await.status_message.as_deref(),
+      Some("Playing the queued track now, skipped 2 tracks queued before it")
+    );
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn queue_and_skip_stays_put_when_the_track_is_not_in_the_queue() {
+    let mut network = Network::mock(App::default(), MockSpotify::default());
+    let track = PlayableId::Track(TrackId::from_id(TRACK).unwrap());
+
+    network.queue_and_skip(track).await;
+
+    assert!(!network.spotify.calls().contains(&"next_track".to_string()));
+    assert_eq!(
+      network.app.lock().await.status_message.as_deref(),
+      Some("Queued, but couldn't find it in the queue to skip to")
+    );
+  }
+
+  #[tokio::test]
+  async fn api_playback_starts_at_the_offset_and_position() {
+    let mut network = Network::mock(App::default(), MockSpotify::default());
+    let track = PlayableId::Track(TrackId::from_id(TRACK).unwrap());
+
+    network
+      .start_playback_at(None, Some(vec![track]), Some(0), Some(754_000))
+      .await;
+    network.start_playback(None, None, None).await;
+
+    assert_eq!(
+      network.spotify.calls(),
+      [
+        format!(
+          "start_uris_playback spotify:track:{} at 0 from 754000",
+          TRACK
+        ),
+        "shuffle false".to_string(),
+        "resume_playback".to_string(),
+        "shuffle false".to_string(),
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn refused_playback_explains_premium_instead_of_erroring() {
+    let client = MockSpotify {
+      fail_with: Some("403 Forbidden: PREMIUM_REQUIRED".to_string()),
+      ..Default::default()
+    };
+    let mut network = Network::mock(App::default(), client);
+
+    network.start_playback(None, None, None).await;
+
+    let app = network.app.lock().await;
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some(PREMIUM_REQUIRED_MESSAGE)
+    );
+    assert_ne!(app.get_current_route().active_block, ActiveBlock::Error);
+  }
+
+  #[tokio::test]
+  async fn other_playback_failures_show_the_error() {
+    let client = MockSpotify {
+      fail_with: Some("500 Internal Server Error".to_string()),
+      ..Default::default()
+    };
+    let mut network = Network::mock(App::default(), client);
+
+    network.start_playback(None, None, None).await;
+
+    let app = network.app.lock().await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Error);
+    assert!(app.api_error.contains("500 Internal Server Error"));
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn transfer_puts_a_restarted_track_back_where_it_was() {
+    let client = MockSpotify::default();
+    // The old device still reports first, then the new one from the top
+    client.respond("GET me/player", Ok(playback_json("speaker", 60_000)));
+    client.respond("GET me/player", Ok(playback_json("kitchen", 0)));
+    let mut network = Network::mock(app_playing_on("speaker", 60_000), client.clone());
+
+    network
+      .transfert_playback_to_device("kitchen".to_string(), false)
+      .await;
+    assert!(network.app.lock().await.current_playback_context.is_none());
+    wait_for_call(&client, "seek_track").await;
+
+    let calls = client.calls();
+    assert_eq!(
+      calls[..3],
+      [
+        "transfer_playback kitchen",
+        "GET me/player",
+        "GET me/player"
+      ]
+    );
+    let seek = &calls[3];
+    assert!(
+      seek.starts_with("seek_track 60") && seek.ends_with(" on kitchen"),
+      "{}",
+      seek
+    );
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn transfer_leaves_a_device_that_kept_the_position_alone() {
+    let client = MockSpotify::default();
+    client.respond("GET me/player", Ok(playback_json("kitchen", 60_200)));
+    let mut network = Network::mock(app_playing_on("speaker", 60_000), client.clone());
+
+    network
+      .transfert_playback_to_device("kitchen".to_string(), false)
+      .await;
+    // Past every poll the transfer makes
+    tokio::time::sleep(Duration::from_secs(3)).await;
+
+    assert_eq!(
+      client.calls(),
+      ["transfer_playback kitchen", "GET me/player"]
+    );
+  }
+
+  #[tokio::test]
+  async fn failed_transfer_keeps_the_current_device() {
+    let client = MockSpotify {
+      fail: true,
+      ..Default::default()
+    };
+    let mut network = Network::mock(app_playing_on("speaker", 60_000), client);
+
+    network
+      .transfert_playback_to_device("kitchen".to_string(), false)
+      .await;
+
+    assert_eq!(network.spotify.calls(), ["transfer_playback kitchen"]);
+    let app = network.app.lock().await;
+    assert!(app.current_playback_context.is_some());
+    assert_eq!(app.api_error, "Token is not valid");
+  }
+}
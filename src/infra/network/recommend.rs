@@ -1,13 +1,37 @@
+use super::library::flush_liked_state_task;
 use super::Network;
-use crate::core::app::{ActiveBlock, RouteId, TrackTableContext};
+use crate::core::app::{ActiveBlock, RecommendationsContext, RouteId, TrackTableContext};
+use crate::core::locale::t;
 use anyhow::anyhow;
 use rspotify::model::{
   enums::Country,
-  idtypes::{ArtistId, TrackId},
+  idtypes::{ArtistId, PlaylistId, TrackId},
   track::FullTrack,
   Market,
 };
 use rspotify::prelude::*;
+use std::collections::HashSet;
+use std::time::Instant;
+
+/// Fewer library tracks than this among the recommendations and the library mode
+/// falls back to plain recommendations
+const MIN_FAMILIAR_TRACKS: usize = 3;
+
+/// How many URIs one library lookup asks about
+const LIBRARY_LOOKUP_CHUNK: usize = 40;
+
+/// Suggestions fetched when enhancing a playlist, enough for a full page
+const ENHANCE_SUGGESTIONS: u32 = 20;
+
+/// Moves the tracks flagged in `familiar` to the front, keeping the order within both groups
+fn familiar_first(tracks: Vec<FullTrack>, familiar: &[bool]) -> Vec<FullTrack> {
+  let (mut front, back): (Vec<_>, Vec<_>) = tracks
+    .into_iter()
+    .enumerate()
+    .partition(|(i, _)| familiar.get(*i).copied().unwrap_or(false));
+  front.extend(back);
+  front.into_iter().map(|(_, track)| track).collect()
+}
 
 pub trait RecommendationNetwork {
   async fn get_recommendations_for_seed(
@@ -22,6 +46,11 @@ pub trait RecommendationNetwork {
     track_id: TrackId<'static>,
     country: Option<Country>,
   );
+  async fn enhance_playlist(
+    &mut self,
+    playlist_id: PlaylistId<'static>,
+    seed_tracks: Vec<TrackId<'static>>,
+  );
 }
 
 impl RecommendationNetwork for Network {
@@ -48,7 +77,6 @@ impl RecommendationNetwork for Network {
       .await
     {
       Ok(recommendations) => {
-        let mut app = self.app.lock().await;
         // Convert SimplifiedTrack to FullTrack (best effort)
         // SimplifiedTrack doesn't have album field which FullTrack needs.
         // This is tricky. Recommendations usually return SimplifiedTracks.
@@ -73,6 +101,19 @@ impl RecommendationNetwork for Network {
           }
         }
 
+        let mut app = self.app.lock().await;
+        if app.recommendations_context == Some(RecommendationsContext::Library) {
+          drop(app);
+          let familiar = self.familiar_tracks(&full_tracks).await;
+          app = self.app.lock().await;
+          if familiar.iter().filter(|is_familiar| **is_familiar).count() >= MIN_FAMILIAR_TRACKS {
+            full_tracks = familiar_first(full_tracks, &familiar);
+          } else {
+            app.recommendations_context = Some(RecommendationsContext::Song);
+            app.set_status_message(t("status.library_recommendations_fallback"), 5);
+          }
+        }
+
         app.track_table.tracks = full_tracks;
 
         // Prepend the seed track if available so user knows context
@@ -100,4 +141,208 @@ impl RecommendationNetwork for Network {
       .get_recommendations_for_seed(None, seed_tracks, first_track, country)
       .await;
   }
+
+  async fn enhance_playlist(
+    &mut self,
+    playlist_id: PlaylistId<'static>,
+    seed_tracks: Vec<TrackId<'static>>,
+  ) {
+    let market = self
+      .app
+      .lock()
+      .await
+      .get_user_country()
+      .map(Market::Country);
+    let suggestions = match self
+      .spotify
+      .recommendations(
+        std::iter::empty(),
+        None::<Vec<ArtistId>>,
+        None::<Vec<&str>>,
+        Some(seed_tracks),
+        market,
+        Some(ENHANCE_SUGGESTIONS),
+      )
+      .await
+    {
+      Ok(recommendations) => {
+        let track_ids: Vec<TrackId> = recommendations
+          .tracks
+          .into_iter()
+          .filter_map(|track| track.id)
+          .collect();
+        self.spotify.tracks(track_ids, market).await
+      }
+      Err(e) => Err(e),
+    };
+
+    let mut app = self.app.lock().await;
+    let app = &mut *app;
+    // Enhance may have been switched off, or onto another playlist, meanwhile
+    let Some(enhance) = app
+      .playlist_enhance
+      .as_mut()
+      .filter(|enhance| enhance.playlist_id == playlist_id)
+    else {
+      return;
+    };
+    match suggestions {
+      Ok(suggestions) if !suggestions.is_empty() => {
+        enhance.suggestions = suggestions;
+        let tracks = std::mem::take(&mut app.track_table.tracks);
+        app.track_table.tracks = enhance.interleave(tracks);
+        app.set_status_message(t("status.enhance_on"), 4);
+      }
+      result => {
+        if let Err(e) = result {
+          log::warn!("playlist suggestions failed: {}", e);
+        }
+        app.playlist_enhance = None;
+        app.set_status_message(t("status.enhance_failed"), 5);
+      }
+    }
+  }
+}
+
+impl Network {
+  /// Whether each track is liked or on a saved album. Liked state comes from the
+  /// shared liked cache, and saved albums the app hasn't seen yet are looked up and
+  /// kept for the table.
+  async fn familiar_tracks(&self, tracks: &[FullTrack]) -> Vec<bool> {
+    let (mut liked, mut saved_albums) = {
+      let app = self.app.lock().await;
+      (
+        app.liked_song_ids_set.clone(),
+        app.saved_album_ids_set.clone(),
+      )
+    };
+
+    let unknown_tracks: Vec<String> = tracks
+      .iter()
+      .filter_map(|track| track.id.as_ref())
+      .filter(|id| !liked.contains(id.id()))
+      .map(|id| id.id().to_string())
+      .collect();
+    let (known, schedule_flush) = self
+      .liked_state
+      .lock()
+      .await
+      .resolve(&unknown_tracks, Instant::now());
+    liked.extend(
+      known
+        .into_iter()
+        .filter(|(_, liked)| *liked)
+        .map(|(id, _)| id),
+    );
+    if schedule_flush {
+      // The order depends on the answers, so don't wait out the coalescing window
+      flush_liked_state_task(
+        self.spotify.clone(),
+        self.app.clone(),
+        self.liked_state.clone(),
+      )
+      .await;
+    }
+    {
+      // Misses queued by someone else's flush count as not liked for now
+      let cache = self.liked_state.lock().await;
+      let now = Instant::now();
+      liked.extend(
+        unknown_tracks
+          .into_iter()
+          .filter(|id| cache.lookup(id, now) == Some(true)),
+      );
+    }
+
+    let album_uris: Vec<String> = tracks
+      .iter()
+      .filter_map(|track| track.album.id.as_ref())
+      .filter(|id| !saved_albums.contains(id.id()))
+      .map(|id| id.uri())
+      .collect::<HashSet<_>>()
+      .into_iter()
+      .collect();
+    let mut newly_saved_albums = Vec::new();
+    for chunk in album_uris.chunks(LIBRARY_LOOKUP_CHUNK) {
+      match self.library_contains_uris(chunk).await {
+        Ok(saved) => newly_saved_albums.extend(
+          chunk
+            .iter()
+            .zip(saved)
+            .filter(|(_, saved)| *saved)
+            .filter_map(|(uri, _)| uri.rsplit(':').next().map(str::to_string)),
+        ),
+        Err(e) => log::warn!("library lookup for recommendations failed: {}", e),
+      }
+    }
+
+    if !newly_saved_albums.is_empty() {
+      let mut app = self.app.lock().await;
+      app
+        .saved_album_ids_set
+        .extend(newly_saved_albums.iter().cloned());
+    }
+    saved_albums.extend(newly_saved_albums);
+
+    tracks
+      .iter()
+      .map(|track| {
+        track.id.as_ref().is_some_and(|id| liked.contains(id.id()))
+          || track
+            .album
+            .id
+            .as_ref()
+            .is_some_and(|id| saved_albums.contains(id.id()))
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn track(name: &str) -> FullTrack {
+    serde_json::from_value(serde_json::json!({
+      "album": {
+        "album_type": "album",
+        "artists": [],
+        "available_markets": [],
+        "external_urls": {},
+        "href": null,
+        "id": null,
+        "images": [],
+        "name": "Album",
+        "release_date": null,
+        "release_date_precision": null
+      },
+      "artists": [],
+      "available_markets": [],
+      "disc_number": 1,
+      "duration_ms": 1000,
+      "explicit": false,
+      "external_ids": {},
+      "external_urls": {},
+      "href": null,
+      "id": null,
+      "is_local": false,
+      "name": name,
+      "popularity": 0,
+      "preview_url": null,
+      "track_number": 1
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn familiar_tracks_move_to_the_front_in_order() {
+    let tracks = ["a", "b", "c", "d", "e"].map(track).to_vec();
+    let familiar = [false, true, false, true, false];
+
+    let names: Vec<String> = familiar_first(tracks, &familiar)
+      .into_iter()
+      .map(|track| track.name)
+      .collect();
+    assert_eq!(names, ["b", "d", "a", "c", "e"]);
+  }
 }
@@ -1,3 +1,4 @@
+use super::client::SpotifyApi;
 use anyhow::anyhow;
 use reqwest::Method;
 use rspotify::clients::BaseClient;
@@ -244,6 +245,24 @@ pub fn is_rate_limited_error(e: &anyhow::Error) -> bool {
   text.contains("429") || text.contains("Too Many Requests") || text.contains("Too many requests")
 }
 
+/// Shown instead of the error screen when Spotify refuses a playback command
+/// because the account isn't Premium
+pub const PREMIUM_REQUIRED_MESSAGE: &str =
+  "Spotify Premium is required for playback controls; browsing and your library still work";
+
+/// Whether a failed playback command was refused because the account isn't Premium.
+///
+/// Spotify says so in the response body (`PREMIUM_REQUIRED`), but errors from
+/// rspotify only carry the status code, so a bare 403 counts too once we know
+/// the account is not Premium.
+pub fn is_premium_required_error(e: &anyhow::Error, account_is_premium: Option<bool>) -> bool {
+  let text = e.to_string().to_lowercase();
+  if text.contains("premium_required") || text.contains("premium required") {
+    return true;
+  }
+  account_is_premium == Some(false) && (text.contains("403") || text.contains("forbidden"))
+}
+
 #[allow(dead_code)]
 pub fn is_transient_network_error(e: &anyhow::Error) -> bool {
   let text = e.to_string().to_lowercase();
@@ -256,11 +275,33 @@ pub fn is_transient_network_error(e: &anyhow::Error) -> bool {
 }
 
 pub async fn spotify_get_typed_compat_for<T: DeserializeOwned>(
-  spotify: &AuthCodePkceSpotify,
+  spotify: &impl SpotifyApi,
   path: &str,
   query: &[(&str, String)],
 ) -> anyhow::Result<T> {
-  let mut value = spotify_api_request_json_for(spotify, Method::GET, path, query, None).await?;
+  let mut value = spotify.request_json(Method::GET, path, query, None).await?;
   normalize_spotify_payload(&mut value);
   Ok(serde_json::from_value(value)?)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn premium_required_is_recognised() {
+    let body = anyhow!(
+      "Spotify API 403 Forbidden failed: {{\"error\":{{\"status\":403,\"message\":\"Player command failed: Premium required\",\"reason\":\"PREMIUM_REQUIRED\"}}}}"
+    );
+    assert!(is_premium_required_error(&body, None));
+
+    // rspotify only reports the status, so it depends on what we know about the account
+    let bare = anyhow!("http error: status code 403 Forbidden");
+    assert!(is_premium_required_error(&bare, Some(false)));
+    assert!(!is_premium_required_error(&bare, Some(true)));
+    assert!(!is_premium_required_error(&bare, None));
+
+    let other = anyhow!("http error: status code 404 Not Found");
+    assert!(!is_premium_required_error(&other, Some(false)));
+  }
+}
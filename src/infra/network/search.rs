@@ -1,3 +1,4 @@
+use super::client::SpotifyApi;
 use super::requests::spotify_get_typed_compat_for;
 use super::{IoEvent, Network};
 use anyhow::anyhow;
@@ -21,53 +22,47 @@ pub trait SearchNetwork {
   async fn get_search_results(&mut self, search_term: String, country: Option<Country>);
 }
 
-impl SearchNetwork for Network {
+impl<C: SpotifyApi> SearchNetwork for Network<C> {
   async fn get_search_results(&mut self, search_term: String, country: Option<Country>) {
     // Don't pass market to search - when market is specified, Spotify doesn't return
     // available_markets field, but rspotify 0.14 models require it for tracks/albums.
     // We'll handle null playlist fields by searching playlists separately without requiring all fields.
     let _market = country.map(Market::Country);
 
-    let search_track = self.spotify.search(
-      &search_term,
-      SearchType::Track,
-      None,
-      None, // include_external
-      Some(self.small_search_limit),
-      Some(0),
-    );
+    // Per-category overrides from the config win over the terminal-sized default
+    let [songs_limit, albums_limit, artists_limit, playlists_limit, podcasts_limit] = {
+      let app = self.app.lock().await;
+      let behavior = &app.user_config.behavior;
+      [
+        behavior.search_limit_songs,
+        behavior.search_limit_albums,
+        behavior.search_limit_artists,
+        behavior.search_limit_playlists,
+        behavior.search_limit_podcasts,
+      ]
+      .map(|limit| limit.unwrap_or(self.small_search_limit))
+    };
 
-    let search_album = self.spotify.search(
-      &search_term,
-      SearchType::Album,
-      None,
-      None, // include_external
-      Some(self.small_search_limit),
-      Some(0),
-    );
+    let search_track = self
+      .spotify
+      .search(&search_term, SearchType::Track, songs_limit);
 
-    let search_playlist = self.spotify.search(
-      &search_term,
-      SearchType::Playlist,
-      None,
-      None, // include_external
-      Some(self.small_search_limit),
-      Some(0),
-    );
+    let search_album = self
+      .spotify
+      .search(&search_term, SearchType::Album, albums_limit);
 
-    let search_show = self.spotify.search(
-      &search_term,
-      SearchType::Show,
-      None,
-      None, // include_external
-      Some(self.small_search_limit),
-      Some(0),
-    );
+    let search_playlist = self
+      .spotify
+      .search(&search_term, SearchType::Playlist, playlists_limit);
+
+    let search_show = self
+      .spotify
+      .search(&search_term, SearchType::Show, podcasts_limit);
 
     let artist_query = vec![
       ("q", search_term.clone()),
       ("type", "artist".to_string()),
-      ("limit", self.small_search_limit.to_string()),
+      ("limit", artists_limit.to_string()),
       ("offset", "0".to_string()),
     ];
 
@@ -152,3 +147,51 @@ impl SearchNetwork for Network {
     app.search_results.shows = show_result;
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::super::client::MockSpotify;
+  use super::*;
+  use crate::core::app::App;
+
+  #[tokio::test]
+  async fn search_runs_every_category_with_its_limit() {
+    let mut app = App::default();
+    app.user_config.behavior.search_limit_songs = Some(10);
+    let mut network = Network::mock(app, MockSpotify::default());
+
+    network.get_search_results("jazz".to_string(), None).await;
+
+    assert_eq!(
+      network.spotify.calls(),
+      [
+        "search \"jazz\" Track 10",
+        "search \"jazz\" Album 4",
+        "search \"jazz\" Show 4",
+        "search \"jazz\" Playlist 4",
+        "GET search q=jazz&type=artist&limit=4&offset=0",
+      ]
+    );
+    let app = network.app.lock().await;
+    assert!(app.search_results.tracks.is_some());
+    assert!(app.search_results.playlists.is_some());
+    // The artist search came back empty-handed, which isn't an error
+    assert!(app.search_results.artists.is_none());
+    assert!(app.api_error.is_empty());
+  }
+
+  #[tokio::test]
+  async fn a_failed_search_shows_the_error() {
+    let client = MockSpotify {
+      fail: true,
+      ..Default::default()
+    };
+    let mut network = Network::mock(App::default(), client);
+
+    network.get_search_results("jazz".to_string(), None).await;
+
+    let app = network.app.lock().await;
+    assert_eq!(app.api_error, "Token is not valid");
+    assert!(app.search_results.tracks.is_none());
+  }
+}
@@ -23,9 +23,11 @@ impl UserNetwork for Network {
       Ok(user) => {
         let mut app = self.app.lock().await;
         app.user = Some(user);
+        app.check_first_load();
       }
       Err(e) => {
         let err = anyhow!(e);
+        self.app.lock().await.abandon_first_load();
         if is_rate_limited_error(&err) {
           let mut app = self.app.lock().await;
           app.status_message = Some(
@@ -3,6 +3,7 @@
 //! Handles authentication, session management, and audio playback with Spotify Connect.
 
 use anyhow::{anyhow, Context, Result};
+use librespot_audio::AudioFile;
 use librespot_connect::{ConnectConfig, LoadRequest, Spirc};
 use librespot_core::{
   authentication::Credentials,
@@ -12,17 +13,20 @@ use librespot_core::{
   spclient::TransferRequest,
   SpotifyUri,
 };
+use librespot_metadata::{audio::AudioFileFormat, Metadata, Track};
 use librespot_oauth::OAuthClientBuilder;
 use librespot_playback::{
   audio_backend,
   config::{AudioFormat, PlayerConfig},
   convert::Converter,
   decoder::AudioPacket,
-  mixer::{softmixer::SoftMixer, Mixer, MixerConfig},
+  mixer::{softmixer::SoftMixer, Mixer, MixerConfig, VolumeGetter},
   player::{Player, PlayerEventChannel},
 };
 use log::info;
+use std::fmt;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio::time::{timeout, Duration};
@@ -42,6 +46,47 @@ impl audio_backend::Sink for NullSink {
   }
 }
 
+/// Why the native player couldn't start, for the failures a user can fix
+#[derive(Debug)]
+pub enum StreamingInitError {
+  /// The audio backend couldn't open an output device
+  NoAudioOutput(String),
+  /// Logging in to Spotify for streaming failed
+  Auth(String),
+}
+
+impl fmt::Display for StreamingInitError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      StreamingInitError::NoAudioOutput(reason) => write!(f, "No audio output device: {}", reason),
+      StreamingInitError::Auth(reason) => write!(f, "Streaming login failed: {}", reason),
+    }
+  }
+}
+
+impl std::error::Error for StreamingInitError {}
+
+/// Open and start a sink once, so a missing output device is reported at startup
+/// rather than as silence on the first track
+fn probe_audio_output(
+  backend: audio_backend::SinkBuilder,
+  device: Option<String>,
+) -> std::result::Result<(), StreamingInitError> {
+  let opened = std::panic::catch_unwind(|| backend(device, AudioFormat::default()));
+  let mut sink = opened.map_err(|panic| {
+    let reason = panic
+      .downcast_ref::<String>()
+      .cloned()
+      .or_else(|| panic.downcast_ref::<&str>().map(|s| s.to_string()))
+      .unwrap_or_else(|| "the audio backend failed to open".to_string());
+    StreamingInitError::NoAudioOutput(reason)
+  })?;
+  sink
+    .start()
+    .and_then(|()| sink.stop())
+    .map_err(|e| StreamingInitError::NoAudioOutput(e.to_string()))
+}
+
 /// OAuth scopes required for streaming (based on spotify-player)
 const STREAMING_SCOPES: [&str; 6] = [
   "streaming",
@@ -72,11 +117,11 @@ fn request_streaming_oauth_credentials() -> Result<Credentials> {
 
   let oauth_client = client_builder
     .build()
-    .map_err(|e| anyhow!("Failed to build OAuth client: {:?}", e))?;
+    .map_err(|e| StreamingInitError::Auth(format!("couldn't build OAuth client: {:?}", e)))?;
 
   let token = oauth_client
     .get_access_token()
-    .map_err(|e| anyhow!("OAuth authentication failed: {:?}", e))?;
+    .map_err(|e| StreamingInitError::Auth(format!("OAuth authentication failed: {:?}", e)))?;
 
   Ok(Credentials::with_access_token(token.access_token))
 }
@@ -120,6 +165,8 @@ pub struct StreamingConfig {
   pub cache_path: Option<PathBuf>,
   /// Initial volume (0-100)
   pub initial_volume: u8,
+  /// Allow software gain past 100%, up to `MAX_BOOSTED_VOLUME`
+  pub volume_boost: bool,
 }
 
 impl Default for StreamingConfig {
@@ -130,6 +177,7 @@ impl Default for StreamingConfig {
       audio_cache: false,
       cache_path: None,
       initial_volume: 100,
+      volume_boost: false,
     }
   }
 }
@@ -161,8 +209,41 @@ pub struct StreamingPlayer {
   config: StreamingConfig,
   #[allow(dead_code)]
   state: Arc<Mutex<PlayerState>>,
+  /// Bumped by every fade so an older, still-running fade stops early
+  fade_generation: AtomicU64,
+  /// Gain on top of a full mixer, as f64 bits; 1.0 unless boosted
+  boost: Arc<AtomicU64>,
+}
+
+/// Highest volume percentage the boost reaches
+pub const MAX_BOOSTED_VOLUME: u8 = 150;
+
+/// The mixer's volume with the boost applied on top. Only a full mixer is boosted,
+/// so turning the volume down from another Connect client drops the boost.
+struct BoostedVolume {
+  soft_volume: Box<dyn VolumeGetter + Send>,
+  boost: Arc<AtomicU64>,
 }
 
+impl VolumeGetter for BoostedVolume {
+  fn attenuation_factor(&self) -> f64 {
+    let factor = self.soft_volume.attenuation_factor();
+    if factor >= 1.0 {
+      factor * f64::from_bits(self.boost.load(Ordering::Relaxed))
+    } else {
+      factor
+    }
+  }
+}
+
+/// Gain for `volume` percent, past what the mixer alone can reach
+fn boost_factor(volume: u8) -> f64 {
+  f64::from(volume.clamp(100, MAX_BOOSTED_VOLUME)) / 100.0
+}
+
+/// Volume changes per fade; enough to sound smooth without flooding the mixer
+const FADE_STEPS: u32 = 10;
+
 #[allow(dead_code)]
 impl StreamingPlayer {
   /// Get a reference to the librespot session (for API calls like rootlist)
@@ -218,13 +299,16 @@ impl StreamingPlayer {
 
     // Set up player configuration
     let player_config = PlayerConfig {
-      bitrate: match config.bitrate {
+      bitrate: match normalized_bitrate(config.bitrate) {
         96 => librespot_playback::config::Bitrate::Bitrate96,
         160 => librespot_playback::config::Bitrate::Bitrate160,
         _ => librespot_playback::config::Bitrate::Bitrate320,
       },
       // Enable periodic position updates for real-time playbar progress
       position_update_interval: Some(std::time::Duration::from_secs(1)),
+      // librespot only applies gain above 1.0 with normalisation on, whose
+      // limiter also keeps boosted peaks in check
+      normalisation: config.volume_boost,
       ..Default::default()
     };
 
@@ -236,6 +320,16 @@ impl StreamingPlayer {
     let volume_u16 = (f64::from(config.initial_volume.min(100)) / 100.0 * 65535.0).round() as u16;
     mixer.set_volume(volume_u16);
 
+    let boost = Arc::new(AtomicU64::new(1.0f64.to_bits()));
+    let volume_getter: Box<dyn VolumeGetter + Send> = if config.volume_boost {
+      Box::new(BoostedVolume {
+        soft_volume: mixer.get_soft_volume(),
+        boost: Arc::clone(&boost),
+      })
+    } else {
+      mixer.get_soft_volume()
+    };
+
     let requested_backend = std::env::var("SPOTATUI_STREAMING_AUDIO_BACKEND").ok();
     let requested_device = std::env::var("SPOTATUI_STREAMING_AUDIO_DEVICE").ok();
 
@@ -251,29 +345,25 @@ impl StreamingPlayer {
             .collect::<Vec<_>>()
             .join(", ")
         ),
-        None => anyhow!("No audio backend available"),
+        None => StreamingInitError::NoAudioOutput("no audio backend available".to_string()).into(),
       })?;
+    probe_audio_output(backend, requested_device.clone())?;
 
     // Create player
-    let player = Player::new(
-      player_config,
-      session.clone(),
-      mixer.get_soft_volume(),
-      move || {
-        let result =
-          std::panic::catch_unwind(|| backend(requested_device.clone(), AudioFormat::default()));
-        match result {
-          Ok(sink) => sink,
-          Err(_) => {
-            eprintln!(
+    let player = Player::new(player_config, session.clone(), volume_getter, move || {
+      let result =
+        std::panic::catch_unwind(|| backend(requested_device.clone(), AudioFormat::default()));
+      match result {
+        Ok(sink) => sink,
+        Err(_) => {
+          eprintln!(
               "Failed to initialize audio output backend; falling back to a null sink (no audio). \
 Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STREAMING_AUDIO_BACKEND to select a backend."
             );
-            Box::new(NullSink)
-          }
+          Box::new(NullSink)
         }
-      },
-    );
+      }
+    });
 
     // Create Connect configuration
     let connect_config = ConnectConfig {
@@ -319,7 +409,9 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
         }
         Ok(Err(e)) => {
           println!("Spirc creation error: {:?}", e);
-          return Err(anyhow!("Failed to create Spirc: {:?}", e));
+          return Err(
+            StreamingInitError::Auth(format!("couldn't connect to Spotify: {:?}", e)).into(),
+          );
         }
         Err(_) if used_cached_credentials && !retried_with_fresh_credentials => {
           println!(
@@ -352,6 +444,8 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
       mixer,
       config,
       state: Arc::new(Mutex::new(PlayerState::default())),
+      fade_generation: AtomicU64::new(0),
+      boost,
     })
   }
 
@@ -360,6 +454,17 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
     &self.config.device_name
   }
 
+  /// Describe the stream format requested from Spotify, e.g. "Ogg Vorbis 320 kbps".
+  ///
+  /// librespot doesn't report which file it actually picked, so this is the
+  /// configured target rather than a measured value.
+  pub fn stream_format(&self) -> String {
+    format!(
+      "Ogg Vorbis {} kbps",
+      normalized_bitrate(self.config.bitrate)
+    )
+  }
+
   /// Check if the session is connected
   pub fn is_connected(&self) -> bool {
     !self.player.is_invalid()
@@ -397,6 +502,41 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
     self.play_uri(&uri).await
   }
 
+  /// Whether downloaded audio is kept on disk, which `cache_track` needs
+  pub fn caches_audio(&self) -> bool {
+    self.config.audio_cache
+  }
+
+  /// Download a track into the audio cache without playing it, so it later plays
+  /// from disk. Returns false if it was already cached.
+  ///
+  /// Best effort: the decryption key still comes from Spotify at play time, and the
+  /// cache is only as persistent as librespot keeps it.
+  pub async fn cache_track(&self, uri: &str) -> Result<bool> {
+    let spotify_uri =
+      SpotifyUri::from_uri(uri).map_err(|e| anyhow!("Invalid Spotify URI '{}': {:?}", uri, e))?;
+    let track = Track::get(&self.session, &spotify_uri)
+      .await
+      .map_err(|e| anyhow!("Failed to fetch track metadata for '{}': {:?}", uri, e))?;
+    let (format, file_id) = cache_formats(self.config.bitrate)
+      .iter()
+      .find_map(|format| track.files.get(format).map(|file_id| (*format, *file_id)))
+      .ok_or_else(|| anyhow!("'{}' isn't available in a playable format", uri))?;
+
+    let mut file = AudioFile::open(&self.session, file_id, format_bytes_per_second(format))
+      .await
+      .map_err(|e| anyhow!("Failed to open audio file for '{}': {:?}", uri, e))?;
+    if file.is_cached() {
+      return Ok(false);
+    }
+    // Reading to the end fetches every chunk, and librespot saves a completed
+    // download to the cache by itself
+    tokio::task::spawn_blocking(move || std::io::copy(&mut file, &mut std::io::sink()))
+      .await?
+      .with_context(|| format!("Failed to download '{}'", uri))?;
+    Ok(true)
+  }
+
   /// Pause playback
   pub fn pause(&self) {
     // Prefer going through Spirc so Connect state stays consistent.
@@ -412,6 +552,39 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
     self.player.play();
   }
 
+  /// Lower the volume to silence over `fade`, pause, then put the volume back
+  /// to `volume` ready for the next play. Does nothing if another fade starts
+  /// before this one finishes.
+  pub async fn pause_with_fade(&self, volume: u8, fade: Duration) {
+    let generation = self.fade_generation.fetch_add(1, Ordering::SeqCst) + 1;
+    if self.ramp_volume(volume, 0, fade, generation).await {
+      self.pause();
+      self.set_volume(volume);
+    }
+  }
+
+  /// Resume at silence and raise the volume to `volume` over `fade`
+  pub async fn play_with_fade(&self, volume: u8, fade: Duration) {
+    let generation = self.fade_generation.fetch_add(1, Ordering::SeqCst) + 1;
+    self.set_volume(0);
+    self.play();
+    self.ramp_volume(0, volume, fade, generation).await;
+  }
+
+  /// Step the volume from `from` to `to`. Returns false if a newer fade took over.
+  async fn ramp_volume(&self, from: u8, to: u8, duration: Duration, generation: u64) -> bool {
+    for step in 1..=FADE_STEPS {
+      tokio::time::sleep(duration / FADE_STEPS).await;
+      if self.fade_generation.load(Ordering::SeqCst) != generation {
+        return false;
+      }
+      let level =
+        i32::from(from) + (i32::from(to) - i32::from(from)) * step as i32 / FADE_STEPS as i32;
+      self.set_volume(level as u8);
+    }
+    true
+  }
+
   /// Stop playback
   pub fn stop(&self) {
     self.player.stop();
@@ -482,16 +655,31 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
     Ok(())
   }
 
-  /// Set the volume (0-100)
+  /// Set the volume (0-100, or up to `MAX_BOOSTED_VOLUME` with the boost on)
   pub fn set_volume(&self, volume: u8) {
+    let boost = if self.config.volume_boost {
+      boost_factor(volume)
+    } else {
+      1.0
+    };
+    self.boost.store(boost.to_bits(), Ordering::Relaxed);
     let volume_u16 = (f64::from(volume.min(100)) / 100.0 * 65535.0).round() as u16;
     self.mixer.set_volume(volume_u16);
   }
 
-  /// Get the current volume (0-100)
+  /// Get the current volume (0-100, more while boosted)
   pub fn get_volume(&self) -> u8 {
     let volume_u16 = self.mixer.volume();
-    ((volume_u16 as f64 / 65535.0) * 100.0).round() as u8
+    let percent = ((volume_u16 as f64 / 65535.0) * 100.0).round() as u8;
+    if percent < 100 {
+      return percent;
+    }
+    (100.0 * f64::from_bits(self.boost.load(Ordering::Relaxed))).round() as u8
+  }
+
+  /// Whether the player was started with the volume boost available
+  pub fn supports_volume_boost(&self) -> bool {
+    self.config.volume_boost
   }
 
   /// Get the current player state
@@ -534,6 +722,59 @@ Set SPOTATUI_STREAMING_AUDIO_DEVICE to select an output device, or SPOTATUI_STRE
 // Re-export PlayerEvent for use in other modules
 pub use librespot_playback::player::PlayerEvent;
 
+/// Bitrates librespot supports; anything else falls back to 320
+fn normalized_bitrate(bitrate: u16) -> u16 {
+  match bitrate {
+    96 | 160 => bitrate,
+    _ => 320,
+  }
+}
+
+/// Audio files in the order librespot's player picks them for `bitrate`, so
+/// `cache_track` downloads the file that will actually be played
+fn cache_formats(bitrate: u16) -> [AudioFileFormat; 7] {
+  match normalized_bitrate(bitrate) {
+    96 => [
+      AudioFileFormat::OGG_VORBIS_96,
+      AudioFileFormat::MP3_96,
+      AudioFileFormat::OGG_VORBIS_160,
+      AudioFileFormat::MP3_160,
+      AudioFileFormat::MP3_256,
+      AudioFileFormat::OGG_VORBIS_320,
+      AudioFileFormat::MP3_320,
+    ],
+    160 => [
+      AudioFileFormat::OGG_VORBIS_160,
+      AudioFileFormat::MP3_160,
+      AudioFileFormat::OGG_VORBIS_96,
+      AudioFileFormat::MP3_96,
+      AudioFileFormat::MP3_256,
+      AudioFileFormat::OGG_VORBIS_320,
+      AudioFileFormat::MP3_320,
+    ],
+    _ => [
+      AudioFileFormat::OGG_VORBIS_320,
+      AudioFileFormat::MP3_320,
+      AudioFileFormat::MP3_256,
+      AudioFileFormat::OGG_VORBIS_160,
+      AudioFileFormat::MP3_160,
+      AudioFileFormat::OGG_VORBIS_96,
+      AudioFileFormat::MP3_96,
+    ],
+  }
+}
+
+/// Rough data rate of `format`, which librespot uses to size its read-ahead
+fn format_bytes_per_second(format: AudioFileFormat) -> usize {
+  let kbps = match format {
+    AudioFileFormat::OGG_VORBIS_96 | AudioFileFormat::MP3_96 => 96,
+    AudioFileFormat::OGG_VORBIS_160 | AudioFileFormat::MP3_160 => 160,
+    AudioFileFormat::MP3_256 => 256,
+    _ => 320,
+  };
+  kbps * 1024 / 8
+}
+
 /// Helper to get the default cache path for streaming
 pub fn get_default_cache_path() -> Option<PathBuf> {
   dirs::home_dir().map(|home| {
@@ -543,3 +784,82 @@ pub fn get_default_cache_path() -> Option<PathBuf> {
       .join("streaming_cache")
   })
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct FixedVolume(f64);
+
+  impl VolumeGetter for FixedVolume {
+    fn attenuation_factor(&self) -> f64 {
+      self.0
+    }
+  }
+
+  fn panicking_sink(_: Option<String>, _: AudioFormat) -> Box<dyn audio_backend::Sink> {
+    panic!("no default output device");
+  }
+
+  struct UnstartableSink;
+
+  impl audio_backend::Sink for UnstartableSink {
+    fn start(&mut self) -> audio_backend::SinkResult<()> {
+      Err(audio_backend::SinkError::ConnectionRefused(
+        "device busy".to_string(),
+      ))
+    }
+
+    fn write(&mut self, _: AudioPacket, _: &mut Converter) -> audio_backend::SinkResult<()> {
+      Ok(())
+    }
+  }
+
+  fn unstartable_sink(_: Option<String>, _: AudioFormat) -> Box<dyn audio_backend::Sink> {
+    Box::new(UnstartableSink)
+  }
+
+  fn null_sink(_: Option<String>, _: AudioFormat) -> Box<dyn audio_backend::Sink> {
+    Box::new(NullSink)
+  }
+
+  #[test]
+  fn probing_reports_a_missing_output_device() {
+    let Err(StreamingInitError::NoAudioOutput(reason)) = probe_audio_output(panicking_sink, None)
+    else {
+      panic!("expected no audio output");
+    };
+    assert_eq!(reason, "no default output device");
+
+    let Err(StreamingInitError::NoAudioOutput(reason)) = probe_audio_output(unstartable_sink, None)
+    else {
+      panic!("expected no audio output");
+    };
+    assert!(reason.contains("device busy"));
+
+    assert!(probe_audio_output(null_sink, None).is_ok());
+  }
+
+  #[test]
+  fn boost_only_applies_on_top_of_a_full_mixer() {
+    let boost = Arc::new(AtomicU64::new(boost_factor(130).to_bits()));
+    let full = BoostedVolume {
+      soft_volume: Box::new(FixedVolume(1.0)),
+      boost: Arc::clone(&boost),
+    };
+    let turned_down = BoostedVolume {
+      soft_volume: Box::new(FixedVolume(0.5)),
+      boost,
+    };
+    assert!((full.attenuation_factor() - 1.3).abs() < 1e-9);
+    assert_eq!(turned_down.attenuation_factor(), 0.5);
+  }
+
+  #[test]
+  fn boost_factor_stays_between_unity_and_the_maximum() {
+    assert_eq!(boost_factor(40), 1.0);
+    assert_eq!(boost_factor(100), 1.0);
+    assert_eq!(boost_factor(150), 1.5);
+    assert_eq!(boost_factor(255), 1.5);
+  }
+}
@@ -0,0 +1,79 @@
+//! Plays Spotify's 30-second preview clips through a local audio sink, so accounts
+//! without Premium (which the Web API refuses to start playback for) can still
+//! listen to something.
+
+use anyhow::{anyhow, Result};
+use std::io::Cursor;
+use std::sync::mpsc;
+use std::thread;
+
+/// Spotify preview clips are always 30 seconds long
+pub const PREVIEW_DURATION_MS: u32 = 30_000;
+
+enum Command {
+  Play { clip: Vec<u8>, volume: f32 },
+  Stop,
+}
+
+/// A tiny player that owns one audio output on a dedicated thread.
+///
+/// The output stream is not `Send`, so everything that touches it lives on that
+/// thread and the rest of the app only ever sends it commands.
+pub struct PreviewPlayer {
+  commands: mpsc::Sender<Command>,
+}
+
+impl PreviewPlayer {
+  /// Opens the default output device, failing if there isn't one
+  pub fn new() -> Result<Self> {
+    let (commands, receiver) = mpsc::channel();
+    let (ready_tx, ready_rx) = mpsc::channel();
+
+    thread::Builder::new()
+      .name("preview-player".to_string())
+      .spawn(move || {
+        let mut stream = match rodio::OutputStreamBuilder::open_default_stream() {
+          Ok(stream) => stream,
+          Err(e) => {
+            let _ = ready_tx.send(Err(anyhow!("no audio output for previews: {}", e)));
+            return;
+          }
+        };
+        stream.log_on_drop(false);
+        let _ = ready_tx.send(Ok(()));
+
+        let mut sink: Option<rodio::Sink> = None;
+        for command in receiver {
+          if let Some(old) = sink.take() {
+            old.stop();
+          }
+          if let Command::Play { clip, volume } = command {
+            match rodio::Decoder::new(Cursor::new(clip)) {
+              Ok(source) => {
+                let new_sink = rodio::Sink::connect_new(stream.mixer());
+                new_sink.set_volume(volume);
+                new_sink.append(source);
+                sink = Some(new_sink);
+              }
+              Err(e) => log::warn!("could not decode preview clip: {}", e),
+            }
+          }
+        }
+      })?;
+
+    ready_rx
+      .recv()
+      .map_err(|_| anyhow!("preview player thread exited"))??;
+    Ok(Self { commands })
+  }
+
+  /// Replaces whatever is playing with `clip` (an MP3 file) at `volume_percent`
+  pub fn play(&self, clip: Vec<u8>, volume_percent: u8) {
+    let volume = f32::from(volume_percent.min(100)) / 100.0;
+    let _ = self.commands.send(Command::Play { clip, volume });
+  }
+
+  pub fn stop(&self) {
+    let _ = self.commands.send(Command::Stop);
+  }
+}
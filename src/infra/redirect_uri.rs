@@ -1,98 +1,267 @@
-use std::{
-  io::prelude::*,
+//! Catches the browser's redirect back from Spotify's authorization page
+//!
+//! Listens on `bind_address` from client.yml at the port of the redirect URI and
+//! waits for the one request carrying the authorization code. Anything else that
+//! reaches it, like a favicon request, gets a 404 and the wait goes on.
+
+use anyhow::{anyhow, Result};
+use reqwest::Url;
+use std::time::Duration;
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
   net::{TcpListener, TcpStream},
+  time,
 };
 
-pub fn redirect_uri_web_server(port: u16) -> Result<String, ()> {
-  let listener = TcpListener::bind(format!("127.0.0.1:{}", port));
-
-  match listener {
-    Ok(listener) => {
-      for stream in listener.incoming() {
-        match stream {
-          Ok(stream) => {
-            if let Some(url) = handle_connection(stream) {
-              return Ok(url);
-            }
-          }
-          Err(e) => {
-            println!("Error: {}", e);
-          }
-        };
-      }
-    }
-    Err(e) => {
-      println!("Error: {}", e);
-    }
-  }
+/// How long to wait for the browser before falling back to pasting the URL
+pub const CALLBACK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// The request line and headers fit well within this, and the body isn't needed
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
 
-  Err(())
+/// Give up on a connection that doesn't send its request within this
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, PartialEq)]
+enum Request {
+  /// The redirect with the code, as the full URL to hand to rspotify
+  Authorized(String),
+  /// The redirect with the error Spotify sent instead of a code
+  Denied(String),
+  NotFound,
+  Malformed,
 }
 
-fn handle_connection(mut stream: TcpStream) -> Option<String> {
-  // The request will be quite large (> 512) so just assign plenty just in case
-  let mut buffer = [0; 1000];
-  let _ = stream.read(&mut buffer).unwrap();
-
-  // convert buffer into string and 'parse' the URL
-  match String::from_utf8(buffer.to_vec()) {
-    Ok(request) => {
-      let split: Vec<&str> = request.split_whitespace().collect();
-
-      if split.len() > 1 {
-        // Extract the path from the HTTP request (e.g., "/callback?code=...&state=...")
-        let path = split[1];
-
-        // Parse the host header to build the full URL
-        let host = request
-          .lines()
-          .find(|line| line.to_lowercase().starts_with("host:"))
-          .and_then(|line| line.split(':').nth(1))
-          .map(|h| h.trim())
-          .unwrap_or("127.0.0.1:8888");
-
-        // Construct the full URL
-        let full_url = format!("http://{}{}", host, path);
-
-        respond_with_success(stream);
-        return Some(full_url);
+/// Waits up to `timeout` for the redirect to `redirect_uri` and returns the URL it
+/// came back on
+pub async fn redirect_uri_web_server(
+  redirect_uri: &str,
+  bind_address: &str,
+  timeout: Duration,
+) -> Result<String> {
+  let redirect = Url::parse(redirect_uri)?;
+  let port = redirect
+    .port_or_known_default()
+    .ok_or_else(|| anyhow!("Redirect URI {} has no port", redirect_uri))?;
+  let listener = TcpListener::bind((bind_address, port))
+    .await
+    .map_err(|e| anyhow!("Couldn't listen on {}:{}: {}", bind_address, port, e))?;
+
+  wait_for_callback(&listener, &redirect, timeout).await
+}
+
+/// Waits up to `timeout` for the redirect to reach `listener`
+async fn wait_for_callback(
+  listener: &TcpListener,
+  redirect: &Url,
+  timeout: Duration,
+) -> Result<String> {
+  time::timeout(timeout, accept_callback(listener, redirect))
+    .await
+    .map_err(|_| {
+      anyhow!(
+        "No authorization callback arrived within {} seconds",
+        timeout.as_secs()
+      )
+    })?
+}
+
+async fn accept_callback(listener: &TcpListener, redirect: &Url) -> Result<String> {
+  loop {
+    let mut stream = match listener.accept().await {
+      Ok((stream, _)) => stream,
+      Err(e) => {
+        log::info!("failed to accept auth callback connection: {}", e);
+        continue;
       }
+    };
+    let request = match time::timeout(READ_TIMEOUT, read_request(&mut stream)).await {
+      Ok(Ok(request)) => request,
+      _ => continue,
+    };
 
-      respond_with_error("Malformed request".to_string(), stream);
-    }
-    Err(e) => {
-      respond_with_error(format!("Invalid UTF-8 sequence: {}", e), stream);
+    match parse_request(&request, redirect) {
+      Request::Authorized(url) => {
+        respond(
+          &mut stream,
+          "200 OK",
+          "Authorized",
+          "You can close this window and return to your terminal.",
+        )
+        .await;
+        return Ok(url);
+      }
+      Request::Denied(error) => {
+        respond(
+          &mut stream,
+          "200 OK",
+          "Authorization failed",
+          &format!(
+            "Spotify answered with \"{}\". Return to your terminal to finish signing in.",
+            error
+          ),
+        )
+        .await;
+        return Err(anyhow!("Spotify denied authorization: {}", error));
+      }
+      Request::NotFound => {
+        respond(
+          &mut stream,
+          "404 Not Found",
+          "Not found",
+          "This isn't the page Spotify redirects to.",
+        )
+        .await
+      }
+      Request::Malformed => {
+        respond(
+          &mut stream,
+          "400 Bad Request",
+          "Bad request",
+          "That request couldn't be read.",
+        )
+        .await
+      }
     }
-  };
+  }
+}
 
-  None
+/// Reads up to the end of the headers
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<String> {
+  let mut request = Vec::new();
+  let mut buffer = [0; 1024];
+  while request.len() < MAX_REQUEST_BYTES && !request.windows(4).any(|w| w == b"\r\n\r\n") {
+    let read = stream.read(&mut buffer).await?;
+    if read == 0 {
+      break;
+    }
+    request.extend_from_slice(&buffer[..read]);
+  }
+  Ok(String::from_utf8_lossy(&request).into_owned())
 }
 
-fn respond_with_success(mut stream: TcpStream) {
-  let contents = include_str!("redirect_uri.html");
+/// Works out what the HTTP `request` is, given the `redirect` URI it should be for.
+/// The URL is rebuilt from the redirect URI rather than the `Host` header, which
+/// needn't match when the browser reaches the server through another address.
+fn parse_request(request: &str, redirect: &Url) -> Request {
+  let mut request_line = request
+    .lines()
+    .next()
+    .unwrap_or_default()
+    .split_whitespace();
+  let target = match (request_line.next(), request_line.next()) {
+    (Some(_method), Some(target)) if target.starts_with('/') => target,
+    _ => return Request::Malformed,
+  };
+  let url = match redirect.join(target) {
+    Ok(url) => url,
+    Err(_) => return Request::Malformed,
+  };
+  if url.path() != redirect.path() {
+    return Request::NotFound;
+  }
 
-  let response = format!(
-    "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
-    contents.len(),
-    contents
-  );
+  let param = |name: &str| {
+    url
+      .query_pairs()
+      .find(|(key, _)| key == name)
+      .map(|(_, value)| value.into_owned())
+  };
+  if param("code").is_some() {
+    Request::Authorized(url.to_string())
+  } else if let Some(error) = param("error") {
+    Request::Denied(error)
+  } else {
+    Request::NotFound
+  }
+}
 
-  stream.write_all(response.as_bytes()).unwrap();
-  stream.flush().unwrap();
-  // Give the browser time to receive the response before closing
-  std::thread::sleep(std::time::Duration::from_millis(100));
+fn escape_html(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
 }
 
-fn respond_with_error(error_message: String, mut stream: TcpStream) {
-  println!("Error: {}", error_message);
-  let body = format!("400 - Bad Request - {}", error_message);
+async fn respond(stream: &mut TcpStream, status: &str, title: &str, message: &str) {
+  let body = include_str!("redirect_uri.html")
+    .replace("{{title}}", &escape_html(title))
+    .replace("{{message}}", &escape_html(message));
   let response = format!(
-    "HTTP/1.1 400 Bad Request\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+    "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+    status,
     body.len(),
     body
   );
 
-  stream.write_all(response.as_bytes()).unwrap();
-  stream.flush().unwrap();
-  std::thread::sleep(std::time::Duration::from_millis(100));
+  if let Err(e) = stream.write_all(response.as_bytes()).await {
+    log::info!("failed to answer auth callback request: {}", e);
+    return;
+  }
+  let _ = stream.shutdown().await;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn redirect() -> Url {
+    Url::parse("http://127.0.0.1:8888/callback").unwrap()
+  }
+
+  #[test]
+  fn only_the_redirect_path_with_a_code_or_error_counts() {
+    let request = "GET /callback?code=abc&state=xyz HTTP/1.1\r\nHost: 172.20.0.1:8888\r\n\r\n";
+    assert_eq!(
+      parse_request(request, &redirect()),
+      Request::Authorized("http://127.0.0.1:8888/callback?code=abc&state=xyz".to_string())
+    );
+    assert_eq!(
+      parse_request(
+        "GET /callback?error=access_denied HTTP/1.1\r\n\r\n",
+        &redirect()
+      ),
+      Request::Denied("access_denied".to_string())
+    );
+    assert_eq!(
+      parse_request("GET /favicon.ico HTTP/1.1\r\n\r\n", &redirect()),
+      Request::NotFound
+    );
+    assert_eq!(
+      parse_request("GET /callback HTTP/1.1\r\n\r\n", &redirect()),
+      Request::NotFound
+    );
+    assert_eq!(parse_request("", &redirect()), Request::Malformed);
+  }
+
+  #[tokio::test]
+  async fn the_callback_url_comes_back_after_other_requests() {
+    // Whatever port is free, so parallel runs don't collide
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let redirect = Url::parse(&format!("http://{}/login", address)).unwrap();
+    let server =
+      tokio::spawn(
+        async move { wait_for_callback(&listener, &redirect, Duration::from_secs(5)).await },
+      );
+
+    let mut response = String::new();
+    for target in ["/favicon.ico", "/login?code=abc&state=xyz"] {
+      let mut stream = TcpStream::connect(address).await.unwrap();
+      stream
+        .write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", target).as_bytes())
+        .await
+        .unwrap();
+      response.clear();
+      stream.read_to_string(&mut response).await.unwrap();
+    }
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("Authorized"));
+    assert_eq!(
+      server.await.unwrap().unwrap(),
+      format!("http://{}/login?code=abc&state=xyz", address)
+    );
+  }
 }
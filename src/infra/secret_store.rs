@@ -0,0 +1,79 @@
+//! Keeps credentials in the OS keyring instead of plaintext files.
+//!
+//! Turned on with `use_keyring: true` in client.yml, for builds with the
+//! `os-keyring` feature. Uses Keychain on macOS, Credential Manager on Windows and
+//! the Secret Service (GNOME Keyring, KWallet) on Linux. When the keyring can't be
+//! reached, as on most headless servers, callers fall back to the files.
+
+/// Keyring service the entries are stored under
+#[cfg_attr(not(feature = "os-keyring"), allow(dead_code))]
+const SERVICE: &str = "spotatui";
+
+/// Account name for the client secret from client.yml
+pub const CLIENT_SECRET: &str = "client_secret";
+
+/// Account name for a cached auth token, keyed like its cache file
+pub fn token_account(cache_file_stem: &str) -> String {
+  format!("token:{}", cache_file_stem)
+}
+
+/// The secret stored for `account`, or `None` when there isn't one or the
+/// keyring is unavailable
+pub fn get(account: &str) -> Option<String> {
+  #[cfg(feature = "os-keyring")]
+  {
+    match keyring::Entry::new(SERVICE, account).and_then(|entry| entry.get_password()) {
+      Ok(secret) => Some(secret),
+      Err(keyring::Error::NoEntry) => None,
+      Err(e) => {
+        log::warn!("OS keyring unavailable, using files instead: {}", e);
+        None
+      }
+    }
+  }
+  #[cfg(not(feature = "os-keyring"))]
+  {
+    unsupported(account);
+    None
+  }
+}
+
+/// Stores `secret` for `account`. Returns false when the keyring is unavailable,
+/// so the caller can keep using the file.
+pub fn set(account: &str, secret: &str) -> bool {
+  #[cfg(feature = "os-keyring")]
+  {
+    match keyring::Entry::new(SERVICE, account).and_then(|entry| entry.set_password(secret)) {
+      Ok(()) => true,
+      Err(e) => {
+        log::warn!("OS keyring unavailable, using files instead: {}", e);
+        false
+      }
+    }
+  }
+  #[cfg(not(feature = "os-keyring"))]
+  {
+    let _ = secret;
+    unsupported(account);
+    false
+  }
+}
+
+/// Removes the secret for `account`, if there is one
+pub fn delete(account: &str) {
+  #[cfg(feature = "os-keyring")]
+  match keyring::Entry::new(SERVICE, account).and_then(|entry| entry.delete_credential()) {
+    Ok(()) | Err(keyring::Error::NoEntry) => {}
+    Err(e) => log::warn!("could not remove {} from the OS keyring: {}", account, e),
+  }
+  #[cfg(not(feature = "os-keyring"))]
+  unsupported(account);
+}
+
+#[cfg(not(feature = "os-keyring"))]
+fn unsupported(account: &str) {
+  log::warn!(
+    "use_keyring is set but this build has no keyring support, keeping {} in files",
+    account
+  );
+}
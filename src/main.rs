@@ -43,11 +43,13 @@ use crate::infra::mpris;
 use crate::infra::network::{IoEvent, Network};
 #[cfg(feature = "streaming")]
 use crate::infra::player;
-use crate::infra::redirect_uri::redirect_uri_web_server;
+use crate::infra::redirect_uri::{redirect_uri_web_server, CALLBACK_TIMEOUT};
+use crate::infra::secret_store;
 use crate::tui::banner::BANNER;
 use crate::tui::event::{self, Key};
 use crate::tui::handlers;
 use crate::tui::ui::{self};
+use crate::tui::window_title::WindowTitle;
 
 use anyhow::{anyhow, Result};
 use backtrace::Backtrace;
@@ -56,9 +58,7 @@ use clap_complete::{generate, Shell};
 use crossterm::{
   cursor::MoveTo,
   event::{DisableMouseCapture, EnableMouseCapture},
-  execute,
-  terminal::SetTitle,
-  ExecutableCommand,
+  execute, ExecutableCommand,
 };
 use log::info;
 use ratatui::backend::Backend;
@@ -130,6 +130,7 @@ struct DiscordPresenceState {
 #[cfg(feature = "mpris")]
 #[derive(Default, PartialEq)]
 struct MprisMetadata {
+  track_id: Option<String>,
   title: String,
   artists: Vec<String>,
   album: String,
@@ -137,7 +138,14 @@ struct MprisMetadata {
   art_url: Option<String>,
 }
 #[cfg(feature = "mpris")]
-type MprisMetadataTuple = (String, Vec<String>, String, u32, Option<String>);
+type MprisMetadataTuple = (
+  Option<String>,
+  String,
+  Vec<String>,
+  String,
+  u32,
+  Option<String>,
+);
 
 #[cfg(feature = "discord-rpc")]
 fn resolve_discord_app_id(user_config: &UserConfig) -> Option<String> {
@@ -233,6 +241,7 @@ fn get_mpris_metadata(app: &App) -> Option<MprisMetadataTuple> {
     let item = context.item.as_ref()?;
     match item {
       PlayableItem::Track(track) => Some((
+        track.id.as_ref().map(|id| id.id().to_string()),
         track.name.clone(),
         vec![create_artist_string(&track.artists)],
         track.album.name.clone(),
@@ -240,6 +249,7 @@ fn get_mpris_metadata(app: &App) -> Option<MprisMetadataTuple> {
         track.album.images.first().map(|image| image.url.clone()),
       )),
       PlayableItem::Episode(episode) => Some((
+        Some(episode.id.id().to_string()),
         episode.name.clone(),
         vec![episode.show.name.clone()],
         String::new(),
@@ -299,8 +309,9 @@ fn update_mpris_metadata(
   last_metadata: &mut Option<MprisMetadata>,
   app: &App,
 ) {
-  if let Some((title, artists, album, duration_ms, art_url)) = get_mpris_metadata(app) {
+  if let Some((track_id, title, artists, album, duration_ms, art_url)) = get_mpris_metadata(app) {
     let new_metadata = MprisMetadata {
+      track_id: track_id.clone(),
       title: title.clone(),
       artists: artists.clone(),
       album: album.clone(),
@@ -310,7 +321,14 @@ fn update_mpris_metadata(
 
     // Only update if metadata changed
     if last_metadata.as_ref() != Some(&new_metadata) {
-      manager.set_metadata(&title, &artists, &album, duration_ms, art_url);
+      manager.set_metadata(
+        track_id.as_deref(),
+        &title,
+        &artists,
+        &album,
+        duration_ms,
+        art_url,
+      );
       *last_metadata = Some(new_metadata);
     }
   } else {
@@ -321,23 +339,46 @@ fn update_mpris_metadata(
   }
 }
 
-// Manual token cache helpers since rspotify's built-in caching isn't working
-async fn save_token_to_file(spotify: &AuthCodePkceSpotify, path: &PathBuf) -> Result<()> {
+// Manual token cache helpers since rspotify's built-in caching isn't working.
+// With `use_keyring` the token goes to the OS keyring, and the file is only
+// written when the keyring can't be reached.
+async fn save_token_to_file(
+  spotify: &AuthCodePkceSpotify,
+  path: &PathBuf,
+  use_keyring: bool,
+) -> Result<()> {
   let token_lock = spotify.token.lock().await.expect("Failed to lock token");
   if let Some(ref token) = *token_lock {
     let token_json = serde_json::to_string_pretty(token)?;
+    if use_keyring && secret_store::set(&token_keyring_account(path), &token_json) {
+      if path.exists() {
+        fs::remove_file(path)?;
+      }
+      info!("token cached in the OS keyring");
+      return Ok(());
+    }
     fs::write(path, token_json)?;
     info!("token cached to {}", path.display());
   }
   Ok(())
 }
 
-async fn load_token_from_file(spotify: &AuthCodePkceSpotify, path: &PathBuf) -> Result<bool> {
-  if !path.exists() {
-    return Ok(false);
-  }
-
-  let token_json = fs::read_to_string(path)?;
+async fn load_token_from_file(
+  spotify: &AuthCodePkceSpotify,
+  path: &PathBuf,
+  use_keyring: bool,
+) -> Result<bool> {
+  let from_keyring = if use_keyring {
+    secret_store::get(&token_keyring_account(path))
+  } else {
+    None
+  };
+  let in_keyring = from_keyring.is_some();
+  let token_json = match from_keyring {
+    Some(token_json) => token_json,
+    None if path.exists() => fs::read_to_string(path)?,
+    None => return Ok(false),
+  };
   let token: Token = serde_json::from_str(&token_json)?;
 
   let mut token_lock = spotify.token.lock().await.expect("Failed to lock token");
@@ -345,9 +386,22 @@ async fn load_token_from_file(spotify: &AuthCodePkceSpotify, path: &PathBuf) ->
   drop(token_lock);
 
   info!("authentication token loaded from cache");
+  // A token cached before the keyring was turned on moves there now
+  if use_keyring && !in_keyring {
+    save_token_to_file(spotify, path, true).await?;
+  }
   Ok(true)
 }
 
+/// Keyring entry for the token that would otherwise be cached at `path`
+fn token_keyring_account(path: &Path) -> String {
+  let stem = path
+    .file_stem()
+    .and_then(|s| s.to_str())
+    .unwrap_or_default();
+  secret_store::token_account(stem)
+}
+
 fn token_cache_path_for_client(base_path: &Path, client_id: &str) -> PathBuf {
   let suffix = &client_id[..8.min(client_id.len())];
   let stem = base_path
@@ -366,15 +420,6 @@ fn redirect_uri_for_client(client_config: &ClientConfig, client_id: &str) -> Str
   }
 }
 
-fn auth_port_from_redirect_uri(redirect_uri: &str) -> u16 {
-  redirect_uri
-    .split(':')
-    .nth(2)
-    .and_then(|v| v.split('/').next())
-    .and_then(|v| v.parse::<u16>().ok())
-    .unwrap_or(8888)
-}
-
 fn build_pkce_spotify_client(
   client_id: &str,
   redirect_uri: String,
@@ -396,9 +441,11 @@ fn build_pkce_spotify_client(
 async fn ensure_auth_token(
   spotify: &mut AuthCodePkceSpotify,
   token_cache_path: &PathBuf,
-  auth_port: u16,
+  redirect_uri: &str,
+  bind_address: &str,
+  use_keyring: bool,
 ) -> Result<()> {
-  let mut needs_auth = match load_token_from_file(spotify, token_cache_path).await {
+  let mut needs_auth = match load_token_from_file(spotify, token_cache_path, use_keyring).await {
     Ok(true) => false,
     Ok(false) => {
       info!("no cached token found, authentication required");
@@ -432,6 +479,9 @@ async fn ensure_auth_token(
             );
           }
         }
+        if use_keyring {
+          secret_store::delete(&token_keyring_account(token_cache_path));
+        }
         needs_auth = true;
       } else {
         return Err(anyhow!(e));
@@ -440,7 +490,10 @@ async fn ensure_auth_token(
   }
 
   if needs_auth {
-    info!("starting spotify authentication flow on port {}", auth_port);
+    info!(
+      "starting spotify authentication flow for {} on {}",
+      redirect_uri, bind_address
+    );
     let auth_url = spotify.get_authorize_url(None)?;
 
     println!("\nAttempting to open this URL in your browser:");
@@ -452,16 +505,16 @@ async fn ensure_auth_token(
     }
 
     println!(
-      "Waiting for authorization callback on http://127.0.0.1:{}...\n",
-      auth_port
+      "Waiting for authorization callback on {} (listening on {})...\n",
+      redirect_uri, bind_address
     );
 
-    match redirect_uri_web_server(auth_port) {
+    match redirect_uri_web_server(redirect_uri, bind_address, CALLBACK_TIMEOUT).await {
       Ok(url) => {
         if let Some(code) = spotify.parse_response_code(&url) {
           info!("authorization code received, requesting access token");
           spotify.request_token(&code).await?;
-          save_token_to_file(spotify, token_cache_path).await?;
+          save_token_to_file(spotify, token_cache_path, use_keyring).await?;
           info!("successfully authenticated with spotify");
         } else {
           return Err(anyhow!(
@@ -469,9 +522,12 @@ async fn ensure_auth_token(
           ));
         }
       }
-      Err(()) => {
-        info!("redirect uri web server failed, using manual authentication");
-        println!("Starting webserver failed. Continuing with manual authentication");
+      Err(e) => {
+        info!(
+          "redirect uri web server failed, using manual authentication: {}",
+          e
+        );
+        println!("{}. Continuing with manual authentication", e);
         println!("Please open this URL in your browser: {}", auth_url);
         println!("Enter the URL you were redirected to: ");
         let mut input = String::new();
@@ -479,7 +535,7 @@ async fn ensure_auth_token(
         if let Some(code) = spotify.parse_response_code(&input) {
           info!("authorization code received from manual input, requesting access token");
           spotify.request_token(&code).await?;
-          save_token_to_file(spotify, token_cache_path).await?;
+          save_token_to_file(spotify, token_cache_path, use_keyring).await?;
           info!("successfully authenticated with spotify");
         } else {
           return Err(anyhow!("Failed to parse authorization code from input URL"));
@@ -673,6 +729,25 @@ of the app. Beware that this comes at a CPU cost!",
         .action(clap::ArgAction::SetTrue)
         .help("Rerun client authentication setup wizard"),
     )
+    .arg(
+      Arg::new("telemetry")
+        .long("telemetry")
+        .value_name("on|off")
+        .value_parser(["on", "off"])
+        .help("Join or leave the anonymous global song counter and save the choice"),
+    )
+    .arg(
+      Arg::new("play-on-start")
+        .long("play-on-start")
+        .value_name("URI")
+        .help("Start playing a Spotify URI or link once the app has connected"),
+    )
+    .arg(
+      Arg::new("stdin-queue")
+        .long("stdin-queue")
+        .action(clap::ArgAction::SetTrue)
+        .help("Read Spotify URIs from stdin, one per line: play the first and queue the rest"),
+    )
     .arg(
       Arg::new("completions")
         .long("completions")
@@ -729,6 +804,14 @@ of the app. Beware that this comes at a CPU cost!",
   }
   user_config.load_config()?;
   info!("user config loaded successfully");
+  crate::core::locale::init(user_config.behavior.language.as_deref());
+
+  if let Some(telemetry) = matches.get_one::<String>("telemetry") {
+    user_config.set_global_song_count(telemetry == "on")?;
+  } else if user_config.song_count_choice_pending {
+    // Nothing is counted until the user answers the prompt in the TUI
+    user_config.behavior.enable_global_song_count = false;
+  }
   let initial_shuffle_enabled = user_config.behavior.shuffle_enabled;
 
   if let Some(tick_rate) = matches
@@ -742,6 +825,25 @@ of the app. Beware that this comes at a CPU cost!",
     }
   }
 
+  // URIs handed over by external pickers are read before anything else touches stdin
+  let mut startup_inputs: Vec<String> = matches
+    .get_one::<String>("play-on-start")
+    .cloned()
+    .into_iter()
+    .collect();
+  if matches.get_flag("stdin-queue") {
+    for line in io::stdin().lines() {
+      startup_inputs.push(line?);
+    }
+  }
+  let (startup_items, mut skipped_startup_uris) =
+    core::startup_queue::parse_startup_items(&startup_inputs);
+  let (startup_events, unqueueable) = core::startup_queue::startup_events(startup_items);
+  skipped_startup_uris.extend(unqueueable);
+  for skipped in &skipped_startup_uris {
+    log::warn!("skipping startup URI {}", skipped);
+  }
+
   let mut client_config = ClientConfig::new();
   client_config.load_config()?;
   info!("client authentication config loaded");
@@ -775,83 +877,6 @@ of the app. Beware that this comes at a CPU cost!",
     }
   }
 
-  // Prompt for global song count opt-in if missing (only for interactive TUI, not CLI)
-  // Keep this after client setup so first-run UX asks for auth mode first.
-  if matches.subcommand_name().is_none() {
-    let config_paths_check = match &user_config.path_to_config {
-      Some(path) => path,
-      None => {
-        user_config.get_or_build_paths()?;
-        user_config.path_to_config.as_ref().unwrap()
-      }
-    };
-
-    let should_prompt = if config_paths_check.config_file_path.exists() {
-      let config_string = fs::read_to_string(&config_paths_check.config_file_path)?;
-      config_string.trim().is_empty() || !config_string.contains("enable_global_song_count")
-    } else {
-      let client_yml_path = config_paths_check
-        .config_file_path
-        .parent()
-        .map(|p| p.join("client.yml"));
-      client_yml_path.is_some_and(|p| p.exists())
-    };
-
-    if should_prompt {
-      println!("\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-      println!("Global Song Counter");
-      println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-      println!("\nspotatui can contribute to a global counter showing total");
-      println!("songs played by all users worldwide.");
-      println!("\nPrivacy: This feature is completely anonymous.");
-      println!("• No personal information is collected");
-      println!("• No song names, artists, or listening history");
-      println!("• Only a simple increment when a new song starts");
-      println!("\nWould you like to participate? (Y/n): ");
-
-      let mut input = String::new();
-      io::stdin().read_line(&mut input)?;
-      let input = input.trim().to_lowercase();
-
-      let enable = input.is_empty() || input == "y" || input == "yes";
-      user_config.behavior.enable_global_song_count = enable;
-
-      let config_yml = if config_paths_check.config_file_path.exists() {
-        fs::read_to_string(&config_paths_check.config_file_path).unwrap_or_default()
-      } else {
-        String::new()
-      };
-
-      let mut config: serde_yaml::Value = if config_yml.trim().is_empty() {
-        serde_yaml::Value::Mapping(serde_yaml::Mapping::new())
-      } else {
-        serde_yaml::from_str(&config_yml)?
-      };
-
-      if let serde_yaml::Value::Mapping(ref mut map) = config {
-        let behavior = map
-          .entry(serde_yaml::Value::String("behavior".to_string()))
-          .or_insert(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
-
-        if let serde_yaml::Value::Mapping(ref mut behavior_map) = behavior {
-          behavior_map.insert(
-            serde_yaml::Value::String("enable_global_song_count".to_string()),
-            serde_yaml::Value::Bool(enable),
-          );
-        }
-      }
-
-      let updated_config = serde_yaml::to_string(&config)?;
-      fs::write(&config_paths_check.config_file_path, updated_config)?;
-
-      if enable {
-        println!("Thank you for participating!\n");
-      } else {
-        println!("Opted out. You can change this anytime in ~/.config/spotatui/config.yml\n");
-      }
-    }
-  }
-
   let config_paths = client_config.get_or_build_paths()?;
   let mut client_candidates = vec![client_config.client_id.clone()];
   if let Some(fallback_id) = client_config.fallback_client_id.clone() {
@@ -867,11 +892,17 @@ of the app. Beware that this comes at a CPU cost!",
   for (index, client_id) in client_candidates.iter().enumerate() {
     let token_cache_path = token_cache_path_for_client(&config_paths.token_cache_path, client_id);
     let redirect_uri = redirect_uri_for_client(&client_config, client_id);
-    let auth_port = auth_port_from_redirect_uri(&redirect_uri);
     let mut candidate =
       build_pkce_spotify_client(client_id, redirect_uri.clone(), token_cache_path.clone());
 
-    let auth_result = ensure_auth_token(&mut candidate, &token_cache_path, auth_port).await;
+    let auth_result = ensure_auth_token(
+      &mut candidate,
+      &token_cache_path,
+      &redirect_uri,
+      &client_config.bind_address,
+      client_config.use_keyring,
+    )
+    .await;
 
     match auth_result {
       Ok(()) => {
@@ -946,6 +977,15 @@ of the app. Beware that this comes at a CPU cost!",
   // Launch the UI (async)
   } else {
     info!("launching interactive terminal ui");
+    if cfg!(feature = "telemetry") && user_config.song_count_choice_pending {
+      app.lock().await.ask_song_count_choice();
+    }
+    if !skipped_startup_uris.is_empty() {
+      app
+        .lock()
+        .await
+        .set_status_message(format!("Skipped {}", skipped_startup_uris.join(", ")), 10);
+    }
     #[cfg(feature = "streaming")]
     let (streaming_supported_for_account, streaming_startup_status_message) =
       if client_config.enable_streaming {
@@ -970,6 +1010,7 @@ of the app. Beware that this comes at a CPU cost!",
         audio_cache: client_config.streaming_audio_cache,
         cache_path: player::get_default_cache_path(),
         initial_volume: user_config.behavior.volume_percent,
+        volume_boost: user_config.behavior.allow_volume_boost,
       };
 
       let client_id = client_config.client_id.clone();
@@ -1008,6 +1049,19 @@ of the app. Beware that this comes at a CPU cost!",
             "failed to initialize streaming: {} - falling back to web api",
             e
           );
+          match e.downcast_ref::<player::StreamingInitError>() {
+            Some(player::StreamingInitError::NoAudioOutput(_)) => {
+              app.lock().await.streaming_warning =
+                Some(crate::core::locale::t("status.no_audio_output"));
+            }
+            Some(player::StreamingInitError::Auth(_)) => {
+              app
+                .lock()
+                .await
+                .set_status_message(crate::core::locale::t("status.streaming_login_failed"), 12);
+            }
+            None => {}
+          }
           None
         }
         Some(Err(e)) => {
@@ -1039,6 +1093,7 @@ of the app. Beware that this comes at a CPU cost!",
     {
       let mut app_mut = app.lock().await;
       app_mut.streaming_player = streaming_player.clone();
+      app_mut.native_stream_format = streaming_player.as_ref().map(|p| p.stream_format());
     }
 
     // Clone streaming player and device name for use in network spawn
@@ -1075,7 +1130,7 @@ of the app. Beware that this comes at a CPU cost!",
     // This registers spotatui as a controllable media player on the session bus
     #[cfg(all(feature = "mpris", target_os = "linux"))]
     let mpris_manager: Option<Arc<mpris::MprisManager>> = if streaming_player.is_some() {
-      match mpris::MprisManager::new() {
+      match mpris::MprisManager::new(Arc::clone(&shared_position)) {
         Ok(mgr) => {
           info!("mpris d-bus interface registered - media keys and playerctl enabled");
           Some(Arc::new(mgr))
@@ -1198,25 +1253,21 @@ of the app. Beware that this comes at a CPU cost!",
       let app_for_events = Arc::clone(&app);
       info!("spawning native player event handler");
       #[cfg(all(feature = "mpris", target_os = "linux"))]
+      let hooks = mpris_for_events;
+      #[cfg(all(feature = "macos-media", target_os = "macos"))]
+      let hooks = macos_media_for_events;
+      #[cfg(not(any(
+        all(feature = "mpris", target_os = "linux"),
+        all(feature = "macos-media", target_os = "macos")
+      )))]
+      let hooks = ();
       tokio::spawn(async move {
         handle_player_events(
           event_rx,
           app_for_events,
           shared_position_for_events,
           shared_is_playing_for_events,
-          mpris_for_events,
-        )
-        .await;
-      });
-      #[cfg(not(all(feature = "mpris", target_os = "linux")))]
-      tokio::spawn(async move {
-        handle_player_events(
-          event_rx,
-          app_for_events,
-          shared_position_for_events,
-          shared_is_playing_for_events,
-          #[cfg(all(feature = "macos-media", target_os = "macos"))]
-          macos_media_for_events,
+          hooks,
         )
         .await;
       });
@@ -1224,7 +1275,7 @@ of the app. Beware that this comes at a CPU cost!",
 
     let cloned_app = Arc::clone(&app);
     info!("spawning spotify network event handler");
-    tokio::spawn(async move {
+    let network_handle = tokio::spawn(async move {
       #[cfg(feature = "streaming")]
       let mut network = Network::new(spotify, client_config, &app, streaming_player_clone);
       #[cfg(not(feature = "streaming"))]
@@ -1246,6 +1297,11 @@ of the app. Beware that this comes at a CPU cost!",
 
         let mut status_message = None;
         let startup_event = match saved_device_id {
+          // Grab playback without forgetting the saved device, so turning the option
+          // off goes back to it
+          Some(_) if network.client_config.streaming_prefer_native => Some(
+            IoEvent::AutoSelectStreamingDevice(device_name.clone(), false),
+          ),
           Some(saved_device_id) => {
             if let Some(devices_vec) = devices_snapshot.as_ref() {
               if devices_vec
@@ -1294,6 +1350,11 @@ of the app. Beware that this comes at a CPU cost!",
         .handle_network_event(IoEvent::Shuffle(initial_shuffle_enabled))
         .await;
 
+      // Device selection is done by now, so launch URIs play on the right device
+      for event in startup_events {
+        network.handle_network_event(event).await;
+      }
+
       start_tokio(sync_io_rx, &mut network).await;
     });
     // The UI must run in the "main" thread
@@ -1321,6 +1382,16 @@ of the app. Beware that this comes at a CPU cost!",
     .await?;
     #[cfg(not(feature = "streaming"))]
     start_ui(user_config, &cloned_app, None, None, discord_rpc_manager).await?;
+
+    // Closing the UI drops the event sender, so the network task ends once it has
+    // sent anything still queued, like a pause or playback handoff on quit
+    if cloned_app.lock().await.wait_on_exit {
+      let _ = tokio::time::timeout(std::time::Duration::from_secs(5), network_handle).await;
+    }
+    let exit_message = cloned_app.lock().await.exit_message.take();
+    if let Some(message) = exit_message {
+      println!("{}", message);
+    }
   }
 
   Ok(())
@@ -1332,293 +1403,273 @@ async fn start_tokio(io_rx: std::sync::mpsc::Receiver<IoEvent>, network: &mut Ne
   }
 }
 
-/// Handle player events from librespot and update app state directly
-/// This bypasses the Spotify Web API for instant UI updates
-#[cfg(all(feature = "streaming", feature = "mpris", target_os = "linux"))]
-async fn handle_player_events(
-  mut event_rx: librespot_playback::player::PlayerEventChannel,
-  app: Arc<Mutex<App>>,
-  shared_position: Arc<AtomicU64>,
-  shared_is_playing: Arc<std::sync::atomic::AtomicBool>,
-  mpris_manager: Option<Arc<mpris::MprisManager>>,
-) {
-  use chrono::TimeDelta;
-  use player::PlayerEvent;
-  use std::sync::atomic::Ordering;
+/// Media session integrations (MPRIS, macOS Now Playing) that mirror native player events.
+/// Every hook is a no-op by default, so builds without one just pass `()`.
+#[cfg(feature = "streaming")]
+trait PlayerEventHooks {
+  fn playback_status(&self, _is_playing: bool) {}
+  fn metadata(
+    &self,
+    _track_id: &str,
+    _title: &str,
+    _artists: &[String],
+    _album: &str,
+    _duration_ms: u32,
+  ) {
+  }
+  fn stopped(&self) {}
+  fn volume(&self, _volume_percent: u8) {}
+  fn position(&self, _position_ms: u64) {}
+  fn seeked(&self, _position_ms: u64) {}
+}
 
-  while let Some(event) = event_rx.recv().await {
-    // Use try_lock() to avoid blocking when the UI thread is busy
-    // If we can't get the lock, skip this update - the UI will catch up on the next tick
-    match event {
-      PlayerEvent::Playing {
-        play_request_id: _,
-        track_id,
-        position_ms,
-      } => {
-        // Always update atomic - this never fails (lock-free for MPRIS)
-        shared_is_playing.store(true, Ordering::Relaxed);
+#[cfg(feature = "streaming")]
+impl PlayerEventHooks for () {}
 
-        // Update MPRIS playback status
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_playback_status(true);
-        }
+#[cfg(feature = "streaming")]
+impl<T: PlayerEventHooks> PlayerEventHooks for Option<Arc<T>> {
+  fn playback_status(&self, is_playing: bool) {
+    if let Some(hooks) = self {
+      hooks.playback_status(is_playing);
+    }
+  }
 
-        // Always update native_is_playing - this is critical for UI state
-        // Use blocking lock since this is a brief operation
-        {
-          let mut app_lock = app.lock().await;
-          app_lock.native_is_playing = Some(true);
-        }
+  fn metadata(
+    &self,
+    track_id: &str,
+    title: &str,
+    artists: &[String],
+    album: &str,
+    duration_ms: u32,
+  ) {
+    if let Some(hooks) = self {
+      hooks.metadata(track_id, title, artists, album, duration_ms);
+    }
+  }
 
-        // Try to get lock for other updates - skip if busy
-        if let Ok(mut app) = app.try_lock() {
-          app.song_progress_ms = position_ms as u128;
+  fn stopped(&self) {
+    if let Some(hooks) = self {
+      hooks.stopped();
+    }
+  }
 
-          // Update is_playing state
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = true;
-            ctx.progress = Some(TimeDelta::milliseconds(position_ms as i64));
-          }
+  fn volume(&self, volume_percent: u8) {
+    if let Some(hooks) = self {
+      hooks.volume(volume_percent);
+    }
+  }
 
-          // Reset the poll timer so we don't immediately overwrite with stale API data
-          app.instant_since_last_current_playback_poll = std::time::Instant::now();
+  fn position(&self, position_ms: u64) {
+    if let Some(hooks) = self {
+      hooks.position(position_ms);
+    }
+  }
 
-          // Check if track changed and dispatch fetch
-          let track_id_str = track_id.to_string();
-          if app.last_track_id.as_ref() != Some(&track_id_str) {
-            app.last_track_id = Some(track_id_str);
-            app.dispatch(IoEvent::GetCurrentPlayback);
-          }
-        }
-      }
-      PlayerEvent::Paused {
-        play_request_id: _,
-        track_id: _,
-        position_ms,
-      } => {
-        // Always update atomic - this never fails (lock-free for MPRIS)
-        shared_is_playing.store(false, Ordering::Relaxed);
+  fn seeked(&self, position_ms: u64) {
+    if let Some(hooks) = self {
+      hooks.seeked(position_ms);
+    }
+  }
+}
 
-        // Update MPRIS playback status
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_playback_status(false);
-        }
+// MPRIS clients pick up seeks from the regular position updates, so `seeked` stays a no-op
+#[cfg(all(feature = "streaming", feature = "mpris", target_os = "linux"))]
+impl PlayerEventHooks for mpris::MprisManager {
+  fn playback_status(&self, is_playing: bool) {
+    self.set_playback_status(is_playing);
+  }
 
-        // Always update native_is_playing - this is critical for UI state
-        // Use blocking lock since this is a brief operation
-        {
-          let mut app_lock = app.lock().await;
-          app_lock.native_is_playing = Some(false);
-        }
+  fn metadata(
+    &self,
+    track_id: &str,
+    title: &str,
+    artists: &[String],
+    album: &str,
+    duration_ms: u32,
+  ) {
+    self.set_metadata(Some(track_id), title, artists, album, duration_ms, None);
+  }
 
-        // Try to get lock for other updates - skip if busy
-        if let Ok(mut app) = app.try_lock() {
-          app.song_progress_ms = position_ms as u128;
+  fn stopped(&self) {
+    self.set_stopped();
+  }
 
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = false;
-            ctx.progress = Some(TimeDelta::milliseconds(position_ms as i64));
-          }
-          app.instant_since_last_current_playback_poll = std::time::Instant::now();
-        }
-      }
-      PlayerEvent::Seeked {
-        play_request_id: _,
-        track_id: _,
-        position_ms,
-      } => {
-        if let Ok(mut app) = app.try_lock() {
-          app.song_progress_ms = position_ms as u128;
-          app.seek_ms = None;
+  fn volume(&self, volume_percent: u8) {
+    self.set_volume(volume_percent);
+  }
 
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.progress = Some(TimeDelta::milliseconds(position_ms as i64));
-          }
-          app.instant_since_last_current_playback_poll = std::time::Instant::now();
-        }
-      }
-      PlayerEvent::TrackChanged { audio_item } => {
-        // Track metadata changed - extract immediate info for instant UI updates
-        use librespot_metadata::audio::UniqueFields;
-
-        // Extract artist names and album from UniqueFields
-        let (artists, album) = match &audio_item.unique_fields {
-          UniqueFields::Track { artists, album, .. } => {
-            // Extract artist names from ArtistsWithRole
-            let artist_names: Vec<String> = artists.0.iter().map(|a| a.name.clone()).collect();
-            (artist_names, album.clone())
-          }
-          UniqueFields::Episode { show_name, .. } => (vec![show_name.clone()], String::new()),
-          UniqueFields::Local { artists, album, .. } => {
-            let artist_vec = artists
-              .as_ref()
-              .map(|a| vec![a.clone()])
-              .unwrap_or_default();
-            let album_str = album.clone().unwrap_or_default();
-            (artist_vec, album_str)
-          }
-        };
+  fn position(&self, position_ms: u64) {
+    self.set_position(position_ms);
+  }
+}
 
-        // Update MPRIS metadata
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_metadata(
-            &audio_item.name,
-            &artists,
-            &album,
-            audio_item.duration_ms,
-            None,
-          );
-        }
+#[cfg(all(feature = "streaming", feature = "macos-media", target_os = "macos"))]
+impl PlayerEventHooks for macos_media::MacMediaManager {
+  fn playback_status(&self, is_playing: bool) {
+    self.set_playback_status(is_playing);
+  }
 
-        // Track metadata updates are critical for playbar correctness; do not drop
-        // them when the UI thread is briefly busy.
-        let mut app = app.lock().await;
-        // Store immediate track info for instant UI display
-        app.native_track_info = Some(app::NativeTrackInfo {
-          name: audio_item.name.clone(),
-          artists_display: artists.join(", "),
-          album: album.clone(),
-          duration_ms: audio_item.duration_ms,
-        });
+  fn metadata(
+    &self,
+    _track_id: &str,
+    title: &str,
+    artists: &[String],
+    album: &str,
+    duration_ms: u32,
+  ) {
+    self.set_metadata(title, artists, album, duration_ms);
+  }
 
-        app.song_progress_ms = 0;
-        app.last_track_id = Some(audio_item.track_id.to_string());
-        // Reset the poll timer so we don't immediately overwrite with stale API data
-        app.instant_since_last_current_playback_poll = std::time::Instant::now();
-        app.dispatch(IoEvent::GetCurrentPlayback);
-      }
-      PlayerEvent::Stopped { .. } => {
-        // Update MPRIS status
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_stopped();
-        }
+  fn stopped(&self) {
+    self.set_stopped();
+  }
 
-        // When a track stops, refresh state.
-        if let Ok(mut app) = app.try_lock() {
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = false;
-          }
-          app.song_progress_ms = 0;
-          // Clear the last track ID so the next Playing event will trigger a full refresh
-          app.last_track_id = None;
-        }
+  fn volume(&self, volume_percent: u8) {
+    self.set_volume(volume_percent);
+  }
 
-        // Small delay to let Spotify's backend transition
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+  fn position(&self, position_ms: u64) {
+    self.set_position(position_ms);
+  }
 
-        // Try to dispatch - skip if busy
-        if let Ok(mut app) = app.try_lock() {
-          app.dispatch(IoEvent::GetCurrentPlayback);
-        }
-      }
-      PlayerEvent::EndOfTrack { track_id, .. } => {
-        // Update MPRIS status
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_stopped();
-        }
+  fn seeked(&self, position_ms: u64) {
+    self.set_position(position_ms);
+  }
+}
 
-        if let Ok(mut app) = app.try_lock() {
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = false;
-          }
-          app.song_progress_ms = 0;
-          app.last_track_id = None;
-        }
+/// Apply a play/pause transition at `position_ms` to the playback context
+#[cfg(feature = "streaming")]
+fn apply_native_play_state(app: &mut App, is_playing: bool, position_ms: u32) {
+  app.set_song_progress(position_ms as u128);
+  if let Some(ref mut ctx) = app.current_playback_context {
+    ctx.is_playing = is_playing;
+    ctx.progress = Some(chrono::TimeDelta::milliseconds(position_ms as i64));
+  }
+  // Reset the poll timer so we don't immediately overwrite with stale API data
+  app.instant_since_last_current_playback_poll = std::time::Instant::now();
+}
 
-        // Ensure we don't land on the next item paused after the track transition.
-        // (librespot Spirc will advance; we may need to resume playback.)
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-        if let Ok(mut app) = app.try_lock() {
-          app.dispatch(IoEvent::EnsurePlaybackContinues(track_id.to_string()));
-        }
-      }
-      PlayerEvent::VolumeChanged { volume } => {
-        // Update MPRIS volume
-        let volume_percent = ((volume as f64 / 65535.0) * 100.0).round() as u8;
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_volume(volume_percent);
-        }
+/// Apply a Playing event, fetching full playback details when the track changed
+#[cfg(feature = "streaming")]
+fn apply_native_playing(app: &mut App, track_id: String, position_ms: u32) {
+  apply_native_play_state(app, true, position_ms);
+  if !track_id.starts_with("spotify:episode:") {
+    app.count_song_play(&track_id, true);
+  }
+  if app.last_track_id.as_ref() != Some(&track_id) {
+    app.last_track_id = Some(track_id);
+    app.dispatch(IoEvent::GetCurrentPlayback);
+  }
+}
 
-        if let Ok(mut app) = app.try_lock() {
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.device.volume_percent = Some(volume_percent as u32);
-          }
-          // Persist the latest volume so it is restored on next launch
-          app.user_config.behavior.volume_percent = volume_percent.min(100);
-          let _ = app.user_config.save_config();
-        }
-      }
-      PlayerEvent::PositionChanged {
-        play_request_id: _,
-        track_id: _,
-        position_ms,
-      } => {
-        // Use atomic store for lock-free position updates
-        // This never blocks or fails, ensuring every position update is captured
-        shared_position.store(position_ms as u64, Ordering::Relaxed);
+#[cfg(feature = "streaming")]
+fn apply_native_seek(app: &mut App, position_ms: u32) {
+  app.set_song_progress(position_ms as u128);
+  app.seek_ms = None;
+  if let Some(ref mut ctx) = app.current_playback_context {
+    ctx.progress = Some(chrono::TimeDelta::milliseconds(position_ms as i64));
+  }
+  app.instant_since_last_current_playback_poll = std::time::Instant::now();
+}
 
-        // Update MPRIS position so external clients (playerctl, desktop widgets) stay in sync
-        if let Some(ref mpris) = mpris_manager {
-          mpris.set_position(position_ms as u64);
-        }
-      }
-      _ => {
-        // Ignore other events
-      }
+/// Apply a Stopped or EndOfTrack event
+#[cfg(feature = "streaming")]
+fn apply_native_stopped(app: &mut App) {
+  if let Some(ref mut ctx) = app.current_playback_context {
+    ctx.is_playing = false;
+  }
+  app.set_song_progress(0);
+  // Clear the last track ID so the next Playing event will trigger a full refresh
+  app.last_track_id = None;
+}
+
+/// Store the new track's metadata for instant playbar display
+#[cfg(feature = "streaming")]
+fn apply_native_track_changed(app: &mut App, track_info: app::NativeTrackInfo, track_id: String) {
+  app.native_track_info = Some(track_info);
+  app.set_song_progress(0);
+  app.last_track_id = Some(track_id);
+  app.instant_since_last_current_playback_poll = std::time::Instant::now();
+  app.dispatch(IoEvent::GetCurrentPlayback);
+}
+
+/// Convert librespot's 0..=65535 volume to a percentage
+#[cfg(feature = "streaming")]
+fn native_volume_percent(volume: u16) -> u8 {
+  ((volume as f64 / 65535.0) * 100.0).round() as u8
+}
+
+#[cfg(feature = "streaming")]
+fn apply_native_volume(app: &mut App, volume_percent: u8) {
+  if let Some(ref mut ctx) = app.current_playback_context {
+    ctx.device.volume_percent = Some(volume_percent as u32);
+  }
+  // Persist the latest volume so it is restored on next launch
+  app.user_config.behavior.volume_percent = volume_percent.min(100);
+  let _ = app.user_config.save_config();
+}
+
+/// Artist names and album title for whatever kind of item librespot is playing
+#[cfg(feature = "streaming")]
+fn native_artists_and_album(
+  unique_fields: &librespot_metadata::audio::UniqueFields,
+) -> (Vec<String>, String) {
+  use librespot_metadata::audio::UniqueFields;
+
+  match unique_fields {
+    UniqueFields::Track { artists, album, .. } => {
+      let artist_names: Vec<String> = artists.0.iter().map(|a| a.name.clone()).collect();
+      (artist_names, album.clone())
+    }
+    UniqueFields::Episode { show_name, .. } => (vec![show_name.clone()], String::new()),
+    UniqueFields::Local { artists, album, .. } => {
+      let artist_vec = artists
+        .as_ref()
+        .map(|a| vec![a.clone()])
+        .unwrap_or_default();
+      let album_str = album.clone().unwrap_or_default();
+      (artist_vec, album_str)
     }
   }
 }
 
 /// Handle player events from librespot and update app state directly
 /// This bypasses the Spotify Web API for instant UI updates
-#[cfg(all(
-  feature = "streaming",
-  not(all(feature = "mpris", target_os = "linux"))
-))]
+#[cfg(feature = "streaming")]
 async fn handle_player_events(
   mut event_rx: librespot_playback::player::PlayerEventChannel,
   app: Arc<Mutex<App>>,
   shared_position: Arc<AtomicU64>,
   shared_is_playing: Arc<std::sync::atomic::AtomicBool>,
-  #[cfg(all(feature = "macos-media", target_os = "macos"))] macos_media_manager: Option<
-    Arc<macos_media::MacMediaManager>,
-  >,
+  hooks: impl PlayerEventHooks,
 ) {
-  use chrono::TimeDelta;
   use player::PlayerEvent;
   use std::sync::atomic::Ordering;
 
   while let Some(event) = event_rx.recv().await {
+    // Use try_lock() to avoid blocking when the UI thread is busy
+    // If we can't get the lock, skip this update - the UI will catch up on the next tick
     match event {
       PlayerEvent::Playing {
         play_request_id: _,
         track_id,
         position_ms,
       } => {
+        // Always update atomic - this never fails (lock-free for MPRIS)
         shared_is_playing.store(true, Ordering::Relaxed);
+        shared_position.store(position_ms as u64, Ordering::Relaxed);
+        hooks.playback_status(true);
 
-        // Update macOS Now Playing playback status
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_playback_status(true);
-        }
-
+        // Always update native_is_playing - this is critical for UI state
+        // Use blocking lock since this is a brief operation
         {
           let mut app_lock = app.lock().await;
           app_lock.native_is_playing = Some(true);
         }
+
+        // Try to get lock for other updates - skip if busy
         if let Ok(mut app) = app.try_lock() {
-          app.song_progress_ms = position_ms as u128;
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = true;
-            ctx.progress = Some(TimeDelta::milliseconds(position_ms as i64));
-          }
-          app.instant_since_last_current_playback_poll = std::time::Instant::now();
-          let track_id_str = track_id.to_string();
-          if app.last_track_id.as_ref() != Some(&track_id_str) {
-            app.last_track_id = Some(track_id_str);
-            app.dispatch(IoEvent::GetCurrentPlayback);
-          }
+          apply_native_playing(&mut app, track_id.to_string(), position_ms);
         }
       }
       PlayerEvent::Paused {
@@ -1627,24 +1678,16 @@ async fn handle_player_events(
         position_ms,
       } => {
         shared_is_playing.store(false, Ordering::Relaxed);
-
-        // Update macOS Now Playing playback status
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_playback_status(false);
-        }
+        shared_position.store(position_ms as u64, Ordering::Relaxed);
+        hooks.playback_status(false);
 
         {
           let mut app_lock = app.lock().await;
           app_lock.native_is_playing = Some(false);
         }
+
         if let Ok(mut app) = app.try_lock() {
-          app.song_progress_ms = position_ms as u128;
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = false;
-            ctx.progress = Some(TimeDelta::milliseconds(position_ms as i64));
-          }
-          app.instant_since_last_current_playback_poll = std::time::Instant::now();
+          apply_native_play_state(&mut app, false, position_ms);
         }
       }
       PlayerEvent::Seeked {
@@ -1652,112 +1695,83 @@ async fn handle_player_events(
         track_id: _,
         position_ms,
       } => {
-        // Update macOS Now Playing position on seek
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_position(position_ms as u64);
-        }
+        shared_position.store(position_ms as u64, Ordering::Relaxed);
+        hooks.seeked(position_ms as u64);
 
         if let Ok(mut app) = app.try_lock() {
-          app.song_progress_ms = position_ms as u128;
-          app.seek_ms = None;
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.progress = Some(TimeDelta::milliseconds(position_ms as i64));
-          }
-          app.instant_since_last_current_playback_poll = std::time::Instant::now();
+          apply_native_seek(&mut app, position_ms);
         }
       }
       PlayerEvent::TrackChanged { audio_item } => {
-        use librespot_metadata::audio::UniqueFields;
-
-        let (artists, album) = match &audio_item.unique_fields {
-          UniqueFields::Track { artists, album, .. } => {
-            let artist_names: Vec<String> = artists.0.iter().map(|a| a.name.clone()).collect();
-            (artist_names, album.clone())
-          }
-          UniqueFields::Episode { show_name, .. } => (vec![show_name.clone()], String::new()),
-          UniqueFields::Local { artists, album, .. } => {
-            let artist_vec = artists
-              .as_ref()
-              .map(|a| vec![a.clone()])
-              .unwrap_or_default();
-            let album_str = album.clone().unwrap_or_default();
-            (artist_vec, album_str)
-          }
-        };
-
-        // Update macOS Now Playing metadata
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_metadata(&audio_item.name, &artists, &album, audio_item.duration_ms);
-        }
+        let (artists, album) = native_artists_and_album(&audio_item.unique_fields);
+        hooks.metadata(
+          &audio_item.track_id.to_string(),
+          &audio_item.name,
+          &artists,
+          &album,
+          audio_item.duration_ms,
+        );
 
         // Track metadata updates are critical for playbar correctness; do not drop
         // them when the UI thread is briefly busy.
         let mut app = app.lock().await;
-        app.native_track_info = Some(app::NativeTrackInfo {
-          name: audio_item.name.clone(),
-          artists_display: artists.join(", "),
-          album: album.clone(),
-          duration_ms: audio_item.duration_ms,
-        });
-        app.song_progress_ms = 0;
-        app.last_track_id = Some(audio_item.track_id.to_string());
-        app.instant_since_last_current_playback_poll = std::time::Instant::now();
-        app.dispatch(IoEvent::GetCurrentPlayback);
+        apply_native_track_changed(
+          &mut app,
+          app::NativeTrackInfo {
+            name: audio_item.name.clone(),
+            artists_display: artists.join(", "),
+            album,
+            duration_ms: audio_item.duration_ms,
+          },
+          audio_item.track_id.to_string(),
+        );
       }
       PlayerEvent::Stopped { .. } => {
-        // Update macOS Now Playing status
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_stopped();
-        }
+        hooks.stopped();
 
         if let Ok(mut app) = app.try_lock() {
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = false;
-          }
-          app.song_progress_ms = 0;
-          app.last_track_id = None;
+          apply_native_stopped(&mut app);
         }
+
+        // Small delay to let Spotify's backend transition
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // Try to dispatch - skip if busy
         if let Ok(mut app) = app.try_lock() {
           app.dispatch(IoEvent::GetCurrentPlayback);
         }
       }
       PlayerEvent::EndOfTrack { track_id, .. } => {
-        // Update macOS Now Playing status
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_stopped();
-        }
+        hooks.stopped();
 
         if let Ok(mut app) = app.try_lock() {
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.is_playing = false;
+          if let Some(path) = &app.user_config.behavior.log_skip_stats {
+            let uri = track_id.to_string();
+            if uri.starts_with("spotify:track:") {
+              crate::core::skip_stats::record(
+                path,
+                &uri,
+                crate::core::skip_stats::Outcome::Completed,
+                app.song_progress_ms,
+              );
+            }
           }
-          app.song_progress_ms = 0;
-          app.last_track_id = None;
+          apply_native_stopped(&mut app);
         }
+
+        // Ensure we don't land on the next item paused after the track transition.
+        // (librespot Spirc will advance; we may need to resume playback.)
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         if let Ok(mut app) = app.try_lock() {
           app.dispatch(IoEvent::EnsurePlaybackContinues(track_id.to_string()));
         }
       }
       PlayerEvent::VolumeChanged { volume } => {
-        let volume_percent = ((volume as f64 / 65535.0) * 100.0).round() as u8;
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_volume(volume_percent);
-        }
+        let volume_percent = native_volume_percent(volume);
+        hooks.volume(volume_percent);
 
         if let Ok(mut app) = app.try_lock() {
-          let volume_percent = volume_percent as u32;
-          if let Some(ref mut ctx) = app.current_playback_context {
-            ctx.device.volume_percent = Some(volume_percent);
-          }
-          app.user_config.behavior.volume_percent = volume_percent.min(100) as u8;
-          let _ = app.user_config.save_config();
+          apply_native_volume(&mut app, volume_percent);
         }
       }
       PlayerEvent::PositionChanged {
@@ -1765,13 +1779,16 @@ async fn handle_player_events(
         track_id: _,
         position_ms,
       } => {
+        // Use atomic store for lock-free position updates
+        // This never blocks or fails, ensuring every position update is captured
         shared_position.store(position_ms as u64, Ordering::Relaxed);
-        #[cfg(all(feature = "macos-media", target_os = "macos"))]
-        if let Some(ref macos_media) = macos_media_manager {
-          macos_media.set_position(position_ms as u64);
-        }
+
+        // Keep external clients (playerctl, desktop widgets) in sync
+        hooks.position(position_ms as u64);
+      }
+      _ => {
+        // Ignore other events
       }
-      _ => {}
     }
   }
 }
@@ -1847,7 +1864,7 @@ async fn handle_mpris_events(
 
         // Update app's song_progress_ms so UI updates even when paused
         if let Ok(mut app_lock) = app.try_lock() {
-          app_lock.song_progress_ms = new_position_ms as u128;
+          app_lock.set_song_progress(new_position_ms as u128);
         }
 
         // Emit Seeked signal so external clients know position jumped
@@ -1866,7 +1883,7 @@ async fn handle_mpris_events(
 
         // Update app's song_progress_ms so UI updates even when paused
         if let Ok(mut app_lock) = app.try_lock() {
-          app_lock.song_progress_ms = new_position_ms as u128;
+          app_lock.set_song_progress(new_position_ms as u128);
         }
 
         // Emit Seeked signal so external clients know position jumped
@@ -1876,14 +1893,8 @@ async fn handle_mpris_events(
         if let Err(e) = player.set_shuffle(shuffle) {
           eprintln!("MPRIS: Failed to set shuffle: {}", e);
         } else {
-          // Update MPRIS state so clients see the new value
-          mpris_manager.set_shuffle(shuffle);
-          // Update app UI state (use await to ensure update happens)
-          let mut app_lock = app.lock().await;
-          if let Some(ref mut ctx) = app_lock.current_playback_context {
-            ctx.shuffle_state = shuffle;
-          }
-          app_lock.user_config.behavior.shuffle_enabled = shuffle;
+          // Update app UI and MPRIS state (use await to ensure update happens)
+          app.lock().await.set_shuffle_state(shuffle);
         }
       }
       MprisEvent::SetLoopStatus(loop_status) => {
@@ -1900,13 +1911,8 @@ async fn handle_mpris_events(
         if let Err(e) = player.set_repeat_mode(repeat_state) {
           eprintln!("MPRIS: Failed to set repeat mode: {}", e);
         } else {
-          // Update MPRIS state so clients see the new value
-          mpris_manager.set_loop_status(loop_status);
-          // Update app UI state (use await to ensure update happens)
-          let mut app_lock = app.lock().await;
-          if let Some(ref mut ctx) = app_lock.current_playback_context {
-            ctx.repeat_state = repeat_state;
-          }
+          // Update app UI and MPRIS state (use await to ensure update happens)
+          app.lock().await.set_repeat_state(repeat_state);
         }
       }
     }
@@ -1977,18 +1983,25 @@ async fn start_ui(
   let _ = discord_rpc_manager;
   // Terminal initialization
   let mut terminal = ratatui::init();
-  execute!(stdout(), EnableMouseCapture)?;
-
-  if user_config.behavior.set_window_title {
-    execute!(stdout(), SetTitle("spt - spotatui"))?;
+  if user_config.behavior.enable_mouse {
+    execute!(stdout(), EnableMouseCapture)?;
   }
 
-  let events = event::Events::new(user_config.behavior.tick_rate_milliseconds);
+  let mut window_title = WindowTitle::default();
+  // Only a new position report re-anchors the progress bar, which extrapolates in between
+  let mut last_reported_position = 0u64;
+
+  let mut events = event::Events::new(user_config.behavior.tick_rate_milliseconds);
+  let color_mode = ui::color::resolve_color_mode(user_config.behavior.color_mode);
+  info!("drawing with color mode {}", color_mode.name());
 
   // Track previous streaming state to detect device changes for MPRIS
   // When switching from native streaming to external device (like spotifyd),
   // we set MPRIS to stopped so the external player's MPRIS interface takes precedence
   let mut prev_is_streaming_active = false;
+  // Whether MPRIS clients were last told they can control playback
+  #[cfg(all(feature = "mpris", target_os = "linux"))]
+  let mut prev_mpris_controllable = None;
 
   // Lazy audio capture: only capture when in Analysis view
   #[cfg(any(feature = "audio-viz", feature = "audio-viz-cpal"))]
@@ -2008,6 +2021,8 @@ async fn start_ui(
     let terminal_size = terminal.backend().size().ok();
     {
       let mut app = app.lock().await;
+      // Settings can change the tick rate while running
+      events.set_tick_rate(app.user_config.behavior.tick_rate_milliseconds);
 
       // MPRIS device change detection: When switching from native streaming to
       // an external device (like spotifyd), set MPRIS to stopped so the external
@@ -2022,6 +2037,21 @@ async fn start_ui(
           }
         }
         prev_is_streaming_active = current_is_streaming_active;
+
+        // Requests from MPRIS only reach the native player, so its controls are
+        // greyed out while another device plays or nothing is loaded
+        let has_track = app.native_track_info.is_some()
+          || app
+            .current_playback_context
+            .as_ref()
+            .is_some_and(|ctx| ctx.item.is_some());
+        let controllable = current_is_streaming_active && has_track;
+        if prev_mpris_controllable != Some(controllable) {
+          if let Some(ref mpris) = mpris_manager {
+            mpris.set_controllable(controllable);
+          }
+          prev_mpris_controllable = Some(controllable);
+        }
       }
 
       // Get the size of the screen on each loop to account for resize event
@@ -2056,37 +2086,46 @@ async fn start_ui(
       };
 
       let current_route = app.get_current_route();
-      terminal.draw(|f| match current_route.active_block {
-        ActiveBlock::HelpMenu => {
-          ui::draw_help_menu(f, &app);
-        }
-        ActiveBlock::Error => {
-          ui::draw_error_screen(f, &app);
-        }
-        ActiveBlock::SelectDevice => {
-          ui::draw_device_list(f, &app);
-        }
-        ActiveBlock::Analysis => {
-          ui::audio_analysis::draw(f, &app);
-        }
-        ActiveBlock::BasicView => {
-          ui::draw_basic_view(f, &app);
-        }
-        ActiveBlock::UpdatePrompt => {
-          ui::draw_update_prompt(f, &app);
-        }
-        ActiveBlock::AnnouncementPrompt => {
-          ui::draw_announcement_prompt(f, &app);
-        }
-        ActiveBlock::ExitPrompt => {
-          ui::draw_exit_prompt(f, &app);
-        }
-        ActiveBlock::Settings => {
-          ui::settings::draw_settings(f, &app);
-        }
-        _ => {
-          ui::draw_main_layout(f, &app);
+      terminal.draw(|f| {
+        match current_route.active_block {
+          ActiveBlock::HelpMenu => {
+            ui::draw_help_menu(f, &app);
+          }
+          ActiveBlock::Error => {
+            ui::draw_error_screen(f, &app);
+          }
+          ActiveBlock::SelectDevice => {
+            ui::draw_device_list(f, &app);
+          }
+          ActiveBlock::Analysis => {
+            ui::audio_analysis::draw(f, &app);
+          }
+          ActiveBlock::BasicView => {
+            ui::draw_basic_view(f, &app);
+          }
+          ActiveBlock::NowPlaying => {
+            ui::draw_now_playing(f, &app);
+          }
+          ActiveBlock::TvView => {
+            ui::draw_tv_view(f, &app);
+          }
+          ActiveBlock::UpdatePrompt => {
+            ui::draw_update_prompt(f, &app);
+          }
+          ActiveBlock::AnnouncementPrompt => {
+            ui::draw_announcement_prompt(f, &app);
+          }
+          ActiveBlock::ExitPrompt => {
+            ui::draw_exit_prompt(f, &app);
+          }
+          ActiveBlock::Settings => {
+            ui::settings::draw_settings(f, &app);
+          }
+          _ => {
+            ui::draw_main_layout(f, &app);
+          }
         }
+        ui::color::adapt_buffer_colors(f.buffer_mut(), color_mode, &app.user_config);
       })?;
 
       if current_route.active_block == ActiveBlock::Input {
@@ -2113,14 +2152,19 @@ async fn start_ui(
       }
     }
 
-    match events.next()? {
+    let Some(event) = events.next().await else {
+      break;
+    };
+    match event {
       event::Event::Input(key) => {
         let mut app = app.lock().await;
         if key == Key::Ctrl('c') {
+          app.prepare_exit();
           app.close_io_channel();
           break;
         }
 
+        let key = handlers::resolve_chord(key, &mut app);
         let current_active_block = app.get_current_route().active_block;
 
         // To avoid swallowing the global key presses `q` and `-` make a special
@@ -2128,6 +2172,7 @@ async fn start_ui(
         if current_active_block == ActiveBlock::ExitPrompt {
           match key {
             Key::Enter | Key::Char('y') | Key::Char('Y') => {
+              app.prepare_exit();
               app.close_io_channel();
               break;
             }
@@ -2141,23 +2186,17 @@ async fn start_ui(
           }
         } else if current_active_block == ActiveBlock::Input {
           handlers::input_handler(key, &mut app);
+        } else if app.is_playlist_filter_open()
+          || app.new_playlist.is_some()
+          || app.playlist_details_edit.is_some()
+          || app.library_search.is_some()
+        {
+          handlers::handle_app(key, &mut app);
         } else if key == app.user_config.keys.back {
-          if current_active_block == ActiveBlock::Settings {
+          if current_active_block == ActiveBlock::Settings
+            || current_active_block == ActiveBlock::AnnouncementPrompt
+          {
             handlers::handle_app(key, &mut app);
-          } else if app.get_current_route().active_block == ActiveBlock::AnnouncementPrompt {
-            if let Some(dismissed_id) = app.dismiss_active_announcement() {
-              app.user_config.mark_announcement_seen(dismissed_id);
-              if let Err(error) = app.user_config.save_config() {
-                app.handle_error(anyhow!(
-                  "Failed to persist dismissed announcement: {}",
-                  error
-                ));
-              }
-            }
-
-            if app.active_announcement.is_none() {
-              app.pop_navigation_stack();
-            }
           } else if app.get_current_route().active_block != ActiveBlock::Input {
             // Go back through navigation stack when not in search input mode and exit the app if there are no more places to back to
 
@@ -2182,16 +2221,19 @@ async fn start_ui(
         let mut app = app.lock().await;
         app.update_on_tick();
 
-        // Flush any pending seeks (throttled to avoid overwhelming player/API)
+        // Flush any pending seeks and volume changes (throttled to avoid overwhelming player/API)
         #[cfg(feature = "streaming")]
         app.flush_pending_native_seek();
         app.flush_pending_api_seek();
+        app.flush_pending_volume();
 
         #[cfg(feature = "discord-rpc")]
         if let Some(ref manager) = discord_rpc_manager {
           update_discord_presence(manager, &mut discord_presence_state, &app);
         }
 
+        window_title.update(&app);
+
         #[cfg(feature = "mpris")]
         if let Some(ref mpris) = mpris_manager {
           update_mpris_metadata(mpris, &mut mpris_metadata_state, &app);
@@ -2209,8 +2251,9 @@ async fn start_ui(
 
             if !recently_seeked {
               let position_ms = pos.load(Ordering::Relaxed);
-              if position_ms > 0 {
-                app.song_progress_ms = position_ms as u128;
+              if position_ms > 0 && position_ms != last_reported_position {
+                last_reported_position = position_ms;
+                app.set_song_progress(position_ms as u128);
               }
             }
           }
@@ -2219,8 +2262,9 @@ async fn start_ui(
         if let Some(ref pos) = shared_position {
           if app.is_streaming_active {
             let position_ms = pos.load(Ordering::Relaxed);
-            if position_ms > 0 {
-              app.song_progress_ms = position_ms as u128;
+            if position_ms > 0 && position_ms != last_reported_position {
+              last_reported_position = position_ms;
+              app.set_song_progress(position_ms as u128);
             }
           }
         }
@@ -2238,7 +2282,7 @@ async fn start_ui(
 
             if let Some(ref capture) = audio_capture {
               if let Some(spectrum) = capture.get_spectrum() {
-                app.spectrum_data = Some(app::SpectrumData {
+                app.update_spectrum(app::SpectrumData {
                   bands: spectrum.bands,
                   peak: spectrum.peak,
                 });
@@ -2249,6 +2293,7 @@ async fn start_ui(
             audio_capture = None;
             app.audio_capture_active = false;
             app.spectrum_data = None;
+            app.silent_spectrum_since = None;
           }
         }
       }
@@ -2289,8 +2334,33 @@ async fn start_ui(
     }
   }
 
-  execute!(stdout(), DisableMouseCapture)?;
+  if user_config.behavior.enable_mouse {
+    execute!(stdout(), DisableMouseCapture)?;
+  }
   ratatui::restore();
+  // Carry on with the rest of the teardown even if the terminal won't take the title back
+  if let Err(e) = window_title.restore() {
+    info!("failed to restore window title: {}", e);
+  }
+
+  // Remember where the changelog was left, what Home showed and the Discover time
+  // range so the next launch resumes there
+  {
+    let mut app = app.lock().await;
+    let home_scroll = app.home_scroll;
+    let home_mode = app.home_mode;
+    let discover_time_range = app.discover_time_range;
+    let scroll_changed = app.user_config.remember_home_scroll(home_scroll);
+    let mode_changed = app.user_config.remember_home_mode(home_mode);
+    let range_changed = app
+      .user_config
+      .remember_discover_time_range(discover_time_range);
+    if scroll_changed || mode_changed || range_changed {
+      if let Err(e) = app.user_config.save_session_state() {
+        info!("failed to save session state: {}", e);
+      }
+    }
+  }
 
   #[cfg(feature = "discord-rpc")]
   if let Some(ref manager) = discord_rpc_manager {
@@ -2318,13 +2388,17 @@ async fn start_ui(
 
   // Terminal initialization
   let mut terminal = ratatui::init();
-  execute!(stdout(), EnableMouseCapture)?;
-
-  if user_config.behavior.set_window_title {
-    execute!(stdout(), SetTitle("spt - spotatui"))?;
+  if user_config.behavior.enable_mouse {
+    execute!(stdout(), EnableMouseCapture)?;
   }
 
-  let events = event::Events::new(user_config.behavior.tick_rate_milliseconds);
+  let mut window_title = WindowTitle::default();
+  // Only a new position report re-anchors the progress bar, which extrapolates in between
+  let mut last_reported_position = 0u64;
+
+  let mut events = event::Events::new(user_config.behavior.tick_rate_milliseconds);
+  let color_mode = ui::color::resolve_color_mode(user_config.behavior.color_mode);
+  info!("drawing with color mode {}", color_mode.name());
 
   // Check for updates SYNCHRONOUSLY before starting the event loop
   {
@@ -2352,6 +2426,8 @@ async fn start_ui(
     let terminal_size = terminal.backend().size().ok();
     {
       let mut app = app.lock().await;
+      // Settings can change the tick rate while running
+      events.set_tick_rate(app.user_config.behavior.tick_rate_milliseconds);
 
       if let Some(size) = terminal_size {
         if is_first_render || app.size != size {
@@ -2390,12 +2466,15 @@ async fn start_ui(
           ActiveBlock::SelectDevice => ui::draw_device_list(f, &app),
           ActiveBlock::Analysis => ui::audio_analysis::draw(f, &app),
           ActiveBlock::BasicView => ui::draw_basic_view(f, &app),
+          ActiveBlock::NowPlaying => ui::draw_now_playing(f, &app),
+          ActiveBlock::TvView => ui::draw_tv_view(f, &app),
           ActiveBlock::UpdatePrompt => ui::draw_update_prompt(f, &app),
           ActiveBlock::AnnouncementPrompt => ui::draw_announcement_prompt(f, &app),
           ActiveBlock::ExitPrompt => ui::draw_exit_prompt(f, &app),
           ActiveBlock::Settings => ui::settings::draw_settings(f, &app),
           _ => ui::draw_main_layout(f, &app),
         }
+        ui::color::adapt_buffer_colors(f.buffer_mut(), color_mode, &app.user_config);
       })?;
 
       if current_route.active_block == ActiveBlock::Input {
@@ -2419,19 +2498,25 @@ async fn start_ui(
       }
     }
 
-    match events.next()? {
+    let Some(event) = events.next().await else {
+      break;
+    };
+    match event {
       event::Event::Input(key) => {
         let mut app = app.lock().await;
         if key == Key::Ctrl('c') {
+          app.prepare_exit();
           app.close_io_channel();
           break;
         }
 
+        let key = handlers::resolve_chord(key, &mut app);
         let current_active_block = app.get_current_route().active_block;
 
         if current_active_block == ActiveBlock::ExitPrompt {
           match key {
             Key::Enter | Key::Char('y') | Key::Char('Y') => {
+              app.prepare_exit();
               app.close_io_channel();
               break;
             }
@@ -2445,23 +2530,17 @@ async fn start_ui(
           }
         } else if current_active_block == ActiveBlock::Input {
           handlers::input_handler(key, &mut app);
+        } else if app.is_playlist_filter_open()
+          || app.new_playlist.is_some()
+          || app.playlist_details_edit.is_some()
+          || app.library_search.is_some()
+        {
+          handlers::handle_app(key, &mut app);
         } else if key == app.user_config.keys.back {
-          if current_active_block == ActiveBlock::Settings {
+          if current_active_block == ActiveBlock::Settings
+            || current_active_block == ActiveBlock::AnnouncementPrompt
+          {
             handlers::handle_app(key, &mut app);
-          } else if app.get_current_route().active_block == ActiveBlock::AnnouncementPrompt {
-            if let Some(dismissed_id) = app.dismiss_active_announcement() {
-              app.user_config.mark_announcement_seen(dismissed_id);
-              if let Err(error) = app.user_config.save_config() {
-                app.handle_error(anyhow!(
-                  "Failed to persist dismissed announcement: {}",
-                  error
-                ));
-              }
-            }
-
-            if app.active_announcement.is_none() {
-              app.pop_navigation_stack();
-            }
           } else if app.get_current_route().active_block != ActiveBlock::Input {
             let pop_result = match app.pop_navigation_stack() {
               Some(ref x) if x.id == RouteId::Search => app.pop_navigation_stack(),
@@ -2492,16 +2571,19 @@ async fn start_ui(
         let mut app = app.lock().await;
         app.update_on_tick();
 
-        // Flush any pending seeks (throttled to avoid overwhelming player/API)
+        // Flush any pending seeks and volume changes (throttled to avoid overwhelming player/API)
         #[cfg(feature = "streaming")]
         app.flush_pending_native_seek();
         app.flush_pending_api_seek();
+        app.flush_pending_volume();
 
         #[cfg(feature = "discord-rpc")]
         if let Some(ref manager) = discord_rpc_manager {
           update_discord_presence(manager, &mut discord_presence_state, &app);
         }
 
+        window_title.update(&app);
+
         // Read position from shared atomic if native streaming is active
         // Skip if we recently seeked - let the UI show our target position until the player catches up
         #[cfg(feature = "streaming")]
@@ -2511,9 +2593,10 @@ async fn start_ui(
             .is_some_and(|t| t.elapsed().as_millis() < app::SEEK_POSITION_IGNORE_MS);
 
           if !recently_seeked {
-            let pos_ms = pos.load(Ordering::Relaxed) as u128;
-            if pos_ms > 0 && app.is_streaming_active {
-              app.song_progress_ms = pos_ms;
+            let position_ms = pos.load(Ordering::Relaxed);
+            if position_ms > 0 && app.is_streaming_active && position_ms != last_reported_position {
+              last_reported_position = position_ms;
+              app.set_song_progress(position_ms as u128);
             }
           }
         }
@@ -2521,8 +2604,9 @@ async fn start_ui(
         if let Some(ref pos) = shared_position {
           if app.is_streaming_active {
             let position_ms = pos.load(Ordering::Relaxed);
-            if position_ms > 0 {
-              app.song_progress_ms = position_ms as u128;
+            if position_ms > 0 && position_ms != last_reported_position {
+              last_reported_position = position_ms;
+              app.set_song_progress(position_ms as u128);
             }
           }
         }
@@ -2540,7 +2624,7 @@ async fn start_ui(
 
             if let Some(ref capture) = audio_capture {
               if let Some(spectrum) = capture.get_spectrum() {
-                app.spectrum_data = Some(app::SpectrumData {
+                app.update_spectrum(app::SpectrumData {
                   bands: spectrum.bands,
                   peak: spectrum.peak,
                 });
@@ -2551,6 +2635,7 @@ async fn start_ui(
             audio_capture = None;
             app.audio_capture_active = false;
             app.spectrum_data = None;
+            app.silent_spectrum_since = None;
           }
         }
       }
@@ -2570,8 +2655,33 @@ async fn start_ui(
     }
   }
 
-  execute!(stdout(), DisableMouseCapture)?;
+  if user_config.behavior.enable_mouse {
+    execute!(stdout(), DisableMouseCapture)?;
+  }
   ratatui::restore();
+  // Carry on with the rest of the teardown even if the terminal won't take the title back
+  if let Err(e) = window_title.restore() {
+    info!("failed to restore window title: {}", e);
+  }
+
+  // Remember where the changelog was left, what Home showed and the Discover time
+  // range so the next launch resumes there
+  {
+    let mut app = app.lock().await;
+    let home_scroll = app.home_scroll;
+    let home_mode = app.home_mode;
+    let discover_time_range = app.discover_time_range;
+    let scroll_changed = app.user_config.remember_home_scroll(home_scroll);
+    let mode_changed = app.user_config.remember_home_mode(home_mode);
+    let range_changed = app
+      .user_config
+      .remember_discover_time_range(discover_time_range);
+    if scroll_changed || mode_changed || range_changed {
+      if let Err(e) = app.user_config.save_session_state() {
+        info!("failed to save session state: {}", e);
+      }
+    }
+  }
 
   #[cfg(feature = "discord-rpc")]
   if let Some(ref manager) = discord_rpc_manager {
@@ -2580,3 +2690,115 @@ async fn start_ui(
 
   Ok(())
 }
+
+#[cfg(all(test, feature = "streaming"))]
+mod tests {
+  use super::*;
+
+  fn app_with_playback() -> App {
+    let mut app = App::default();
+    app.current_playback_context = Some(
+      serde_json::from_value(serde_json::json!({
+        "device": {
+          "id": "native-id",
+          "is_active": true,
+          "is_private_session": false,
+          "is_restricted": false,
+          "name": "spotatui",
+          "type": "Computer",
+          "volume_percent": 40
+        },
+        "repeat_state": "off",
+        "shuffle_state": false,
+        "context": null,
+        "timestamp": 0,
+        "progress_ms": 0,
+        "is_playing": true,
+        "item": null,
+        "currently_playing_type": "unknown",
+        "actions": { "disallows": {} }
+      }))
+      .unwrap(),
+    );
+    app
+  }
+
+  #[test]
+  fn native_player_events_map_onto_playback_state() {
+    let mut app = app_with_playback();
+
+    apply_native_play_state(&mut app, false, 12_000);
+    let ctx = app.current_playback_context.as_ref().unwrap();
+    assert!(!ctx.is_playing);
+    assert_eq!(ctx.progress.unwrap().num_milliseconds(), 12_000);
+    assert_eq!(app.song_progress_ms, 12_000);
+
+    app.is_loading = false;
+    apply_native_playing(&mut app, "spotify:track:a".to_string(), 0);
+    assert!(app.current_playback_context.as_ref().unwrap().is_playing);
+    assert_eq!(app.last_track_id.as_deref(), Some("spotify:track:a"));
+    assert!(app.is_loading, "a new track should fetch playback details");
+
+    // Resuming the same track does not refetch
+    app.is_loading = false;
+    apply_native_playing(&mut app, "spotify:track:a".to_string(), 500);
+    assert!(!app.is_loading);
+
+    app.seek_ms = Some(9_000);
+    apply_native_seek(&mut app, 9_000);
+    assert_eq!(app.seek_ms, None);
+    assert_eq!(app.song_progress_ms, 9_000);
+
+    apply_native_stopped(&mut app);
+    assert!(!app.current_playback_context.as_ref().unwrap().is_playing);
+    assert_eq!(app.song_progress_ms, 0);
+    assert_eq!(app.last_track_id, None);
+
+    assert_eq!(native_volume_percent(u16::MAX), 100);
+    assert_eq!(native_volume_percent(0), 0);
+    assert_eq!(native_volume_percent(32_768), 50);
+    apply_native_volume(&mut app, native_volume_percent(45_875));
+    assert_eq!(
+      app
+        .current_playback_context
+        .as_ref()
+        .unwrap()
+        .device
+        .volume_percent,
+      Some(70)
+    );
+    assert_eq!(app.user_config.behavior.volume_percent, 70);
+  }
+
+  #[test]
+  #[cfg(feature = "telemetry")]
+  fn song_counter_counts_each_track_once() {
+    let (io_tx, io_rx) = std::sync::mpsc::channel();
+    let mut app = App::new(io_tx, UserConfig::new(), std::time::SystemTime::now());
+    let counted = |rx: &std::sync::mpsc::Receiver<IoEvent>| {
+      rx.try_iter()
+        .filter(|event| matches!(event, IoEvent::IncrementGlobalSongCount))
+        .count()
+    };
+
+    app.is_streaming_active = true;
+    apply_native_playing(&mut app, "spotify:track:a".to_string(), 0);
+    // Pausing and resuming, or the poll seeing the same track, doesn't recount
+    apply_native_playing(&mut app, "spotify:track:a".to_string(), 500);
+    app.count_song_play("a", false);
+    assert_eq!(counted(&io_rx), 1);
+
+    // External devices don't count once the counter is limited to native plays
+    app.user_config.behavior.global_song_count_native_only = true;
+    app.is_streaming_active = false;
+    app.count_song_play("b", false);
+    assert_eq!(counted(&io_rx), 0);
+    app.is_streaming_active = true;
+    apply_native_playing(&mut app, "spotify:track:b".to_string(), 0);
+    assert_eq!(counted(&io_rx), 1);
+
+    app.user_config.behavior.enable_global_song_count = false;
+    apply_native_playing(&mut app, "spotify:track:c".to_string(), 0);
+    assert_eq!(counted(&io_rx), 0);
+  }
+}
@@ -1,6 +1,13 @@
 use super::key::Key;
-use crossterm::event::{self, Event as CrosstermEvent, KeyEventKind, MouseEvent, MouseEventKind};
-use std::{sync::mpsc, thread, time::Duration};
+use crossterm::event::{
+  Event as CrosstermEvent, EventStream, KeyEventKind, MouseEvent, MouseEventKind,
+};
+use futures::stream::{Stream, StreamExt};
+use std::{io, time::Duration};
+use tokio::{
+  sync::{mpsc, watch},
+  time::{self, MissedTickBehavior},
+};
 
 #[derive(Debug, Clone, Copy)]
 /// Configuration for event handling.
@@ -31,12 +38,12 @@ pub enum Event {
   Tick,
 }
 
-/// A small event handler that wrap crossterm input and tick event. Each event
-/// type is handled in its own thread and returned to a common `Receiver`
+/// A small event handler that merges crossterm input with a tick timer on a tokio
+/// task. Input is passed on as soon as it arrives, whatever the tick rate, and the
+/// tick rate can be changed while running.
 pub struct Events {
-  rx: mpsc::Receiver<Event>,
-  // Need to be kept around to prevent disposing the sender side.
-  _tx: mpsc::Sender<Event>,
+  rx: mpsc::UnboundedReceiver<Event>,
+  tick_rate: watch::Sender<Duration>,
 }
 
 impl Events {
@@ -50,52 +57,187 @@ impl Events {
 
   /// Constructs an new instance of `Events` from given config.
   pub fn with_config(config: EventConfig) -> Events {
-    let (tx, rx) = mpsc::channel();
-
-    let event_tx = tx.clone();
-    thread::spawn(move || {
-      loop {
-        // poll for tick rate duration, if no event, sent tick event.
-        if event::poll(config.tick_rate).unwrap() {
-          match event::read().unwrap() {
-            CrosstermEvent::Key(key) => {
-              // Only process key press events, not release or repeat.
-              // This fixes duplicate key events on Windows where both
-              // Press and Release events are sent for each key press.
-              if key.kind == KeyEventKind::Press {
-                let key = Key::from(key);
-                // If send fails, the receiver has been dropped (app is closing)
-                if event_tx.send(Event::Input(key)).is_err() {
-                  break;
-                }
-              }
-            }
-            CrosstermEvent::Mouse(mouse) => {
-              if matches!(
-                mouse.kind,
-                MouseEventKind::Down(_) | MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
-              ) && event_tx.send(Event::Mouse(mouse)).is_err()
-              {
-                break;
-              }
-            }
-            _ => {}
+    Events::from_stream(EventStream::new(), config)
+  }
+
+  /// Reads terminal events from `input` instead of the terminal
+  fn from_stream<S>(input: S, config: EventConfig) -> Events
+  where
+    S: Stream<Item = io::Result<CrosstermEvent>> + Send + Unpin + 'static,
+  {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (tick_rate, tick_rate_rx) = watch::channel(config.tick_rate);
+    tokio::spawn(forward_events(input, tx, tick_rate_rx));
+    Events { rx, tick_rate }
+  }
+
+  /// Waits for the next event. `None` once the terminal's event stream has ended.
+  pub async fn next(&mut self) -> Option<Event> {
+    self.rx.recv().await
+  }
+
+  /// Tick every `tick_rate` ms from now on. Does nothing if that is already the rate.
+  pub fn set_tick_rate(&self, tick_rate: u64) {
+    let tick_rate = Duration::from_millis(tick_rate.max(1));
+    self.tick_rate.send_if_modified(|current| {
+      let changed = *current != tick_rate;
+      *current = tick_rate;
+      changed
+    });
+  }
+}
+
+fn tick_interval(tick_rate: Duration) -> time::Interval {
+  // The first tick of an interval fires at once, so start a period from now
+  let mut interval = time::interval_at(time::Instant::now() + tick_rate, tick_rate);
+  // A slow frame shouldn't be followed by a burst of catch-up ticks
+  interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+  interval
+}
+
+async fn forward_events<S>(
+  mut input: S,
+  tx: mpsc::UnboundedSender<Event>,
+  mut tick_rate: watch::Receiver<Duration>,
+) where
+  S: Stream<Item = io::Result<CrosstermEvent>> + Unpin,
+{
+  let mut ticks = tick_interval(*tick_rate.borrow_and_update());
+  loop {
+    let event = tokio::select! {
+      input_event = input.next() => match input_event {
+        Some(Ok(CrosstermEvent::Key(key))) => {
+          // Only process key press events, not release or repeat.
+          // This fixes duplicate key events on Windows where both
+          // Press and Release events are sent for each key press.
+          if key.kind != KeyEventKind::Press {
+            continue;
           }
+          Event::Input(Key::from(key))
         }
-
-        // If send fails, the receiver has been dropped (app is closing)
-        if event_tx.send(Event::Tick).is_err() {
+        Some(Ok(CrosstermEvent::Mouse(mouse)))
+          if matches!(
+            mouse.kind,
+            MouseEventKind::Down(_) | MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+          ) =>
+        {
+          Event::Mouse(mouse)
+        }
+        Some(Ok(_)) => continue,
+        Some(Err(e)) => {
+          log::error!("reading terminal events failed: {}", e);
           break;
         }
+        None => break,
+      },
+      _ = ticks.tick() => Event::Tick,
+      changed = tick_rate.changed() => {
+        // The `Events` was dropped
+        if changed.is_err() {
+          break;
+        }
+        ticks = tick_interval(*tick_rate.borrow_and_update());
+        continue;
       }
-    });
+    };
 
-    Events { rx, _tx: tx }
+    // If send fails, the receiver has been dropped (app is closing)
+    if tx.send(event).is_err() {
+      break;
+    }
   }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+  use tokio::time::{timeout, Instant};
+
+  fn key_event(code: KeyCode, modifiers: KeyModifiers) -> io::Result<CrosstermEvent> {
+    Ok(CrosstermEvent::Key(KeyEvent::new(code, modifiers)))
+  }
+
+  /// `Events` fed from a channel standing in for the terminal
+  fn events_with_input(
+    tick_rate: Duration,
+  ) -> (Events, mpsc::UnboundedSender<io::Result<CrosstermEvent>>) {
+    let (input_tx, mut input_rx) = mpsc::unbounded_channel();
+    let input = Box::pin(futures::stream::poll_fn(move |cx| input_rx.poll_recv(cx)));
+    let events = Events::from_stream(
+      input,
+      EventConfig {
+        tick_rate,
+        ..Default::default()
+      },
+    );
+    (events, input_tx)
+  }
+
+  #[tokio::test]
+  async fn keys_arrive_without_waiting_for_a_tick() {
+    let (mut events, input) = events_with_input(Duration::from_secs(60));
+
+    input
+      .send(key_event(KeyCode::Char('c'), KeyModifiers::CONTROL))
+      .unwrap();
+    let event = timeout(Duration::from_secs(1), events.next())
+      .await
+      .unwrap();
+    assert!(matches!(event, Some(Event::Input(Key::Ctrl('c')))));
+  }
+
+  #[tokio::test]
+  async fn tick_rate_changes_take_effect_while_running() {
+    let (mut events, _input) = events_with_input(Duration::from_secs(60));
+
+    // Nothing ticks at the slow rate
+    assert!(timeout(Duration::from_millis(50), events.next())
+      .await
+      .is_err());
+
+    events.set_tick_rate(10);
+    let started = Instant::now();
+    for _ in 0..3 {
+      let event = timeout(Duration::from_secs(1), events.next())
+        .await
+        .unwrap();
+      assert!(matches!(event, Some(Event::Tick)));
+    }
+    assert!(started.elapsed() < Duration::from_secs(1));
+  }
+
+  #[tokio::test]
+  async fn same_tick_rate_does_not_restart_the_timer() {
+    let (events, _input) = events_with_input(Duration::from_millis(250));
+    let tick_rate = events.tick_rate.subscribe();
+
+    events.set_tick_rate(250);
+    assert!(!tick_rate.has_changed().unwrap());
+    events.set_tick_rate(0);
+    assert!(tick_rate.has_changed().unwrap());
+    assert_eq!(*tick_rate.borrow(), Duration::from_millis(1));
+  }
+
+  #[tokio::test]
+  async fn key_releases_are_dropped_and_the_end_of_input_closes_events() {
+    let (mut events, input) = events_with_input(Duration::from_secs(60));
+
+    let mut release = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+    release.kind = KeyEventKind::Release;
+    input.send(Ok(CrosstermEvent::Key(release))).unwrap();
+    input
+      .send(key_event(KeyCode::Char('j'), KeyModifiers::NONE))
+      .unwrap();
+    drop(input);
 
-  /// Attempts to read an event.
-  /// This function will block the current thread.
-  pub fn next(&self) -> Result<Event, mpsc::RecvError> {
-    self.rx.recv()
+    let event = timeout(Duration::from_secs(1), events.next())
+      .await
+      .unwrap();
+    assert!(matches!(event, Some(Event::Input(Key::Char('j')))));
+    let end = timeout(Duration::from_secs(1), events.next())
+      .await
+      .unwrap();
+    assert!(end.is_none());
   }
 }
@@ -64,6 +64,8 @@ pub enum Key {
   Char(char),
   Ctrl(char),
   Alt(char),
+  /// Two plain keys pressed one after the other, e.g. `g` then `a`
+  Chord(char, char),
   Unknown,
 }
 
@@ -104,6 +106,7 @@ impl fmt::Display for Key {
       Key::Alt(c) => write!(f, "<Alt+{}>", c),
       Key::Ctrl(c) => write!(f, "<Ctrl+{}>", c),
       Key::Char(c) => write!(f, "{}", c),
+      Key::Chord(first, second) => write!(f, "{} {}", Key::Char(first), Key::Char(second)),
       Key::Left | Key::Right | Key::Up | Key::Down => write!(f, "<{:?} Arrow Key>", self),
       Key::Enter
       | Key::Tab
@@ -1,8 +1,9 @@
 use super::common_key_events;
 use crate::{
-  app::{ActiveBlock, AlbumTableContext, App, RouteId, SelectedFullAlbum},
+  app::{ActiveBlock, AlbumTableContext, App, PendingBulkRemoval, RouteId, SelectedFullAlbum},
   event::Key,
 };
+use rspotify::{model::idtypes::AlbumId, prelude::*};
 
 pub fn handler(key: Key, app: &mut App) {
   match key {
@@ -53,7 +54,19 @@ pub fn handler(key: Key, app: &mut App) {
     }
     k if k == app.user_config.keys.next_page => app.get_current_user_saved_albums_next(),
     k if k == app.user_config.keys.previous_page => app.get_current_user_saved_albums_previous(),
-    Key::Char('D') => app.current_user_saved_album_delete(ActiveBlock::AlbumList),
+    Key::Char('V') => toggle_marked_album(app),
+    Key::Char('D') => {
+      if app.marked_album_ids.is_empty() {
+        app.current_user_saved_album_delete(ActiveBlock::AlbumList)
+      } else {
+        let album_ids = app
+          .marked_album_ids
+          .iter()
+          .filter_map(|id| AlbumId::from_id(id.clone()).ok())
+          .collect();
+        app.begin_bulk_library_removal(PendingBulkRemoval::Albums(album_ids));
+      }
+    }
     // Open sort menu
     Key::Char(',') => {
       super::sort_menu::open_sort_menu(app, crate::core::sort::SortContext::SavedAlbums);
@@ -62,6 +75,22 @@ pub fn handler(key: Key, app: &mut App) {
   };
 }
 
+fn toggle_marked_album(app: &mut App) {
+  let Some(album_id) = app
+    .library
+    .saved_albums
+    .get_results(None)
+    .and_then(|albums| albums.items.get(app.album_list_index))
+    .map(|saved| saved.album.id.id().to_string())
+  else {
+    return;
+  };
+
+  if !app.marked_album_ids.remove(&album_id) {
+    app.marked_album_ids.insert(album_id);
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -1,5 +1,6 @@
 use super::common_key_events;
 use crate::core::app::{AlbumTableContext, App, RecommendationsContext};
+use crate::core::availability;
 use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
 use rspotify::{
@@ -55,37 +56,42 @@ pub fn handler(key: Key, app: &mut App) {
     k if common_key_events::low_event(k) => handle_low_event(app),
     Key::Char('s') => handle_save_event(app),
     Key::Char('w') => handle_save_album_event(app),
-    Key::Enter => match app.album_table_context {
-      AlbumTableContext::Full => {
-        if let Some(selected_album) = app.selected_album_full.clone() {
-          let context_id = Some(PlayContextId::Album(selected_album.album.id.into_static()));
-          app.dispatch(IoEvent::StartPlayback(
-            context_id,
-            None,
-            Some(app.saved_album_tracks_index),
-          ));
-        };
+    Key::Enter => {
+      if refuse_unavailable_track(app) {
+        return;
       }
-      AlbumTableContext::Simplified => {
-        if let Some(selected_album_simplified) = &app.selected_album_simplified.clone() {
-          let context_id = selected_album_simplified
-            .album
-            .id
-            .clone()
-            .map(|id| PlayContextId::Album(id.into_static()));
-          app.dispatch(IoEvent::StartPlayback(
-            context_id,
-            None,
-            Some(selected_album_simplified.selected_index),
-          ));
-        };
+      match app.album_table_context {
+        AlbumTableContext::Full => {
+          if let Some(selected_album) = app.selected_album_full.clone() {
+            let context_id = Some(PlayContextId::Album(selected_album.album.id.into_static()));
+            app.dispatch(IoEvent::StartPlayback(
+              context_id,
+              None,
+              Some(app.saved_album_tracks_index),
+            ));
+          };
+        }
+        AlbumTableContext::Simplified => {
+          if let Some(selected_album_simplified) = &app.selected_album_simplified.clone() {
+            let context_id = selected_album_simplified
+              .album
+              .id
+              .clone()
+              .map(|id| PlayContextId::Album(id.into_static()));
+            app.dispatch(IoEvent::StartPlayback(
+              context_id,
+              None,
+              Some(selected_album_simplified.selected_index),
+            ));
+          };
+        }
       }
-    },
+    }
     //recommended playlist based on selected track
     Key::Char('r') => {
       handle_recommended_tracks(app);
     }
-    _ if key == app.user_config.keys.add_item_to_queue => match app.album_table_context {
+    _ if common_key_events::queue_event(key, app) => match app.album_table_context {
       AlbumTableContext::Full => {
         if let Some(selected_album) = app.selected_album_full.clone() {
           if let Some(track) = selected_album
@@ -95,9 +101,11 @@ pub fn handler(key: Key, app: &mut App) {
             .get(app.saved_album_tracks_index)
           {
             if let Some(track_id) = &track.id {
-              app.dispatch(IoEvent::AddItemToQueue(PlayableId::Track(
-                track_id.clone().into_static(),
-              )));
+              common_key_events::queue_selected(
+                app,
+                key,
+                PlayableId::Track(track_id.clone().into_static()),
+              );
             }
           }
         };
@@ -110,9 +118,11 @@ pub fn handler(key: Key, app: &mut App) {
             .get(selected_album_simplified.selected_index)
           {
             if let Some(track_id) = &track.id {
-              app.dispatch(IoEvent::AddItemToQueue(PlayableId::Track(
-                track_id.clone().into_static(),
-              )));
+              common_key_events::queue_selected(
+                app,
+                key,
+                PlayableId::Track(track_id.clone().into_static()),
+              );
             }
           }
         };
@@ -122,6 +132,32 @@ pub fn handler(key: Key, app: &mut App) {
   };
 }
 
+/// Explain why the selected track can't be played instead of starting it
+fn refuse_unavailable_track(app: &mut App) -> bool {
+  let country = app.get_user_country();
+  let message = match app.album_table_context {
+    AlbumTableContext::Full => app.selected_album_full.as_ref().and_then(|selected| {
+      let track = selected
+        .album
+        .tracks
+        .items
+        .get(app.saved_album_tracks_index)?;
+      availability::unavailable_message(track, country)
+    }),
+    AlbumTableContext::Simplified => app.selected_album_simplified.as_ref().and_then(|selected| {
+      let track = selected.tracks.items.get(selected.selected_index)?;
+      availability::unavailable_message(track, country)
+    }),
+  };
+  match message {
+    Some(message) => {
+      app.set_status_message(message, 4);
+      true
+    }
+    None => false,
+  }
+}
+
 fn handle_high_event(app: &mut App) {
   match app.album_table_context {
     AlbumTableContext::Full => {
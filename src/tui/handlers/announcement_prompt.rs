@@ -2,22 +2,75 @@ use crate::core::app::App;
 use crate::tui::event::Key;
 
 pub fn handler(key: Key, app: &mut App) {
-  match key {
-    Key::Enter | Key::Esc | Key::Char('q') | Key::Char(' ') => {
-      if let Some(dismissed_id) = app.dismiss_active_announcement() {
-        app.user_config.mark_announcement_seen(dismissed_id);
-        if let Err(error) = app.user_config.save_config() {
-          app.handle_error(anyhow::anyhow!(
-            "Failed to persist dismissed announcement: {}",
-            error
-          ));
-        }
-      }
-
-      if app.active_announcement.is_none() {
-        app.pop_navigation_stack();
-      }
+  if app.is_song_count_prompt_open() {
+    match key {
+      Key::Enter | Key::Char('y') | Key::Char('Y') => app.answer_song_count_prompt(true),
+      Key::Esc | Key::Char('n') | Key::Char('N') => app.answer_song_count_prompt(false),
+      _ if key == app.user_config.keys.back => app.answer_song_count_prompt(false),
+      _ => {}
     }
+    return;
+  }
+
+  match key {
+    Key::Enter | Key::Esc | Key::Char('q') | Key::Char(' ') => dismiss(app),
+    _ if key == app.user_config.keys.back => dismiss(app),
     _ => {}
   }
 }
+
+fn dismiss(app: &mut App) {
+  if let Some(dismissed_id) = app.dismiss_active_announcement() {
+    app.user_config.mark_announcement_seen(dismissed_id);
+    if let Err(error) = app.user_config.save_config() {
+      app.handle_error(anyhow::anyhow!(
+        "Failed to persist dismissed announcement: {}",
+        error
+      ));
+    }
+  }
+
+  if app.active_announcement.is_none() {
+    app.pop_navigation_stack();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::app::ActiveBlock;
+  use crate::core::user_config::UserConfigPaths;
+
+  fn app_with_song_count_prompt(name: &str) -> App {
+    let mut app = App::default();
+    app.user_config.path_to_config = Some(UserConfigPaths {
+      config_file_path: std::env::temp_dir().join(format!("spotatui-{}.yml", name)),
+    });
+    app.ask_song_count_choice();
+    app
+  }
+
+  #[test]
+  fn song_count_prompt_saves_the_answer() {
+    let mut app = app_with_song_count_prompt("song-count-yes");
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::AnnouncementPrompt
+    );
+    handler(Key::Char('y'), &mut app);
+    assert!(app.user_config.behavior.enable_global_song_count);
+    assert!(!app.user_config.song_count_choice_pending);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Empty);
+
+    let mut app = app_with_song_count_prompt("song-count-no");
+    // Global bindings such as next track must not fire behind the prompt
+    crate::tui::handlers::handle_app(Key::Char('n'), &mut app);
+    assert!(!app.user_config.behavior.enable_global_song_count);
+    assert!(!app.user_config.song_count_choice_pending);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Empty);
+
+    for name in ["song-count-yes", "song-count-no"] {
+      let _ = std::fs::remove_file(std::env::temp_dir().join(format!("spotatui-{}.yml", name)));
+    }
+  }
+}
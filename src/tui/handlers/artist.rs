@@ -1,5 +1,8 @@
 use super::common_key_events;
-use crate::core::app::{ActiveBlock, App, ArtistBlock, RecommendationsContext, TrackTableContext};
+use crate::core::app::{
+  ActiveBlock, App, Artist, ArtistBlock, RecommendationsContext, TrackTableContext,
+};
+use crate::core::availability;
 use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
 use rspotify::{model::PlayableId, prelude::*};
@@ -185,24 +188,45 @@ fn handle_recommend_event_on_selected_block(app: &mut App) {
   }
 }
 
+fn top_track_ids(artist: &Artist) -> Vec<PlayableId<'static>> {
+  artist
+    .top_tracks
+    .iter()
+    .filter_map(|track| {
+      track
+        .id
+        .as_ref()
+        .map(|id| PlayableId::Track(id.clone().into_static()))
+    })
+    .collect()
+}
+
+/// Play the whole top tracks list from the start, like the artist page's Play button
+fn handle_play_all_top_tracks(app: &mut App) {
+  if let Some(artist) = &app.artist {
+    let top_tracks = top_track_ids(artist);
+    if !top_tracks.is_empty() {
+      app.dispatch(IoEvent::StartPlayback(None, Some(top_tracks), Some(0)));
+    }
+  }
+}
+
 fn handle_enter_event_on_selected_block(app: &mut App) {
   if let Some(artist) = &mut app.artist.clone() {
     match artist.artist_selected_block {
       ArtistBlock::TopTracks => {
         let selected_index = artist.selected_top_track_index;
-        let top_tracks: Vec<PlayableId<'static>> = artist
+        if let Some(message) = artist
           .top_tracks
-          .iter()
-          .filter_map(|track| {
-            track
-              .id
-              .as_ref()
-              .map(|id| PlayableId::Track(id.clone().into_static()))
-          })
-          .collect();
+          .get(selected_index)
+          .and_then(|track| availability::unavailable_message(track, app.get_user_country()))
+        {
+          app.set_status_message(message, 4);
+          return;
+        }
         app.dispatch(IoEvent::StartPlayback(
           None,
-          Some(top_tracks),
+          Some(top_track_ids(artist)),
           Some(selected_index),
         ));
       }
@@ -306,6 +330,13 @@ pub fn handler(key: Key, app: &mut App) {
           handle_recommend_event_on_selected_block(app);
         }
       }
+      Key::Char('e')
+        if artist.artist_selected_block == ArtistBlock::TopTracks
+          || (artist.artist_selected_block == ArtistBlock::Empty
+            && artist.artist_hovered_block == ArtistBlock::TopTracks) =>
+      {
+        handle_play_all_top_tracks(app);
+      }
       Key::Char('w') => match artist.artist_selected_block {
         ArtistBlock::Albums => app.current_user_saved_album_add(ActiveBlock::ArtistBlock),
         ArtistBlock::RelatedArtists => app.user_follow_artists(ActiveBlock::ArtistBlock),
@@ -316,14 +347,16 @@ pub fn handler(key: Key, app: &mut App) {
         ArtistBlock::RelatedArtists => app.user_unfollow_artists(ActiveBlock::ArtistBlock),
         _ => (),
       },
-      _ if key == app.user_config.keys.add_item_to_queue => {
+      _ if common_key_events::queue_event(key, app) => {
         if let Some(artist) = &app.artist {
           if let ArtistBlock::TopTracks = artist.artist_selected_block {
             if let Some(track) = artist.top_tracks.get(artist.selected_top_track_index) {
               if let Some(track_id) = &track.id {
-                app.dispatch(IoEvent::AddItemToQueue(PlayableId::Track(
-                  track_id.clone().into_static(),
-                )));
+                common_key_events::queue_selected(
+                  app,
+                  key,
+                  PlayableId::Track(track_id.clone().into_static()),
+                );
               }
             };
           }
@@ -348,4 +381,83 @@ mod tests {
     let current_route = app.get_current_route();
     assert_eq!(current_route.active_block, ActiveBlock::Empty);
   }
+
+  fn artist_with_top_tracks(ids: &[&str]) -> Artist {
+    let top_tracks = ids
+      .iter()
+      .map(|id| {
+        serde_json::from_value(serde_json::json!({
+          "album": {
+            "album_type": "album",
+            "artists": [],
+            "available_markets": [],
+            "external_urls": {},
+            "href": null,
+            "id": null,
+            "images": [],
+            "name": "Album",
+          },
+          "artists": [],
+          "available_markets": [],
+          "disc_number": 1,
+          "duration_ms": 1000,
+          "explicit": false,
+          "external_ids": {},
+          "external_urls": {},
+          "href": null,
+          "id": id,
+          "is_local": false,
+          "name": id,
+          "popularity": 0,
+          "preview_url": null,
+          "track_number": 1
+        }))
+        .unwrap()
+      })
+      .collect();
+    Artist {
+      artist_id: "artist".to_string(),
+      artist_name: "Artist".to_string(),
+      albums: serde_json::from_value(serde_json::json!({
+        "href": "",
+        "items": [],
+        "limit": 0,
+        "next": null,
+        "offset": 0,
+        "previous": null,
+        "total": 0
+      }))
+      .unwrap(),
+      related_artists: vec![],
+      top_tracks,
+      selected_album_index: 0,
+      selected_related_artist_index: 0,
+      selected_top_track_index: 1,
+      artist_hovered_block: ArtistBlock::TopTracks,
+      artist_selected_block: ArtistBlock::Empty,
+    }
+  }
+
+  #[test]
+  fn play_all_starts_top_tracks_from_the_first() {
+    let mut app = App::default();
+    app.artist = Some(artist_with_top_tracks(&[
+      "4uLU6hMCjMI75M1A2tKUQC",
+      "3n3Ppam7vgaVa1iaRUc9Lp",
+    ]));
+
+    handler(Key::Char('e'), &mut app);
+    assert!(app.is_loading);
+
+    // Not on the top tracks column, nothing happens
+    app.is_loading = false;
+    if let Some(artist) = &mut app.artist {
+      artist.artist_hovered_block = ArtistBlock::Albums;
+    }
+    handler(Key::Char('e'), &mut app);
+    assert!(!app.is_loading);
+
+    let artist = app.artist.as_ref().unwrap();
+    assert_eq!(top_track_ids(artist).len(), 2);
+  }
 }
@@ -4,6 +4,11 @@ use crate::tui::event::Key;
 use rspotify::model::{context::CurrentPlaybackContext, PlayableId, PlayableItem};
 
 pub fn handler(key: Key, app: &mut App) {
+  if let Key::Char('f') = key {
+    app.lyrics_focus_mode = !app.lyrics_focus_mode;
+    return;
+  }
+
   if let Key::Char('s') = key {
     if let Some(CurrentPlaybackContext {
       item: Some(item), ..
@@ -26,3 +31,20 @@ pub fn handler(key: Key, app: &mut App) {
     };
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn f_toggles_lyrics_focus_mode() {
+    let mut app = App::default();
+    assert!(!app.lyrics_focus_mode);
+
+    handler(Key::Char('f'), &mut app);
+    assert!(app.lyrics_focus_mode);
+
+    handler(Key::Char('f'), &mut app);
+    assert!(!app.lyrics_focus_mode);
+  }
+}
@@ -1,5 +1,7 @@
 use crate::core::app::{ActiveBlock, App, RouteId};
+use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
+use rspotify::model::PlayableId;
 
 pub fn down_event(key: Key) -> bool {
   matches!(key, Key::Down | Key::Char('j') | Key::Ctrl('n'))
@@ -29,6 +31,20 @@ pub fn low_event(key: Key) -> bool {
   matches!(key, Key::Char('L'))
 }
 
+/// The queue key, or the one that queues and skips straight to the track
+pub fn queue_event(key: Key, app: &App) -> bool {
+  key == app.user_config.keys.add_item_to_queue || key == app.user_config.keys.queue_and_skip
+}
+
+/// Queue the selected `item`, playing it right away when `key` is queue-and-skip
+pub fn queue_selected(app: &mut App, key: Key, item: PlayableId<'static>) {
+  if key == app.user_config.keys.queue_and_skip {
+    app.queue_and_skip(item);
+  } else {
+    app.dispatch(IoEvent::AddItemToQueue(item));
+  }
+}
+
 pub fn on_down_press_handler<T>(selection_data: &[T], selection_index: Option<usize>) -> usize {
   match selection_index {
     Some(selection_index) => {
@@ -117,6 +133,9 @@ pub fn handle_right_event(app: &mut App) {
           Some(ActiveBlock::RecentlyPlayed),
         );
       }
+      RouteId::Queue => {
+        app.set_current_route_state(Some(ActiveBlock::Queue), Some(ActiveBlock::Queue));
+      }
       RouteId::Search => {
         app.set_current_route_state(
           Some(ActiveBlock::SearchResultBlock),
@@ -134,6 +153,8 @@ pub fn handle_right_event(app: &mut App) {
       RouteId::Error => {}
       RouteId::Analysis => {}
       RouteId::BasicView => {}
+      RouteId::NowPlaying => {}
+      RouteId::TvView => {}
       RouteId::Dialog => {}
       RouteId::UpdatePrompt => {}
       RouteId::AnnouncementPrompt => {}
@@ -1,5 +1,7 @@
 use super::common_key_events;
-use crate::core::app::{ActiveBlock, App, DialogContext};
+use crate::core::app::{ActiveBlock, App, DialogContext, PendingBulkRemoval, PlaylistTrackAdd};
+#[cfg(feature = "streaming")]
+use crate::core::locale::tf;
 use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
 
@@ -11,9 +13,13 @@ pub fn handler(key: Key, app: &mut App) {
 
   match dialog_context {
     DialogContext::AddTrackToPlaylistPicker => handle_add_to_playlist_picker(key, app),
+    DialogContext::ArtistPicker => handle_artist_picker(key, app),
     DialogContext::PlaylistWindow
     | DialogContext::PlaylistSearch
-    | DialogContext::RemoveTrackFromPlaylistConfirm => {
+    | DialogContext::RemoveTrackFromPlaylistConfirm
+    | DialogContext::BulkRemoveFromLibraryConfirm
+    | DialogContext::DuplicateTrackAddConfirm
+    | DialogContext::CachePlaylistOfflineConfirm => {
       handle_confirmation_dialog(key, app, dialog_context)
     }
   }
@@ -29,7 +35,14 @@ fn handle_confirmation_dialog(key: Key, app: &mut App, dialog_context: DialogCon
           DialogContext::RemoveTrackFromPlaylistConfirm => {
             handle_remove_track_from_playlist_confirm(app);
           }
-          DialogContext::AddTrackToPlaylistPicker => {}
+          DialogContext::BulkRemoveFromLibraryConfirm => handle_bulk_remove_confirm(app),
+          DialogContext::DuplicateTrackAddConfirm => handle_duplicate_add_confirm(app),
+          #[cfg(feature = "streaming")]
+          DialogContext::CachePlaylistOfflineConfirm => handle_offline_cache_confirm(app),
+          // Never opened without a native player to cache with
+          #[cfg(not(feature = "streaming"))]
+          DialogContext::CachePlaylistOfflineConfirm => {}
+          DialogContext::AddTrackToPlaylistPicker | DialogContext::ArtistPicker => {}
         }
       }
       close_dialog(app);
@@ -88,10 +101,21 @@ fn handle_add_to_playlist_picker(key: Key, app: &mut App) {
             .playlist_picker_selected_index
             .min(playlist_count.saturating_sub(1)),
         ) {
-          app.dispatch(IoEvent::AddTrackToPlaylist(
-            playlist.id.clone().into_static(),
-            pending_add.track_id,
-          ));
+          let playlist_id = playlist.id.clone().into_static();
+          if app.user_config.behavior.warn_duplicate_add {
+            let add = PlaylistTrackAdd {
+              playlist_id,
+              playlist_name: playlist.name.clone(),
+              track_id: pending_add.track_id,
+              track_name: pending_add.track_name,
+            };
+            app.dispatch(IoEvent::AddTrackToPlaylistChecked(add));
+          } else {
+            app.dispatch(IoEvent::AddTrackToPlaylist(
+              playlist_id,
+              pending_add.track_id,
+            ));
+          }
         }
       }
       close_dialog(app);
@@ -103,6 +127,34 @@ fn handle_add_to_playlist_picker(key: Key, app: &mut App) {
   }
 }
 
+fn handle_artist_picker(key: Key, app: &mut App) {
+  let selected = Some(app.artist_picker_selected_index);
+  match key {
+    k if common_key_events::down_event(k) => {
+      app.artist_picker_selected_index =
+        common_key_events::on_down_press_handler(&app.artist_picker, selected);
+    }
+    k if common_key_events::up_event(k) => {
+      app.artist_picker_selected_index =
+        common_key_events::on_up_press_handler(&app.artist_picker, selected);
+    }
+    Key::Enter => {
+      let artist = app
+        .artist_picker
+        .get(app.artist_picker_selected_index)
+        .cloned();
+      close_dialog(app);
+      if let Some(artist) = artist {
+        app.open_track_artist(&[artist]);
+      }
+    }
+    Key::Char('q') => {
+      close_dialog(app);
+    }
+    _ => {}
+  }
+}
+
 fn handle_playlist_dialog(app: &mut App) {
   app.user_unfollow_playlist()
 }
@@ -121,10 +173,44 @@ fn handle_remove_track_from_playlist_confirm(app: &mut App) {
   }
 }
 
+fn handle_duplicate_add_confirm(app: &mut App) {
+  if let Some(add) = app.pending_duplicate_track_add.clone() {
+    app.dispatch(IoEvent::AddTrackToPlaylist(add.playlist_id, add.track_id));
+  }
+}
+
+fn handle_bulk_remove_confirm(app: &mut App) {
+  if let Some(removal) = app.pending_bulk_removal.take() {
+    app.set_status_message(
+      format!("Removing {} items from library...", removal.count()),
+      5,
+    );
+    match removal {
+      PendingBulkRemoval::Tracks(track_ids) => {
+        app.dispatch(IoEvent::CurrentUserSavedTracksDelete(track_ids));
+      }
+      PendingBulkRemoval::Albums(album_ids) => {
+        app.dispatch(IoEvent::CurrentUserSavedAlbumsDelete(album_ids));
+      }
+    }
+  }
+}
+
+#[cfg(feature = "streaming")]
+fn handle_offline_cache_confirm(app: &mut App) {
+  if let Some((playlist_id, name)) = app.pending_offline_cache.take() {
+    app.set_status_message(tf("status.offline_cache_started", &[("name", &name)]), 4);
+    app.dispatch(IoEvent::CachePlaylistOffline(playlist_id, name));
+  }
+}
+
 fn close_dialog(app: &mut App) {
   app.pop_navigation_stack();
   app.dialog = None;
   app.confirm = false;
+  app.pending_bulk_removal = None;
+  app.pending_offline_cache = None;
+  app.artist_picker.clear();
   app.clear_playlist_track_dialog_state();
 }
 
@@ -132,6 +218,7 @@ fn close_dialog(app: &mut App) {
 mod tests {
   use super::*;
   use crate::core::app::RouteId;
+  use rspotify::model::idtypes::{PlaylistId, TrackId};
 
   #[test]
   fn confirmation_dialog_toggles_with_vim_hl() {
@@ -148,4 +235,128 @@ mod tests {
     handler(Key::Char('h'), &mut app);
     assert!(!app.confirm);
   }
+
+  #[test]
+  fn confirming_a_duplicate_add_sends_it_and_clears_state() {
+    let mut app = App::default();
+    app.pending_duplicate_track_add = Some(PlaylistTrackAdd {
+      playlist_id: PlaylistId::from_id("37i9dQZF1DXcBWIGoYBM5M")
+        .unwrap()
+        .into_static(),
+      playlist_name: "Today's Top Hits".to_string(),
+      track_id: TrackId::from_id("4iV5W9uYEdYUVa79Axb7Rh")
+        .unwrap()
+        .into_static(),
+      track_name: "New Rules".to_string(),
+    });
+    app.push_navigation_stack(
+      RouteId::Dialog,
+      ActiveBlock::Dialog(DialogContext::DuplicateTrackAddConfirm),
+    );
+    app.confirm = true;
+
+    handler(Key::Enter, &mut app);
+
+    assert!(app.is_loading);
+    assert!(app.pending_duplicate_track_add.is_none());
+    assert_ne!(
+      app.get_current_route().active_block,
+      ActiveBlock::Dialog(DialogContext::DuplicateTrackAddConfirm)
+    );
+  }
+
+  fn road_trip() -> PlaylistId<'static> {
+    PlaylistId::from_id("37i9dQZF1DXcBWIGoYBM5M")
+      .unwrap()
+      .into_static()
+  }
+
+  #[test]
+  fn offline_cache_needs_the_native_audio_cache() {
+    let mut app = App::default();
+    app.begin_offline_cache(road_trip(), "Road Trip".to_string());
+    assert!(app.status_message.is_some());
+    assert!(app.pending_offline_cache.is_none());
+    assert_ne!(
+      app.get_current_route().active_block,
+      ActiveBlock::Dialog(DialogContext::CachePlaylistOfflineConfirm)
+    );
+  }
+
+  #[cfg(feature = "streaming")]
+  #[test]
+  fn confirming_offline_cache_starts_the_download() {
+    let mut app = App::default();
+    app.pending_offline_cache = Some((road_trip(), "Road Trip".to_string()));
+    app.push_navigation_stack(
+      RouteId::Dialog,
+      ActiveBlock::Dialog(DialogContext::CachePlaylistOfflineConfirm),
+    );
+    app.confirm = true;
+
+    handler(Key::Enter, &mut app);
+    assert!(app.is_loading);
+    assert!(app.pending_offline_cache.is_none());
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Caching Road Trip for offline playback...")
+    );
+  }
+
+  #[test]
+  fn bulk_remove_confirm_clears_pending_state() {
+    let mut app = App::default();
+    app.begin_bulk_library_removal(PendingBulkRemoval::Tracks(vec![TrackId::from_id(
+      "4iV5W9uYEdYUVa79Axb7Rh",
+    )
+    .unwrap()
+    .into_static()]));
+    app.confirm = true;
+
+    handler(Key::Enter, &mut app);
+
+    assert!(app.pending_bulk_removal.is_none());
+    assert_ne!(
+      app.get_current_route().active_block,
+      ActiveBlock::Dialog(DialogContext::BulkRemoveFromLibraryConfirm)
+    );
+  }
+
+  #[test]
+  fn artist_picker_only_appears_for_several_artists() {
+    use rspotify::model::artist::SimplifiedArtist;
+
+    let artist = |id: &str, name: &str| -> SimplifiedArtist {
+      serde_json::from_value(serde_json::json!({
+        "external_urls": {},
+        "href": null,
+        "id": id,
+        "name": name
+      }))
+      .unwrap()
+    };
+    let duo = [
+      artist("0TnOYISbd1XYRBk9myaseg", "Pitbull"),
+      artist("7bXgB6jMjp9ATFy66eO08Z", "Chris Brown"),
+    ];
+
+    let mut app = App::default();
+    app.open_track_artist(&duo[..1]);
+    assert!(app.is_loading);
+    assert_eq!(app.get_current_route().id, RouteId::Home);
+
+    let mut app = App::default();
+    app.open_track_artist(&duo);
+    assert!(!app.is_loading);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Dialog(DialogContext::ArtistPicker)
+    );
+
+    handler(Key::Down, &mut app);
+    handler(Key::Enter, &mut app);
+    assert!(app.is_loading);
+    assert!(app.artist_picker.is_empty());
+    assert_eq!(app.get_current_route().id, RouteId::Home);
+  }
 }
@@ -29,21 +29,17 @@ pub fn handler(key: Key, app: &mut App) {
     k if common_key_events::right_event(k) => {
       if app.discover_selected_index == 1 {
         // Only cycle time range when Top Tracks is selected
-        app.discover_time_range = app.discover_time_range.next();
-        // Clear cache so it refetches with new time range
-        app.discover_top_tracks.clear();
+        app.set_discover_time_range(app.discover_time_range.next());
       }
     }
     Key::Char('[') => {
       if app.discover_selected_index == 1 {
-        app.discover_time_range = app.discover_time_range.prev();
-        app.discover_top_tracks.clear();
+        app.set_discover_time_range(app.discover_time_range.prev());
       }
     }
     Key::Char(']') => {
       if app.discover_selected_index == 1 {
-        app.discover_time_range = app.discover_time_range.next();
-        app.discover_top_tracks.clear();
+        app.set_discover_time_range(app.discover_time_range.next());
       }
     }
     Key::Enter => {
@@ -78,7 +74,7 @@ pub fn handler(key: Key, app: &mut App) {
         _ => {}
       }
     }
-    _ if key == app.user_config.keys.add_item_to_queue => {
+    _ if common_key_events::queue_event(key, app) => {
       // Add selected track from top tracks to queue if available
       let tracks = match app.discover_selected_index {
         0 => &app.discover_artists_mix,
@@ -87,9 +83,7 @@ pub fn handler(key: Key, app: &mut App) {
       };
       if let Some(track) = tracks.first() {
         if let Some(track_id) = &track.id {
-          app.dispatch(IoEvent::AddItemToQueue(PlayableId::Track(
-            track_id.clone_static(),
-          )));
+          common_key_events::queue_selected(app, key, PlayableId::Track(track_id.clone_static()));
         }
       }
     }
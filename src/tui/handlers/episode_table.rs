@@ -3,7 +3,10 @@ use crate::core::app::ActiveBlock;
 use crate::core::app::{App, EpisodeTableContext};
 use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
-use rspotify::{model::PlayableId, prelude::*};
+use rspotify::{
+  model::{PlayableId, ResumePoint},
+  prelude::*,
+};
 
 pub fn handler(key: Key, app: &mut App) {
   match key {
@@ -70,14 +73,35 @@ fn on_enter(app: &mut App) {
       .iter()
       .map(|episode| PlayableId::Episode(episode.id.clone().into_static()))
       .collect();
-    app.dispatch(IoEvent::StartPlayback(
-      None,
-      Some(episode_ids),
-      Some(app.episode_list_index),
-    ));
+    let resume_ms = if app.user_config.behavior.resume_episodes {
+      episodes
+        .items
+        .get(app.episode_list_index)
+        .and_then(|episode| resume_position_ms(episode.resume_point.as_ref()))
+    } else {
+      None
+    };
+    let offset = Some(app.episode_list_index);
+    match resume_ms {
+      Some(position_ms) => app.dispatch(IoEvent::StartPlaybackAt(
+        None,
+        Some(episode_ids),
+        offset,
+        position_ms,
+      )),
+      None => app.dispatch(IoEvent::StartPlayback(None, Some(episode_ids), offset)),
+    }
   }
 }
 
+/// Where an unfinished episode was left off. Finished and unstarted ones start
+/// from the beginning.
+fn resume_position_ms(resume_point: Option<&ResumePoint>) -> Option<u32> {
+  let resume_point = resume_point.filter(|point| !point.fully_played)?;
+  let position_ms = resume_point.resume_position.num_milliseconds();
+  (position_ms > 0).then(|| position_ms.min(u32::MAX as i64) as u32)
+}
+
 fn handle_prev_event(app: &mut App) {
   app.get_episode_table_previous();
 }
@@ -133,3 +157,82 @@ fn toggle_sort_by_date(app: &mut App) {
     app.episode_list_index = 0;
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::app::ScrollableResultPages;
+
+  fn app_with_episodes(ids: &[&str]) -> App {
+    let mut app = App::default();
+    let items: Vec<serde_json::Value> = ids
+      .iter()
+      .map(|id| {
+        serde_json::json!({
+          "audio_preview_url": null,
+          "description": "",
+          "duration_ms": 1000,
+          "explicit": false,
+          "external_urls": {},
+          "href": "",
+          "id": id,
+          "images": [],
+          "is_externally_hosted": false,
+          "is_playable": true,
+          "language": "en",
+          "languages": [],
+          "name": id,
+          "release_date": "2024-01-01",
+          "release_date_precision": "day"
+        })
+      })
+      .collect();
+    let page = serde_json::from_value(serde_json::json!({
+      "href": "",
+      "items": items,
+      "limit": 50,
+      "next": null,
+      "offset": 0,
+      "previous": null,
+      "total": ids.len()
+    }))
+    .unwrap();
+    app.library.show_episodes = ScrollableResultPages::new();
+    app.library.show_episodes.add_pages(page);
+    app
+  }
+
+  #[test]
+  fn episodes_after_follow_the_list_order() {
+    let first = "512ojhOuo1ktJprKbVcKyQ";
+    let second = "0Q86acNRm6V9GYx55SXKwf";
+    let mut app = app_with_episodes(&[first, second]);
+
+    let after_first = app
+      .show_episodes_after(&format!("spotify:episode:{}", first))
+      .unwrap();
+    assert_eq!(after_first.len(), 1);
+    assert_eq!(after_first[0].id(), second);
+    assert_eq!(app.show_episodes_after(second), Some(vec![]));
+    assert_eq!(app.show_episodes_after("4uLU6hMCjMI75M1A2tKUQC"), None);
+
+    // Sorting by date flips which episode comes next
+    handler(Key::Char('S'), &mut app);
+    assert_eq!(app.show_episodes_after(first), Some(vec![]));
+  }
+
+  #[test]
+  fn only_unfinished_episodes_resume() {
+    let point = |fully_played, ms| ResumePoint {
+      fully_played,
+      resume_position: chrono::Duration::milliseconds(ms),
+    };
+    assert_eq!(
+      resume_position_ms(Some(&point(false, 754_000))),
+      Some(754_000)
+    );
+    assert_eq!(resume_position_ms(Some(&point(true, 754_000))), None);
+    assert_eq!(resume_position_ms(Some(&point(false, 0))), None);
+    assert_eq!(resume_position_ms(None), None);
+  }
+}
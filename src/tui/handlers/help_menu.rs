@@ -97,7 +97,7 @@ mod tests {
     assert_eq!(app.get_current_route().id, RouteId::HelpMenu);
 
     // Close help menu with 'q' (simulating the back key handling in main.rs)
-    let back_key = app.user_config.keys.back;
+    let back_key = app.user_config.keys.back.primary();
     assert_eq!(back_key, Key::Char('q'));
 
     let pop_result = app.pop_navigation_stack();
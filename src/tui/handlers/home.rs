@@ -1,20 +1,30 @@
 use super::common_key_events;
 use crate::core::app::App;
 use crate::tui::event::Key;
+use std::time::Instant;
 
 const LARGE_SCROLL: u16 = 10;
 const SMALL_SCROLL: u16 = 1;
 
 pub fn handler(key: Key, app: &mut App) {
+  let step = app.user_config.behavior.home_scroll_step.max(SMALL_SCROLL);
+  // Manual scrolling pushes back the next auto-scroll step
+  app.home_last_auto_scroll = Instant::now();
+
   match key {
     k if common_key_events::left_event(k) => common_key_events::handle_left_event(app),
+    Key::Char('m') => app.toggle_home_mode(),
     k if common_key_events::down_event(k) => {
-      app.home_scroll += SMALL_SCROLL;
+      app.home_scroll = app.home_scroll.saturating_add(step);
     }
     k if common_key_events::up_event(k) => {
-      if app.home_scroll > 0 {
-        app.home_scroll -= SMALL_SCROLL;
-      }
+      app.home_scroll = app.home_scroll.saturating_sub(step);
+    }
+    k if k == app.user_config.keys.jump_to_start => {
+      app.home_scroll = 0;
+    }
+    k if k == app.user_config.keys.jump_to_end => {
+      app.home_scroll = app.home_scroll_max.get();
     }
     k if k == app.user_config.keys.next_page => {
       app.home_scroll += LARGE_SCROLL;
@@ -34,6 +44,20 @@ pub fn handler(key: Key, app: &mut App) {
 mod tests {
   use super::*;
 
+  #[test]
+  fn m_toggles_between_changelog_and_stats() {
+    use crate::core::app::HomeMode;
+
+    let mut app = App::default();
+    assert_eq!(app.home_mode, HomeMode::Changelog);
+
+    handler(Key::Char('m'), &mut app);
+    assert_eq!(app.home_mode, HomeMode::Stats);
+
+    handler(Key::Char('m'), &mut app);
+    assert_eq!(app.home_mode, HomeMode::Changelog);
+  }
+
   #[test]
   fn on_small_down_press() {
     let mut app = App::default();
@@ -75,6 +99,32 @@ mod tests {
     assert_eq!(app.home_scroll, LARGE_SCROLL * 2);
   }
 
+  #[test]
+  fn scroll_step_is_configurable() {
+    let mut app = App::default();
+    app.user_config.behavior.home_scroll_step = 3;
+
+    handler(Key::Down, &mut app);
+    assert_eq!(app.home_scroll, 3);
+
+    handler(Key::Up, &mut app);
+    handler(Key::Up, &mut app);
+    assert_eq!(app.home_scroll, 0);
+  }
+
+  #[test]
+  fn jump_to_top_and_bottom() {
+    let mut app = App::default();
+    app.home_scroll_max.set(120);
+    app.home_scroll = 40;
+
+    handler(Key::Ctrl('e'), &mut app);
+    assert_eq!(app.home_scroll, 120);
+
+    handler(Key::Ctrl('a'), &mut app);
+    assert_eq!(app.home_scroll, 0);
+  }
+
   #[test]
   fn on_large_up_press() {
     let mut app = App::default();
@@ -127,6 +127,22 @@ fn spotify_resource_id(base: &str, uri: &str, sep: &str, resource_type: &str) ->
   (id_string, matched)
 }
 
+/// Canonical `spotify:<type>:<id>` URI for a Spotify URI or open.spotify.com link
+pub fn canonical_spotify_uri(input: &str) -> Option<String> {
+  const RESOURCE_TYPES: [&str; 6] = ["album", "artist", "track", "playlist", "show", "episode"];
+
+  let input = input.trim();
+  for (base, sep) in [("https://open.spotify.com/", "/"), ("spotify:", ":")] {
+    for resource_type in RESOURCE_TYPES {
+      let (id, matched) = spotify_resource_id(base, input, sep, resource_type);
+      if matched && !id.is_empty() {
+        return Some(format!("spotify:{}:{}", resource_type, id));
+      }
+    }
+  }
+  None
+}
+
 // Returns true if the input was successfully processed as a Spotify URI.
 fn attempt_process_uri(app: &mut App, input: &str, base: &str, sep: &str) -> bool {
   let (album_id, matched) = spotify_resource_id(base, input, sep, "album");
@@ -33,14 +33,9 @@ pub fn handler(key: Key, app: &mut App) {
     // `library` should probably be an array of structs with enums rather than just using indexes
     // like this
     Key::Enter => match app.library.selected_index {
-      0 => {
-        app.push_navigation_stack(RouteId::Discover, ActiveBlock::Discover);
-      }
+      0 => app.open_discover(),
       // Recently Played,
-      1 => {
-        app.dispatch(IoEvent::GetRecentlyPlayed);
-        app.push_navigation_stack(RouteId::RecentlyPlayed, ActiveBlock::RecentlyPlayed);
-      }
+      1 => app.open_recently_played(),
       // Liked Songs,
       2 => {
         app.dispatch(IoEvent::GetCurrentSavedTracks(None));
@@ -63,6 +58,8 @@ pub fn handler(key: Key, app: &mut App) {
         app.dispatch(IoEvent::GetCurrentUserSavedShows(None));
         app.push_navigation_stack(RouteId::Podcasts, ActiveBlock::Podcasts);
       }
+      // Queue,
+      6 => app.open_queue(),
       // This is required because Rust can't tell if this pattern in exhaustive
       _ => {}
     },
@@ -0,0 +1,176 @@
+use super::playlist;
+use crate::core::app::{
+  ActiveBlock, AlbumTableContext, App, RouteId, SelectedFullAlbum, TrackTableContext,
+};
+use crate::core::library_search::{self, LibraryItem};
+use crate::tui::event::Key;
+
+/// Keys typed while the library search overlay is open. Text goes into the
+/// query, so only arrow keys move the selection.
+pub fn handler(key: Key, app: &mut App) {
+  let Some((query, selected)) = app.library_search.clone() else {
+    return;
+  };
+  let count = library_search::search(app, &query).len();
+  match key {
+    Key::Esc => app.library_search = None,
+    Key::Enter => {
+      if let Some(found) = library_search::search(app, &query).get(selected) {
+        app.library_search = None;
+        open(app, found.item);
+      }
+    }
+    Key::Down | Key::Ctrl('n') => {
+      if count > 0 {
+        app.library_search = Some((query, (selected + 1) % count));
+      }
+    }
+    Key::Up | Key::Ctrl('p') => {
+      if count > 0 {
+        let previous = if selected == 0 {
+          count - 1
+        } else {
+          selected - 1
+        };
+        app.library_search = Some((query, previous));
+      }
+    }
+    Key::Backspace => {
+      let mut query = query;
+      query.pop();
+      app.library_search = Some((query, 0));
+    }
+    Key::Char(c) => {
+      let mut query = query;
+      query.push(c);
+      app.library_search = Some((query, 0));
+    }
+    _ => {}
+  }
+}
+
+fn open(app: &mut App, item: LibraryItem) {
+  match item {
+    LibraryItem::Track { page, index } => {
+      let Some(saved) = app.library.saved_tracks.pages.get(page) else {
+        return;
+      };
+      app.track_table.tracks = saved.items.iter().map(|item| item.track.clone()).collect();
+      app.track_table.selected_index = index;
+      app.track_table.context = Some(TrackTableContext::SavedTracks);
+      app.library.saved_tracks.index = page;
+      app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+    }
+    LibraryItem::Album { page, index } => {
+      let Some(saved) = app
+        .library
+        .saved_albums
+        .pages
+        .get(page)
+        .and_then(|saved| saved.items.get(index))
+      else {
+        return;
+      };
+      app.selected_album_full = Some(SelectedFullAlbum {
+        album: saved.album.clone(),
+        selected_index: 0,
+      });
+      app.album_table_context = AlbumTableContext::Full;
+      app.push_navigation_stack(RouteId::AlbumTracks, ActiveBlock::AlbumTracks);
+    }
+    LibraryItem::Artist { page, index } => {
+      let Some(artist) = app
+        .library
+        .saved_artists
+        .pages
+        .get(page)
+        .and_then(|saved| saved.items.get(index))
+      else {
+        return;
+      };
+      let (id, name) = (artist.id.clone().into_static(), artist.name.clone());
+      app.get_artist(id, name);
+    }
+    LibraryItem::Playlist(index) => playlist::open_playlist(app, index),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tui::handlers::handle_app;
+
+  fn app_with_library() -> App {
+    let mut app = App::default();
+    app.all_playlists = ["Road Trip", "Jazz"]
+      .iter()
+      .enumerate()
+      .map(|(i, name)| {
+        serde_json::from_value(serde_json::json!({
+          "collaborative": false,
+          "external_urls": {},
+          "href": "",
+          "id": format!("37i9dQZF1DXcBWIGoYBM5{}", i),
+          "images": [],
+          "name": name,
+          "owner": { "external_urls": {}, "href": "", "id": "owner" },
+          "public": true,
+          "snapshot_id": "",
+          "tracks": { "href": "", "total": 0 },
+        }))
+        .unwrap()
+      })
+      .collect();
+    app.library.saved_artists.add_pages(
+      serde_json::from_value(serde_json::json!({
+        "href": "",
+        "items": [{
+          "external_urls": {},
+          "followers": { "href": null, "total": 0 },
+          "genres": [],
+          "href": "",
+          "id": "0OdUWJ0sBjDrqHygGUXeCF",
+          "images": [],
+          "name": "Roadrunners",
+          "popularity": 0,
+        }],
+        "limit": 20,
+        "next": null,
+        "cursors": { "after": null },
+        "total": 1,
+      }))
+      .unwrap(),
+    );
+    app
+  }
+
+  #[test]
+  fn typing_filters_loaded_items_and_enter_opens_the_selection() {
+    let mut app = app_with_library();
+    handle_app(Key::Alt('f'), &mut app);
+    for c in "road".chars() {
+      handle_app(Key::Char(c), &mut app);
+    }
+
+    let matches = library_search::search(&app, "road");
+    let names: Vec<_> = matches.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["Roadrunners", "Road Trip"]);
+    assert_eq!(app.library_search, Some(("road".to_string(), 0)));
+
+    handle_app(Key::Down, &mut app);
+    handle_app(Key::Enter, &mut app);
+    assert_eq!(app.library_search, None);
+    assert_eq!(app.active_playlist_index, Some(0));
+  }
+
+  #[test]
+  fn escape_closes_without_opening_anything() {
+    let mut app = app_with_library();
+    handle_app(Key::Alt('f'), &mut app);
+    handle_app(Key::Char('j'), &mut app);
+    handle_app(Key::Esc, &mut app);
+    assert_eq!(app.library_search, None);
+    assert_eq!(app.active_playlist_index, None);
+    assert!(library_search::search(&app, "").is_empty());
+  }
+}
@@ -15,10 +15,12 @@ mod help_menu;
 mod home;
 mod input;
 mod library;
+mod library_search;
 mod mouse;
 mod playbar;
 mod playlist;
 mod podcasts;
+mod queue;
 mod recently_played;
 mod search_results;
 mod select_device;
@@ -27,23 +29,98 @@ mod sort_menu;
 mod track_table;
 mod update_prompt;
 
-use crate::core::app::{ActiveBlock, App, ArtistBlock, RouteId, SearchResultBlock};
+use crate::core::app::{
+  ActiveBlock, AlbumTableContext, App, ArtistBlock, RouteId, SearchResultBlock,
+};
+use crate::core::locale::t;
+use crate::core::user_config::SpaceAction;
 use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
 use rspotify::model::idtypes::PlaylistId;
 use rspotify::model::{context::CurrentPlaybackContext, PlayableItem};
+use rspotify::prelude::Id;
+use std::time::{Duration, Instant};
 
-pub use input::handler as input_handler;
+pub use input::{canonical_spotify_uri, handler as input_handler};
 pub use mouse::handler as mouse_handler;
 
+/// How long the first key of a chord waits for the second one
+const CHORD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Combine two key presses into the `Key::Chord` they are bound to.
+///
+/// The first key of a chord is held and reported as `Key::Unknown`. If the next
+/// key (within `CHORD_TIMEOUT`) doesn't complete a bound chord, it is handled on
+/// its own and the held key is dropped. Text entry never forms chords.
+pub fn resolve_chord(key: Key, app: &mut App) -> Key {
+  let pending = app
+    .pending_chord
+    .take()
+    .filter(|(_, pressed_at)| pressed_at.elapsed() < CHORD_TIMEOUT);
+
+  let typing = matches!(
+    app.get_current_route().active_block,
+    ActiveBlock::Input | ActiveBlock::ExitPrompt | ActiveBlock::Settings
+  ) || app.is_playlist_filter_open()
+    || app.new_playlist.is_some()
+    || app.playlist_details_edit.is_some()
+    || app.library_search.is_some();
+  let Key::Char(c) = key else {
+    return key;
+  };
+  if typing {
+    return key;
+  }
+
+  if let Some((first, _)) = pending {
+    let chord = Key::Chord(first, c);
+    if app.user_config.keys.has_chord(chord) {
+      return chord;
+    }
+  }
+
+  if app.user_config.keys.starts_chord(c) {
+    app.pending_chord = Some((c, Instant::now()));
+    return Key::Unknown;
+  }
+  key
+}
+
 pub fn handle_app(key: Key, app: &mut App) {
-  if app.get_current_route().active_block == ActiveBlock::Settings
-    && app.settings_unsaved_prompt_visible
+  // The settings filter takes every key too, so typing isn't caught by global bindings
+  if app.get_current_route().active_block == ActiveBlock::Settings && settings::takes_key(key, app)
   {
     settings::handler(key, app);
     return;
   }
 
+  // Announcements are modal, so global bindings like "next track" stay quiet
+  if app.get_current_route().active_block == ActiveBlock::AnnouncementPrompt {
+    announcement_prompt::handler(key, app);
+    return;
+  }
+
+  if app.new_playlist.is_some() {
+    playlist::new_playlist_handler(key, app);
+    return;
+  }
+
+  if app.playlist_details_edit.is_some() {
+    playlist::playlist_details_handler(key, app);
+    return;
+  }
+
+  if app.library_search.is_some() {
+    library_search::handler(key, app);
+    return;
+  }
+
+  // The sidebar filter takes every key so typing isn't caught by global bindings
+  if app.is_playlist_filter_open() {
+    playlist::filter_handler(key, app);
+    return;
+  }
+
   // First handle any global event and then move to block event
   match key {
     Key::Esc => {
@@ -62,15 +139,24 @@ pub fn handle_app(key: Key, app: &mut App) {
     _ if key == app.user_config.keys.jump_to_context => {
       handle_jump_to_context(app);
     }
+    _ if key == app.user_config.keys.jump_to_playing => {
+      handle_jump_to_playing_row(app);
+    }
     _ if key == app.user_config.keys.manage_devices => {
       app.dispatch(IoEvent::GetDevices);
     }
+    _ if key == app.user_config.keys.toggle_native_playback => {
+      app.toggle_native_playback();
+    }
     _ if key == app.user_config.keys.decrease_volume => {
       app.decrease_volume();
     }
     _ if key == app.user_config.keys.increase_volume => {
       app.increase_volume();
     }
+    Key::Char(' ') if app.user_config.keys.space_action != SpaceAction::TogglePlayback => {
+      handle_space_action(app);
+    }
     // Press space to toggle playback
     _ if key == app.user_config.keys.toggle_playback => {
       app.toggle_playback();
@@ -81,6 +167,12 @@ pub fn handle_app(key: Key, app: &mut App) {
     _ if key == app.user_config.keys.seek_forwards => {
       app.seek_forwards();
     }
+    _ if key == app.user_config.keys.seek_backwards_large => {
+      app.seek_backwards_large();
+    }
+    _ if key == app.user_config.keys.seek_forwards_large => {
+      app.seek_forwards_large();
+    }
     _ if key == app.user_config.keys.next_track => {
       app.next_track();
     }
@@ -97,6 +189,14 @@ pub fn handle_app(key: Key, app: &mut App) {
     _ if key == app.user_config.keys.repeat => {
       app.repeat();
     }
+    _ if key == app.user_config.keys.repeat_one_or_all => {
+      app.toggle_repeat_one_or_all();
+    }
+    _ if key == app.user_config.keys.search
+      && app.get_current_route().active_block == ActiveBlock::MyPlaylists =>
+    {
+      handle_block_events(key, app);
+    }
     _ if key == app.user_config.keys.search => {
       app.set_current_route_state(Some(ActiveBlock::Input), Some(ActiveBlock::Input));
     }
@@ -106,16 +206,59 @@ pub fn handle_app(key: Key, app: &mut App) {
     _ if key == app.user_config.keys.copy_album_url => {
       app.copy_album_url();
     }
+    _ if key == app.user_config.keys.copy_song_url_at_position => {
+      app.copy_song_url_at_position();
+    }
     _ if key == app.user_config.keys.audio_analysis => {
       app.get_audio_analysis();
     }
     _ if key == app.user_config.keys.basic_view => {
       app.push_navigation_stack(RouteId::BasicView, ActiveBlock::BasicView);
     }
+    _ if key == app.user_config.keys.now_playing => {
+      app.push_navigation_stack(RouteId::NowPlaying, ActiveBlock::NowPlaying);
+    }
+    _ if key == app.user_config.keys.tv_view => {
+      if app.get_current_route().id == RouteId::TvView {
+        app.pop_navigation_stack();
+      } else {
+        app.push_navigation_stack(RouteId::TvView, ActiveBlock::TvView);
+      }
+    }
     _ if key == app.user_config.keys.open_settings => {
       app.load_settings_for_category();
       app.push_navigation_stack(RouteId::Settings, ActiveBlock::Settings);
     }
+    _ if key == app.user_config.keys.open_queue => {
+      app.open_queue();
+    }
+    _ if key == app.user_config.keys.open_discover => {
+      app.open_discover();
+    }
+    _ if key == app.user_config.keys.open_recently_played => {
+      app.open_recently_played();
+    }
+    _ if key == app.user_config.keys.decrease_seek_step => {
+      app.adjust_seek_step(false);
+    }
+    _ if key == app.user_config.keys.increase_seek_step => {
+      app.adjust_seek_step(true);
+    }
+    _ if key == app.user_config.keys.toggle_prevent_sleep => {
+      app.toggle_prevent_sleep();
+    }
+    _ if key == app.user_config.keys.library_recommendations => {
+      app.get_library_recommendations_for_playing();
+    }
+    _ if key == app.user_config.keys.ab_loop => {
+      app.cycle_ab_loop();
+    }
+    _ if key == app.user_config.keys.cycle_discover_time_range => {
+      app.cycle_discover_time_range();
+    }
+    _ if key == app.user_config.keys.search_library => {
+      app.library_search = Some((String::new(), 0));
+    }
     Key::Char('W') => match app.get_current_route().active_block {
       ActiveBlock::Input
       | ActiveBlock::Dialog(_)
@@ -130,6 +273,18 @@ pub fn handle_app(key: Key, app: &mut App) {
   }
 }
 
+fn handle_space_action(app: &mut App) {
+  match app.user_config.keys.space_action {
+    SpaceAction::TogglePlayback => app.toggle_playback(),
+    SpaceAction::LikeTrack => playbar::toggle_save_currently_playing(app),
+    // Queue whatever the focused block would queue with the regular binding
+    SpaceAction::AddToQueue => {
+      let queue_key = app.user_config.keys.add_item_to_queue.primary();
+      handle_block_events(queue_key, app);
+    }
+  }
+}
+
 // Handle event for the current active block
 fn handle_block_events(key: Key, app: &mut App) {
   let current_route = app.get_current_route();
@@ -182,6 +337,9 @@ fn handle_block_events(key: Key, app: &mut App) {
     ActiveBlock::RecentlyPlayed => {
       recently_played::handler(key, app);
     }
+    ActiveBlock::Queue => {
+      queue::handler(key, app);
+    }
     ActiveBlock::Artists => {
       artists::handler(key, app);
     }
@@ -207,6 +365,8 @@ fn handle_block_events(key: Key, app: &mut App) {
       announcement_prompt::handler(key, app);
     }
     ActiveBlock::ExitPrompt => {}
+    // Only the global playback keys apply here
+    ActiveBlock::NowPlaying | ActiveBlock::TvView => {}
     ActiveBlock::Settings => {
       settings::handler(key, app);
     }
@@ -233,9 +393,10 @@ fn handle_escape(app: &mut App) {
       app.pop_navigation_stack();
       app.dialog = None;
       app.confirm = false;
+      app.pending_bulk_removal = None;
       app.clear_playlist_track_dialog_state();
     }
-    ActiveBlock::HelpMenu => {
+    ActiveBlock::HelpMenu | ActiveBlock::NowPlaying | ActiveBlock::TvView => {
       app.pop_navigation_stack();
     }
     // These are global views that have no active/inactive distinction so do nothing
@@ -274,6 +435,84 @@ fn handle_jump_to_context(app: &mut App) {
   }
 }
 
+// Selects the playing song in the focused table, without loading anything
+fn handle_jump_to_playing_row(app: &mut App) {
+  let playing_id = match &app.current_playback_context {
+    Some(CurrentPlaybackContext {
+      item: Some(PlayableItem::Track(track)),
+      ..
+    }) => track.id.as_ref().map(|id| id.id().to_string()),
+    Some(CurrentPlaybackContext {
+      item: Some(PlayableItem::Episode(episode)),
+      ..
+    }) => Some(episode.id.id().to_string()),
+    _ => None,
+  };
+  let Some(playing_id) = playing_id else {
+    return;
+  };
+  let is_playing = |id: Option<&str>| id == Some(playing_id.as_str());
+
+  let found = match app.get_current_route().active_block {
+    ActiveBlock::TrackTable => app
+      .track_table
+      .tracks
+      .iter()
+      .position(|track| is_playing(track.id.as_ref().map(|id| id.id())))
+      .map(|index| app.track_table.selected_index = index),
+    ActiveBlock::AlbumTracks => match app.album_table_context {
+      AlbumTableContext::Full => app
+        .selected_album_full
+        .as_ref()
+        .and_then(|selected| {
+          selected
+            .album
+            .tracks
+            .items
+            .iter()
+            .position(|track| is_playing(track.id.as_ref().map(|id| id.id())))
+        })
+        .map(|index| app.saved_album_tracks_index = index),
+      AlbumTableContext::Simplified => {
+        app.selected_album_simplified.as_mut().and_then(|selected| {
+          selected
+            .tracks
+            .items
+            .iter()
+            .position(|track| is_playing(track.id.as_ref().map(|id| id.id())))
+            .map(|index| selected.selected_index = index)
+        })
+      }
+    },
+    ActiveBlock::RecentlyPlayed => app
+      .recently_played
+      .result
+      .as_ref()
+      .and_then(|recent| {
+        recent
+          .items
+          .iter()
+          .position(|item| is_playing(item.track.id.as_ref().map(|id| id.id())))
+      })
+      .map(|index| app.recently_played.index = index),
+    ActiveBlock::EpisodeTable => app
+      .library
+      .show_episodes
+      .get_results(None)
+      .and_then(|episodes| {
+        episodes
+          .items
+          .iter()
+          .position(|episode| is_playing(Some(episode.id.id())))
+      })
+      .map(|index| app.episode_list_index = index),
+    _ => return,
+  };
+  if found.is_none() {
+    app.set_status_message(t("status.playing_not_in_list"), 3);
+  }
+}
+
 fn handle_jump_to_album(app: &mut App) {
   if let Some(CurrentPlaybackContext {
     item: Some(item), ..
@@ -290,20 +529,14 @@ fn handle_jump_to_album(app: &mut App) {
   }
 }
 
-// NOTE: this only finds the first artist of the song and jumps to their albums
+// Jumps to the song's artist, asking which one when the song has several
 fn handle_jump_to_artist_album(app: &mut App) {
   if let Some(CurrentPlaybackContext {
     item: Some(item), ..
   }) = app.current_playback_context.to_owned()
   {
     match item {
-      PlayableItem::Track(track) => {
-        if let Some(artist) = track.artists.first() {
-          if let Some(artist_id) = &artist.id {
-            app.get_artist(artist_id.as_ref().into_static(), artist.name.clone());
-          }
-        }
-      }
+      PlayableItem::Track(track) => app.open_track_artist(&track.artists),
       PlayableItem::Episode(_episode) => {
         // Do nothing for episode (yet!)
       }
@@ -315,6 +548,564 @@ fn handle_jump_to_artist_album(app: &mut App) {
 mod tests {
   use super::*;
 
+  fn play_on_external_device(app: &mut App) {
+    app.current_playback_context = Some(
+      serde_json::from_value(serde_json::json!({
+        "device": {
+          "id": "speaker-id",
+          "is_active": true,
+          "is_private_session": false,
+          "is_restricted": false,
+          "name": "Kitchen",
+          "type": "Speaker",
+          "volume_percent": 40
+        },
+        "repeat_state": "off",
+        "shuffle_state": false,
+        "context": null,
+        "timestamp": 0,
+        "progress_ms": 0,
+        "is_playing": true,
+        "item": null,
+        "currently_playing_type": "unknown",
+        "actions": { "disallows": {} }
+      }))
+      .unwrap(),
+    );
+  }
+
+  #[test]
+  fn native_toggle_reports_where_playback_goes() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    app.last_external_device = Some(("speaker-id".to_string(), "Kitchen".to_string()));
+
+    // Without a native player the only direction is away from it
+    #[cfg(not(feature = "streaming"))]
+    {
+      handle_app(Key::Alt('d'), &mut app);
+      assert_eq!(
+        app.status_message.as_deref(),
+        Some("Switching playback to Kitchen")
+      );
+    }
+    #[cfg(feature = "streaming")]
+    {
+      handle_app(Key::Alt('d'), &mut app);
+      assert_eq!(
+        app.status_message.as_deref(),
+        Some("Native playback is not running")
+      );
+    }
+  }
+
+  #[test]
+  fn chords_combine_two_key_presses() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    app.user_config.keys.jump_to_album = Key::Chord('g', 'a').into();
+
+    assert_eq!(resolve_chord(Key::Char('g'), &mut app), Key::Unknown);
+    assert_eq!(
+      resolve_chord(Key::Char('a'), &mut app),
+      Key::Chord('g', 'a')
+    );
+
+    // A second key that completes no chord is handled by itself
+    assert_eq!(resolve_chord(Key::Char('g'), &mut app), Key::Unknown);
+    assert_eq!(resolve_chord(Key::Char('x'), &mut app), Key::Char('x'));
+    assert!(app.pending_chord.is_none());
+
+    // The held key expires
+    app.pending_chord = Some(('g', Instant::now() - CHORD_TIMEOUT));
+    assert_eq!(resolve_chord(Key::Char('a'), &mut app), Key::Char('a'));
+
+    // Typing into the search box never forms chords
+    app.set_current_route_state(Some(ActiveBlock::Input), Some(ActiveBlock::Input));
+    assert_eq!(resolve_chord(Key::Char('g'), &mut app), Key::Char('g'));
+  }
+
+  #[test]
+  fn held_volume_key_sends_only_the_latest_value() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    app.user_config.behavior.volume_increment = 5;
+    play_on_external_device(&mut app);
+
+    for _ in 0..3 {
+      handle_app(app.user_config.keys.increase_volume.primary(), &mut app);
+    }
+
+    // The UI follows every press, the API only gets the first and the latest
+    let volume = |app: &App| {
+      app
+        .current_playback_context
+        .as_ref()
+        .unwrap()
+        .device
+        .volume_percent
+    };
+    assert_eq!(volume(&app), Some(55));
+    assert!(app.last_api_volume.is_some());
+    assert_eq!(app.pending_volume, Some(55));
+
+    app.last_api_volume = Some(Instant::now() - std::time::Duration::from_millis(200));
+    app.flush_pending_volume();
+    assert_eq!(app.pending_volume, None);
+  }
+
+  #[test]
+  fn polled_volume_is_adopted_unless_our_own_change_is_newer() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    app.user_config.behavior.volume_increment = 5;
+    play_on_external_device(&mut app);
+    let poll = |app: &mut App, volume: u32| {
+      let mut context = app.current_playback_context.clone().unwrap();
+      context.device.volume_percent = Some(volume);
+      app.sync_polled_volume(&mut context);
+      app.current_playback_context = Some(context);
+    };
+    let volume = |app: &App| {
+      app
+        .current_playback_context
+        .as_ref()
+        .unwrap()
+        .device
+        .volume_percent
+    };
+
+    // A poll sent before our change reached the device doesn't undo it
+    handle_app(app.user_config.keys.increase_volume.primary(), &mut app);
+    assert_eq!(volume(&app), Some(45));
+    poll(&mut app, 40);
+    assert_eq!(volume(&app), Some(45));
+
+    // Once that has settled, a change from the phone is taken over right away
+    app.volume_dirty_until = Some(Instant::now() - std::time::Duration::from_millis(1));
+    poll(&mut app, 80);
+    assert_eq!(volume(&app), Some(80));
+    assert_eq!(app.user_config.behavior.volume_percent, 80);
+
+    // And the next key press carries on from it
+    app.last_api_volume = None;
+    handle_app(app.user_config.keys.decrease_volume.primary(), &mut app);
+    assert_eq!(volume(&app), Some(75));
+    assert!(app.volume_dirty_until.is_some());
+  }
+
+  #[test]
+  fn playlists_auto_refresh_on_their_interval_but_not_under_a_prompt() {
+    let mut app = App::default();
+    app.user_config.behavior.playlist_auto_refresh_secs = 60;
+    app.playlists_fully_loaded = true;
+
+    // The startup fetch counts as the first refresh
+    app.update_on_tick();
+    let started = app.last_playlist_auto_refresh.unwrap();
+    app.update_on_tick();
+    assert_eq!(app.last_playlist_auto_refresh, Some(started));
+
+    let due = Instant::now() - std::time::Duration::from_secs(61);
+    app.last_playlist_auto_refresh = Some(due);
+    app.new_playlist = Some((String::new(), true));
+    app.update_on_tick();
+    assert_eq!(app.last_playlist_auto_refresh, Some(due));
+
+    app.new_playlist = None;
+    app.update_on_tick();
+    assert!(app.last_playlist_auto_refresh.unwrap() > due);
+
+    // A too short interval is raised to the minimum
+    app.user_config.behavior.playlist_auto_refresh_secs = 1;
+    let recent = Instant::now() - std::time::Duration::from_secs(2);
+    app.last_playlist_auto_refresh = Some(recent);
+    app.update_on_tick();
+    assert_eq!(app.last_playlist_auto_refresh, Some(recent));
+  }
+
+  #[test]
+  fn previous_restarts_late_in_a_track_unless_pressed_twice() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    let previous = app.user_config.keys.previous_track.primary();
+
+    // Early in the track goes straight to the previous one
+    app.song_progress_ms = 2_000;
+    handle_app(previous, &mut app);
+    assert_eq!(app.last_api_seek, None);
+    assert_eq!(app.song_progress_ms, 2_000);
+
+    // Late in the track restarts it, once the last press is outside the window
+    app.last_previous_press = Some(Instant::now() - Duration::from_secs(3));
+    app.song_progress_ms = 60_000;
+    handle_app(previous, &mut app);
+    assert!(app.last_api_seek.is_some());
+    assert_eq!(app.song_progress_ms, 0);
+
+    // A second press within two seconds skips back even if the poll moved progress on
+    app.last_api_seek = None;
+    app.song_progress_ms = 5_000;
+    handle_app(previous, &mut app);
+    assert_eq!(app.last_api_seek, None);
+    assert_eq!(app.song_progress_ms, 5_000);
+  }
+
+  /// Playing a 200 second track on an external device, `progress_ms` in as of a poll `polled_ago`
+  fn playing_track(progress_ms: u32, polled_ago: Duration) -> App {
+    use crate::core::fixtures;
+    let mut app = App::default();
+    let track = fixtures::track("Harbor Lights", "The Tides", "Coastline", 200_000);
+    app.current_playback_context = Some(fixtures::playback(
+      &fixtures::device("Kitchen", true),
+      &track,
+      true,
+      progress_ms,
+    ));
+    app.instant_since_last_current_playback_poll = Instant::now() - polled_ago;
+    app.song_progress_anchor = (u128::from(progress_ms), Instant::now() - polled_ago);
+    app
+  }
+
+  #[test]
+  fn position_link_notes_where_playback_is() {
+    let mut app = playing_track(83_000, Duration::ZERO);
+    app.set_song_progress(83_000);
+    assert_eq!(
+      app.playing_url_at_position().as_deref(),
+      Some("https://open.spotify.com/track/HarborLightsHarborLigh @1:23")
+    );
+
+    app.current_playback_context = None;
+    assert_eq!(app.playing_url_at_position(), None);
+  }
+
+  #[test]
+  fn progress_extrapolates_from_the_last_report_and_freezes_while_paused() {
+    let mut app = playing_track(10_000, Duration::from_millis(100));
+    app.update_on_tick();
+    assert!((10_100..10_400).contains(&app.song_progress_ms));
+
+    // Between polls it follows the clock, not the number of ticks
+    app.song_progress_anchor = (10_000, Instant::now() - Duration::from_millis(1_500));
+    app.update_on_tick();
+    assert!((11_500..11_800).contains(&app.song_progress_ms));
+
+    app.song_progress_anchor = (199_000, Instant::now() - Duration::from_secs(5));
+    app.update_on_tick();
+    assert_eq!(app.song_progress_ms, 200_000);
+
+    app.set_song_progress(50_000);
+    app.song_progress_anchor.1 -= Duration::from_secs(2);
+    app.current_playback_context.as_mut().unwrap().is_playing = false;
+    app.update_on_tick();
+    assert_eq!(app.song_progress_ms, 50_000);
+
+    // Resuming carries on from where it froze
+    app.current_playback_context.as_mut().unwrap().is_playing = true;
+    app.update_on_tick();
+    assert!((50_000..50_300).contains(&app.song_progress_ms));
+  }
+
+  #[test]
+  fn progress_waits_at_the_extrapolation_cap_for_the_next_report() {
+    let mut app = playing_track(10_000, Duration::from_secs(1));
+    app.song_progress_anchor = (10_000, Instant::now() - Duration::from_secs(8));
+    app.update_on_tick();
+    assert_eq!(app.song_progress_ms, 12_000);
+
+    app.user_config.behavior.max_progress_extrapolation_ms = 10_000;
+    app.update_on_tick();
+    assert!((18_000..18_300).contains(&app.song_progress_ms));
+
+    // Without extrapolation progress only moves when a report comes in
+    app.user_config.behavior.max_progress_extrapolation_ms = 0;
+    app.update_on_tick();
+    assert_eq!(app.song_progress_ms, 10_000);
+    app.set_song_progress(14_000);
+    app.update_on_tick();
+    assert_eq!(app.song_progress_ms, 14_000);
+  }
+
+  #[test]
+  fn seek_target_outlasts_stale_polls_until_the_ignore_window_ends() {
+    let mut app = playing_track(10_000, Duration::from_millis(100));
+    app.update_on_tick();
+    app.seek_forwards();
+    let target = 10_000 + app.user_config.behavior.seek_milliseconds as u128;
+    assert!(app.song_progress_ms >= target);
+
+    // The poll still says 10s in, but the seek was just now
+    app.sync_polled_progress(10_000);
+    app.update_on_tick();
+    assert!((target..target + 300).contains(&app.song_progress_ms));
+
+    // Once the window is over, a poll is trusted again however long before the
+    // next tick it came in
+    app.last_api_seek = Some(Instant::now() - Duration::from_secs(1));
+    app.sync_polled_progress(40_000);
+    app.song_progress_anchor.1 -= Duration::from_millis(500);
+    app.update_on_tick();
+    assert!((40_500..40_800).contains(&app.song_progress_ms));
+  }
+
+  #[test]
+  fn episodes_seek_by_the_podcast_step() {
+    use crate::core::app::seek_step_for;
+    use crate::core::fixtures;
+    use rspotify::model::PlayableItem;
+    let behavior = App::default().user_config.behavior;
+    let track = PlayableItem::Track(fixtures::track(
+      "Harbor Lights",
+      "The Tides",
+      "Coastline",
+      1,
+    ));
+    let episode = PlayableItem::Episode(fixtures::episode("Pilot", "Night Owls", 1));
+
+    assert_eq!(seek_step_for(Some(&track), &behavior), 5_000);
+    assert_eq!(seek_step_for(Some(&episode), &behavior), 30_000);
+    // Nothing playing falls back to the music step
+    assert_eq!(seek_step_for(None, &behavior), 5_000);
+  }
+
+  #[test]
+  fn seeking_says_the_step_when_it_changes_with_what_is_playing() {
+    use crate::core::fixtures;
+    use rspotify::model::PlayableItem;
+    let mut app = playing_track(10_000, Duration::ZERO);
+    app.seek_forwards();
+    assert!(app.status_message.is_none());
+    app.seek_backwards();
+    assert!(app.status_message.is_none());
+
+    app.current_playback_context.as_mut().unwrap().item = Some(PlayableItem::Episode(
+      fixtures::episode("Pilot", "Night Owls", 3_600_000),
+    ));
+    app.seek_forwards();
+    assert_eq!(app.status_message.as_deref(), Some("Seek step: 30s"));
+  }
+
+  #[test]
+  fn jump_to_playing_selects_the_playing_row_of_the_focused_table() {
+    use crate::core::app::TrackTableContext;
+    let mut app = playing_track(10_000, Duration::ZERO);
+    app.track_table.tracks = ["Paper Planes", "Harbor Lights", "Slow Burn"]
+      .iter()
+      .map(|name| crate::core::fixtures::track(name, "The Tides", "Coastline", 200_000))
+      .collect();
+    app.track_table.context = Some(TrackTableContext::SavedTracks);
+    app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+    let jump = app.user_config.keys.jump_to_playing.primary();
+
+    handle_app(jump, &mut app);
+    assert_eq!(app.track_table.selected_index, 1);
+    assert_eq!(app.status_message, None);
+
+    app.track_table.tracks.remove(1);
+    app.track_table.selected_index = 0;
+    handle_app(jump, &mut app);
+    assert_eq!(app.track_table.selected_index, 0);
+    assert!(app.status_message.is_some());
+  }
+
+  #[test]
+  fn pause_on_exit_only_pauses_when_enabled() {
+    let mut app = App::default();
+    play_on_external_device(&mut app);
+    // Handoff only applies to native playback, an external device keeps playing anyway
+    app.user_config.behavior.handoff_device = Some("spotifyd".to_string());
+
+    app.prepare_exit();
+    assert!(!app.is_loading);
+    assert!(!app.wait_on_exit);
+
+    app.user_config.behavior.pause_on_exit = true;
+    app.prepare_exit();
+    assert!(app.is_loading);
+    assert!(app.wait_on_exit);
+  }
+
+  #[test]
+  fn external_repeat_and_shuffle_show_at_once_and_confirm_later() {
+    use rspotify::model::enums::RepeatState;
+
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    play_on_external_device(&mut app);
+
+    handle_app(Key::Ctrl('r'), &mut app);
+    handle_app(Key::Ctrl('s'), &mut app);
+
+    let ctx = app.current_playback_context.as_ref().unwrap();
+    assert_eq!(ctx.repeat_state, RepeatState::Context);
+    assert!(ctx.shuffle_state);
+    assert!(app.user_config.behavior.shuffle_enabled);
+    assert!(app.playback_confirm_at.is_some());
+  }
+
+  #[test]
+  fn repeat_one_or_all_skips_off_and_remembers_the_choice() {
+    use rspotify::model::enums::RepeatState;
+
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    play_on_external_device(&mut app);
+    app.user_config.behavior.preferred_repeat = RepeatState::Track;
+
+    let repeat_state = |app: &App| app.current_playback_context.as_ref().unwrap().repeat_state;
+
+    // From Off it goes to the remembered mode, then alternates
+    handle_app(Key::Alt('l'), &mut app);
+    assert_eq!(repeat_state(&app), RepeatState::Track);
+    handle_app(Key::Alt('l'), &mut app);
+    assert_eq!(repeat_state(&app), RepeatState::Context);
+    assert_eq!(
+      app.user_config.behavior.preferred_repeat,
+      RepeatState::Context
+    );
+    handle_app(Key::Alt('l'), &mut app);
+    assert_eq!(repeat_state(&app), RepeatState::Track);
+
+    // The full cycle still reaches Off
+    handle_app(Key::Ctrl('r'), &mut app);
+    assert_eq!(repeat_state(&app), RepeatState::Off);
+    handle_app(Key::Alt('l'), &mut app);
+    assert_eq!(repeat_state(&app), RepeatState::Track);
+  }
+
+  #[test]
+  fn view_keys_open_their_views_from_anywhere() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+
+    handle_app(Key::Char('E'), &mut app);
+    assert_eq!(app.get_current_route().id, RouteId::Discover);
+
+    handle_app(Key::Char('R'), &mut app);
+    assert_eq!(app.get_current_route().id, RouteId::RecentlyPlayed);
+    assert!(app.is_loading);
+
+    handle_app(Key::Char('Q'), &mut app);
+    assert_eq!(app.get_current_route().id, RouteId::Queue);
+  }
+
+  #[test]
+  fn controls_without_playback_explain_or_change_preferences() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+
+    // No device and no native player: volume has nowhere to go
+    handle_app(Key::Char('+'), &mut app);
+    assert!(!app.is_loading);
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Nothing is playing, press d to pick a device")
+    );
+
+    // Shuffle is remembered for whatever plays next
+    let shuffle_enabled = app.user_config.behavior.shuffle_enabled;
+    handle_app(Key::Ctrl('s'), &mut app);
+    assert_eq!(app.user_config.behavior.shuffle_enabled, !shuffle_enabled);
+    assert!(!app.is_loading);
+    assert!(app
+      .status_message
+      .as_deref()
+      .is_some_and(|message| message.ends_with("for the next playback")));
+  }
+
+  #[test]
+  fn large_seek_jumps_four_steps() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    app.song_progress_ms = 60_000;
+
+    handle_app(Key::Char('<'), &mut app);
+    assert_eq!(app.song_progress_ms, 55_000);
+    handle_app(Key::Alt('<'), &mut app);
+    assert_eq!(app.song_progress_ms, 35_000);
+    handle_app(Key::Alt('<'), &mut app);
+    assert_eq!(app.song_progress_ms, 15_000);
+    handle_app(Key::Alt('<'), &mut app);
+    assert_eq!(app.song_progress_ms, 0);
+  }
+
+  #[test]
+  fn discover_time_range_key_cycles_from_anywhere() {
+    use crate::core::app::{DiscoverTimeRange, TrackTableContext};
+
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+
+    handle_app(Key::Alt('t'), &mut app);
+    assert_eq!(app.discover_time_range, DiscoverTimeRange::Long);
+    // Saved once on exit rather than on every press
+    assert_eq!(
+      app.user_config.behavior.discover_time_range,
+      DiscoverTimeRange::default()
+    );
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Discover time range: All time")
+    );
+    // Nothing Discover is on screen, so nothing is refetched
+    assert!(!app.is_loading);
+
+    app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+    app.track_table.context = Some(TrackTableContext::DiscoverPlaylist);
+    app.discover_selected_index = 1;
+    handle_app(Key::Alt('t'), &mut app);
+    assert_eq!(app.discover_time_range, DiscoverTimeRange::Short);
+    assert!(app.is_loading);
+  }
+
+  #[test]
+  fn seek_step_keys_change_the_step_within_bounds() {
+    let mut app = App::default();
+    app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Library));
+    assert_eq!(app.user_config.behavior.seek_milliseconds, 5_000);
+
+    handle_app(Key::Char('}'), &mut app);
+    assert_eq!(app.user_config.behavior.seek_milliseconds, 10_000);
+    assert_eq!(app.status_message.as_deref(), Some("Seek step: 10s"));
+
+    handle_app(Key::Char('{'), &mut app);
+    handle_app(Key::Char('{'), &mut app);
+    assert_eq!(app.user_config.behavior.seek_milliseconds, 1_000);
+
+    app.user_config.behavior.seek_milliseconds = 118_000;
+    handle_app(Key::Char('}'), &mut app);
+    assert_eq!(app.user_config.behavior.seek_milliseconds, 120_000);
+  }
+
+  #[test]
+  fn tv_view_key_toggles_the_view() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+
+    handle_app(Key::Alt('v'), &mut app);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::TvView);
+
+    handle_app(Key::Alt('v'), &mut app);
+    assert_eq!(app.get_current_route().id, RouteId::TrackTable);
+  }
+
+  #[test]
+  fn now_playing_opens_from_anywhere_and_escape_goes_back() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+
+    handle_app(Key::Alt('n'), &mut app);
+    assert_eq!(app.get_current_route().id, RouteId::NowPlaying);
+
+    handle_app(Key::Esc, &mut app);
+    assert_eq!(app.get_current_route().id, RouteId::TrackTable);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::TrackTable
+    );
+  }
+
   #[test]
   fn global_shift_w_adds_current_track_from_anywhere() {
     let mut app = App::default();
@@ -338,4 +1129,43 @@ mod tests {
     assert_eq!(app.input, vec!['W']);
     assert!(app.status_message.is_none());
   }
+
+  #[test]
+  fn ab_loop_sets_a_then_b_then_clears() {
+    let mut app = App::default();
+    handle_app(Key::Alt('b'), &mut app);
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("A-B repeat needs the native player")
+    );
+    assert_eq!(app.ab_loop_start, None);
+
+    app.last_track_id = Some("spotify:track:a".to_string());
+    app.next_ab_loop_point(30_000);
+    assert_eq!(app.ab_loop, None);
+    // B before A just swaps them
+    app.next_ab_loop_point(10_000);
+    assert_eq!(app.ab_loop, Some((10_000, 30_000)));
+    assert_eq!(app.ab_loop_start, None);
+    app.next_ab_loop_point(20_000);
+    assert_eq!(app.ab_loop, None);
+  }
+
+  #[cfg(feature = "streaming")]
+  #[test]
+  fn ab_loop_jumps_back_at_b_and_ends_on_track_change() {
+    let mut app = App::default();
+    app.last_track_id = Some("spotify:track:a".to_string());
+    app.next_ab_loop_point(10_000);
+    app.next_ab_loop_point(30_000);
+
+    app.song_progress_ms = 29_000;
+    assert_eq!(app.ab_loop_seek_target(), None);
+    app.song_progress_ms = 30_200;
+    assert_eq!(app.ab_loop_seek_target(), Some(10_000));
+
+    app.last_track_id = Some("spotify:track:b".to_string());
+    assert_eq!(app.ab_loop_seek_target(), None);
+    assert_eq!(app.ab_loop, None);
+  }
 }
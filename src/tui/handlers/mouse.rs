@@ -1,124 +1,332 @@
-use super::{library, playlist, settings, track_table};
+use super::{artist, handle_block_events, library, playlist, search_results, settings};
 use crate::core::app::{
-  ActiveBlock, App, RouteId, SettingValue, SettingsCategory, LIBRARY_OPTIONS,
+  ActiveBlock, AlbumTableContext, App, ArtistBlock, MouseRegion, MouseTarget, PlaylistFolderItem,
+  RouteId, SearchResultBlock, SettingValue, SettingsCategory, LIBRARY_OPTIONS,
 };
 use crate::tui::event::Key;
-use crate::tui::ui::util::{get_main_layout_margin, SMALL_TERMINAL_WIDTH};
 use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::Rect;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-const COMPACT_TOP_ROW_THRESHOLD: u16 = 60;
-const COMPACT_HELP_WIDTH: u16 = 8;
-const COMPACT_SETTINGS_WIDTH: u16 = 12;
-const SETTINGS_UNSAVED_PROMPT_WIDTH: u16 = 58;
-const SETTINGS_UNSAVED_PROMPT_HEIGHT: u16 = 9;
-
 pub fn handler(mouse: MouseEvent, app: &mut App) {
-  if app.get_current_route().active_block == ActiveBlock::Settings {
-    handle_settings_screen_mouse(mouse, app);
+  if !is_mouse_interactive(app.get_current_route().active_block) {
     return;
   }
 
-  if !is_main_layout_mouse_interactive(app.get_current_route().active_block) {
+  // Hit-test against what the last frame actually drew, so the layout only lives in the renderer
+  let Some(region) = app.mouse_region_at(mouse.column, mouse.row) else {
     return;
+  };
+
+  match region.target {
+    MouseTarget::Input => handle_input_mouse(mouse, region.area, app),
+    MouseTarget::Help => handle_help_mouse(mouse, app),
+    MouseTarget::Settings => handle_settings_mouse(mouse, app),
+    MouseTarget::Library => handle_library_mouse(mouse, region, app),
+    MouseTarget::Playlists => handle_playlist_mouse(mouse, region, app),
+    MouseTarget::ContentTable => handle_content_table_mouse(mouse, region, app),
+    MouseTarget::SearchResult(block) => handle_search_result_mouse(mouse, block, region, app),
+    MouseTarget::Artist(block) => handle_artist_mouse(mouse, block, region, app),
+    // The unsaved-changes prompt covers the rest of the settings screen
+    MouseTarget::SettingsTabs | MouseTarget::SettingsList
+      if app.settings_unsaved_prompt_visible => {}
+    MouseTarget::SettingsTabs => handle_settings_tabs_mouse(mouse, region.area, app),
+    MouseTarget::SettingsList => handle_settings_list_mouse(mouse, region.area, app),
+    MouseTarget::UnsavedPromptYes => handle_unsaved_settings_prompt_mouse(mouse, 'y', app),
+    MouseTarget::UnsavedPromptNo => handle_unsaved_settings_prompt_mouse(mouse, 'n', app),
   }
+}
 
-  let Some(areas) = main_layout_areas(app) else {
-    return;
-  };
+/// A table in the content area that can be scrolled and clicked
+struct ContentTable {
+  block: ActiveBlock,
+  item_count: usize,
+}
 
-  if let Some(input_area) = areas.input {
-    if rect_contains(input_area, mouse.column, mouse.row) {
-      handle_input_mouse(mouse, input_area, app);
-      return;
+fn content_table(app: &App) -> Option<ContentTable> {
+  let (block, item_count) = match app.get_current_route().id {
+    RouteId::TrackTable | RouteId::Recommendations => {
+      (ActiveBlock::TrackTable, app.track_table.tracks.len())
     }
+    RouteId::AlbumTracks => (
+      ActiveBlock::AlbumTracks,
+      match app.album_table_context {
+        AlbumTableContext::Full => app
+          .selected_album_full
+          .as_ref()
+          .map_or(0, |album| album.album.tracks.items.len()),
+        AlbumTableContext::Simplified => app
+          .selected_album_simplified
+          .as_ref()
+          .map_or(0, |album| album.tracks.items.len()),
+      },
+    ),
+    RouteId::RecentlyPlayed => (
+      ActiveBlock::RecentlyPlayed,
+      app
+        .recently_played
+        .result
+        .as_ref()
+        .map_or(0, |page| page.items.len()),
+    ),
+    RouteId::AlbumList => (
+      ActiveBlock::AlbumList,
+      app
+        .library
+        .saved_albums
+        .get_results(None)
+        .map_or(0, |page| page.items.len()),
+    ),
+    RouteId::Artists => (ActiveBlock::Artists, app.artists.len()),
+    RouteId::Podcasts => (
+      ActiveBlock::Podcasts,
+      app
+        .library
+        .saved_shows
+        .get_results(None)
+        .map_or(0, |page| page.items.len()),
+    ),
+    RouteId::PodcastEpisodes => (
+      ActiveBlock::EpisodeTable,
+      app
+        .library
+        .show_episodes
+        .get_results(None)
+        .map_or(0, |page| page.items.len()),
+    ),
+    RouteId::Queue => (ActiveBlock::Queue, app.queue.result.len()),
+    _ => return None,
+  };
+  Some(ContentTable { block, item_count })
+}
+
+fn set_content_table_index(block: ActiveBlock, index: usize, app: &mut App) {
+  match block {
+    ActiveBlock::TrackTable => app.track_table.selected_index = index,
+    ActiveBlock::AlbumTracks => match app.album_table_context {
+      AlbumTableContext::Full => app.saved_album_tracks_index = index,
+      AlbumTableContext::Simplified => {
+        if let Some(album) = &mut app.selected_album_simplified {
+          album.selected_index = index;
+        }
+      }
+    },
+    ActiveBlock::RecentlyPlayed => app.recently_played.index = index,
+    ActiveBlock::AlbumList => app.album_list_index = index,
+    ActiveBlock::Artists => app.artists_list_index = index,
+    ActiveBlock::Podcasts => app.shows_list_index = index,
+    ActiveBlock::EpisodeTable => app.episode_list_index = index,
+    ActiveBlock::Queue => app.queue.index = index,
+    _ => {}
   }
+}
 
-  if let Some(help_area) = areas.help {
-    if rect_contains(help_area, mouse.column, mouse.row) {
-      handle_help_mouse(mouse, app);
-      return;
-    }
+fn handle_content_table_mouse(mouse: MouseEvent, region: MouseRegion, app: &mut App) {
+  let Some(table) = content_table(app) else {
+    return;
+  };
+  if table.item_count == 0 {
+    return;
   }
 
-  if let Some(settings_area) = areas.settings {
-    if rect_contains(settings_area, mouse.column, mouse.row) {
-      handle_settings_mouse(mouse, app);
-      return;
+  match mouse.kind {
+    MouseEventKind::ScrollDown => {
+      app.set_current_route_state(Some(table.block), Some(table.block));
+      scroll(Key::Down, app, handle_block_events);
     }
+    MouseEventKind::ScrollUp => {
+      app.set_current_route_state(Some(table.block), Some(table.block));
+      scroll(Key::Up, app, handle_block_events);
+    }
+    MouseEventKind::Down(MouseButton::Left) => {
+      let Some(clicked_index) =
+        table_item_index_from_click(region.area, mouse.row, region.offset, table.item_count)
+      else {
+        return;
+      };
+
+      app.set_current_route_state(Some(table.block), Some(table.block));
+      set_content_table_index(table.block, clicked_index, app);
+
+      // A double-click plays or opens the row
+      if app.register_click(MouseTarget::ContentTable, clicked_index) {
+        handle_block_events(Key::Enter, app);
+      }
+    }
+    _ => {}
   }
+}
 
-  if rect_contains(areas.library, mouse.column, mouse.row) {
-    handle_library_mouse(mouse, areas.library, app);
-    return;
+/// Send `key` once per configured wheel line
+fn scroll(key: Key, app: &mut App, handler: impl Fn(Key, &mut App)) {
+  for _ in 0..app.user_config.behavior.mouse_scroll_lines {
+    handler(key, app);
   }
+}
 
-  if rect_contains(areas.playlists, mouse.column, mouse.row) {
-    handle_playlist_mouse(mouse, areas.playlists, app);
-    return;
+fn search_result_count(app: &App, block: SearchResultBlock) -> usize {
+  let results = &app.search_results;
+  match block {
+    SearchResultBlock::SongSearch => results.tracks.as_ref().map_or(0, |page| page.items.len()),
+    SearchResultBlock::ArtistSearch => results.artists.as_ref().map_or(0, |page| page.items.len()),
+    SearchResultBlock::AlbumSearch => results.albums.as_ref().map_or(0, |page| page.items.len()),
+    SearchResultBlock::PlaylistSearch => results
+      .playlists
+      .as_ref()
+      .map_or(0, |page| page.items.len()),
+    SearchResultBlock::ShowSearch => results.shows.as_ref().map_or(0, |page| page.items.len()),
+    SearchResultBlock::Empty => 0,
   }
+}
 
-  if app.get_current_route().id == RouteId::TrackTable
-    && rect_contains(areas.content, mouse.column, mouse.row)
-  {
-    handle_song_table_mouse(mouse, areas.content, app);
+fn set_search_result_index(app: &mut App, block: SearchResultBlock, index: usize) {
+  let results = &mut app.search_results;
+  match block {
+    SearchResultBlock::SongSearch => results.selected_tracks_index = Some(index),
+    SearchResultBlock::ArtistSearch => results.selected_artists_index = Some(index),
+    SearchResultBlock::AlbumSearch => results.selected_album_index = Some(index),
+    SearchResultBlock::PlaylistSearch => results.selected_playlists_index = Some(index),
+    SearchResultBlock::ShowSearch => results.selected_shows_index = Some(index),
+    SearchResultBlock::Empty => {}
   }
 }
 
-fn handle_library_mouse(mouse: MouseEvent, list_area: Rect, app: &mut App) {
+fn focus_search_result(app: &mut App, block: SearchResultBlock) {
+  app.set_current_route_state(
+    Some(ActiveBlock::SearchResultBlock),
+    Some(ActiveBlock::SearchResultBlock),
+  );
+  app.search_results.hovered_block = block;
+  app.search_results.selected_block = block;
+}
+
+fn handle_search_result_mouse(
+  mouse: MouseEvent,
+  block: SearchResultBlock,
+  region: MouseRegion,
+  app: &mut App,
+) {
+  let item_count = search_result_count(app, block);
+  if item_count == 0 {
+    return;
+  }
+
   match mouse.kind {
     MouseEventKind::ScrollDown => {
-      focus_library(app);
-      library::handler(Key::Down, app);
+      focus_search_result(app, block);
+      scroll(Key::Down, app, search_results::handler);
     }
     MouseEventKind::ScrollUp => {
-      focus_library(app);
-      library::handler(Key::Up, app);
+      focus_search_result(app, block);
+      scroll(Key::Up, app, search_results::handler);
     }
     MouseEventKind::Down(MouseButton::Left) => {
-      focus_library(app);
-      select_clicked_library_item(mouse.row, list_area, app);
+      let Some(clicked_index) =
+        list_item_index_from_click(region.area, mouse.row, region.offset, item_count)
+      else {
+        return;
+      };
+
+      focus_search_result(app, block);
+      set_search_result_index(app, block, clicked_index);
+      if app.register_click(MouseTarget::SearchResult(block), clicked_index) {
+        search_results::handler(Key::Enter, app);
+      }
     }
     _ => {}
   }
 }
 
-fn handle_playlist_mouse(mouse: MouseEvent, list_area: Rect, app: &mut App) {
+fn artist_block_count(app: &App, block: ArtistBlock) -> usize {
+  app.artist.as_ref().map_or(0, |artist| match block {
+    ArtistBlock::TopTracks => artist.top_tracks.len(),
+    ArtistBlock::Albums => artist.albums.items.len(),
+    ArtistBlock::RelatedArtists => artist.related_artists.len(),
+    ArtistBlock::Empty => 0,
+  })
+}
+
+fn focus_artist_block(app: &mut App, block: ArtistBlock, index: Option<usize>) {
+  app.set_current_route_state(
+    Some(ActiveBlock::ArtistBlock),
+    Some(ActiveBlock::ArtistBlock),
+  );
+  if let Some(artist) = &mut app.artist {
+    artist.artist_hovered_block = block;
+    artist.artist_selected_block = block;
+    if let Some(index) = index {
+      match block {
+        ArtistBlock::TopTracks => artist.selected_top_track_index = index,
+        ArtistBlock::Albums => artist.selected_album_index = index,
+        ArtistBlock::RelatedArtists => artist.selected_related_artist_index = index,
+        ArtistBlock::Empty => {}
+      }
+    }
+  }
+}
+
+fn handle_artist_mouse(mouse: MouseEvent, block: ArtistBlock, region: MouseRegion, app: &mut App) {
+  let item_count = artist_block_count(app, block);
+  if item_count == 0 {
+    return;
+  }
+
   match mouse.kind {
     MouseEventKind::ScrollDown => {
-      focus_playlists(app);
-      playlist::handler(Key::Down, app);
+      focus_artist_block(app, block, None);
+      scroll(Key::Down, app, artist::handler);
     }
     MouseEventKind::ScrollUp => {
-      focus_playlists(app);
-      playlist::handler(Key::Up, app);
+      focus_artist_block(app, block, None);
+      scroll(Key::Up, app, artist::handler);
     }
     MouseEventKind::Down(MouseButton::Left) => {
-      focus_playlists(app);
-      select_clicked_playlist(mouse.row, list_area, app);
+      let Some(clicked_index) =
+        list_item_index_from_click(region.area, mouse.row, region.offset, item_count)
+      else {
+        return;
+      };
+
+      focus_artist_block(app, block, Some(clicked_index));
+      if app.register_click(MouseTarget::Artist(block), clicked_index) {
+        artist::handler(Key::Enter, app);
+      }
     }
     _ => {}
   }
 }
 
-fn handle_song_table_mouse(mouse: MouseEvent, table_area: Rect, app: &mut App) {
-  if app.track_table.tracks.is_empty() {
-    return;
+fn handle_library_mouse(mouse: MouseEvent, region: MouseRegion, app: &mut App) {
+  match mouse.kind {
+    MouseEventKind::ScrollDown => {
+      focus_library(app);
+      scroll(Key::Down, app, library::handler);
+    }
+    MouseEventKind::ScrollUp => {
+      focus_library(app);
+      scroll(Key::Up, app, library::handler);
+    }
+    MouseEventKind::Down(MouseButton::Left) => {
+      focus_library(app);
+      select_clicked_library_item(mouse.row, region, app);
+    }
+    _ => {}
   }
+}
 
+fn handle_playlist_mouse(mouse: MouseEvent, region: MouseRegion, app: &mut App) {
   match mouse.kind {
     MouseEventKind::ScrollDown => {
-      focus_song_table(app);
-      track_table::handler(Key::Down, app);
+      focus_playlists(app);
+      scroll(Key::Down, app, playlist::handler);
     }
     MouseEventKind::ScrollUp => {
-      focus_song_table(app);
-      track_table::handler(Key::Up, app);
+      focus_playlists(app);
+      scroll(Key::Up, app, playlist::handler);
     }
     MouseEventKind::Down(MouseButton::Left) => {
-      focus_song_table(app);
-      select_clicked_song(mouse.row, table_area, app);
+      focus_playlists(app);
+      select_clicked_playlist(mouse.row, region, app);
     }
     _ => {}
   }
@@ -146,42 +354,10 @@ fn handle_settings_mouse(mouse: MouseEvent, app: &mut App) {
   }
 }
 
-fn handle_settings_screen_mouse(mouse: MouseEvent, app: &mut App) {
-  if app.settings_unsaved_prompt_visible {
-    handle_unsaved_settings_prompt_mouse(mouse, app);
-    return;
-  }
-
-  let Some(areas) = settings_layout_areas(app) else {
-    return;
-  };
-
-  if rect_contains(areas.tabs, mouse.column, mouse.row) {
-    handle_settings_tabs_mouse(mouse, areas.tabs, app);
-    return;
-  }
-
-  if rect_contains(areas.list, mouse.column, mouse.row) {
-    handle_settings_list_mouse(mouse, areas.list, app);
-  }
-}
-
-fn handle_unsaved_settings_prompt_mouse(mouse: MouseEvent, app: &mut App) {
-  if !matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
-    return;
-  }
-
-  let Some(areas) = settings_unsaved_prompt_areas(app) else {
-    return;
-  };
-
-  if rect_contains(areas.yes, mouse.column, mouse.row) {
-    settings::handler(Key::Char('y'), app);
-    return;
-  }
-
-  if rect_contains(areas.no, mouse.column, mouse.row) {
-    settings::handler(Key::Char('n'), app);
+/// Answer the unsaved-changes prompt as if `answer` was typed
+fn handle_unsaved_settings_prompt_mouse(mouse: MouseEvent, answer: char, app: &mut App) {
+  if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
+    settings::handler(Key::Char(answer), app);
   }
 }
 
@@ -229,8 +405,10 @@ fn selected_setting_expects_key_capture(app: &App) -> bool {
 }
 
 fn select_clicked_setting(mouse_row: u16, list_area: Rect, app: &mut App) {
-  let item_count = app.settings_items.len();
-  let Some(clicked_index) = settings_item_index_from_click(list_area, mouse_row, item_count) else {
+  let visible = app.visible_settings_indices();
+  let Some(clicked_index) =
+    settings_item_index_from_click(list_area, mouse_row, visible.len()).map(|row| visible[row])
+  else {
     return;
   };
 
@@ -286,7 +464,7 @@ fn switch_settings_category_to(index: usize, app: &mut App) {
   }
 }
 
-fn is_main_layout_mouse_interactive(active_block: ActiveBlock) -> bool {
+fn is_mouse_interactive(active_block: ActiveBlock) -> bool {
   !matches!(
     active_block,
     ActiveBlock::HelpMenu
@@ -294,10 +472,11 @@ fn is_main_layout_mouse_interactive(active_block: ActiveBlock) -> bool {
       | ActiveBlock::SelectDevice
       | ActiveBlock::Analysis
       | ActiveBlock::BasicView
+      | ActiveBlock::NowPlaying
+      | ActiveBlock::TvView
       | ActiveBlock::UpdatePrompt
       | ActiveBlock::AnnouncementPrompt
       | ActiveBlock::ExitPrompt
-      | ActiveBlock::Settings
       | ActiveBlock::Dialog(_)
       | ActiveBlock::SortMenu
   )
@@ -314,10 +493,6 @@ fn focus_library(app: &mut App) {
   app.set_current_route_state(Some(ActiveBlock::Library), Some(ActiveBlock::Library));
 }
 
-fn focus_song_table(app: &mut App) {
-  app.set_current_route_state(Some(ActiveBlock::TrackTable), Some(ActiveBlock::TrackTable));
-}
-
 fn focus_input(app: &mut App) {
   app.set_current_route_state(Some(ActiveBlock::Input), Some(ActiveBlock::Input));
 }
@@ -344,72 +519,46 @@ fn set_input_cursor_from_column(input_area: Rect, mouse_column: u16, app: &mut A
   app.input_cursor_position = width as u16;
 }
 
-fn select_clicked_library_item(mouse_row: u16, list_area: Rect, app: &mut App) {
-  let item_count = LIBRARY_OPTIONS.len();
-  let selected_index = app.library.selected_index.min(item_count.saturating_sub(1));
-
+fn select_clicked_library_item(mouse_row: u16, region: MouseRegion, app: &mut App) {
   let Some(clicked_index) =
-    list_item_index_from_click(list_area, mouse_row, selected_index, item_count)
+    list_item_index_from_click(region.area, mouse_row, region.offset, LIBRARY_OPTIONS.len())
   else {
     return;
   };
 
-  let was_selected = app.library.selected_index == clicked_index;
   app.library.selected_index = clicked_index;
-
-  if was_selected {
+  if app.register_click(MouseTarget::Library, clicked_index) {
     library::handler(Key::Enter, app);
   }
 }
 
-fn select_clicked_playlist(mouse_row: u16, list_area: Rect, app: &mut App) {
+fn select_clicked_playlist(mouse_row: u16, region: MouseRegion, app: &mut App) {
   let item_count = app.get_playlist_display_count();
-  if item_count == 0 {
-    return;
-  }
-
-  let selected_index = app
-    .selected_playlist_index
-    .unwrap_or(0)
-    .min(item_count.saturating_sub(1));
-
   let Some(clicked_index) =
-    list_item_index_from_click(list_area, mouse_row, selected_index, item_count)
+    list_item_index_from_click(region.area, mouse_row, region.offset, item_count)
   else {
     return;
   };
 
-  let was_selected = app.selected_playlist_index == Some(clicked_index);
+  let is_folder = matches!(
+    app.get_playlist_display_item_at(clicked_index),
+    Some(PlaylistFolderItem::Folder(_))
+  );
   app.selected_playlist_index = Some(clicked_index);
 
-  // Open the selected item when clicking it again.
-  if was_selected {
+  // Folders (and their back entry) open on the first click since entering one starts nothing;
+  // playlists open on a double-click.
+  let double_click = app.register_click(MouseTarget::Playlists, clicked_index);
+  if double_click || is_folder {
     playlist::handler(Key::Enter, app);
   }
 }
 
-fn select_clicked_song(mouse_row: u16, table_area: Rect, app: &mut App) {
-  let item_count = app.track_table.tracks.len();
-  let selected_index = app
-    .track_table
-    .selected_index
-    .min(item_count.saturating_sub(1));
-
-  let Some(clicked_index) =
-    table_item_index_from_click(table_area, mouse_row, selected_index, item_count)
-  else {
-    return;
-  };
-
-  app.track_table.selected_index = clicked_index;
-  // Song clicks should behave like immediate selection + play.
-  track_table::handler(Key::Enter, app);
-}
-
+/// The item under `mouse_row` in a bordered list drawn in `list_area` from item `offset`
 fn list_item_index_from_click(
   list_area: Rect,
   mouse_row: u16,
-  selected_index: usize,
+  offset: usize,
   item_count: usize,
 ) -> Option<usize> {
   if item_count == 0 || list_area.height <= 2 {
@@ -431,21 +580,17 @@ fn list_item_index_from_click(
     return None;
   }
 
-  // `draw_selectable_list` recreates list state each frame with offset 0, so ratatui scrolls
-  // just enough to keep the selected row visible.
-  let offset = selected_index
-    .saturating_add(1)
-    .saturating_sub(viewport_height);
   let row_index = (mouse_row - inner_top) as usize;
   let clicked_index = offset + row_index;
 
   (clicked_index < item_count).then_some(clicked_index)
 }
 
+/// The row under `mouse_row` in a table drawn in `table_area` from row `offset`
 fn table_item_index_from_click(
   table_area: Rect,
   mouse_row: u16,
-  selected_index: usize,
+  offset: usize,
   item_count: usize,
 ) -> Option<usize> {
   if item_count == 0 || table_area.height <= 5 {
@@ -463,13 +608,6 @@ fn table_item_index_from_click(
     return None;
   }
 
-  let offset = table_area
-    .height
-    .checked_sub(5)
-    .map(|height| height as usize)
-    .map(|visible_rows| (selected_index / visible_rows) * visible_rows)
-    .unwrap_or(0);
-
   let row_index = (mouse_row - first_data_row) as usize;
   let row_index = row_index.min(visible_rows.saturating_sub(1));
   let clicked_index = (offset + row_index).min(item_count.saturating_sub(1));
@@ -567,215 +705,15 @@ fn settings_item_index_from_click(
   (row_index < item_count).then_some(row_index)
 }
 
-struct MainLayoutAreas {
-  input: Option<Rect>,
-  help: Option<Rect>,
-  settings: Option<Rect>,
-  library: Rect,
-  playlists: Rect,
-  content: Rect,
-}
-
-struct SettingsLayoutAreas {
-  tabs: Rect,
-  list: Rect,
-}
-
-struct SettingsUnsavedPromptAreas {
-  yes: Rect,
-  no: Rect,
-}
-
-fn settings_layout_areas(app: &App) -> Option<SettingsLayoutAreas> {
-  if app.size.width == 0 || app.size.height == 0 {
-    return None;
-  }
-
-  let root = Rect::new(0, 0, app.size.width, app.size.height);
-  let [tabs_area, list_area, _help_area] = root.layout(
-    &Layout::vertical([
-      Constraint::Length(3),
-      Constraint::Min(1),
-      Constraint::Length(3),
-    ])
-    .margin(2),
-  );
-
-  Some(SettingsLayoutAreas {
-    tabs: tabs_area,
-    list: list_area,
-  })
-}
-
-fn settings_unsaved_prompt_areas(app: &App) -> Option<SettingsUnsavedPromptAreas> {
-  if app.size.width == 0 || app.size.height == 0 {
-    return None;
-  }
-
-  let bounds = Rect::new(0, 0, app.size.width, app.size.height);
-  let width = std::cmp::min(
-    bounds.width.saturating_sub(4),
-    SETTINGS_UNSAVED_PROMPT_WIDTH,
-  );
-  if width == 0 {
-    return None;
-  }
-
-  let height = SETTINGS_UNSAVED_PROMPT_HEIGHT.min(bounds.height.saturating_sub(2).max(1));
-  let left = bounds.x + bounds.width.saturating_sub(width) / 2;
-  let top = bounds.y + bounds.height.saturating_sub(height) / 2;
-  let rect = Rect::new(left, top, width, height);
-
-  let [_message_area, buttons_area, _hint_area] = rect.layout(
-    &Layout::vertical([
-      Constraint::Min(2),
-      Constraint::Length(3),
-      Constraint::Length(1),
-    ])
-    .margin(1),
-  );
-
-  let [yes_area, no_area] = buttons_area.layout(
-    &Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).horizontal_margin(3),
-  );
-
-  Some(SettingsUnsavedPromptAreas {
-    yes: yes_area,
-    no: no_area,
-  })
-}
-
-fn main_layout_areas(app: &App) -> Option<MainLayoutAreas> {
-  if app.size.width == 0 || app.size.height == 0 {
-    return None;
-  }
-
-  let root = Rect::new(0, 0, app.size.width, app.size.height);
-  let margin = get_main_layout_margin(app);
-  let wide_layout =
-    app.size.width >= SMALL_TERMINAL_WIDTH && !app.user_config.behavior.enforce_wide_search_bar;
-
-  let routes_area = if wide_layout {
-    let [routes_area, _playbar_area] =
-      root.layout(&Layout::vertical([Constraint::Min(1), Constraint::Length(6)]).margin(margin));
-    routes_area
-  } else {
-    let [input_area, routes_area, _playbar_area] = root.layout(
-      &Layout::vertical([
-        Constraint::Length(3),
-        Constraint::Min(1),
-        Constraint::Length(6),
-      ])
-      .margin(margin),
-    );
-    let [input_text_area, help_area, settings_area] =
-      split_input_help_and_settings(app, input_area);
-
-    let [library_area, playlist_area] =
-      user_area_for_routes(routes_area).layout(&Layout::vertical([
-        Constraint::Percentage(30),
-        Constraint::Percentage(70),
-      ]));
-    let [_user_area, content_area] = routes_area.layout(&Layout::horizontal([
-      Constraint::Percentage(20),
-      Constraint::Percentage(80),
-    ]));
-
-    return Some(MainLayoutAreas {
-      input: Some(input_text_area),
-      help: Some(help_area),
-      settings: Some(settings_area),
-      library: library_area,
-      playlists: playlist_area,
-      content: content_area,
-    });
-  };
-
-  let [user_area, content_area] = routes_area.layout(&Layout::horizontal([
-    Constraint::Percentage(20),
-    Constraint::Percentage(80),
-  ]));
-
-  if wide_layout {
-    let [input_area, library_area, playlist_area] = user_area.layout(&Layout::vertical([
-      Constraint::Length(3),
-      Constraint::Percentage(30),
-      Constraint::Percentage(70),
-    ]));
-    let [input_text_area, help_area, settings_area] =
-      split_input_help_and_settings(app, input_area);
-    Some(MainLayoutAreas {
-      input: Some(input_text_area),
-      help: Some(help_area),
-      settings: Some(settings_area),
-      library: library_area,
-      playlists: playlist_area,
-      content: content_area,
-    })
-  } else {
-    let [library_area, playlist_area] = user_area.layout(&Layout::vertical([
-      Constraint::Percentage(30),
-      Constraint::Percentage(70),
-    ]));
-    Some(MainLayoutAreas {
-      input: None,
-      help: None,
-      settings: None,
-      library: library_area,
-      playlists: playlist_area,
-      content: content_area,
-    })
-  }
-}
-
-fn split_input_help_and_settings(app: &App, input_row_area: Rect) -> [Rect; 3] {
-  let compact_top_row = input_row_area.width < COMPACT_TOP_ROW_THRESHOLD;
-
-  let constraints = if compact_top_row {
-    [
-      Constraint::Min(1),
-      Constraint::Length(COMPACT_HELP_WIDTH),
-      Constraint::Length(COMPACT_SETTINGS_WIDTH),
-    ]
-  } else if app.size.width >= SMALL_TERMINAL_WIDTH
-    && !app.user_config.behavior.enforce_wide_search_bar
-  {
-    [
-      Constraint::Percentage(65),
-      Constraint::Percentage(18),
-      Constraint::Percentage(17),
-    ]
-  } else {
-    [
-      Constraint::Percentage(80),
-      Constraint::Percentage(10),
-      Constraint::Percentage(10),
-    ]
-  };
-
-  input_row_area.layout(&Layout::horizontal(constraints))
-}
-
-fn user_area_for_routes(routes_area: Rect) -> Rect {
-  let [user_area, _content_area] = routes_area.layout(&Layout::horizontal([
-    Constraint::Percentage(20),
-    Constraint::Percentage(80),
-  ]));
-  user_area
-}
-
-fn rect_contains(rect: Rect, x: u16, y: u16) -> bool {
-  let right = rect.x.saturating_add(rect.width);
-  let bottom = rect.y.saturating_add(rect.height);
-  x >= rect.x && x < right && y >= rect.y && y < bottom
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::core::app::{PlaylistFolderItem, RouteId, SettingValue, SettingsCategory};
+  use crate::core::app::{
+    Artist, PlaylistFolder, SelectedFullAlbum, SettingValue, SettingsCategory, DOUBLE_CLICK_WINDOW,
+  };
+  use crate::core::fixtures;
   use crossterm::event::{KeyModifiers, MouseEvent};
-  use ratatui::layout::Size;
+  use ratatui::{backend::TestBackend, layout::Size, Terminal};
 
   fn mouse_event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
     MouseEvent {
@@ -786,21 +724,68 @@ mod tests {
     }
   }
 
+  fn app_of_size(width: u16, height: u16) -> App {
+    let mut app = App::default();
+    app.size = Size { width, height };
+    app
+  }
+
+  /// Draws a frame the way the main loop does, recording where everything landed
+  fn draw(app: &App) {
+    let mut terminal = Terminal::new(TestBackend::new(app.size.width, app.size.height)).unwrap();
+    terminal
+      .draw(|f| match app.get_current_route().active_block {
+        ActiveBlock::Settings => crate::tui::ui::settings::draw_settings(f, app),
+        _ => crate::tui::ui::draw_main_layout(f, app),
+      })
+      .unwrap();
+  }
+
+  /// Handles `event` against the current frame, then draws the next one
+  fn send(app: &mut App, event: MouseEvent) {
+    handler(event, app);
+    draw(app);
+  }
+
+  fn left_click(column: u16, row: u16) -> MouseEvent {
+    mouse_event(MouseEventKind::Down(MouseButton::Left), column, row)
+  }
+
+  /// Where the last frame drew `target`
+  fn area_of(app: &App, target: MouseTarget) -> Rect {
+    app
+      .mouse_regions
+      .borrow()
+      .iter()
+      .find(|region| region.target == target)
+      .map(|region| region.area)
+      .unwrap_or_else(|| panic!("{:?} wasn't drawn", target))
+  }
+
+  /// Pretends the last click happened long enough ago not to pair with the next one
+  fn let_last_click_age(app: &mut App) {
+    app.last_click = app.last_click.map(|(at, target, index)| {
+      (
+        at.checked_sub(DOUBLE_CLICK_WINDOW)
+          .expect("instant in range"),
+        target,
+        index,
+      )
+    });
+  }
+
   fn with_playlist_items(app: &mut App) {
-    app.playlist_folder_items = vec![
-      PlaylistFolderItem::Playlist {
-        index: 0,
-        current_id: 0,
-      },
-      PlaylistFolderItem::Playlist {
-        index: 1,
-        current_id: 0,
-      },
-      PlaylistFolderItem::Playlist {
-        index: 2,
-        current_id: 0,
-      },
+    app.all_playlists = vec![
+      fixtures::playlist("Road Trip", "Sam", 42),
+      fixtures::playlist("Focus", "Spotify", 80),
+      fixtures::playlist("Late Night Jazz", "Sam", 17),
     ];
+    app.playlist_folder_items = (0..app.all_playlists.len())
+      .map(|index| PlaylistFolderItem::Playlist {
+        index,
+        current_id: 0,
+      })
+      .collect();
   }
 
   fn open_settings(app: &mut App) {
@@ -811,23 +796,20 @@ mod tests {
 
   #[test]
   fn scroll_over_playlists_changes_selection() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
     with_playlist_items(&mut app);
     app.selected_playlist_index = Some(0);
+    draw(&app);
 
-    let areas = main_layout_areas(&app).expect("layout areas");
-    let x = areas.playlists.x + 1;
-    let y = areas.playlists.y + 1;
+    let playlists = area_of(&app, MouseTarget::Playlists);
+    let x = playlists.x + 1;
+    let y = playlists.y + 1;
 
-    handler(mouse_event(MouseEventKind::ScrollDown, x, y), &mut app);
+    send(&mut app, mouse_event(MouseEventKind::ScrollDown, x, y));
     assert_eq!(app.selected_playlist_index, Some(1));
 
-    handler(mouse_event(MouseEventKind::ScrollUp, x, y), &mut app);
+    send(&mut app, mouse_event(MouseEventKind::ScrollUp, x, y));
     assert_eq!(app.selected_playlist_index, Some(0));
 
     let current_route = app.get_current_route();
@@ -837,26 +819,14 @@ mod tests {
 
   #[test]
   fn click_search_input_focuses_input() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     app.input = "hello".chars().collect();
     app.input_idx = 0;
     app.input_cursor_position = 0;
+    draw(&app);
 
-    let areas = main_layout_areas(&app).expect("layout areas");
-    let input = areas.input.expect("input area");
-
-    handler(
-      mouse_event(
-        MouseEventKind::Down(MouseButton::Left),
-        input.x + 2,
-        input.y + 1,
-      ),
-      &mut app,
-    );
+    let input = area_of(&app, MouseTarget::Input);
+    send(&mut app, left_click(input.x + 2, input.y + 1));
 
     let route = app.get_current_route();
     assert_eq!(route.active_block, ActiveBlock::Input);
@@ -865,23 +835,11 @@ mod tests {
 
   #[test]
   fn click_settings_opens_settings_screen() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
-
-    let areas = main_layout_areas(&app).expect("layout areas");
-    let settings = areas.settings.expect("settings area");
-
-    handler(
-      mouse_event(
-        MouseEventKind::Down(MouseButton::Left),
-        settings.x + 1,
-        settings.y + 1,
-      ),
-      &mut app,
-    );
+    let mut app = app_of_size(160, 50);
+    draw(&app);
+
+    let settings = area_of(&app, MouseTarget::Settings);
+    send(&mut app, left_click(settings.x + 1, settings.y + 1));
 
     let route = app.get_current_route();
     assert_eq!(route.id, RouteId::Settings);
@@ -891,15 +849,12 @@ mod tests {
 
   #[test]
   fn click_settings_tab_switches_category() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     open_settings(&mut app);
+    draw(&app);
 
-    let areas = settings_layout_areas(&app).expect("settings layout areas");
-    let inner_left = areas.tabs.x + 1;
+    let tabs = area_of(&app, MouseTarget::SettingsTabs);
+    let inner_left = tabs.x + 1;
     let left_padding = 1u16;
     let right_padding = 1u16;
     let divider = 1u16;
@@ -910,12 +865,9 @@ mod tests {
     let theme_title_start =
       inner_left + behavior_tab_width + divider + keybindings_tab_width + divider + left_padding;
     let theme_tab_x = theme_title_start + 1;
-    let tab_y = areas.tabs.y + 1;
+    let tab_y = tabs.y + 1;
 
-    handler(
-      mouse_event(MouseEventKind::Down(MouseButton::Left), theme_tab_x, tab_y),
-      &mut app,
-    );
+    send(&mut app, left_click(theme_tab_x, tab_y));
 
     assert_eq!(app.settings_category, SettingsCategory::Theme);
     assert_eq!(app.settings_selected_index, 0);
@@ -924,32 +876,25 @@ mod tests {
 
   #[test]
   fn scroll_in_settings_list_changes_selected_item() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     open_settings(&mut app);
+    draw(&app);
 
-    let areas = settings_layout_areas(&app).expect("settings layout areas");
-    let x = areas.list.x + 2;
-    let y = areas.list.y + 2;
+    let list = area_of(&app, MouseTarget::SettingsList);
+    let x = list.x + 2;
+    let y = list.y + 2;
 
     assert_eq!(app.settings_selected_index, 0);
-    handler(mouse_event(MouseEventKind::ScrollDown, x, y), &mut app);
+    send(&mut app, mouse_event(MouseEventKind::ScrollDown, x, y));
     assert_eq!(app.settings_selected_index, 1);
 
-    handler(mouse_event(MouseEventKind::ScrollUp, x, y), &mut app);
+    send(&mut app, mouse_event(MouseEventKind::ScrollUp, x, y));
     assert_eq!(app.settings_selected_index, 0);
   }
 
   #[test]
   fn clicking_selected_bool_setting_toggles_value() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     open_settings(&mut app);
 
     let bool_index = app
@@ -968,12 +913,10 @@ mod tests {
       })
       .expect("selected setting should be boolean");
 
-    let areas = settings_layout_areas(&app).expect("settings layout areas");
-    let y = areas.list.y + 1 + bool_index as u16;
-    handler(
-      mouse_event(MouseEventKind::Down(MouseButton::Left), areas.list.x + 2, y),
-      &mut app,
-    );
+    draw(&app);
+    let list = area_of(&app, MouseTarget::SettingsList);
+    let y = list.y + 1 + bool_index as u16;
+    send(&mut app, left_click(list.x + 2, y));
 
     let updated_value = app
       .settings_items
@@ -989,11 +932,7 @@ mod tests {
 
   #[test]
   fn keybinding_capture_can_be_cancelled_with_mouse_click() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     open_settings(&mut app);
     app.settings_category = SettingsCategory::Keybindings;
     app.load_settings_for_category();
@@ -1008,20 +947,15 @@ mod tests {
       })
       .expect("first keybinding should be a key setting");
 
-    let areas = settings_layout_areas(&app).expect("settings layout areas");
-    let x = areas.list.x + 2;
-    let y = areas.list.y + 1;
+    draw(&app);
+    let list = area_of(&app, MouseTarget::SettingsList);
+    let x = list.x + 2;
+    let y = list.y + 1;
 
-    handler(
-      mouse_event(MouseEventKind::Down(MouseButton::Left), x, y),
-      &mut app,
-    );
+    send(&mut app, left_click(x, y));
     assert!(app.settings_edit_mode);
 
-    handler(
-      mouse_event(MouseEventKind::Down(MouseButton::Left), x, y),
-      &mut app,
-    );
+    send(&mut app, left_click(x, y));
 
     let current_value = app
       .settings_items
@@ -1038,11 +972,7 @@ mod tests {
 
   #[test]
   fn click_no_on_unsaved_prompt_discards_changes_and_exits() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     open_settings(&mut app);
 
     let bool_index = app
@@ -1056,59 +986,128 @@ mod tests {
     settings::handler(Key::Esc, &mut app);
     assert!(app.settings_unsaved_prompt_visible);
 
-    let prompt_areas = settings_unsaved_prompt_areas(&app).expect("unsaved prompt areas");
-    handler(
-      mouse_event(
-        MouseEventKind::Down(MouseButton::Left),
-        prompt_areas.no.x + 1,
-        prompt_areas.no.y,
-      ),
-      &mut app,
-    );
+    draw(&app);
+    let no = area_of(&app, MouseTarget::UnsavedPromptNo);
+    send(&mut app, left_click(no.x + 1, no.y));
 
     assert!(!app.settings_unsaved_prompt_visible);
     assert_ne!(app.get_current_route().active_block, ActiveBlock::Settings);
   }
 
+  #[test]
+  fn clicks_around_the_unsaved_prompt_are_ignored() {
+    let mut app = app_of_size(160, 50);
+    open_settings(&mut app);
+    app.settings_unsaved_prompt_visible = true;
+    draw(&app);
+
+    let list = area_of(&app, MouseTarget::SettingsList);
+    send(&mut app, left_click(list.x + 2, list.y + 2));
+
+    assert!(app.settings_unsaved_prompt_visible);
+    assert_eq!(app.settings_selected_index, 0);
+  }
+
   #[test]
   fn click_in_playlist_selects_row() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
     with_playlist_items(&mut app);
     app.selected_playlist_index = Some(0);
+    draw(&app);
 
-    let areas = main_layout_areas(&app).expect("layout areas");
-    let x = areas.playlists.x + 1;
-    let y = areas.playlists.y + 2;
-
-    handler(
-      mouse_event(MouseEventKind::Down(MouseButton::Left), x, y),
-      &mut app,
-    );
+    let playlists = area_of(&app, MouseTarget::Playlists);
+    send(&mut app, left_click(playlists.x + 1, playlists.y + 2));
 
     assert_eq!(app.selected_playlist_index, Some(1));
     let current_route = app.get_current_route();
     assert_eq!(current_route.active_block, ActiveBlock::MyPlaylists);
   }
 
+  #[test]
+  fn clicking_a_folder_enters_it_and_back_returns() {
+    let mut app = app_of_size(160, 50);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
+    app.all_playlists = vec![fixtures::playlist("Road Trip", "Sam", 42)];
+    app.playlist_folder_items = vec![
+      PlaylistFolderItem::Playlist {
+        index: 0,
+        current_id: 0,
+      },
+      PlaylistFolderItem::Folder(PlaylistFolder {
+        name: "Chill".to_string(),
+        current_id: 0,
+        target_id: 1,
+      }),
+      PlaylistFolderItem::Folder(PlaylistFolder {
+        name: "\u{2190} Back".to_string(),
+        current_id: 1,
+        target_id: 0,
+      }),
+    ];
+    app.selected_playlist_index = Some(0);
+    draw(&app);
+
+    let playlists = area_of(&app, MouseTarget::Playlists);
+    let x = playlists.x + 1;
+    let first_row = playlists.y + 1;
+
+    send(&mut app, left_click(x, first_row + 1));
+    assert_eq!(app.current_playlist_folder_id, 1);
+    assert_eq!(app.selected_playlist_index, Some(0));
+
+    send(&mut app, left_click(x, first_row));
+    assert_eq!(app.current_playlist_folder_id, 0);
+  }
+
+  #[test]
+  fn double_clicking_a_library_entry_opens_it() {
+    let mut app = app_of_size(160, 50);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
+    app.library.selected_index = 0;
+    draw(&app);
+
+    let library = area_of(&app, MouseTarget::Library);
+    let queue_row = library.y + 1 + (LIBRARY_OPTIONS.len() - 1) as u16;
+    let click = left_click(library.x + 1, queue_row);
+
+    send(&mut app, click);
+    assert_eq!(app.library.selected_index, LIBRARY_OPTIONS.len() - 1);
+    assert_eq!(app.get_current_route().id, RouteId::Home);
+
+    send(&mut app, click);
+    assert_eq!(app.get_current_route().id, RouteId::Queue);
+  }
+
+  #[test]
+  fn clicks_too_far_apart_are_not_a_double_click() {
+    let mut app = app_of_size(160, 50);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
+    app.library.selected_index = 0;
+    draw(&app);
+
+    let library = area_of(&app, MouseTarget::Library);
+    let queue_row = library.y + 1 + (LIBRARY_OPTIONS.len() - 1) as u16;
+    let click = left_click(library.x + 1, queue_row);
+
+    send(&mut app, click);
+    let_last_click_age(&mut app);
+    send(&mut app, click);
+    assert_eq!(app.get_current_route().id, RouteId::Home);
+
+    // The slow click still counts as the first of a new double-click
+    send(&mut app, click);
+    assert_eq!(app.get_current_route().id, RouteId::Queue);
+  }
+
   #[test]
   fn click_outside_playlist_is_ignored() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     with_playlist_items(&mut app);
     app.selected_playlist_index = Some(1);
+    draw(&app);
 
-    handler(
-      mouse_event(MouseEventKind::Down(MouseButton::Left), 0, 0),
-      &mut app,
-    );
+    send(&mut app, left_click(0, 0));
 
     assert_eq!(app.selected_playlist_index, Some(1));
   }
@@ -1116,48 +1115,201 @@ mod tests {
   #[test]
   fn clicked_index_respects_list_scroll_offset() {
     let area = Rect::new(0, 0, 20, 8); // Inner height = 6 rows
-    let selected_index = 8;
+    let offset = 3;
     let total_items = 20;
 
-    let first_visible = list_item_index_from_click(area, 1, selected_index, total_items);
-    let second_visible = list_item_index_from_click(area, 2, selected_index, total_items);
+    let first_visible = list_item_index_from_click(area, 1, offset, total_items);
+    let second_visible = list_item_index_from_click(area, 2, offset, total_items);
 
     assert_eq!(first_visible, Some(3));
     assert_eq!(second_visible, Some(4));
   }
 
+  #[test]
+  fn clicks_land_on_the_row_a_scrolled_list_shows() {
+    let mut app = app_of_size(160, 30);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
+    app.all_playlists = (0..40)
+      .map(|i| fixtures::playlist(&format!("Mix {}", i), "Sam", 10))
+      .collect();
+    app.playlist_folder_items = (0..app.all_playlists.len())
+      .map(|index| PlaylistFolderItem::Playlist {
+        index,
+        current_id: 0,
+      })
+      .collect();
+    app.selected_playlist_index = Some(30);
+    draw(&app);
+
+    let offset = app
+      .mouse_regions
+      .borrow()
+      .iter()
+      .find(|region| region.target == MouseTarget::Playlists)
+      .map(|region| region.offset)
+      .unwrap();
+    assert!(offset > 0);
+
+    let playlists = area_of(&app, MouseTarget::Playlists);
+    send(&mut app, left_click(playlists.x + 1, playlists.y + 1));
+    assert_eq!(app.selected_playlist_index, Some(offset));
+  }
+
   #[test]
   fn scroll_over_library_changes_selection() {
-    let mut app = App::default();
-    app.size = Size {
-      width: 160,
-      height: 50,
-    };
+    let mut app = app_of_size(160, 50);
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Home);
     app.library.selected_index = 0;
+    draw(&app);
 
-    let areas = main_layout_areas(&app).expect("layout areas");
-    let x = areas.library.x + 1;
-    let y = areas.library.y + 1;
+    let library = area_of(&app, MouseTarget::Library);
+    let x = library.x + 1;
+    let y = library.y + 1;
 
-    handler(mouse_event(MouseEventKind::ScrollDown, x, y), &mut app);
+    send(&mut app, mouse_event(MouseEventKind::ScrollDown, x, y));
     assert_eq!(app.library.selected_index, 1);
 
-    handler(mouse_event(MouseEventKind::ScrollUp, x, y), &mut app);
+    send(&mut app, mouse_event(MouseEventKind::ScrollUp, x, y));
     assert_eq!(app.library.selected_index, 0);
 
     let current_route = app.get_current_route();
     assert_eq!(current_route.active_block, ActiveBlock::Library);
   }
 
+  #[test]
+  fn wheel_and_clicks_work_in_content_tables() {
+    let mut app = app_of_size(160, 50);
+    app.user_config.behavior.mouse_scroll_lines = 2;
+    app.queue.result = (0..5)
+      .map(|i| crate::core::app::QueueItem {
+        id: i.to_string(),
+        uri: format!("spotify:track:{}", i),
+        name: i.to_string(),
+        artist: String::new(),
+        duration_ms: 0,
+        added_here: false,
+      })
+      .collect();
+    app.push_navigation_stack(RouteId::Queue, ActiveBlock::Empty);
+    draw(&app);
+
+    let content = area_of(&app, MouseTarget::ContentTable);
+    let x = content.x + 2;
+    send(
+      &mut app,
+      mouse_event(MouseEventKind::ScrollDown, x, content.y + 3),
+    );
+    assert_eq!(app.queue.index, 2);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Queue);
+
+    // The first data row sits below the border and the header
+    send(&mut app, left_click(x, content.y + 2));
+    assert_eq!(app.queue.index, 0);
+  }
+
+  #[test]
+  fn clicks_select_album_tracks() {
+    let mut app = app_of_size(160, 50);
+    app.selected_album_full = Some(SelectedFullAlbum {
+      album: fixtures::album("Coastline", "The Tides", &["Harbor Lights", "Low Tide"]),
+      selected_index: 0,
+    });
+    app.album_table_context = AlbumTableContext::Full;
+    app.push_navigation_stack(RouteId::AlbumTracks, ActiveBlock::Empty);
+    draw(&app);
+
+    let table = area_of(&app, MouseTarget::ContentTable);
+    send(&mut app, left_click(table.x + 2, table.y + 3));
+
+    assert_eq!(app.saved_album_tracks_index, 1);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::AlbumTracks
+    );
+  }
+
+  #[test]
+  fn clicking_a_search_result_selects_it() {
+    let mut app = app_of_size(160, 50);
+    app.search_results.tracks = Some(fixtures::page(vec![
+      fixtures::track("Harbor Lights", "The Tides", "Coastline", 214_000),
+      fixtures::track("Paper Planes", "Northern Echo", "Drift", 187_000),
+    ]));
+    app.search_results.artists = Some(fixtures::page(vec![
+      fixtures::artist("Night Owls"),
+      fixtures::artist("The Tides"),
+    ]));
+    app.push_navigation_stack(RouteId::Search, ActiveBlock::Input);
+    draw(&app);
+
+    let artists = area_of(
+      &app,
+      MouseTarget::SearchResult(SearchResultBlock::ArtistSearch),
+    );
+    send(&mut app, left_click(artists.x + 2, artists.y + 2));
+
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::SearchResultBlock
+    );
+    assert_eq!(
+      app.search_results.selected_block,
+      SearchResultBlock::ArtistSearch
+    );
+    assert_eq!(app.search_results.selected_artists_index, Some(1));
+
+    let songs = area_of(
+      &app,
+      MouseTarget::SearchResult(SearchResultBlock::SongSearch),
+    );
+    send(
+      &mut app,
+      mouse_event(MouseEventKind::ScrollDown, songs.x + 2, songs.y + 1),
+    );
+    assert_eq!(
+      app.search_results.selected_block,
+      SearchResultBlock::SongSearch
+    );
+    assert_eq!(app.search_results.selected_tracks_index, Some(0));
+  }
+
+  #[test]
+  fn clicking_an_artist_top_track_selects_it() {
+    let mut app = app_of_size(160, 50);
+    app.artist = Some(Artist {
+      artist_id: fixtures::id("The Tides"),
+      artist_name: "The Tides".to_string(),
+      albums: fixtures::page(Vec::new()),
+      related_artists: Vec::new(),
+      top_tracks: vec![
+        fixtures::track("Harbor Lights", "The Tides", "Coastline", 214_000),
+        fixtures::track("Low Tide", "The Tides", "Coastline", 198_000),
+      ],
+      selected_album_index: 0,
+      selected_related_artist_index: 0,
+      selected_top_track_index: 0,
+      artist_hovered_block: ArtistBlock::Albums,
+      artist_selected_block: ArtistBlock::Empty,
+    });
+    app.push_navigation_stack(RouteId::Artist, ActiveBlock::ArtistBlock);
+    draw(&app);
+
+    let top_tracks = area_of(&app, MouseTarget::Artist(ArtistBlock::TopTracks));
+    send(&mut app, left_click(top_tracks.x + 2, top_tracks.y + 2));
+
+    let artist = app.artist.as_ref().unwrap();
+    assert_eq!(artist.artist_selected_block, ArtistBlock::TopTracks);
+    assert_eq!(artist.selected_top_track_index, 1);
+  }
+
   #[test]
   fn table_click_mapping_respects_table_offset() {
     let area = Rect::new(0, 0, 80, 12);
-    let selected_index = 15;
+    let offset = 14;
     let item_count = 40;
 
-    let first = table_item_index_from_click(area, 2, selected_index, item_count);
-    let second = table_item_index_from_click(area, 3, selected_index, item_count);
+    let first = table_item_index_from_click(area, 2, offset, item_count);
+    let second = table_item_index_from_click(area, 3, offset, item_count);
 
     assert_eq!(first, Some(14));
     assert_eq!(second, Some(15));
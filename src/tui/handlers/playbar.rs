@@ -9,27 +9,7 @@ pub fn handler(key: Key, app: &mut App) {
     k if common_key_events::up_event(k) => {
       app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::MyPlaylists));
     }
-    Key::Char('s') => {
-      if let Some(CurrentPlaybackContext {
-        item: Some(item), ..
-      }) = app.current_playback_context.to_owned()
-      {
-        match item {
-          PlayableItem::Track(track) => {
-            if let Some(track_id) = track.id {
-              app.dispatch(IoEvent::ToggleSaveTrack(PlayableId::Track(
-                track_id.into_static(),
-              )));
-            }
-          }
-          PlayableItem::Episode(episode) => {
-            app.dispatch(IoEvent::ToggleSaveTrack(PlayableId::Episode(
-              episode.id.into_static(),
-            )));
-          }
-        };
-      };
-    }
+    Key::Char('s') => toggle_save_currently_playing(app),
     Key::Char('w') => {
       add_currently_playing_track_to_playlist(app);
     }
@@ -37,6 +17,28 @@ pub fn handler(key: Key, app: &mut App) {
   };
 }
 
+pub(crate) fn toggle_save_currently_playing(app: &mut App) {
+  if let Some(CurrentPlaybackContext {
+    item: Some(item), ..
+  }) = app.current_playback_context.to_owned()
+  {
+    match item {
+      PlayableItem::Track(track) => {
+        if let Some(track_id) = track.id {
+          app.dispatch(IoEvent::ToggleSaveTrack(PlayableId::Track(
+            track_id.into_static(),
+          )));
+        }
+      }
+      PlayableItem::Episode(episode) => {
+        app.dispatch(IoEvent::ToggleSaveTrack(PlayableId::Episode(
+          episode.id.into_static(),
+        )));
+      }
+    };
+  };
+}
+
 pub(crate) fn add_currently_playing_track_to_playlist(app: &mut App) {
   if let Some(CurrentPlaybackContext {
     item: Some(item), ..
@@ -83,4 +85,43 @@ mod tests {
       Some("No track currently playing")
     );
   }
+
+  #[test]
+  fn saving_an_episode_updates_episode_set_and_animates() {
+    use rspotify::model::idtypes::EpisodeId;
+
+    let mut app = App::default();
+    let episode = PlayableId::Episode(EpisodeId::from_id("512ojhOuo1ktJprKbVcKyQ").unwrap());
+
+    assert!(!app.is_playable_saved(&episode));
+    app.apply_playable_saved(&episode, true);
+    assert!(app.is_playable_saved(&episode));
+    assert!(app.saved_episode_ids_set.contains("512ojhOuo1ktJprKbVcKyQ"));
+    assert!(app.liked_song_ids_set.is_empty());
+    assert_eq!(app.liked_song_animation_frame, Some(10));
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Saved to Your Episodes")
+    );
+
+    app.apply_playable_saved(&episode, false);
+    assert!(!app.is_playable_saved(&episode));
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Removed from Your Episodes")
+    );
+  }
+
+  #[test]
+  fn saving_a_track_updates_liked_songs() {
+    use rspotify::model::idtypes::TrackId;
+
+    let mut app = App::default();
+    let track = PlayableId::Track(TrackId::from_id("4uLU6hMCjMI75M1A2tKUQC").unwrap());
+
+    app.apply_playable_saved(&track, true);
+    assert!(app.is_playable_saved(&track));
+    assert!(app.saved_episode_ids_set.is_empty());
+    assert_eq!(app.status_message.as_deref(), Some("Added to Liked Songs"));
+  }
 }
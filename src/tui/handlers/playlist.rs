@@ -52,25 +52,19 @@ pub fn handler(key: Key, app: &mut App) {
               app.current_playlist_folder_id = folder.target_id;
               app.selected_playlist_index = Some(0);
             }
-            PlaylistFolderItem::Playlist { index, .. } => {
-              // Open the playlist tracks
-              if let Some(playlist) = app.all_playlists.get(*index) {
-                app.active_playlist_index = Some(*index);
-                app.track_table.context = Some(TrackTableContext::MyPlaylists);
-                app.playlist_offset = 0;
-                let playlist_id = playlist.id.clone().into_static();
-                app.dispatch(IoEvent::GetPlaylistItems(
-                  playlist_id.clone(),
-                  app.playlist_offset,
-                ));
-                // Pre-fetch more pages in background for seamless playback
-                app.dispatch(IoEvent::PreFetchAllPlaylistTracks(playlist_id));
-              }
-            }
+            PlaylistFolderItem::Playlist { index, .. } => open_playlist(app, *index),
+            PlaylistFolderItem::PinnedHeader => {}
           }
         }
       }
     }
+    _ if key == app.user_config.keys.search => app.open_playlist_filter(),
+    Key::Char('P') => app.toggle_selected_playlist_public(),
+    Key::Char('O') => app.toggle_selected_playlist_collaborative(),
+    Key::Char('U') => app.view_selected_playlist_owner(),
+    Key::Char('N') => app.open_new_playlist_prompt(),
+    Key::Char('r') => app.open_playlist_details_prompt(),
+    Key::Char('f') => app.toggle_pin_selected_playlist(),
     Key::Char('D') => {
       if let Some(selected_idx) = app.selected_playlist_index {
         if let Some(PlaylistFolderItem::Playlist { index, .. }) =
@@ -93,8 +87,461 @@ pub fn handler(key: Key, app: &mut App) {
   }
 }
 
+pub(super) fn open_playlist(app: &mut App, index: usize) {
+  // Open the playlist tracks
+  if let Some(playlist) = app.all_playlists.get(index) {
+    app.active_playlist_index = Some(index);
+    app.track_table.context = Some(TrackTableContext::MyPlaylists);
+    app.playlist_offset = 0;
+    let playlist_id = playlist.id.clone().into_static();
+    app.dispatch(IoEvent::GetPlaylistItems(
+      playlist_id.clone(),
+      app.playlist_offset,
+    ));
+    // Pre-fetch more pages in background for seamless playback
+    app.dispatch(IoEvent::PreFetchAllPlaylistTracks(playlist_id));
+  }
+}
+
+/// Keys typed while the sidebar filter is open. Text goes into the query, so
+/// only arrow keys move the selection.
+pub fn filter_handler(key: Key, app: &mut App) {
+  match key {
+    Key::Esc => app.close_playlist_filter(),
+    Key::Enter => {
+      let selected = app
+        .selected_playlist_index
+        .and_then(|selected_idx| app.get_playlist_display_item_at(selected_idx));
+      let playlist_index = match selected {
+        Some(PlaylistFolderItem::Playlist { index, .. }) => Some(*index),
+        _ => None,
+      };
+      if let Some(index) = playlist_index {
+        app.close_playlist_filter();
+        open_playlist(app, index);
+      }
+    }
+    Key::Down | Key::Ctrl('n') => {
+      let count = app.get_playlist_display_count();
+      if count > 0 {
+        let current = app.selected_playlist_index.unwrap_or(0);
+        app.selected_playlist_index = Some((current + 1) % count);
+      }
+    }
+    Key::Up | Key::Ctrl('p') => {
+      let count = app.get_playlist_display_count();
+      if count > 0 {
+        let current = app.selected_playlist_index.unwrap_or(0);
+        app.selected_playlist_index = Some(if current == 0 { count - 1 } else { current - 1 });
+      }
+    }
+    Key::Backspace => {
+      if let Some(query) = &mut app.playlist_filter {
+        query.pop();
+      }
+      reset_filter_selection(app);
+    }
+    Key::Char(c) => {
+      if let Some(query) = &mut app.playlist_filter {
+        query.push(c);
+      }
+      reset_filter_selection(app);
+    }
+    _ => {}
+  }
+}
+
+/// Keys typed while the new playlist prompt is open
+pub fn new_playlist_handler(key: Key, app: &mut App) {
+  match key {
+    Key::Esc => app.new_playlist = None,
+    Key::Enter => app.submit_new_playlist(),
+    Key::Tab => {
+      if let Some((_, public)) = &mut app.new_playlist {
+        *public = !*public;
+      }
+    }
+    Key::Backspace => {
+      if let Some((name, _)) = &mut app.new_playlist {
+        name.pop();
+      }
+    }
+    Key::Char(c) => {
+      if let Some((name, _)) = &mut app.new_playlist {
+        name.push(c);
+      }
+    }
+    _ => {}
+  }
+}
+
+/// Keys typed while the rename prompt is open
+pub fn playlist_details_handler(key: Key, app: &mut App) {
+  match key {
+    Key::Esc => app.playlist_details_edit = None,
+    Key::Enter => app.submit_playlist_details(),
+    Key::Tab => {
+      if let Some(edit) = &mut app.playlist_details_edit {
+        edit.editing_description = !edit.editing_description;
+      }
+    }
+    Key::Backspace => {
+      if let Some(edit) = &mut app.playlist_details_edit {
+        if edit.editing_description {
+          edit.description.pop();
+        } else {
+          edit.name.pop();
+        }
+      }
+    }
+    Key::Char(c) => {
+      if let Some(edit) = &mut app.playlist_details_edit {
+        if edit.editing_description {
+          edit.description.push(c);
+        } else {
+          edit.name.push(c);
+        }
+      }
+    }
+    _ => {}
+  }
+}
+
+fn reset_filter_selection(app: &mut App) {
+  app.selected_playlist_index = if app.get_playlist_display_count() > 0 {
+    Some(0)
+  } else {
+    None
+  };
+}
+
 #[cfg(test)]
 mod tests {
+  use super::*;
+  use crate::core::app::{toggled_playlist_collaborative, toggled_playlist_public, PlaylistFolder};
+  use rspotify::model::playlist::SimplifiedPlaylist;
+
+  fn playlist(id: &str, name: &str) -> SimplifiedPlaylist {
+    serde_json::from_value(serde_json::json!({
+      "collaborative": false,
+      "external_urls": {},
+      "href": "",
+      "id": id,
+      "images": [],
+      "name": name,
+      "owner": { "external_urls": {}, "href": "", "id": "owner" },
+      "public": true,
+      "snapshot_id": "",
+      "tracks": { "href": "", "total": 0 },
+    }))
+    .unwrap()
+  }
+
+  /// A root folder holding "Road Trip" and a "Chill" folder holding "Road Home"
+  fn app_with_folders() -> App {
+    folders_in(App::default())
+  }
+
+  /// `app` with the playlists and folders of `app_with_folders`
+  fn folders_in(mut app: App) -> App {
+    app.all_playlists = vec![
+      playlist("37i9dQZF1DXcBWIGoYBM5M", "Road Trip"),
+      playlist("37i9dQZF1DX4sWSpwq3LiO", "Road Home"),
+    ];
+    app.playlist_folder_items = vec![
+      PlaylistFolderItem::Folder(PlaylistFolder {
+        name: "Chill".to_string(),
+        current_id: 0,
+        target_id: 1,
+      }),
+      PlaylistFolderItem::Playlist {
+        index: 0,
+        current_id: 0,
+      },
+      PlaylistFolderItem::Playlist {
+        index: 1,
+        current_id: 1,
+      },
+    ];
+    app.set_current_route_state(
+      Some(ActiveBlock::MyPlaylists),
+      Some(ActiveBlock::MyPlaylists),
+    );
+    app.selected_playlist_index = Some(1);
+    app
+  }
+
+  #[test]
+  fn filter_matches_playlists_across_folders() {
+    let mut app = app_with_folders();
+    assert_eq!(app.get_playlist_display_count(), 2);
+
+    handler(Key::Char('/'), &mut app);
+    assert!(app.is_playlist_filter_open());
+    for c in "road".chars() {
+      filter_handler(Key::Char(c), &mut app);
+    }
+    assert_eq!(app.get_playlist_display_count(), 2);
+
+    filter_handler(Key::Char(' '), &mut app);
+    filter_handler(Key::Char('h'), &mut app);
+    assert_eq!(app.get_playlist_display_count(), 1);
+    assert_eq!(app.selected_playlist_index, Some(0));
+  }
+
+  #[test]
+  fn closing_filter_restores_folder_view() {
+    let mut app = app_with_folders();
+    handler(Key::Char('/'), &mut app);
+    filter_handler(Key::Char('x'), &mut app);
+    assert_eq!(app.get_playlist_display_count(), 0);
+
+    filter_handler(Key::Esc, &mut app);
+    assert!(!app.is_playlist_filter_open());
+    assert_eq!(app.current_playlist_folder_id, 0);
+    assert_eq!(app.selected_playlist_index, Some(1));
+    assert_eq!(app.get_playlist_display_count(), 2);
+  }
+
+  #[test]
+  fn enter_opens_match_and_clears_filter() {
+    let mut app = app_with_folders();
+    handler(Key::Char('/'), &mut app);
+    for c in "home".chars() {
+      filter_handler(Key::Char(c), &mut app);
+    }
+
+    filter_handler(Key::Enter, &mut app);
+    assert!(app.playlist_filter.is_none());
+    assert_eq!(app.active_playlist_index, Some(1));
+    assert_eq!(app.current_playlist_folder_id, 0);
+  }
+
+  #[test]
+  fn leaving_the_sidebar_closes_the_filter() {
+    let mut app = app_with_folders();
+    handler(Key::Char('/'), &mut app);
+    for c in "home".chars() {
+      filter_handler(Key::Char(c), &mut app);
+    }
+    assert_eq!(app.get_playlist_display_count(), 1);
+
+    // Like a click on the library
+    app.set_current_route_state(Some(ActiveBlock::Library), Some(ActiveBlock::Library));
+    assert!(app.playlist_filter.is_none());
+    assert_eq!(app.get_playlist_display_count(), 2);
+    assert_eq!(app.selected_playlist_index, Some(1));
+  }
+
+  #[test]
+  fn pinned_playlists_lead_the_root_folder() {
+    let mut app = app_with_folders();
+    app.current_playlist_folder_id = 1;
+    app.selected_playlist_index = Some(0);
+    handler(Key::Char('f'), &mut app);
+    assert_eq!(
+      app.user_config.behavior.pinned_playlists,
+      ["37i9dQZF1DX4sWSpwq3LiO"]
+    );
+
+    // Still in its folder, and first at the root under a "Pinned" header
+    assert!(matches!(
+      app.get_playlist_display_item_at(0),
+      Some(PlaylistFolderItem::Playlist { index: 1, .. })
+    ));
+    app.current_playlist_folder_id = 0;
+    assert_eq!(app.get_playlist_display_count(), 4);
+    assert!(matches!(
+      app.get_playlist_display_item_at(0),
+      Some(PlaylistFolderItem::PinnedHeader)
+    ));
+    assert!(matches!(
+      app.get_playlist_display_item_at(1),
+      Some(PlaylistFolderItem::Playlist { index: 1, .. })
+    ));
+
+    // Pinning a root playlist moves it up rather than listing it twice
+    app.selected_playlist_index = Some(3);
+    handler(Key::Char('f'), &mut app);
+    assert_eq!(app.get_playlist_display_count(), 4);
+    assert_eq!(app.selected_playlist_index, Some(2));
+    assert!(matches!(
+      app.get_playlist_display_item_at(3),
+      Some(PlaylistFolderItem::Folder(_))
+    ));
+
+    // The header goes with the last pin
+    app.selected_playlist_index = Some(1);
+    handler(Key::Char('f'), &mut app);
+    assert_eq!(
+      app.user_config.behavior.pinned_playlists,
+      ["37i9dQZF1DXcBWIGoYBM5M"]
+    );
+    assert_eq!(app.get_playlist_display_count(), 3);
+    app.selected_playlist_index = Some(1);
+    handler(Key::Char('f'), &mut app);
+    assert!(app.user_config.behavior.pinned_playlists.is_empty());
+    assert_eq!(app.get_playlist_display_count(), 2);
+    assert!(matches!(
+      app.get_playlist_display_item_at(0),
+      Some(PlaylistFolderItem::Folder(_))
+    ));
+  }
+
+  #[test]
+  fn enter_on_the_pinned_header_does_nothing() {
+    let mut app = app_with_folders();
+    app.user_config.behavior.pinned_playlists = vec!["37i9dQZF1DX4sWSpwq3LiO".to_string()];
+    app.selected_playlist_index = Some(0);
+    handler(Key::Enter, &mut app);
+    assert_eq!(app.current_playlist_folder_id, 0);
+    assert_eq!(app.active_playlist_index, None);
+  }
+
   #[test]
   fn test() {}
+
+  #[test]
+  fn new_playlist_prompt_starts_on_configured_visibility() {
+    let mut app = app_with_folders();
+    app.user_config.behavior.default_playlist_public = false;
+    handler(Key::Char('N'), &mut app);
+    assert_eq!(app.new_playlist, Some((String::new(), false)));
+
+    // Typing goes into the name instead of triggering sidebar keys
+    for c in "New".chars() {
+      crate::tui::handlers::handle_app(Key::Char(c), &mut app);
+    }
+    crate::tui::handlers::handle_app(Key::Tab, &mut app);
+    assert_eq!(app.new_playlist, Some(("New".to_string(), true)));
+
+    crate::tui::handlers::handle_app(Key::Enter, &mut app);
+    assert!(app.new_playlist.is_none());
+  }
+
+  #[test]
+  fn rename_prompt_only_opens_on_owned_playlists() {
+    use crate::core::fixtures;
+    use crate::core::user_config::UserConfig;
+
+    let (io_tx, io_rx) = std::sync::mpsc::channel();
+    let mut app = folders_in(App::new(
+      io_tx,
+      UserConfig::new(),
+      std::time::SystemTime::now(),
+    ));
+    handler(Key::Char('r'), &mut app);
+    assert!(app.playlist_details_edit.is_none());
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("You can only change playlists you own")
+    );
+
+    let user = fixtures::user("Sam");
+    app.all_playlists[0].owner.id = user.id.clone();
+    app.user = Some(user);
+    handler(Key::Char('r'), &mut app);
+    assert!(matches!(
+      io_rx.try_recv(),
+      Ok(IoEvent::GetPlaylistDescription(_))
+    ));
+    let edit = app.playlist_details_edit.as_mut().unwrap();
+    assert_eq!(edit.name, "Road Trip");
+    // As the description request fills it in
+    edit.description = "Songs for the car".to_string();
+    edit.original_description = Some(edit.description.clone());
+
+    // Typing goes into the prompt, Tab switches to the description
+    crate::tui::handlers::handle_app(Key::Backspace, &mut app);
+    for c in "ps".chars() {
+      crate::tui::handlers::handle_app(Key::Char(c), &mut app);
+    }
+    crate::tui::handlers::handle_app(Key::Tab, &mut app);
+    crate::tui::handlers::handle_app(Key::Char('!'), &mut app);
+    let edit = app.playlist_details_edit.as_ref().unwrap();
+    assert_eq!(edit.name, "Road Trips");
+    assert_eq!(edit.description, "Songs for the car!");
+
+    crate::tui::handlers::handle_app(Key::Enter, &mut app);
+    assert!(app.playlist_details_edit.is_none());
+    assert!(matches!(
+      io_rx.try_recv(),
+      Ok(IoEvent::ChangePlaylistDetails(_, Some(name), Some(description)))
+        if name == "Road Trips" && description == "Songs for the car!"
+    ));
+  }
+
+  #[test]
+  fn emptying_the_description_clears_it() {
+    use crate::core::app::PlaylistDetailsEdit;
+    use crate::core::user_config::UserConfig;
+
+    let (io_tx, io_rx) = std::sync::mpsc::channel();
+    let mut app = folders_in(App::new(
+      io_tx,
+      UserConfig::new(),
+      std::time::SystemTime::now(),
+    ));
+    app.playlist_details_edit = Some(PlaylistDetailsEdit {
+      playlist_id: app.all_playlists[0].id.clone(),
+      name: "Road Trip".to_string(),
+      description: String::new(),
+      original_description: Some("Songs for the car".to_string()),
+      editing_description: true,
+    });
+    playlist_details_handler(Key::Enter, &mut app);
+    assert!(matches!(
+      io_rx.try_recv(),
+      Ok(IoEvent::ChangePlaylistDetails(_, None, Some(description))) if description.is_empty()
+    ));
+
+    // Nothing changed, nothing sent
+    app.playlist_details_edit = Some(PlaylistDetailsEdit {
+      playlist_id: app.all_playlists[0].id.clone(),
+      name: "Road Trip".to_string(),
+      description: "Songs for the car".to_string(),
+      original_description: Some("Songs for the car".to_string()),
+      editing_description: false,
+    });
+    playlist_details_handler(Key::Enter, &mut app);
+    assert!(io_rx.try_recv().is_err());
+  }
+
+  #[test]
+  fn owner_key_lists_owner_playlists_except_for_spotify() {
+    let mut app = app_with_folders();
+    handler(Key::Char('U'), &mut app);
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Loading playlists by owner")
+    );
+
+    app.all_playlists[0].owner.id = rspotify::model::idtypes::UserId::from_id("spotify")
+      .unwrap()
+      .into_static();
+    handler(Key::Char('U'), &mut app);
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Curated by Spotify: https://open.spotify.com/user/spotify")
+    );
+  }
+
+  #[test]
+  fn toggling_public_clears_collaborative() {
+    assert_eq!(toggled_playlist_public(Some(false), true), (true, false));
+    assert_eq!(toggled_playlist_public(None, false), (true, false));
+    assert_eq!(toggled_playlist_public(Some(true), false), (false, false));
+  }
+
+  #[test]
+  fn enabling_collaborative_makes_playlist_private() {
+    assert_eq!(
+      toggled_playlist_collaborative(Some(true), false),
+      (false, true)
+    );
+    assert_eq!(
+      toggled_playlist_collaborative(Some(false), true),
+      (false, false)
+    );
+  }
 }
@@ -0,0 +1,77 @@
+use super::common_key_events;
+use crate::core::app::App;
+use crate::tui::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+  let items = &app.queue.result;
+  match key {
+    k if common_key_events::left_event(k) => common_key_events::handle_left_event(app),
+    k if common_key_events::down_event(k) => {
+      app.queue.index = common_key_events::on_down_press_handler(items, Some(app.queue.index));
+    }
+    k if common_key_events::up_event(k) => {
+      app.queue.index = common_key_events::on_up_press_handler(items, Some(app.queue.index));
+    }
+    k if common_key_events::high_event(k) => {
+      app.queue.index = common_key_events::on_high_press_handler();
+    }
+    k if common_key_events::middle_event(k) => {
+      app.queue.index = common_key_events::on_middle_press_handler(items);
+    }
+    k if common_key_events::low_event(k) => {
+      if !items.is_empty() {
+        app.queue.index = common_key_events::on_low_press_handler(items);
+      }
+    }
+    Key::Char('r') => app.open_queue(),
+    _ => {}
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::app::QueueItem;
+
+  fn item(id: &str) -> QueueItem {
+    QueueItem::from_json(&serde_json::json!({
+      "id": id,
+      "uri": format!("spotify:track:{}", id),
+      "name": id,
+      "duration_ms": 1000,
+      "artists": [{ "name": "One" }, { "name": "Two" }],
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn empty_refresh_keeps_previous_queue_as_stale() {
+    let mut app = App::default();
+    app.locally_queued_uris = vec!["spotify:track:b".to_string()];
+    app.apply_queue_update(Some(vec![item("a"), item("b")]));
+    assert_eq!(app.queue_length, Some(2));
+    assert_eq!(app.queue.result[0].artist, "One, Two");
+    assert!(app.queue.result[1].added_here);
+
+    app.apply_queue_update(Some(Vec::new()));
+    assert!(app.queue_stale);
+    assert_eq!(app.queue.result.len(), 2);
+
+    // Asked twice, the queue really is empty
+    app.apply_queue_update(Some(Vec::new()));
+    assert!(!app.queue_stale);
+    assert!(app.queue.result.is_empty());
+    assert_eq!(app.queue_length, Some(0));
+  }
+
+  #[test]
+  fn played_local_additions_are_forgotten() {
+    let mut app = App::default();
+    app.locally_queued_uris = vec!["spotify:track:a".to_string()];
+    app.apply_queue_update(Some(vec![item("b")]));
+    assert!(app.locally_queued_uris.is_empty());
+
+    handler(Key::Down, &mut app);
+    assert_eq!(app.queue.index, 0);
+  }
+}
@@ -88,13 +88,15 @@ pub fn handler(key: Key, app: &mut App) {
         };
       };
     }
-    _ if key == app.user_config.keys.add_item_to_queue => {
+    _ if common_key_events::queue_event(key, app) => {
       if let Some(recently_played_result) = &app.recently_played.result.clone() {
         if let Some(selected_track) = recently_played_result.items.get(app.recently_played.index) {
           if let Some(track_id) = &selected_track.track.id {
-            app.dispatch(IoEvent::AddItemToQueue(PlayableId::Track(
-              track_id.clone().into_static(),
-            )));
+            common_key_events::queue_selected(
+              app,
+              key,
+              PlayableId::Track(track_id.clone().into_static()),
+            );
           };
         };
       };
@@ -59,25 +59,38 @@ fn handle_down_press_on_selected_block(app: &mut App) {
   }
 }
 
+/// Row and column of `block` in the configured search layout
+fn search_block_position(app: &App, block: SearchResultBlock) -> Option<(usize, usize)> {
+  app
+    .search_block_rows()
+    .iter()
+    .enumerate()
+    .find_map(|(row, blocks)| {
+      blocks
+        .iter()
+        .position(|layout| layout.block == block)
+        .map(|column| (row, column))
+    })
+}
+
+/// Hover the block above or below, wrapping around and keeping the column where possible
+fn move_hovered_block_vertically(app: &mut App, down: bool) {
+  let rows = app.search_block_rows();
+  let Some((row, column)) = search_block_position(app, app.search_results.hovered_block) else {
+    app.search_results.hovered_block = rows[0][0].block;
+    return;
+  };
+  let next_row = if down {
+    (row + 1) % rows.len()
+  } else {
+    (row + rows.len() - 1) % rows.len()
+  };
+  let next_column = column.min(rows[next_row].len() - 1);
+  app.search_results.hovered_block = rows[next_row][next_column].block;
+}
+
 fn handle_down_press_on_hovered_block(app: &mut App) {
-  match app.search_results.hovered_block {
-    SearchResultBlock::AlbumSearch => {
-      app.search_results.hovered_block = SearchResultBlock::ShowSearch;
-    }
-    SearchResultBlock::SongSearch => {
-      app.search_results.hovered_block = SearchResultBlock::AlbumSearch;
-    }
-    SearchResultBlock::ArtistSearch => {
-      app.search_results.hovered_block = SearchResultBlock::PlaylistSearch;
-    }
-    SearchResultBlock::PlaylistSearch => {
-      app.search_results.hovered_block = SearchResultBlock::ShowSearch;
-    }
-    SearchResultBlock::ShowSearch => {
-      app.search_results.hovered_block = SearchResultBlock::SongSearch;
-    }
-    SearchResultBlock::Empty => {}
-  }
+  move_hovered_block_vertically(app, true);
 }
 
 fn handle_up_press_on_selected_block(app: &mut App) {
@@ -133,24 +146,7 @@ fn handle_up_press_on_selected_block(app: &mut App) {
 }
 
 fn handle_up_press_on_hovered_block(app: &mut App) {
-  match app.search_results.hovered_block {
-    SearchResultBlock::AlbumSearch => {
-      app.search_results.hovered_block = SearchResultBlock::SongSearch;
-    }
-    SearchResultBlock::SongSearch => {
-      app.search_results.hovered_block = SearchResultBlock::ShowSearch;
-    }
-    SearchResultBlock::ArtistSearch => {
-      app.search_results.hovered_block = SearchResultBlock::ShowSearch;
-    }
-    SearchResultBlock::PlaylistSearch => {
-      app.search_results.hovered_block = SearchResultBlock::ArtistSearch;
-    }
-    SearchResultBlock::ShowSearch => {
-      app.search_results.hovered_block = SearchResultBlock::AlbumSearch;
-    }
-    SearchResultBlock::Empty => {}
-  }
+  move_hovered_block_vertically(app, false);
 }
 
 fn handle_high_press_on_selected_block(app: &mut App) {
@@ -261,7 +257,7 @@ fn handle_low_press_on_selected_block(app: &mut App) {
   }
 }
 
-fn handle_add_item_to_queue(app: &mut App) {
+fn handle_add_item_to_queue(app: &mut App, key: Key) {
   match &app.search_results.selected_block {
     SearchResultBlock::SongSearch => {
       if let (Some(index), Some(tracks)) = (
@@ -270,9 +266,11 @@ fn handle_add_item_to_queue(app: &mut App) {
       ) {
         if let Some(track) = tracks.items.get(index) {
           if let Some(track_id) = &track.id {
-            app.dispatch(IoEvent::AddItemToQueue(PlayableId::Track(
-              track_id.clone().into_static(),
-            )));
+            common_key_events::queue_selected(
+              app,
+              key,
+              PlayableId::Track(track_id.clone().into_static()),
+            );
           }
         }
       }
@@ -383,6 +381,15 @@ fn handle_enter_event_on_hovered_block(app: &mut App) {
   };
 }
 
+fn handle_jump_to_block(app: &mut App, block: SearchResultBlock) {
+  // Hidden blocks can't be jumped to
+  if search_block_position(app, block).is_none() {
+    return;
+  }
+  app.search_results.hovered_block = block;
+  handle_enter_event_on_hovered_block(app);
+}
+
 fn handle_recommended_tracks(app: &mut App) {
   match app.search_results.selected_block {
     SearchResultBlock::AlbumSearch => {}
@@ -439,42 +446,19 @@ pub fn handler(key: Key, app: &mut App) {
     }
     k if common_key_events::left_event(k) => {
       app.search_results.selected_block = SearchResultBlock::Empty;
-      match app.search_results.hovered_block {
-        SearchResultBlock::AlbumSearch => {
-          common_key_events::handle_left_event(app);
-        }
-        SearchResultBlock::SongSearch => {
-          common_key_events::handle_left_event(app);
+      match search_block_position(app, app.search_results.hovered_block) {
+        Some((row, column)) if column > 0 => {
+          app.search_results.hovered_block = app.search_block_rows()[row][column - 1].block;
         }
-        SearchResultBlock::ArtistSearch => {
-          app.search_results.hovered_block = SearchResultBlock::SongSearch;
-        }
-        SearchResultBlock::PlaylistSearch => {
-          app.search_results.hovered_block = SearchResultBlock::AlbumSearch;
-        }
-        SearchResultBlock::ShowSearch => {
-          common_key_events::handle_left_event(app);
-        }
-        SearchResultBlock::Empty => {}
+        _ => common_key_events::handle_left_event(app),
       }
     }
     k if common_key_events::right_event(k) => {
       app.search_results.selected_block = SearchResultBlock::Empty;
-      match app.search_results.hovered_block {
-        SearchResultBlock::AlbumSearch => {
-          app.search_results.hovered_block = SearchResultBlock::PlaylistSearch;
-        }
-        SearchResultBlock::SongSearch => {
-          app.search_results.hovered_block = SearchResultBlock::ArtistSearch;
-        }
-        SearchResultBlock::ArtistSearch => {
-          app.search_results.hovered_block = SearchResultBlock::SongSearch;
-        }
-        SearchResultBlock::PlaylistSearch => {
-          app.search_results.hovered_block = SearchResultBlock::AlbumSearch;
-        }
-        SearchResultBlock::ShowSearch => {}
-        SearchResultBlock::Empty => {}
+      if let Some((row, column)) = search_block_position(app, app.search_results.hovered_block) {
+        let rows = app.search_block_rows();
+        let row = &rows[row];
+        app.search_results.hovered_block = row[(column + 1) % row.len()].block;
       }
     }
     k if common_key_events::high_event(k) => {
@@ -537,9 +521,139 @@ pub fn handler(key: Key, app: &mut App) {
       SearchResultBlock::ShowSearch => app.user_unfollow_show(ActiveBlock::SearchResultBlock),
       SearchResultBlock::Empty => {}
     },
+    Key::Char('U') if app.search_results.selected_block == SearchResultBlock::PlaylistSearch => {
+      let owner = app
+        .search_results
+        .playlists
+        .as_ref()
+        .zip(app.search_results.selected_playlists_index)
+        .and_then(|(playlists, index)| playlists.items.get(index))
+        .map(|playlist| playlist.owner.clone());
+      if let Some(owner) = owner {
+        app.view_playlist_owner(owner);
+      }
+    }
     Key::Char('r') => handle_recommended_tracks(app),
-    _ if key == app.user_config.keys.add_item_to_queue => handle_add_item_to_queue(app),
+    // Jump straight to a block by its position: Songs, Artists, Albums, Playlists, Podcasts
+    Key::Char('1') => handle_jump_to_block(app, SearchResultBlock::SongSearch),
+    Key::Char('2') => handle_jump_to_block(app, SearchResultBlock::ArtistSearch),
+    Key::Char('3') => handle_jump_to_block(app, SearchResultBlock::AlbumSearch),
+    Key::Char('4') => handle_jump_to_block(app, SearchResultBlock::PlaylistSearch),
+    Key::Char('5') => handle_jump_to_block(app, SearchResultBlock::ShowSearch),
+    _ if common_key_events::queue_event(key, app) => handle_add_item_to_queue(app, key),
     // Add `s` to "see more" on each option
     _ => {}
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn number_keys_jump_to_block() {
+    let mut app = App::default();
+
+    handler(Key::Char('5'), &mut app);
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::ShowSearch
+    );
+    assert_eq!(
+      app.search_results.selected_block,
+      SearchResultBlock::ShowSearch
+    );
+    assert_eq!(app.search_results.selected_shows_index, Some(0));
+
+    handler(Key::Char('2'), &mut app);
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::ArtistSearch
+    );
+    assert_eq!(
+      app.search_results.selected_block,
+      SearchResultBlock::ArtistSearch
+    );
+  }
+
+  #[test]
+  fn jump_keeps_existing_selection_index() {
+    let mut app = App::default();
+    app.search_results.selected_album_index = Some(3);
+
+    handler(Key::Char('3'), &mut app);
+    assert_eq!(
+      app.search_results.selected_block,
+      SearchResultBlock::AlbumSearch
+    );
+    assert_eq!(app.search_results.selected_album_index, Some(3));
+  }
+
+  #[test]
+  fn navigation_follows_the_configured_blocks() {
+    use crate::core::user_config::SearchBlockLayout;
+
+    let mut app = App::default();
+    app.user_config.behavior.search_blocks = [
+      SearchResultBlock::AlbumSearch,
+      SearchResultBlock::SongSearch,
+      SearchResultBlock::ArtistSearch,
+    ]
+    .into_iter()
+    .map(|block| SearchBlockLayout { block, weight: 1 })
+    .collect();
+    app.search_results.hovered_block = SearchResultBlock::AlbumSearch;
+
+    handler(Key::Right, &mut app);
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::SongSearch
+    );
+    handler(Key::Down, &mut app);
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::ArtistSearch
+    );
+    handler(Key::Down, &mut app);
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::AlbumSearch
+    );
+
+    // Podcasts are hidden, so their jump key does nothing
+    handler(Key::Char('5'), &mut app);
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::AlbumSearch
+    );
+  }
+
+  #[test]
+  fn results_start_on_a_block_the_layout_shows() {
+    use crate::core::user_config::{SearchBlockLayout, UserConfig};
+
+    let mut user_config = UserConfig::new();
+    user_config.behavior.search_blocks = [
+      SearchResultBlock::ArtistSearch,
+      SearchResultBlock::AlbumSearch,
+    ]
+    .into_iter()
+    .map(|block| SearchBlockLayout { block, weight: 1 })
+    .collect();
+    let (io_tx, _io_rx) = std::sync::mpsc::channel();
+    let app = App::new(io_tx, user_config, std::time::SystemTime::now());
+
+    assert_eq!(
+      app.search_results.hovered_block,
+      SearchResultBlock::ArtistSearch
+    );
+    assert_eq!(
+      app.visible_search_block(SearchResultBlock::AlbumSearch),
+      SearchResultBlock::AlbumSearch
+    );
+    assert_eq!(
+      app.visible_search_block(SearchResultBlock::PlaylistSearch),
+      SearchResultBlock::ArtistSearch
+    );
+  }
+}
@@ -1,4 +1,5 @@
 use crate::core::app::{App, SettingValue, SettingsCategory};
+use crate::core::locale::tf;
 use crate::handlers::common_key_events::{down_event, left_event, right_event, up_event};
 use crate::tui::event::Key;
 
@@ -7,14 +8,61 @@ pub fn handler(key: Key, app: &mut App) {
     handle_unsaved_changes_prompt(key, app);
     return;
   }
+  if app.settings_reset_prompt_visible {
+    handle_reset_prompt(key, app);
+    return;
+  }
 
   if app.settings_edit_mode {
     handle_edit_mode(key, app);
+  } else if app.settings_filter.is_some() {
+    handle_filter(key, app);
   } else {
     handle_navigation(key, app);
   }
 }
 
+/// Keys typed while the settings filter is open. Text goes into the query, so
+/// only arrow keys move the selection. Closing the filter keeps the selected
+/// setting, which makes it a quick way to jump to one.
+fn handle_filter(key: Key, app: &mut App) {
+  match key {
+    Key::Esc => app.settings_filter = None,
+    Key::Enter => {
+      if app
+        .visible_settings_indices()
+        .contains(&app.settings_selected_index)
+      {
+        enter_edit_mode(app);
+      }
+    }
+    Key::Down | Key::Ctrl('n') => select_next_item(app),
+    Key::Up | Key::Ctrl('p') => select_previous_item(app),
+    key if key == app.user_config.keys.save_settings => {
+      let _ = save_settings(app);
+    }
+    Key::Backspace => {
+      if let Some(query) = &mut app.settings_filter {
+        query.pop();
+      }
+      select_first_match(app);
+    }
+    Key::Char(c) => {
+      if let Some(query) = &mut app.settings_filter {
+        query.push(c);
+      }
+      select_first_match(app);
+    }
+    _ => {}
+  }
+}
+
+fn select_first_match(app: &mut App) {
+  if let Some(&first) = app.visible_settings_indices().first() {
+    app.settings_selected_index = first;
+  }
+}
+
 fn handle_navigation(key: Key, app: &mut App) {
   match key {
     // Category switching with left/right (only when not in edit mode)
@@ -28,6 +76,12 @@ fn handle_navigation(key: Key, app: &mut App) {
     // Enter edit mode
     Key::Enter => enter_edit_mode(app),
 
+    key if key == app.user_config.keys.search => app.settings_filter = Some(String::new()),
+
+    // Restore defaults for the selected setting, or the whole tab after confirming
+    Key::Char('d') => reset_selected_setting(app),
+    Key::Char('D') => app.settings_reset_prompt_visible = true,
+
     // Save settings
     key if key == app.user_config.keys.save_settings => {
       let _ = save_settings(app);
@@ -71,6 +125,48 @@ fn handle_unsaved_changes_prompt(key: Key, app: &mut App) {
   }
 }
 
+/// Whether the settings screen handles `key` itself rather than leaving it to a
+/// global binding, like `d` for reset instead of manage devices
+pub fn takes_key(key: Key, app: &App) -> bool {
+  app.settings_unsaved_prompt_visible
+    || app.settings_reset_prompt_visible
+    || app.settings_filter.is_some()
+    || (!app.settings_edit_mode && matches!(key, Key::Char('d') | Key::Char('D')))
+}
+
+fn reset_selected_setting(app: &mut App) {
+  let index = app.settings_selected_index;
+  let Some(name) = app
+    .settings_items
+    .get(index)
+    .map(|setting| setting.name.clone())
+  else {
+    return;
+  };
+  if app.reset_setting(index) {
+    app.set_status_message(tf("status.setting_reset", &[("name", &name)]), 3);
+  } else {
+    app.set_status_message(tf("status.setting_already_default", &[("name", &name)]), 3);
+  }
+}
+
+fn handle_reset_prompt(key: Key, app: &mut App) {
+  if matches!(key, Key::Char('y') | Key::Char('Y') | Key::Enter) {
+    let count = app.reset_settings_category();
+    app.set_status_message(
+      tf(
+        "status.settings_reset",
+        &[
+          ("count", &count.to_string()),
+          ("category", app.settings_category.name()),
+        ],
+      ),
+      3,
+    );
+  }
+  app.settings_reset_prompt_visible = false;
+}
+
 fn request_exit_settings(app: &mut App) {
   if has_unsaved_settings_changes(app) {
     app.settings_unsaved_prompt_visible = true;
@@ -229,9 +325,14 @@ fn check_keybinding_conflict(app: &App, new_key: Key, current_setting_id: &str)
     // Get the key value from this setting
     if let SettingValue::Key(key_string) = &setting.value {
       // Parse the key string to compare
-      if let Ok(existing_key) = crate::core::user_config::parse_key_public(key_string.clone()) {
-        // Check if keys match (case-sensitive comparison)
-        if existing_key == new_key {
+      if let Ok(existing_keys) = crate::core::user_config::parse_key_list_public(key_string) {
+        // Check if keys match (case-sensitive comparison). A key that starts a
+        // chord is taken too, since it only ever waits for the chord's second key
+        let taken = existing_keys.keys().iter().any(|&existing_key| {
+          existing_key == new_key
+            || matches!((existing_key, new_key), (Key::Chord(first, _), Key::Char(c)) if first == c)
+        });
+        if taken {
           // Return the friendly name of the conflicting action
           return Some(setting.name.clone());
         }
@@ -297,6 +398,11 @@ fn key_to_config_string(key: &Key) -> String {
     Key::Char(c) => c.to_string(),
     Key::Ctrl(c) => format!("ctrl-{}", c),
     Key::Alt(c) => format!("alt-{}", c),
+    Key::Chord(first, second) => format!(
+      "{} {}",
+      key_to_config_string(&Key::Char(*first)),
+      key_to_config_string(&Key::Char(*second))
+    ),
     Key::Enter => "enter".to_string(),
     Key::Esc => "esc".to_string(),
     Key::Backspace => "backspace".to_string(),
@@ -348,20 +454,35 @@ fn switch_category_right(app: &mut App) {
   app.load_settings_for_category();
 }
 
+/// Moves through the settings the filter shows, wrapping at either end
 fn select_next_item(app: &mut App) {
-  if !app.settings_items.is_empty() {
-    app.settings_selected_index = (app.settings_selected_index + 1) % app.settings_items.len();
+  let visible = app.visible_settings_indices();
+  if visible.is_empty() {
+    return;
   }
+  let next = match visible
+    .iter()
+    .position(|&i| i == app.settings_selected_index)
+  {
+    Some(position) => (position + 1) % visible.len(),
+    None => 0,
+  };
+  app.settings_selected_index = visible[next];
 }
 
 fn select_previous_item(app: &mut App) {
-  if !app.settings_items.is_empty() {
-    if app.settings_selected_index == 0 {
-      app.settings_selected_index = app.settings_items.len() - 1;
-    } else {
-      app.settings_selected_index -= 1;
-    }
+  let visible = app.visible_settings_indices();
+  if visible.is_empty() {
+    return;
   }
+  let previous = match visible
+    .iter()
+    .position(|&i| i == app.settings_selected_index)
+  {
+    Some(0) | None => visible.len() - 1,
+    Some(position) => position - 1,
+  };
+  app.settings_selected_index = visible[previous];
 }
 
 fn enter_edit_mode(app: &mut App) {
@@ -519,4 +640,129 @@ mod tests {
     assert!(!app.settings_unsaved_prompt_visible);
     assert_eq!(app.get_current_route().id, previous_route);
   }
+
+  #[test]
+  fn filter_searches_every_category_and_keeps_the_match_selected() {
+    let mut app = App::default();
+    app.settings_category = SettingsCategory::All;
+    open_settings(&mut app);
+    let all = app.settings_items.len();
+
+    handler(Key::Char('/'), &mut app);
+    for c in "keys.next_t".chars() {
+      handler(Key::Char(c), &mut app);
+    }
+    let visible = app.visible_settings_indices();
+    assert_eq!(visible.len(), 1);
+    assert_eq!(app.settings_items[visible[0]].id, "keys.next_track");
+    assert_eq!(app.settings_selected_index, visible[0]);
+
+    // Arrows stay on the matches, Esc goes back to the full list on the match
+    handler(Key::Down, &mut app);
+    assert_eq!(app.settings_selected_index, visible[0]);
+    handler(Key::Esc, &mut app);
+    assert!(app.settings_filter.is_none());
+    assert_eq!(app.visible_settings_indices().len(), all);
+    assert_eq!(
+      app.settings_items[app.settings_selected_index].id,
+      "keys.next_track"
+    );
+
+    // Switching categories drops the filter
+    handler(Key::Char('/'), &mut app);
+    handler(Key::Char('x'), &mut app);
+    switch_category_left(&mut app);
+    assert!(app.settings_filter.is_none());
+    assert_eq!(app.settings_category, SettingsCategory::Theme);
+  }
+
+  #[test]
+  fn d_resets_one_setting_and_shift_d_the_whole_tab() {
+    let mut app = App::default();
+    app.user_config.behavior.seek_milliseconds = 1_234;
+    app.user_config.behavior.pause_on_exit = true;
+    open_settings(&mut app);
+    let index_of = |app: &App, id: &str| {
+      app
+        .settings_items
+        .iter()
+        .position(|setting| setting.id == id)
+        .unwrap()
+    };
+    app.settings_selected_index = index_of(&app, "behavior.seek_milliseconds");
+
+    // `d` is manage devices everywhere else
+    crate::handlers::handle_app(Key::Char('d'), &mut app);
+    assert_eq!(app.user_config.behavior.seek_milliseconds, 5_000);
+    assert!(app.user_config.behavior.pause_on_exit);
+    assert!(has_unsaved_settings_changes(&app));
+
+    crate::handlers::handle_app(Key::Char('D'), &mut app);
+    assert!(app.settings_reset_prompt_visible);
+    crate::handlers::handle_app(Key::Char('n'), &mut app);
+    assert!(!app.settings_reset_prompt_visible);
+    assert!(app.user_config.behavior.pause_on_exit);
+
+    crate::handlers::handle_app(Key::Char('D'), &mut app);
+    crate::handlers::handle_app(Key::Char('y'), &mut app);
+    assert!(!app.user_config.behavior.pause_on_exit);
+    let index = index_of(&app, "behavior.pause_on_exit");
+    assert_eq!(
+      app.default_setting_value("behavior.pause_on_exit"),
+      Some(&app.settings_items[index].value)
+    );
+  }
+
+  #[test]
+  fn every_setting_resets_through_its_config_field() {
+    let mut app = App::default();
+    app.settings_category = SettingsCategory::All;
+    open_settings(&mut app);
+
+    // Move every setting off its default and read them back from the config, so
+    // an id that isn't wired to a field shows up as unchanged
+    for setting in &mut app.settings_items {
+      setting.value = match &setting.value {
+        SettingValue::Bool(v) => SettingValue::Bool(!v),
+        SettingValue::Number(v) if *v > 1 => SettingValue::Number(v - 1),
+        SettingValue::Number(v) => SettingValue::Number(v + 1),
+        // Settings that only take a fixed set of words
+        SettingValue::String(_) if setting.id == "behavior.liked_play_order" => {
+          SettingValue::String("shuffle".to_string())
+        }
+        SettingValue::String(v) => SettingValue::String(format!("{}x", v)),
+        SettingValue::Color(v) if v == "Red" => SettingValue::Color("Blue".to_string()),
+        SettingValue::Color(_) => SettingValue::Color("Red".to_string()),
+        SettingValue::Key(_) => SettingValue::Key("f12".to_string()),
+        SettingValue::Preset(v) => SettingValue::Preset(v.clone()),
+      };
+    }
+    app.apply_settings_changes();
+    app.load_settings_for_category();
+    // Theme colors only take effect after a restart
+    for setting in &app.settings_items {
+      if !matches!(
+        setting.value,
+        SettingValue::Color(_) | SettingValue::Preset(_)
+      ) {
+        assert_ne!(
+          app.default_setting_value(&setting.id),
+          Some(&setting.value),
+          "{} is not read from or written to the config",
+          setting.id
+        );
+      }
+    }
+
+    app.reset_settings_category();
+    app.load_settings_for_category();
+    for setting in &app.settings_items {
+      assert_eq!(
+        app.default_setting_value(&setting.id),
+        Some(&setting.value),
+        "{} did not reset",
+        setting.id
+      );
+    }
+  }
 }
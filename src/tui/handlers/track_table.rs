@@ -1,15 +1,19 @@
 use super::common_key_events;
 use crate::core::app::{
-  ActiveBlock, App, DialogContext, PendingPlaylistTrackRemoval, PendingTrackSelection,
-  RecommendationsContext, RouteId, TrackTable, TrackTableContext,
+  ActiveBlock, App, DialogContext, PendingBulkRemoval, PendingPlaylistTrackRemoval,
+  PendingTrackSelection, RecommendationsContext, RouteId, TrackTable, TrackTableContext,
 };
+use crate::core::availability;
+use crate::core::locale::{t, tf};
+use crate::core::user_config::LikedPlayOrder;
 use crate::infra::network::IoEvent;
 use crate::tui::event::Key;
-use rand::{thread_rng, Rng};
+use rand::{seq::SliceRandom, thread_rng, Rng};
 use rspotify::model::{
   idtypes::{PlayContextId, PlaylistId, TrackId},
   PlayableId,
 };
+use rspotify::prelude::*;
 
 pub fn handler(key: Key, app: &mut App) {
   match key {
@@ -165,13 +169,26 @@ pub fn handler(key: Key, app: &mut App) {
     Key::Char('x') => open_remove_from_playlist_dialog(app),
     Key::Char('s') => handle_save_track_event(app),
     Key::Char('S') => play_random_song(app),
+    Key::Char('V') => toggle_marked_track(app),
+    Key::Char('D') => open_bulk_unlike_dialog(app),
     k if k == app.user_config.keys.jump_to_end => jump_to_end(app),
     k if k == app.user_config.keys.jump_to_start => jump_to_start(app),
     //recommended song radio
     Key::Char('r') => {
       handle_recommended_tracks(app);
     }
-    _ if key == app.user_config.keys.add_item_to_queue => on_queue(app),
+    _ if common_key_events::queue_event(key, app) => on_queue(app, key),
+    _ if key == app.user_config.keys.cache_playlist_offline => {
+      if let Some((playlist_id, name)) = active_playlist_target_for_track_table_context(app) {
+        app.begin_offline_cache(playlist_id, name);
+      }
+    }
+    _ if key == app.user_config.keys.toggle_enhance => {
+      match active_playlist_target_for_track_table_context(app) {
+        Some((playlist_id, _)) => app.toggle_playlist_enhance(playlist_id),
+        None => app.set_status_message(t("status.enhance_playlists_only"), 4),
+      }
+    }
     // Open sort menu
     Key::Char(',') => {
       super::sort_menu::open_sort_menu(app, crate::core::sort::SortContext::PlaylistTracks);
@@ -191,6 +208,42 @@ fn open_add_to_playlist_dialog(app: &mut App) {
   app.begin_add_track_to_playlist_flow(track_id, track_name);
 }
 
+/// With Enhance on, a suggestion isn't in the playlist context, so the mixed
+/// table is played as a plain list of tracks instead. False when there's no
+/// suggestion under the cursor.
+fn play_enhanced_playlist(app: &mut App) -> bool {
+  let TrackTable {
+    context,
+    selected_index,
+    tracks,
+  } = &app.track_table;
+  let is_suggestion = match (&app.playlist_enhance, tracks.get(*selected_index)) {
+    (Some(enhance), Some(track)) => track
+      .id
+      .as_ref()
+      .is_some_and(|id| enhance.is_suggestion(id.id())),
+    _ => false,
+  };
+  if context != &Some(TrackTableContext::MyPlaylists) || !is_suggestion {
+    return false;
+  }
+
+  let playable_ids: Vec<PlayableId<'static>> = tracks
+    .iter()
+    .filter_map(|track| track_playable_id(track.id.clone()))
+    .collect();
+  let offset = tracks[..*selected_index]
+    .iter()
+    .filter(|track| track.id.is_some())
+    .count();
+  app.dispatch(IoEvent::StartPlayback(
+    None,
+    Some(playable_ids),
+    Some(offset),
+  ));
+  true
+}
+
 fn open_remove_from_playlist_dialog(app: &mut App) {
   let playlist_context = match active_playlist_target_for_track_table_context(app) {
     Some(context) => context,
@@ -217,10 +270,18 @@ fn open_remove_from_playlist_dialog(app: &mut App) {
   };
   let track_name = track.name.clone();
 
+  let row = match &app.playlist_enhance {
+    Some(enhance) => enhance.playlist_row(&app.track_table.tracks, app.track_table.selected_index),
+    None => Some(app.track_table.selected_index),
+  };
+  let Some(row) = row else {
+    app.set_status_message(t("status.enhance_suggestion"), 4);
+    return;
+  };
   let position = match app
     .playlist_track_positions
     .as_ref()
-    .and_then(|positions| positions.get(app.track_table.selected_index))
+    .and_then(|positions| positions.get(row))
     .copied()
   {
     Some(position) => position,
@@ -246,6 +307,42 @@ fn open_remove_from_playlist_dialog(app: &mut App) {
   );
 }
 
+fn toggle_marked_track(app: &mut App) {
+  if app.track_table.context != Some(TrackTableContext::SavedTracks) {
+    app.set_status_message("Multi-select only works in Liked Songs".to_string(), 4);
+    return;
+  }
+
+  let Some(track_id) = app
+    .track_table
+    .tracks
+    .get(app.track_table.selected_index)
+    .and_then(|track| track.id.as_ref())
+    .map(|id| id.id().to_string())
+  else {
+    return;
+  };
+
+  if !app.marked_track_ids.remove(&track_id) {
+    app.marked_track_ids.insert(track_id);
+  }
+}
+
+fn open_bulk_unlike_dialog(app: &mut App) {
+  if app.track_table.context != Some(TrackTableContext::SavedTracks)
+    || app.marked_track_ids.is_empty()
+  {
+    return;
+  }
+
+  let track_ids = app
+    .marked_track_ids
+    .iter()
+    .filter_map(|id| TrackId::from_id(id.clone()).ok())
+    .collect();
+  app.begin_bulk_library_removal(PendingBulkRemoval::Tracks(track_ids));
+}
+
 fn play_random_song(app: &mut App) {
   if let Some(context) = &app.track_table.context {
     match context {
@@ -375,6 +472,28 @@ fn jump_to_end(app: &mut App) {
 }
 
 fn on_enter(app: &mut App) {
+  if app
+    .track_table
+    .tracks
+    .get(app.track_table.selected_index)
+    .is_some_and(|track| track.is_local)
+  {
+    app.set_status_message("Local files can't be played through Spotify Connect", 4);
+    return;
+  }
+  if let Some(message) = app
+    .track_table
+    .tracks
+    .get(app.track_table.selected_index)
+    .and_then(|track| availability::unavailable_message(track, app.get_user_country()))
+  {
+    app.set_status_message(message, 4);
+    return;
+  }
+  if play_enhanced_playlist(app) {
+    return;
+  }
+
   let TrackTable {
     context,
     selected_index,
@@ -430,36 +549,49 @@ fn on_enter(app: &mut App) {
       TrackTableContext::SavedTracks => {
         // Collect tracks from ALL loaded pages (not just current page)
         // This gives us a larger playback range as the user browses
-        let mut all_playable_ids: Vec<PlayableId<'static>> = Vec::new();
         let current_page_index = app.library.saved_tracks.index;
+        let pages = &app.library.saved_tracks.pages;
+        let all_tracks: Vec<(PlayableId<'static>, String)> = pages
+          .iter()
+          .flat_map(|page| &page.items)
+          .filter_map(|item| {
+            let id = track_playable_id(item.track.id.clone())?;
+            Some((id, item.track.name.clone()))
+          })
+          .collect();
 
-        // Iterate through all loaded pages
-        for (page_idx, page) in app.library.saved_tracks.pages.iter().enumerate() {
-          for item in &page.items {
-            if let Some(id) = track_playable_id(item.track.id.clone()) {
-              all_playable_ids.push(id);
-            }
-          }
-          // If this is the current page, calculate the absolute offset for the selected track
-          if page_idx == current_page_index {
-            // This is handled below by calculating from page sizes
-          }
-        }
-
-        if !all_playable_ids.is_empty() {
+        if !all_tracks.is_empty() {
           // Calculate absolute offset: (sum of previous page sizes) + selected index in current page
-          let mut absolute_offset = 0;
-          for page_idx in 0..current_page_index {
-            if let Some(page) = app.library.saved_tracks.pages.get(page_idx) {
-              absolute_offset += page.items.len();
-            }
+          let absolute_offset = pages
+            .iter()
+            .take(current_page_index)
+            .map(|page| page.items.len())
+            .sum::<usize>()
+            + app.track_table.selected_index;
+          let loaded = all_tracks.len();
+          let total = pages.last().map_or(0, |page| page.total as usize);
+
+          let order = app.user_config.behavior.liked_play_order;
+          let (all_playable_ids, offset) = order_liked_tracks(all_tracks, absolute_offset, order);
+          // The rest of the library is still being prefetched, so say what the
+          // order covers rather than quietly leaving songs out
+          if order != LikedPlayOrder::AddedNewest && loaded < total {
+            app.set_status_message(
+              tf(
+                "status.liked_order_partial",
+                &[
+                  ("loaded", &loaded.to_string()),
+                  ("total", &total.to_string()),
+                ],
+              ),
+              4,
+            );
           }
-          absolute_offset += app.track_table.selected_index;
 
           app.dispatch(IoEvent::StartPlayback(
             None,
             Some(all_playable_ids),
-            Some(absolute_offset),
+            Some(offset),
           ));
         }
       }
@@ -515,7 +647,7 @@ fn on_enter(app: &mut App) {
   };
 }
 
-fn on_queue(app: &mut App) {
+fn on_queue(app: &mut App, key: Key) {
   let TrackTable {
     context,
     selected_index,
@@ -526,14 +658,14 @@ fn on_queue(app: &mut App) {
       TrackTableContext::MyPlaylists => {
         if let Some(track) = tracks.get(*selected_index) {
           if let Some(playable_id) = track_playable_id(track.id.clone()) {
-            app.dispatch(IoEvent::AddItemToQueue(playable_id));
+            common_key_events::queue_selected(app, key, playable_id);
           }
         };
       }
       TrackTableContext::RecommendedTracks => {
         if let Some(full_track) = app.recommended_tracks.get(app.track_table.selected_index) {
           if let Some(playable_id) = track_playable_id(full_track.id.clone()) {
-            app.dispatch(IoEvent::AddItemToQueue(playable_id));
+            common_key_events::queue_selected(app, key, playable_id);
           }
         }
       }
@@ -541,7 +673,7 @@ fn on_queue(app: &mut App) {
         if let Some(page) = app.library.saved_tracks.get_results(None) {
           if let Some(saved_track) = page.items.get(app.track_table.selected_index) {
             if let Some(playable_id) = track_playable_id(saved_track.track.id.clone()) {
-              app.dispatch(IoEvent::AddItemToQueue(playable_id));
+              common_key_events::queue_selected(app, key, playable_id);
             }
           }
         }
@@ -555,14 +687,14 @@ fn on_queue(app: &mut App) {
         } = &app.track_table;
         if let Some(track) = tracks.get(*selected_index) {
           if let Some(playable_id) = track_playable_id(track.id.clone()) {
-            app.dispatch(IoEvent::AddItemToQueue(playable_id));
+            common_key_events::queue_selected(app, key, playable_id);
           }
         };
       }
       TrackTableContext::DiscoverPlaylist => {
         if let Some(track) = tracks.get(*selected_index) {
           if let Some(playable_id) = track_playable_id(track.id.clone()) {
-            app.dispatch(IoEvent::AddItemToQueue(playable_id));
+            common_key_events::queue_selected(app, key, playable_id);
           }
         }
       }
@@ -636,6 +768,202 @@ fn playlist_context_id_from_ref(id: &PlaylistId<'_>) -> PlayContextId<'static> {
   PlayContextId::Playlist(id.clone().into_static())
 }
 
+/// Liked Songs in the configured play order, starting from the selected one.
+/// Returns the list and where the selected song ended up in it.
+fn order_liked_tracks(
+  mut tracks: Vec<(PlayableId<'static>, String)>,
+  selected: usize,
+  order: LikedPlayOrder,
+) -> (Vec<PlayableId<'static>>, usize) {
+  let selected = selected.min(tracks.len().saturating_sub(1));
+  let offset = match order {
+    LikedPlayOrder::AddedNewest => selected,
+    LikedPlayOrder::AddedOldest => {
+      tracks.reverse();
+      tracks.len() - 1 - selected
+    }
+    LikedPlayOrder::Alphabetical => {
+      let chosen = tracks[selected].0.clone();
+      tracks.sort_by_cached_key(|(_, name)| name.to_lowercase());
+      tracks.iter().position(|(id, _)| *id == chosen).unwrap_or(0)
+    }
+    LikedPlayOrder::Shuffle => {
+      let chosen = tracks.remove(selected);
+      tracks.shuffle(&mut thread_rng());
+      tracks.insert(0, chosen);
+      0
+    }
+  };
+  (tracks.into_iter().map(|(id, _)| id).collect(), offset)
+}
+
 fn track_playable_id(id: Option<TrackId<'_>>) -> Option<PlayableId<'static>> {
   id.map(|track_id| PlayableId::Track(track_id.into_static()))
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bulk_unlike_opens_confirmation_for_marked_tracks() {
+    let mut app = App::default();
+    app.track_table.context = Some(TrackTableContext::SavedTracks);
+    app
+      .marked_track_ids
+      .insert("4iV5W9uYEdYUVa79Axb7Rh".to_string());
+
+    handler(Key::Char('D'), &mut app);
+
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Dialog(DialogContext::BulkRemoveFromLibraryConfirm)
+    );
+    assert_eq!(
+      app.pending_bulk_removal.as_ref().map(|r| r.count()),
+      Some(1)
+    );
+  }
+
+  #[test]
+  fn enter_on_a_local_file_explains_instead_of_playing() {
+    let mut app = App::default();
+    app.track_table.context = Some(TrackTableContext::MyPlaylists);
+    app.track_table.tracks = vec![serde_json::from_value(serde_json::json!({
+      "album": {
+        "artists": [],
+        "available_markets": [],
+        "external_urls": {},
+        "href": null,
+        "id": null,
+        "images": [],
+        "name": "",
+        "album_type": null,
+        "release_date": null,
+        "release_date_precision": null
+      },
+      "artists": [],
+      "available_markets": [],
+      "disc_number": 0,
+      "duration_ms": 215000,
+      "explicit": false,
+      "external_ids": {},
+      "external_urls": {},
+      "href": null,
+      "id": null,
+      "is_local": true,
+      "name": "Home Recording",
+      "popularity": 0,
+      "preview_url": null,
+      "track_number": 0
+    }))
+    .unwrap()];
+
+    handler(Key::Enter, &mut app);
+
+    assert!(!app.is_loading);
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Local files can't be played through Spotify Connect")
+    );
+  }
+
+  #[test]
+  fn enter_on_a_restricted_track_says_why_instead_of_playing() {
+    let mut app = App::default();
+    app.track_table.context = Some(TrackTableContext::SavedTracks);
+    let mut track = crate::core::fixtures::track("Harbor Lights", "The Tides", "Coastline", 1000);
+    track.is_playable = Some(false);
+    app.track_table.tracks = vec![track];
+
+    handler(Key::Enter, &mut app);
+
+    assert!(!app.is_loading);
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("This song isn't available in your country")
+    );
+  }
+
+  #[test]
+  fn enhance_suggestions_play_as_tracks_but_cant_be_removed() {
+    use crate::core::fixtures;
+    let mut app = App::default();
+    app.all_playlists = vec![fixtures::playlist("Road Trip", "Sam", 3)];
+    app.active_playlist_index = Some(0);
+    app.track_table.context = Some(TrackTableContext::MyPlaylists);
+    app.track_table.tracks = ["a", "b", "c"]
+      .iter()
+      .map(|name| fixtures::track(name, "Artist", "Album", 1000))
+      .collect();
+
+    handler(Key::Alt('e'), &mut app);
+    assert!(app.playlist_enhance.is_some());
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Finding suggestions...")
+    );
+
+    let enhance = app.playlist_enhance.as_mut().unwrap();
+    enhance.suggestions = vec![fixtures::track("x", "Artist", "Album", 1000)];
+    let tracks = std::mem::take(&mut app.track_table.tracks);
+    app.track_table.tracks = enhance.interleave(tracks);
+    app.track_table.selected_index = 3;
+
+    handler(Key::Char('x'), &mut app);
+    assert!(app.dialog.is_none());
+    assert_eq!(
+      app.status_message.as_deref(),
+      Some("Suggestions from Enhance aren't in the playlist")
+    );
+
+    app.is_loading = false;
+    handler(Key::Enter, &mut app);
+    assert!(app.is_loading);
+
+    handler(Key::Alt('e'), &mut app);
+    assert!(app.playlist_enhance.is_none());
+    assert_eq!(app.track_table.tracks.len(), 3);
+  }
+
+  #[test]
+  fn bulk_unlike_ignored_without_marks() {
+    let mut app = App::default();
+    app.track_table.context = Some(TrackTableContext::SavedTracks);
+
+    handler(Key::Char('D'), &mut app);
+
+    assert!(app.pending_bulk_removal.is_none());
+  }
+
+  #[test]
+  fn liked_songs_play_in_the_configured_order() {
+    let id = |n: usize| PlayableId::Track(TrackId::from_id(format!("{:0>22}", n)).unwrap());
+    // Library order is newest first
+    let tracks = || {
+      ["beta", "Alpha", "delta", "charlie"]
+        .iter()
+        .enumerate()
+        .map(|(n, name)| (id(n), name.to_string()))
+        .collect::<Vec<_>>()
+    };
+
+    let (ids, offset) = order_liked_tracks(tracks(), 1, LikedPlayOrder::AddedNewest);
+    assert_eq!(ids, vec![id(0), id(1), id(2), id(3)]);
+    assert_eq!(offset, 1);
+
+    let (ids, offset) = order_liked_tracks(tracks(), 1, LikedPlayOrder::AddedOldest);
+    assert_eq!(ids, vec![id(3), id(2), id(1), id(0)]);
+    assert_eq!(ids[offset], id(1));
+
+    let (ids, offset) = order_liked_tracks(tracks(), 2, LikedPlayOrder::Alphabetical);
+    assert_eq!(ids, vec![id(1), id(0), id(3), id(2)]);
+    assert_eq!(ids[offset], id(2));
+
+    // Shuffle still starts on the chosen song
+    let (ids, offset) = order_liked_tracks(tracks(), 3, LikedPlayOrder::Shuffle);
+    assert_eq!(offset, 0);
+    assert_eq!(ids[0], id(3));
+    assert_eq!(ids.len(), 4);
+  }
+}
@@ -4,3 +4,4 @@ pub mod cover_art;
 pub mod event;
 pub mod handlers;
 pub mod ui;
+pub mod window_title;
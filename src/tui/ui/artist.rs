@@ -1,4 +1,5 @@
-use crate::core::app::{App, ArtistBlock};
+use crate::core::app::{App, ArtistBlock, MouseTarget};
+use crate::core::availability;
 use ratatui::{
   layout::{Constraint, Layout, Rect},
   Frame,
@@ -34,19 +35,36 @@ pub fn draw_artist_albums(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
           }
         };
         name.push_str(&top_track.name);
+        if availability::is_unavailable(top_track, app.get_user_country()) {
+          name.push_str(" (unavailable)");
+        }
         name
       })
       .collect::<Vec<String>>();
+    let unavailable = artist
+      .top_tracks
+      .iter()
+      .filter(|track| availability::is_unavailable(*track, app.get_user_country()))
+      .count();
+    let mut top_tracks_title = format!("{} - Top Tracks", &artist.artist_name);
+    if unavailable > 0 {
+      top_tracks_title.push_str(&format!(" ({} unavailable)", unavailable));
+    }
 
-    draw_selectable_list(
+    let offset = draw_selectable_list(
       f,
       app,
       tracks_area,
-      &format!("{} - Top Tracks", &artist.artist_name),
+      &top_tracks_title,
       &top_tracks,
       get_artist_highlight_state(app, ArtistBlock::TopTracks),
       Some(artist.selected_top_track_index),
     );
+    app.record_mouse_region(
+      MouseTarget::Artist(ArtistBlock::TopTracks),
+      tracks_area,
+      offset,
+    );
 
     let albums = &artist
       .albums
@@ -69,7 +87,7 @@ pub fn draw_artist_albums(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
       })
       .collect::<Vec<String>>();
 
-    draw_selectable_list(
+    let offset = draw_selectable_list(
       f,
       app,
       albums_area,
@@ -78,6 +96,11 @@ pub fn draw_artist_albums(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
       get_artist_highlight_state(app, ArtistBlock::Albums),
       Some(artist.selected_album_index),
     );
+    app.record_mouse_region(
+      MouseTarget::Artist(ArtistBlock::Albums),
+      albums_area,
+      offset,
+    );
 
     let related_artists = artist
       .related_artists
@@ -92,7 +115,7 @@ pub fn draw_artist_albums(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
       })
       .collect::<Vec<String>>();
 
-    draw_selectable_list(
+    let offset = draw_selectable_list(
       f,
       app,
       related_artists_area,
@@ -101,5 +124,10 @@ pub fn draw_artist_albums(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
       get_artist_highlight_state(app, ArtistBlock::RelatedArtists),
       Some(artist.selected_related_artist_index),
     );
+    app.record_mouse_region(
+      MouseTarget::Artist(ArtistBlock::RelatedArtists),
+      related_artists_area,
+      offset,
+    );
   };
 }
@@ -13,6 +13,18 @@ use ratatui::{
 use tui_bar_graph::{BarGraph, BarStyle, ColorMode};
 use tui_equalizer::{Band, Equalizer};
 
+/// How to get system audio into the visualizer on this platform
+fn loopback_hint() -> &'static str {
+  #[cfg(target_os = "linux")]
+  return "Hint: Ensure PipeWire or PulseAudio is running with a monitor device";
+  #[cfg(target_os = "windows")]
+  return "Hint: Audio loopback should work automatically on Windows";
+  #[cfg(target_os = "macos")]
+  return "Hint: macOS requires a virtual audio device like BlackHole";
+  #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+  return "Hint: Audio capture may not be supported on this platform";
+}
+
 pub fn draw(f: &mut Frame<'_>, app: &App) {
   let margin = util::get_main_layout_margin(app);
 
@@ -70,6 +82,20 @@ pub fn draw(f: &mut Frame<'_>, app: &App) {
       .style(Style::default().fg(app.user_config.theme.text));
     f.render_widget(p, info_area);
 
+    // Flat bars while music plays look broken, explain what is missing instead
+    if app.capture_hears_nothing() {
+      let silent_text = vec![
+        Line::from("Music is playing, but no sound is reaching the visualizer"),
+        Line::from(""),
+        Line::from(loopback_hint()),
+      ];
+      let p = Paragraph::new(silent_text)
+        .block(bar_chart_block)
+        .style(Style::default().fg(app.user_config.theme.text));
+      f.render_widget(p, visualizer_area);
+      return;
+    }
+
     // Calculate inner area for visualizer (within the block borders)
     let inner_area = bar_chart_block.inner(visualizer_area);
 
@@ -89,14 +115,7 @@ pub fn draw(f: &mut Frame<'_>, app: &App) {
     let no_capture_text = vec![
       Line::from("No audio capture available"),
       Line::from(""),
-      #[cfg(target_os = "linux")]
-      Line::from("Hint: Ensure PipeWire or PulseAudio is running with a monitor device"),
-      #[cfg(target_os = "windows")]
-      Line::from("Hint: Audio loopback should work automatically on Windows"),
-      #[cfg(target_os = "macos")]
-      Line::from("Hint: macOS requires a virtual audio device like BlackHole"),
-      #[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
-      Line::from("Hint: Audio capture may not be supported on this platform"),
+      Line::from(loopback_hint()),
     ];
 
     let p = Paragraph::new(no_capture_text)
@@ -266,3 +285,32 @@ fn interpolate_bands(bands: &[f32], target_width: usize) -> Vec<f64> {
 
   result
 }
+
+#[cfg(all(test, any(feature = "audio-viz", feature = "audio-viz-cpal")))]
+mod tests {
+  use crate::core::app::{App, SpectrumData};
+  use std::time::{Duration, Instant};
+
+  #[test]
+  fn long_silence_during_playback_counts_as_nothing_captured() {
+    let mut app = App::default();
+    app.native_is_playing = Some(true);
+
+    app.update_spectrum(SpectrumData::default());
+    assert!(!app.capture_hears_nothing());
+
+    app.silent_spectrum_since = Some(Instant::now() - Duration::from_secs(10));
+    assert!(app.capture_hears_nothing());
+
+    // Paused music is silent too, that's expected
+    app.native_is_playing = Some(false);
+    assert!(!app.capture_hears_nothing());
+
+    app.native_is_playing = Some(true);
+    app.update_spectrum(SpectrumData {
+      bands: [0.5; 12],
+      peak: 0.5,
+    });
+    assert!(!app.capture_hears_nothing());
+  }
+}
@@ -0,0 +1,281 @@
+use crate::core::user_config::{ColorMode, Theme, UserConfig};
+use ratatui::{
+  buffer::{Buffer, Cell},
+  style::{Color, Modifier},
+};
+
+/// Cube levels of the xterm 256-color palette (indices 16..=231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 basic colors with xterm's default RGB values
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+  (Color::Black, (0, 0, 0)),
+  (Color::Red, (205, 0, 0)),
+  (Color::Green, (0, 205, 0)),
+  (Color::Yellow, (205, 205, 0)),
+  (Color::Blue, (0, 0, 238)),
+  (Color::Magenta, (205, 0, 205)),
+  (Color::Cyan, (0, 205, 205)),
+  (Color::Gray, (229, 229, 229)),
+  (Color::DarkGray, (127, 127, 127)),
+  (Color::LightRed, (255, 0, 0)),
+  (Color::LightGreen, (0, 255, 0)),
+  (Color::LightYellow, (255, 255, 0)),
+  (Color::LightBlue, (92, 92, 255)),
+  (Color::LightMagenta, (255, 0, 255)),
+  (Color::LightCyan, (0, 255, 255)),
+  (Color::White, (255, 255, 255)),
+];
+
+/// Color mode to draw with: the configured one, or a guess from the environment.
+///
+/// A non-empty `$NO_COLOR` means monochrome unless the config names a mode itself,
+/// as <https://no-color.org> asks.
+pub fn resolve_color_mode(configured: ColorMode) -> ColorMode {
+  match configured {
+    ColorMode::Auto if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) => {
+      ColorMode::Monochrome
+    }
+    ColorMode::Auto => detect_color_mode(
+      std::env::var("COLORTERM").ok().as_deref(),
+      std::env::var("TERM").ok().as_deref(),
+    ),
+    mode => mode,
+  }
+}
+
+/// Guess the color support from `$COLORTERM` and `$TERM`.
+///
+/// Unknown terminals keep truecolor so nothing changes for them; only terminals
+/// known to be limited are mapped down.
+pub fn detect_color_mode(colorterm: Option<&str>, term: Option<&str>) -> ColorMode {
+  if matches!(colorterm, Some("truecolor" | "24bit")) {
+    return ColorMode::TrueColor;
+  }
+  let Some(term) = term else {
+    return ColorMode::TrueColor;
+  };
+  if term.contains("direct") || term.contains("truecolor") {
+    ColorMode::TrueColor
+  } else if term.contains("256color") {
+    ColorMode::Ansi256
+  } else if matches!(term, "linux" | "ansi" | "dumb" | "cons25") || term.starts_with("vt") {
+    ColorMode::Ansi16
+  } else {
+    ColorMode::TrueColor
+  }
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+  let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+  d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_cube_level(value: u8) -> usize {
+  (0..CUBE_LEVELS.len())
+    .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - value as i32).abs())
+    .unwrap_or(0)
+}
+
+/// Nearest entry of the 256-color palette, ignoring the 16 themeable colors
+pub fn nearest_ansi256(rgb: (u8, u8, u8)) -> u8 {
+  let (r, g, b) = rgb;
+  let (ri, gi, bi) = (
+    nearest_cube_level(r),
+    nearest_cube_level(g),
+    nearest_cube_level(b),
+  );
+  let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+  let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+  // Grayscale ramp 232..=255 runs from 8 to 238 in steps of 10
+  let average = (r as u32 + g as u32 + b as u32) / 3;
+  let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+  let gray_level = 8 + 10 * gray_step;
+  let gray = (gray_level, gray_level, gray_level);
+
+  if distance(rgb, gray) < distance(rgb, cube) {
+    232 + gray_step
+  } else {
+    cube_index as u8
+  }
+}
+
+/// RGB value of a 256-color palette entry
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+  match index {
+    0..=15 => BASIC_COLORS[index as usize].1,
+    16..=231 => {
+      let i = (index - 16) as usize;
+      (
+        CUBE_LEVELS[i / 36],
+        CUBE_LEVELS[(i / 6) % 6],
+        CUBE_LEVELS[i % 6],
+      )
+    }
+    _ => {
+      let level = 8 + 10 * (index - 232);
+      (level, level, level)
+    }
+  }
+}
+
+/// Nearest of the 16 basic colors
+pub fn nearest_ansi16(rgb: (u8, u8, u8)) -> Color {
+  BASIC_COLORS
+    .iter()
+    .min_by_key(|(_, value)| distance(rgb, *value))
+    .map(|(color, _)| *color)
+    .unwrap_or(Color::Reset)
+}
+
+/// Map a color onto what the terminal can show
+pub fn adapt_color(color: Color, mode: ColorMode) -> Color {
+  match (mode, color) {
+    (ColorMode::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_ansi256((r, g, b))),
+    (ColorMode::Ansi16, Color::Rgb(r, g, b)) => nearest_ansi16((r, g, b)),
+    (ColorMode::Ansi16, Color::Indexed(index)) if index >= 16 => {
+      nearest_ansi16(ansi256_to_rgb(index))
+    }
+    _ => color,
+  }
+}
+
+/// Trade a cell's theme colors for text attributes: selections turn bold, errors
+/// bold and underlined, inactive borders dim and highlighted rows reversed.
+fn monochrome_cell(cell: &mut Cell, theme: &Theme) {
+  let fg = cell.fg;
+  let emphasis = if fg == Color::Reset || fg == theme.text {
+    Modifier::empty()
+  } else if fg == theme.error_text || fg == theme.error_border {
+    Modifier::BOLD | Modifier::UNDERLINED
+  } else if fg == theme.selected || fg == theme.active || fg == theme.highlighted_lyrics {
+    Modifier::BOLD
+  } else if fg == theme.inactive {
+    Modifier::DIM
+  } else {
+    Modifier::empty()
+  };
+  let bg = cell.bg;
+  let highlighted = bg != Color::Reset && bg != theme.background && bg != theme.playbar_background;
+
+  let mut modifier = cell.modifier | emphasis;
+  if highlighted {
+    modifier |= Modifier::REVERSED;
+  }
+  cell.modifier = modifier;
+  cell.fg = Color::Reset;
+  cell.bg = Color::Reset;
+}
+
+/// Map every cell of a drawn frame onto the terminal's colors.
+///
+/// Running this over the finished buffer covers the theme, the lyrics and
+/// banner gradients and anything else that builds `Color::Rgb` itself.
+/// `behavior.monochrome` switches to monochrome while the app runs.
+pub fn adapt_buffer_colors(buffer: &mut Buffer, mode: ColorMode, config: &UserConfig) {
+  let mode = if config.behavior.monochrome {
+    ColorMode::Monochrome
+  } else {
+    mode
+  };
+  match mode {
+    ColorMode::TrueColor | ColorMode::Auto => {}
+    ColorMode::Monochrome => {
+      for cell in buffer.content.iter_mut() {
+        monochrome_cell(cell, &config.theme);
+      }
+    }
+    ColorMode::Ansi256 | ColorMode::Ansi16 => {
+      for cell in buffer.content.iter_mut() {
+        cell.fg = adapt_color(cell.fg, mode);
+        cell.bg = adapt_color(cell.bg, mode);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rgb_maps_to_nearest_256_color() {
+    assert_eq!(nearest_ansi256((0, 0, 0)), 16);
+    assert_eq!(nearest_ansi256((255, 255, 255)), 231);
+    assert_eq!(nearest_ansi256((255, 0, 0)), 196);
+    // Spotify green lands in the green part of the cube
+    assert_eq!(nearest_ansi256((29, 185, 84)), 35);
+    // Grays use the grayscale ramp
+    assert_eq!(nearest_ansi256((100, 100, 100)), 241);
+    assert_eq!(nearest_ansi256((179, 179, 179)), 249);
+  }
+
+  #[test]
+  fn rgb_and_indexed_map_to_nearest_16_color() {
+    assert_eq!(nearest_ansi16((29, 185, 84)), Color::Green);
+    assert_eq!(nearest_ansi16((100, 100, 100)), Color::DarkGray);
+    assert_eq!(nearest_ansi16((250, 250, 250)), Color::White);
+    assert_eq!(
+      adapt_color(Color::Indexed(196), ColorMode::Ansi16),
+      Color::LightRed
+    );
+    assert_eq!(
+      adapt_color(Color::Indexed(3), ColorMode::Ansi16),
+      Color::Indexed(3)
+    );
+    assert_eq!(adapt_color(Color::Reset, ColorMode::Ansi256), Color::Reset);
+    assert_eq!(
+      adapt_color(Color::Rgb(1, 2, 3), ColorMode::TrueColor),
+      Color::Rgb(1, 2, 3)
+    );
+  }
+
+  #[test]
+  fn monochrome_turns_theme_colors_into_attributes() {
+    let theme = Theme::default();
+    let mut config = UserConfig::new();
+    config.behavior.monochrome = true;
+
+    let mut buffer = Buffer::empty(ratatui::layout::Rect::new(0, 0, 4, 1));
+    buffer.content[0].set_fg(theme.selected);
+    buffer.content[1].set_fg(theme.inactive);
+    buffer.content[2].set_fg(theme.error_text);
+    buffer.content[3].set_bg(Color::Rgb(40, 40, 40));
+    adapt_buffer_colors(&mut buffer, ColorMode::TrueColor, &config);
+
+    let modifiers: Vec<Modifier> = buffer.content.iter().map(|cell| cell.modifier).collect();
+    assert_eq!(
+      modifiers,
+      vec![
+        Modifier::BOLD,
+        Modifier::DIM,
+        Modifier::BOLD | Modifier::UNDERLINED,
+        Modifier::REVERSED,
+      ]
+    );
+    assert!(buffer
+      .content
+      .iter()
+      .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset));
+  }
+
+  #[test]
+  fn color_mode_is_detected_from_the_environment() {
+    assert_eq!(
+      detect_color_mode(Some("truecolor"), Some("xterm-256color")),
+      ColorMode::TrueColor
+    );
+    assert_eq!(
+      detect_color_mode(None, Some("tmux-256color")),
+      ColorMode::Ansi256
+    );
+    assert_eq!(detect_color_mode(None, Some("linux")), ColorMode::Ansi16);
+    assert_eq!(detect_color_mode(None, Some("vt100")), ColorMode::Ansi16);
+    assert_eq!(
+      detect_color_mode(None, Some("xterm-kitty")),
+      ColorMode::TrueColor
+    );
+    assert_eq!(detect_color_mode(None, None), ColorMode::TrueColor);
+  }
+}
@@ -1,4 +1,4 @@
-use crate::core::app::{ActiveBlock, App};
+use crate::core::app::{ActiveBlock, App, HomeMode};
 use crate::tui::banner::BANNER;
 use colorgrad::{self, Gradient};
 use ratatui::{
@@ -11,7 +11,7 @@ use ratatui::{
 use std::sync::{Mutex, OnceLock};
 use unicode_width::UnicodeWidthStr;
 
-use super::util::get_color;
+use super::util::{create_artist_string, get_color};
 
 #[derive(Clone, PartialEq)]
 struct ChangelogCacheKey {
@@ -75,6 +75,15 @@ pub fn draw_home(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     .block(Block::default());
   f.render_widget(top_text, banner_area);
 
+  if app.home_mode == HomeMode::Stats {
+    let stats = Paragraph::new(Text::from(build_stats_lines(app)))
+      .block(Block::default())
+      .style(app.user_config.theme.base_style())
+      .wrap(Wrap { trim: false });
+    f.render_widget(stats, changelog_area);
+    return;
+  }
+
   // Prepend global counter status to the changelog view
   let mut changelog_lines = Vec::with_capacity(base_changelog_lines.len() + 2);
   let counter_message = if cfg!(feature = "telemetry") {
@@ -101,6 +110,11 @@ pub fn draw_home(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
   changelog_lines.push(Line::from(""));
   changelog_lines.extend(base_changelog_lines);
 
+  let line_count = u16::try_from(changelog_lines.len()).unwrap_or(u16::MAX);
+  app
+    .home_scroll_max
+    .set(line_count.saturating_sub(changelog_area.height));
+
   // CHANGELOG
   let bottom_text = Paragraph::new(Text::from(changelog_lines))
     .block(Block::default())
@@ -110,6 +124,80 @@ pub fn draw_home(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
   f.render_widget(bottom_text, changelog_area);
 }
 
+/// Counts come from whatever the library views have already fetched, so a
+/// section that was never opened shows as not loaded instead of zero.
+fn build_stats_lines(app: &App) -> Vec<Line<'static>> {
+  let theme = &app.user_config.theme;
+  let label_style = Style::default().fg(theme.hint);
+  let value_style = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
+
+  let liked_songs = app
+    .library
+    .saved_tracks
+    .pages
+    .first()
+    .map(|page| page.total as usize)
+    .or_else(|| (!app.liked_song_ids_set.is_empty()).then_some(app.liked_song_ids_set.len()));
+  let followed_artists = app
+    .library
+    .saved_artists
+    .pages
+    .first()
+    .and_then(|page| page.total.map(|total| total as usize))
+    .or_else(|| {
+      (!app.followed_artist_ids_set.is_empty()).then_some(app.followed_artist_ids_set.len())
+    });
+  let playlists = (!app.all_playlists.is_empty()).then_some(app.all_playlists.len());
+
+  let mut lines = vec![
+    Line::from(Span::styled(
+      "Your library",
+      Style::default()
+        .fg(theme.active)
+        .add_modifier(Modifier::BOLD),
+    )),
+    Line::from(""),
+  ];
+  for (label, count) in [
+    ("Liked songs", liked_songs),
+    ("Followed artists", followed_artists),
+    ("Playlists", playlists),
+  ] {
+    let value = count.map_or_else(|| "not loaded yet".to_string(), |count| count.to_string());
+    lines.push(Line::from(vec![
+      Span::styled(format!("{:<18}", label), label_style),
+      Span::styled(value, value_style),
+    ]));
+  }
+
+  lines.push(Line::from(""));
+  lines.push(Line::from(Span::styled(
+    "Recently played",
+    Style::default()
+      .fg(theme.active)
+      .add_modifier(Modifier::BOLD),
+  )));
+  lines.push(Line::from(""));
+  match &app.recently_played.result {
+    Some(history) if !history.items.is_empty() => {
+      for item in history.items.iter().take(5) {
+        lines.push(Line::from(vec![
+          Span::styled(item.track.name.clone(), value_style),
+          Span::styled(
+            format!(" - {}", create_artist_string(&item.track.artists)),
+            label_style,
+          ),
+        ]));
+      }
+    }
+    _ => lines.push(Line::from(Span::styled(
+      "Nothing yet - open Recently Played from the library to load it.",
+      label_style,
+    ))),
+  }
+  lines
+}
+
 fn get_clean_changelog() -> &'static str {
   CLEAN_CHANGELOG
     .get_or_init(|| {
@@ -0,0 +1,120 @@
+use crate::core::app::{ActiveBlock, App};
+use crate::core::locale;
+use crate::core::user_config::{key_binding_info, FIXED_KEYS, KEY_BINDINGS};
+use ratatui::{
+  layout::Rect,
+  style::Style,
+  text::{Line, Span},
+  widgets::Paragraph,
+  Frame,
+};
+
+/// Keys worth showing for the focused block, in registry order. Both the
+/// configurable actions and the fixed keys say where they're worth a hint, and
+/// configurable ones are read from `user_config.keys` so rebinding shows up here too.
+pub fn key_hints(app: &App) -> Vec<(String, &'static str)> {
+  let keys = &app.user_config.keys;
+  let block = app.get_current_route().active_block;
+  let hint_in = |hints: &'static [(ActiveBlock, &'static str)]| {
+    hints
+      .iter()
+      .find(|(hint_block, _)| *hint_block == block)
+      .map(|(_, label)| *label)
+  };
+
+  let fixed = FIXED_KEYS
+    .iter()
+    .filter_map(|(_, keys, _, hints)| Some((footer_key(keys), hint_in(hints)?)));
+  let bound = KEY_BINDINGS.iter().filter_map(|binding| {
+    let label = hint_in(binding.hints)?;
+    Some((keys.get(binding.name)?.primary().to_string(), label))
+  });
+  let mut hints: Vec<_> = fixed.chain(bound).collect();
+
+  // Block keys win when they shadow a global one, like "/" filtering playlists
+  for binding in ["search", "help"].into_iter().filter_map(key_binding_info) {
+    let Some(key) = keys
+      .get(binding.name)
+      .map(|keys| keys.primary().to_string())
+    else {
+      continue;
+    };
+    if !hints.iter().any(|(bound, _)| *bound == key) {
+      hints.push((key, binding.label));
+    }
+  }
+  hints
+}
+
+/// The first of a help entry's keys, without the angle brackets: "<Enter>" is "Enter"
+fn footer_key(keys: &str) -> String {
+  let first = keys.split(" | ").next().unwrap_or(keys);
+  first
+    .trim_start_matches('<')
+    .trim_end_matches('>')
+    .to_string()
+}
+
+pub fn draw_key_hints(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
+  let theme = &app.user_config.theme;
+  let mut spans = Vec::new();
+  for (key, label) in key_hints(app) {
+    if !spans.is_empty() {
+      spans.push(Span::raw("  "));
+    }
+    spans.push(Span::styled(key, Style::default().fg(theme.hint)));
+    let label = locale::translation(&format!("hints.{}", label)).unwrap_or(label.to_string());
+    spans.push(Span::styled(
+      format!(" {}", label),
+      Style::default().fg(theme.inactive),
+    ));
+  }
+  f.render_widget(Paragraph::new(Line::from(spans)), layout_chunk);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::app::RouteId;
+  use crate::tui::event::Key;
+
+  #[test]
+  fn hints_follow_focus_and_keybindings() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+    app.user_config.keys.add_item_to_queue = Key::Char('Q').into();
+
+    let hints = key_hints(&app);
+    assert_eq!(hints[0], ("Enter".to_string(), "Play"));
+    assert!(hints.contains(&("Q".to_string(), "Queue")));
+    assert_eq!(hints.last(), Some(&("?".to_string(), "Help")));
+
+    app.push_navigation_stack(RouteId::Search, ActiveBlock::Input);
+    assert_eq!(key_hints(&app)[0], ("Enter".to_string(), "Search"));
+
+    // Fixed keys and rebound actions tagged for the sidebar both show up there
+    app.user_config.keys.search = Key::Char('F').into();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::MyPlaylists);
+    let hints = key_hints(&app);
+    assert!(hints.contains(&("r".to_string(), "Rename")));
+    assert!(hints.contains(&("f".to_string(), "Pin")));
+    assert!(hints.contains(&("F".to_string(), "Filter")));
+  }
+
+  #[test]
+  fn each_key_is_tagged_once_per_block() {
+    let tags = FIXED_KEYS
+      .iter()
+      .map(|(_, _, _, hints)| *hints)
+      .chain(KEY_BINDINGS.iter().map(|binding| binding.hints));
+    for hints in tags {
+      for (index, (block, _)) in hints.iter().enumerate() {
+        assert!(
+          !hints[..index].iter().any(|(earlier, _)| earlier == block),
+          "{:?} is tagged twice",
+          block
+        );
+      }
+    }
+  }
+}
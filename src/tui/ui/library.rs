@@ -1,21 +1,66 @@
-use crate::core::app::{ActiveBlock, App, LIBRARY_OPTIONS};
+use crate::core::app::{ActiveBlock, App, MouseTarget, LIBRARY_OPTIONS};
 use ratatui::{
   layout::{Constraint, Layout, Rect},
+  style::Style,
+  text::{Line, Span},
+  widgets::Paragraph,
   Frame,
 };
+use rspotify::model::{PrivateUser, SubscriptionLevel};
+use rspotify::prelude::Id;
 
 use super::{
   search::draw_input_and_help_box,
-  util::{draw_selectable_list, SMALL_TERMINAL_WIDTH},
+  util::{draw_selectable_list, is_wide_layout},
 };
 
+/// Display name (falling back to the user ID) and product tier of the signed-in account
+fn user_summary(user: &PrivateUser) -> (String, Option<&'static str>) {
+  let name = user
+    .display_name
+    .clone()
+    .filter(|name| !name.trim().is_empty())
+    .unwrap_or_else(|| user.id.id().to_string());
+  let tier = user.product.map(|product| match product {
+    SubscriptionLevel::Premium => "Premium",
+    SubscriptionLevel::Free => "Free",
+  });
+  (name, tier)
+}
+
+/// One-line header naming the account in use, so it's clear which one is active
+pub fn draw_user_header(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
+  let theme = &app.user_config.theme;
+  let line = match &app.user {
+    Some(user) => {
+      let (name, tier) = user_summary(user);
+      let mut spans = vec![Span::styled(
+        format!(" {}", name),
+        Style::default().fg(theme.header),
+      )];
+      if let Some(tier) = tier {
+        spans.push(Span::styled(
+          format!(" \u{00B7} {}", tier),
+          Style::default().fg(theme.inactive),
+        ));
+      }
+      Line::from(spans)
+    }
+    None => Line::from(Span::styled(
+      " Loading account...",
+      Style::default().fg(theme.inactive),
+    )),
+  };
+  f.render_widget(Paragraph::new(line), layout_chunk);
+}
+
 pub fn draw_library_block(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
   let current_route = app.get_current_route();
   let highlight_state = (
     current_route.active_block == ActiveBlock::Library,
     current_route.hovered_block == ActiveBlock::Library,
   );
-  draw_selectable_list(
+  let offset = draw_selectable_list(
     f,
     app,
     layout_chunk,
@@ -24,6 +69,7 @@ pub fn draw_library_block(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     highlight_state,
     Some(app.library.selected_index),
   );
+  app.record_mouse_region(MouseTarget::Library, layout_chunk, offset);
 }
 
 pub fn draw_playlist_block(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
@@ -50,8 +96,17 @@ pub fn draw_playlist_block(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
         crate::core::app::PlaylistFolderItem::Playlist { index, .. } => app
           .all_playlists
           .get(*index)
-          .map(|p| p.name.clone())
+          .map(|p| {
+            if app.is_playlist_pinned(*index) {
+              format!("{}{}", app.user_config.padded_pinned_icon(), p.name)
+            } else {
+              p.name.clone()
+            }
+          })
           .unwrap_or_else(|| "Unknown".to_string()),
+        crate::core::app::PlaylistFolderItem::PinnedHeader => {
+          "\u{2500}\u{2500} Pinned \u{2500}\u{2500}".to_string()
+        }
       })
       .collect()
   };
@@ -63,38 +118,84 @@ pub fn draw_playlist_block(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     current_route.hovered_block == ActiveBlock::MyPlaylists,
   );
 
-  draw_selectable_list(
+  let title = if let Some(query) = &app.playlist_filter {
+    format!("Playlists / {}_", query)
+  } else if app.playlists_fully_loaded {
+    format!("Playlists ({})", app.all_playlists.len())
+  } else {
+    "Playlists".to_string()
+  };
+
+  let offset = draw_selectable_list(
     f,
     app,
     layout_chunk,
-    "Playlists",
+    &title,
     &playlist_items,
     highlight_state,
     app.selected_playlist_index,
   );
+  app.record_mouse_region(MouseTarget::Playlists, layout_chunk, offset);
 }
 
 pub fn draw_user_block(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
   // Check for width to make a responsive layout
-  if app.size.width >= SMALL_TERMINAL_WIDTH && !app.user_config.behavior.enforce_wide_search_bar {
-    let [input_area, library_area, playlist_area] = layout_chunk.layout(&Layout::vertical([
-      Constraint::Length(3),
-      Constraint::Percentage(30),
-      Constraint::Percentage(70),
-    ]));
+  if is_wide_layout(app) {
+    let [input_area, user_area, library_area, playlist_area] =
+      layout_chunk.layout(&Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(1),
+        Constraint::Percentage(30),
+        Constraint::Percentage(70),
+      ]));
 
     // Search input and help
     draw_input_and_help_box(f, app, input_area);
+    draw_user_header(f, app, user_area);
     draw_library_block(f, app, library_area);
     draw_playlist_block(f, app, playlist_area);
   } else {
-    let [library_area, playlist_area] = layout_chunk.layout(&Layout::vertical([
+    let [user_area, library_area, playlist_area] = layout_chunk.layout(&Layout::vertical([
+      Constraint::Length(1),
       Constraint::Percentage(30),
       Constraint::Percentage(70),
     ]));
 
     // Search input and help
+    draw_user_header(f, app, user_area);
     draw_library_block(f, app, library_area);
     draw_playlist_block(f, app, playlist_area);
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn user(display_name: Option<&str>, product: Option<&str>) -> PrivateUser {
+    serde_json::from_value(serde_json::json!({
+      "id": "listener42",
+      "display_name": display_name,
+      "product": product,
+      "external_urls": {},
+      "href": "https://api.spotify.com/v1/users/listener42"
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn user_summary_shows_name_and_tier() {
+    assert_eq!(
+      user_summary(&user(Some("Ada"), Some("premium"))),
+      ("Ada".to_string(), Some("Premium"))
+    );
+    assert_eq!(
+      user_summary(&user(None, Some("free"))),
+      ("listener42".to_string(), Some("Free"))
+    );
+    assert_eq!(
+      user_summary(&user(Some(" "), None)),
+      ("listener42".to_string(), None)
+    );
+  }
+}
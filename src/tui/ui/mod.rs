@@ -1,13 +1,17 @@
 pub mod artist;
 pub mod audio_analysis;
+pub mod color;
 pub mod discover;
 pub mod help;
 pub mod home;
+pub mod key_hints;
 pub mod library;
 pub mod player;
 pub mod popups;
 pub mod search;
 pub mod settings;
+#[cfg(test)]
+mod snapshot_tests;
 pub mod tables;
 pub mod util;
 
@@ -20,38 +24,54 @@ use ratatui::{
 pub use self::artist::draw_artist_albums;
 pub use self::discover::draw_discover;
 pub use self::home::draw_home;
+pub use self::key_hints::draw_key_hints;
 pub use self::library::draw_user_block;
-pub use self::player::{draw_basic_view, draw_device_list, draw_playbar};
+pub use self::player::{
+  draw_basic_view, draw_device_list, draw_now_playing, draw_playbar, draw_tv_view,
+};
 pub use self::popups::{
   draw_announcement_prompt, draw_dialog, draw_error_screen, draw_exit_prompt, draw_help_menu,
-  draw_sort_menu, draw_update_prompt,
+  draw_library_search, draw_new_playlist_prompt, draw_playlist_details_prompt, draw_sort_menu,
+  draw_update_prompt,
 };
 pub use self::search::{draw_input_and_help_box, draw_search_results};
 pub use self::tables::{
-  draw_album_list, draw_album_table, draw_artist_table, draw_podcast_table,
+  draw_album_list, draw_album_table, draw_artist_table, draw_podcast_table, draw_queue_table,
   draw_recently_played_table, draw_recommendations_table, draw_show_episodes, draw_song_table,
 };
-use self::util::{get_main_layout_margin, SMALL_TERMINAL_WIDTH};
+use self::util::{get_main_layout_margin, is_wide_layout};
 
 pub fn draw_main_layout(f: &mut Frame<'_>, app: &App) {
+  app.clear_mouse_regions();
   let margin = get_main_layout_margin(app);
-  // Responsive layout: new one kicks in at width 150 or higher
-  if app.size.width >= SMALL_TERMINAL_WIDTH && !app.user_config.behavior.enforce_wide_search_bar {
-    let [routes_area, playbar_area] = f
-      .area()
-      .layout(&Layout::vertical([Constraint::Min(1), Constraint::Length(6)]).margin(margin));
+  let hints_height = u16::from(app.user_config.behavior.show_key_hints);
+  // Responsive layout: new one kicks in at `behavior.wide_layout_min_width`
+  if is_wide_layout(app) {
+    let [routes_area, playbar_area, hints_area] = f.area().layout(
+      &Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(6),
+        Constraint::Length(hints_height),
+      ])
+      .margin(margin),
+    );
 
     // Nested main block with potential routes
     draw_routes(f, app, routes_area);
 
     // Currently playing
     draw_playbar(f, app, playbar_area);
+
+    if app.user_config.behavior.show_key_hints {
+      draw_key_hints(f, app, hints_area);
+    }
   } else {
-    let [input_area, routes_area, playbar_area] = f.area().layout(
+    let [input_area, routes_area, playbar_area, hints_area] = f.area().layout(
       &Layout::vertical([
         Constraint::Length(3),
         Constraint::Min(1),
         Constraint::Length(6),
+        Constraint::Length(hints_height),
       ])
       .margin(margin),
     );
@@ -64,6 +84,10 @@ pub fn draw_main_layout(f: &mut Frame<'_>, app: &App) {
 
     // Currently playing
     draw_playbar(f, app, playbar_area);
+
+    if app.user_config.behavior.show_key_hints {
+      draw_key_hints(f, app, hints_area);
+    }
   }
 
   // Possibly draw confirm dialog
@@ -71,6 +95,15 @@ pub fn draw_main_layout(f: &mut Frame<'_>, app: &App) {
 
   // Possibly draw sort menu
   draw_sort_menu(f, app);
+
+  // Possibly draw the new playlist prompt
+  draw_new_playlist_prompt(f, app);
+
+  // Possibly draw the playlist rename prompt
+  draw_playlist_details_prompt(f, app);
+
+  // Possibly draw the library search overlay
+  draw_library_search(f, app);
 }
 
 pub fn draw_routes(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
@@ -120,10 +153,15 @@ pub fn draw_routes(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     RouteId::Recommendations => {
       draw_recommendations_table(f, app, content_area);
     }
+    RouteId::Queue => {
+      draw_queue_table(f, app, content_area);
+    }
     RouteId::Error => {} // This is handled as a "full screen" route in main.rs
     RouteId::SelectedDevice => {} // This is handled as a "full screen" route in main.rs
     RouteId::Analysis => {} // This is handled as a "full screen" route in main.rs
     RouteId::BasicView => {} // This is handled as a "full screen" route in main.rs
+    RouteId::NowPlaying => {} // This is handled as a "full screen" route in main.rs
+    RouteId::TvView => {} // This is handled as a "full screen" route in main.rs
     RouteId::Dialog => {} // This is handled in the draw_dialog function in mod.rs
     RouteId::UpdatePrompt => {} // This is handled as a "full screen" route in main.rs
     RouteId::AnnouncementPrompt => {} // This is handled as a "full screen" route in main.rs
@@ -1,11 +1,13 @@
 use crate::core::app::{ActiveBlock, App};
+use crate::core::availability;
+use crate::core::locale::{t, tf};
 use ratatui::{
-  layout::{Alignment, Constraint, Direction, Layout, Rect},
+  layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
   style::{Color, Modifier, Style},
   text::{Line, Span, Text},
   widgets::{
-    canvas::Canvas, Block, BorderType, Borders, LineGauge, List, ListItem, ListState, Paragraph,
-    Wrap,
+    canvas::Canvas, Block, BorderType, Borders, Gauge, LineGauge, List, ListItem, ListState,
+    Paragraph, Wrap,
   },
   Frame,
 };
@@ -13,12 +15,202 @@ use rspotify::model::enums::RepeatState;
 use rspotify::model::PlayableItem;
 use rspotify::prelude::Id;
 
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::key_hints::draw_key_hints;
 use super::util::{
   create_artist_string, display_track_progress, get_color, get_track_progress_percentage,
   BASIC_VIEW_HEIGHT,
 };
+use crate::core::user_config::BehaviorConfig;
+
+/// Build the playbar title, showing the configured icons and falling back to
+/// words for any icon that is set to an empty string.
+fn playbar_title(
+  behavior: &BehaviorConfig,
+  is_playing: bool,
+  device_name: &str,
+  shuffle: bool,
+  repeat: RepeatState,
+  volume: u32,
+) -> String {
+  fn icon_or<'a>(icon: &'a str, text: &'a str) -> &'a str {
+    if icon.is_empty() {
+      text
+    } else {
+      icon
+    }
+  }
+
+  let play_title = if is_playing {
+    icon_or(&behavior.playing_icon, "Playing")
+  } else {
+    icon_or(&behavior.paused_icon, "Paused")
+  };
+  let shuffle_text = if shuffle {
+    icon_or(&behavior.shuffle_icon, "On")
+  } else {
+    "Off"
+  };
+  let repeat_text = match repeat {
+    RepeatState::Off => "Off",
+    RepeatState::Track => icon_or(&behavior.repeat_track_icon, "Track"),
+    RepeatState::Context => icon_or(&behavior.repeat_context_icon, "All"),
+  };
+
+  let boost = if volume > 100 { " BOOST" } else { "" };
+
+  format!(
+    "{} ({} | Shuffle: {} | Repeat: {} | Volume: {:-2}%{})",
+    play_title, device_name, shuffle_text, repeat_text, volume, boost
+  )
+}
+
+/// Cut `text` to at most `width` terminal cells, ending with "…" when shortened
+fn truncate_to_width(text: &str, width: usize) -> String {
+  if text.width() <= width {
+    return text.to_string();
+  }
+  let mut result = String::new();
+  let mut used = 0;
+  for c in text.chars() {
+    let char_width = c.width().unwrap_or(0);
+    if used + char_width + 1 > width {
+      break;
+    }
+    used += char_width;
+    result.push(c);
+  }
+  if width > 0 {
+    result.push('…');
+  }
+  result
+}
+
+/// What is playing, as the playbar and the full-screen views show it
+struct PlayingItem {
+  name: String,
+  artists: String,
+  album: String,
+  duration_ms: u64,
+  is_playing: bool,
+  is_saved: bool,
+  /// Whether the track plays in the user's market, when that is known
+  availability: Option<bool>,
+}
+
+impl PlayingItem {
+  fn progress_ms(app: &App) -> u128 {
+    app.seek_ms.unwrap_or(app.song_progress_ms)
+  }
+
+  fn progress_label(&self, app: &App) -> String {
+    display_track_progress(
+      Self::progress_ms(app),
+      std::time::Duration::from_millis(self.duration_ms),
+    )
+  }
+}
+
+/// The playing track or episode. Native track info is used when there is some,
+/// since it switches as soon as a track is skipped while the API lags behind.
+fn playing_item(app: &App) -> Option<PlayingItem> {
+  let context = app.current_playback_context.as_ref()?;
+  let item = context.item.as_ref()?;
+  // Use native playing state when streaming is active (more reliable for MPRIS controls)
+  let is_playing = app
+    .native_is_playing
+    .filter(|_| app.is_streaming_active)
+    .unwrap_or(context.is_playing);
+
+  let (name, artists, album, duration_ms, is_saved) = match item {
+    PlayableItem::Track(track) => (
+      track.name.to_owned(),
+      create_artist_string(&track.artists),
+      track.album.name.to_owned(),
+      track.duration.num_milliseconds() as u64,
+      track
+        .id
+        .as_ref()
+        .is_some_and(|id| app.liked_song_ids_set.contains(id.id())),
+    ),
+    PlayableItem::Episode(episode) => (
+      episode.name.to_owned(),
+      format!("{} - {}", episode.name, episode.show.name),
+      episode.show.name.to_owned(),
+      episode.duration.num_milliseconds() as u64,
+      app.saved_episode_ids_set.contains(episode.id.id()),
+    ),
+  };
+  // Native info switches tracks before the API does, so only trust a matching track
+  let availability = match item {
+    PlayableItem::Track(track)
+      if app
+        .native_track_info
+        .as_ref()
+        .is_none_or(|native| native.name == track.name) =>
+    {
+      availability::market_availability(track, app.get_user_country())
+    }
+    _ => None,
+  };
+
+  let playing = PlayingItem {
+    name,
+    artists,
+    album,
+    duration_ms,
+    is_playing,
+    is_saved,
+    availability,
+  };
+  Some(match &app.native_track_info {
+    Some(native) => PlayingItem {
+      name: native.name.clone(),
+      artists: native.artists_display.clone(),
+      album: native.album.clone(),
+      duration_ms: native.duration_ms as u64,
+      ..playing
+    },
+    None => playing,
+  })
+}
+
+/// The progress gauge for `playing`, labelled with `label`
+fn song_progress_gauge<'a>(app: &App, playing: &PlayingItem, label: &'a str) -> LineGauge<'a> {
+  let duration = std::time::Duration::from_millis(playing.duration_ms);
+  let perc = get_track_progress_percentage(PlayingItem::progress_ms(app), duration);
+  let modifier = if app.user_config.behavior.enable_text_emphasis {
+    Modifier::ITALIC | Modifier::BOLD
+  } else {
+    Modifier::empty()
+  };
+  LineGauge::default()
+    .filled_style(
+      Style::default()
+        .fg(app.user_config.theme.playbar_progress)
+        .add_modifier(modifier),
+    )
+    .unfilled_style(
+      Style::default()
+        .fg(app.user_config.theme.playbar_background)
+        .add_modifier(modifier),
+    )
+    .ratio(perc as f64 / 100.0)
+    .filled_symbol("⣿")
+    .unfilled_symbol("⣉")
+    .label(Span::styled(
+      label,
+      Style::default().fg(app.user_config.theme.playbar_progress_text),
+    ))
+}
 
 pub fn draw_basic_view(f: &mut Frame<'_>, app: &App) {
+  if app.lyrics_focus_mode {
+    draw_lyrics(f, app, f.area(), false);
+    return;
+  }
+
   let chunks = Layout::default()
     .direction(Direction::Vertical)
     .constraints([
@@ -27,21 +219,257 @@ pub fn draw_basic_view(f: &mut Frame<'_>, app: &App) {
     ])
     .split(f.area());
 
-  draw_lyrics(f, app, chunks[0]);
+  draw_lyrics(f, app, chunks[0], true);
   draw_playbar(f, app, chunks[1]);
 }
 
-fn draw_lyrics(f: &mut Frame<'_>, app: &App, area: Rect) {
-  use crate::core::app::LyricsStatus;
-
-  // Draw bordered block first
+/// A full-screen view of what is playing: large track details, a wide progress
+/// bar, the playback state and a legend of the playback keys
+pub fn draw_now_playing(f: &mut Frame<'_>, app: &App) {
+  let theme = &app.user_config.theme;
   let block = Block::default()
     .borders(Borders::ALL)
-    .title(" Lyrics ")
-    .style(Style::default().fg(Color::Rgb(100, 100, 100))); // RGB for cross-terminal compat
-  f.render_widget(block.clone(), area);
+    .border_type(BorderType::Rounded)
+    .title(Span::styled(
+      " Now Playing ",
+      Style::default().fg(theme.active),
+    ))
+    .border_style(Style::default().fg(theme.inactive));
+  let inner = block.inner(f.area());
+  f.render_widget(block, f.area());
+
+  let [body, legend_area] = inner
+    .layout(&Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).horizontal_margin(1));
+  draw_key_hints(f, app, legend_area);
+
+  let (Some(context), Some(playing)) = (&app.current_playback_context, playing_item(app)) else {
+    let message = app
+      .status_message
+      .clone()
+      .or_else(|| app.streaming_warning.clone())
+      .unwrap_or_else(|| t("player.nothing_playing"));
+    let [center] = body.layout(&Layout::vertical([Constraint::Length(1)]).flex(Flex::Center));
+    f.render_widget(
+      Paragraph::new(message)
+        .style(Style::default().fg(theme.inactive))
+        .alignment(Alignment::Center),
+      center,
+    );
+    return;
+  };
+
+  #[cfg(feature = "cover-art")]
+  let body = if app
+    .user_config
+    .do_draw_cover_art(app.cover_art.full_image_support())
+    && app.cover_art.available()
+  {
+    // Half the height, twice as many columns since cells are about twice as tall as wide
+    let height = body.height / 2;
+    let [cover_art, _, rest] = body.layout(&Layout::horizontal([
+      Constraint::Length(height * 2),
+      Constraint::Length(2),
+      Constraint::Fill(1),
+    ]));
+    let [cover_art] =
+      cover_art.layout(&Layout::vertical([Constraint::Length(height)]).flex(Flex::Center));
+    app.cover_art.render(f, cover_art);
+    rest
+  } else {
+    body
+  };
+
+  let name = if playing.is_saved {
+    format!("{}{}", app.user_config.padded_liked_icon(), playing.name)
+  } else {
+    playing.name.clone()
+  };
+  let mut details = vec![
+    Line::from(Span::styled(
+      name,
+      Style::default()
+        .fg(theme.selected)
+        .add_modifier(Modifier::BOLD),
+    )),
+    Line::default(),
+    Line::from(Span::styled(
+      playing.artists.as_str(),
+      Style::default().fg(theme.playbar_text),
+    )),
+    Line::from(Span::styled(
+      playing.album.as_str(),
+      Style::default().fg(theme.inactive),
+    )),
+  ];
+  if playing.availability == Some(false) {
+    details.push(Line::from(Span::styled(
+      t("player.restricted"),
+      Style::default().fg(theme.error_text),
+    )));
+  }
 
-  let inner_area = block.inner(area);
+  let state = playbar_title(
+    &app.user_config.behavior,
+    playing.is_playing,
+    &context.device.name,
+    context.shuffle_state,
+    context.repeat_state,
+    context.device.volume_percent.unwrap_or(0),
+  );
+  let [details_area, _, progress_area, _, state_area] = body.layout(
+    &Layout::vertical([
+      Constraint::Length(details.len() as u16),
+      Constraint::Length(1),
+      Constraint::Length(1),
+      Constraint::Length(1),
+      Constraint::Length(1),
+    ])
+    .flex(Flex::Center),
+  );
+  f.render_widget(
+    Paragraph::new(details)
+      .alignment(Alignment::Center)
+      .wrap(Wrap { trim: true }),
+    details_area,
+  );
+  let label = playing.progress_label(app);
+  f.render_widget(song_progress_gauge(app, &playing, &label), progress_area);
+  f.render_widget(
+    Paragraph::new(truncate_to_width(&state, state_area.width as usize))
+      .style(Style::default().fg(theme.playbar_text))
+      .alignment(Alignment::Center),
+    state_area,
+  );
+}
+
+/// Rows of the progress bar in the TV view, thick enough to read from across the room
+const TV_PROGRESS_HEIGHT: u16 = 3;
+
+/// What is playing, for a screen across the room: the cover art, the track
+/// details and a thick progress bar, centered with nothing around them
+pub fn draw_tv_view(f: &mut Frame<'_>, app: &App) {
+  let theme = &app.user_config.theme;
+  let area = f.area();
+
+  let Some(playing) = playing_item(app) else {
+    let [center] = area.layout(&Layout::vertical([Constraint::Length(1)]).flex(Flex::Center));
+    f.render_widget(
+      Paragraph::new(t("player.nothing_playing"))
+        .style(Style::default().fg(theme.inactive))
+        .alignment(Alignment::Center),
+      center,
+    );
+    return;
+  };
+
+  let name = if playing.is_saved {
+    format!("{}{}", app.user_config.padded_liked_icon(), playing.name)
+  } else {
+    playing.name.clone()
+  };
+  let mut details = vec![
+    Line::from(Span::styled(
+      name,
+      Style::default()
+        .fg(theme.selected)
+        .add_modifier(Modifier::BOLD),
+    )),
+    Line::default(),
+    Line::from(Span::styled(
+      playing.artists.as_str(),
+      Style::default().fg(theme.playbar_text),
+    )),
+    Line::from(Span::styled(
+      playing.album.as_str(),
+      Style::default().fg(theme.inactive),
+    )),
+  ];
+  if !playing.is_playing {
+    details.push(Line::default());
+    details.push(Line::from(Span::styled(
+      t("player.paused"),
+      Style::default().fg(theme.inactive),
+    )));
+  }
+
+  #[cfg(feature = "cover-art")]
+  let art_height = if app
+    .user_config
+    .do_draw_cover_art(app.cover_art.full_image_support())
+    && app.cover_art.available()
+  {
+    area.height / 2
+  } else {
+    0
+  };
+  #[cfg(not(feature = "cover-art"))]
+  let art_height = 0;
+
+  let [column] = area.layout(&Layout::horizontal([Constraint::Percentage(80)]).flex(Flex::Center));
+  // Long names wrap, so leave room for every row they take at this width
+  let details = Paragraph::new(details)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+  let details_height = u16::try_from(details.line_count(column.width)).unwrap_or(u16::MAX);
+  let [art_area, _, details_area, _, progress_area] = column.layout(
+    &Layout::vertical([
+      Constraint::Length(art_height),
+      Constraint::Length(art_height.min(1)),
+      Constraint::Length(details_height),
+      Constraint::Length(1),
+      Constraint::Length(TV_PROGRESS_HEIGHT),
+    ])
+    .flex(Flex::Center),
+  );
+
+  #[cfg(feature = "cover-art")]
+  if art_height > 0 {
+    // Twice as many columns as rows since cells are about twice as tall as wide
+    let [cover_art] =
+      art_area.layout(&Layout::horizontal([Constraint::Length(art_height * 2)]).flex(Flex::Center));
+    app.cover_art.render(f, cover_art);
+  }
+  #[cfg(not(feature = "cover-art"))]
+  let _ = art_area;
+
+  f.render_widget(details, details_area);
+
+  let duration = std::time::Duration::from_millis(playing.duration_ms);
+  let label = playing.progress_label(app);
+  f.render_widget(
+    Gauge::default()
+      .gauge_style(
+        Style::default()
+          .fg(theme.playbar_progress)
+          .bg(theme.playbar_background),
+      )
+      .percent(get_track_progress_percentage(
+        PlayingItem::progress_ms(app),
+        duration,
+      ))
+      .label(Span::styled(
+        label,
+        Style::default().fg(theme.playbar_progress_text),
+      ))
+      .use_unicode(true),
+    progress_area,
+  );
+}
+
+fn draw_lyrics(f: &mut Frame<'_>, app: &App, area: Rect, bordered: bool) {
+  use crate::core::app::LyricsStatus;
+
+  // Draw bordered block first, focus mode uses the whole area
+  let inner_area = if bordered {
+    let block = Block::default()
+      .borders(Borders::ALL)
+      .title(" Lyrics ")
+      .style(Style::default().fg(Color::Rgb(100, 100, 100))); // RGB for cross-terminal compat
+    f.render_widget(block.clone(), area);
+    block.inner(area)
+  } else {
+    area
+  };
 
   if app.lyrics_status != LyricsStatus::Found {
     let text = match app.lyrics_status {
@@ -182,204 +610,193 @@ pub fn draw_playbar(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     .margin(1),
   );
 
+  #[cfg(feature = "preview")]
+  if let Some(preview) = &app.preview {
+    draw_preview_playbar(f, app, preview, layout_chunk, artist_area, progress_area);
+    return;
+  }
+
   let mut drew_playbar = false;
 
   // If no track is playing, render paragraph showing which device is selected, if no selected
   // give hint to choose a device
-  if let Some(current_playback_context) = &app.current_playback_context {
-    if let Some(track_item) = &current_playback_context.item {
-      // Use native playing state when streaming is active (more reliable for MPRIS controls)
-      let is_playing = app
-        .native_is_playing
-        .filter(|_| app.is_streaming_active)
-        .unwrap_or(current_playback_context.is_playing);
-
-      let play_title = if is_playing { "Playing" } else { "Paused" };
-
-      let shuffle_text = if current_playback_context.shuffle_state {
-        "On"
-      } else {
-        "Off"
-      };
-
-      let repeat_text = match current_playback_context.repeat_state {
-        RepeatState::Off => "Off",
-        RepeatState::Track => "Track",
-        RepeatState::Context => "All",
-      };
+  if let (Some(current_playback_context), Some(playing)) =
+    (&app.current_playback_context, playing_item(app))
+  {
+    let mut title = playbar_title(
+      &app.user_config.behavior,
+      playing.is_playing,
+      &current_playback_context.device.name,
+      current_playback_context.shuffle_state,
+      current_playback_context.repeat_state,
+      current_playback_context.device.volume_percent.unwrap_or(0),
+    );
 
-      let mut title = format!(
-        "{:-7} ({} | Shuffle: {:-3} | Repeat: {:-5} | Volume: {:-2}%)",
-        play_title,
-        current_playback_context.device.name,
-        shuffle_text,
-        repeat_text,
-        current_playback_context.device.volume_percent.unwrap_or(0)
-      );
+    if let Some(format) = app
+      .native_stream_format
+      .as_ref()
+      .filter(|_| app.is_streaming_active)
+    {
+      title = format!("{} | {}", title, format);
+    }
 
-      if let Some(message) = app.status_message.as_ref() {
-        title = format!("{} | {}", title, message);
-      }
+    if let Some(queue_length) = app
+      .queue_length
+      .filter(|length| *length > 0 && app.user_config.behavior.show_queue_length)
+    {
+      title = format!("{} | Queue: {}", title, queue_length);
+    }
 
-      let current_route = app.get_current_route();
-      let highlight_state = (
-        current_route.active_block == ActiveBlock::PlayBar,
-        current_route.hovered_block == ActiveBlock::PlayBar,
+    if let Some((name, done, total)) = app.offline_cache_progress.as_ref() {
+      let caching = tf(
+        "player.caching",
+        &[
+          ("name", name),
+          ("done", &done.to_string()),
+          ("total", &total.to_string()),
+        ],
       );
+      title = format!("{} | {}", title, caching);
+    }
 
-      let title_block = Block::default()
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .style(Style::default().bg(app.user_config.theme.playbar_background))
-        .title(Span::styled(
-          &title,
-          get_color(highlight_state, app.user_config.theme),
-        ))
-        .border_style(get_color(highlight_state, app.user_config.theme));
+    if let Some(message) = app
+      .status_message
+      .as_ref()
+      .or(app.streaming_warning.as_ref())
+    {
+      title = format!("{} | {}", title, message);
+    }
 
-      f.render_widget(title_block, layout_chunk);
+    let current_route = app.get_current_route();
+    let highlight_state = (
+      current_route.active_block == ActiveBlock::PlayBar,
+      current_route.hovered_block == ActiveBlock::PlayBar,
+    );
 
-      let (item_id, name, duration) = match track_item {
-        PlayableItem::Track(track) => (
-          track
-            .id
-            .as_ref()
-            .map(|id| id.id().to_string())
-            .unwrap_or_default(),
-          track.name.to_owned(),
-          track.duration,
-        ),
-        PlayableItem::Episode(episode) => (
-          episode.id.id().to_string(),
-          episode.name.to_owned(),
-          episode.duration,
-        ),
-      };
+    // Emoji icons can take two cells, so cut by display width rather than chars
+    let title = truncate_to_width(&title, layout_chunk.width.saturating_sub(2) as usize);
+    let title_block = Block::default()
+      .borders(Borders::ALL)
+      .border_type(BorderType::Rounded)
+      .style(Style::default().bg(app.user_config.theme.playbar_background))
+      .title(Span::styled(
+        &title,
+        get_color(highlight_state, app.user_config.theme),
+      ))
+      .border_style(get_color(highlight_state, app.user_config.theme));
+
+    f.render_widget(title_block, layout_chunk);
+
+    let track_name = if playing.is_saved {
+      format!("{}{}", &app.user_config.padded_liked_icon(), playing.name)
+    } else {
+      playing.name.clone()
+    };
 
-      // Use native track info for instant display when available (e.g., after skipping tracks)
-      // Falls back to API data when native info is not available
-      let (display_name, display_artists, display_duration_ms) =
-        if let Some(ref native_info) = app.native_track_info {
-          (
-            native_info.name.clone(),
-            native_info.artists_display.clone(),
-            native_info.duration_ms as u64,
-          )
-        } else {
-          let artists_str = match track_item {
-            PlayableItem::Track(track) => create_artist_string(&track.artists),
-            PlayableItem::Episode(episode) => format!("{} - {}", episode.name, episode.show.name),
-          };
-          (
-            name.clone(),
-            artists_str,
-            duration.num_milliseconds() as u64,
-          )
-        };
+    let lines = Text::from(Span::styled(
+      &playing.artists,
+      Style::default().fg(app.user_config.theme.playbar_text),
+    ));
 
-      let track_name = if app.liked_song_ids_set.contains(&item_id) {
-        format!("{}{}", &app.user_config.padded_liked_icon(), display_name)
-      } else {
-        display_name
-      };
+    let mut title_spans = vec![Span::styled(
+      track_name,
+      Style::default()
+        .fg(app.user_config.theme.selected)
+        .add_modifier(Modifier::BOLD),
+    )];
+    match playing.availability {
+      Some(true) => title_spans.push(Span::styled(
+        format!(" · {}", t("player.available")),
+        Style::default().fg(app.user_config.theme.inactive),
+      )),
+      Some(false) => title_spans.push(Span::styled(
+        format!(" · {}", t("player.restricted_short")),
+        Style::default().fg(app.user_config.theme.error_text),
+      )),
+      None => {}
+    }
 
-      let lines = Text::from(Span::styled(
-        display_artists,
-        Style::default().fg(app.user_config.theme.playbar_text),
-      ));
-
-      let artist = Paragraph::new(lines)
-        .style(Style::default().fg(app.user_config.theme.playbar_text))
-        .block(
-          Block::default().title(Span::styled(
-            track_name,
-            Style::default()
-              .fg(app.user_config.theme.selected)
-              .add_modifier(Modifier::BOLD),
-          )),
-        );
-      f.render_widget(artist, artist_area);
-
-      let progress_ms = match app.seek_ms {
-        Some(seek_ms) => seek_ms,
-        None => app.song_progress_ms,
-      };
+    let artist = Paragraph::new(lines)
+      .style(Style::default().fg(app.user_config.theme.playbar_text))
+      .block(Block::default().title(Line::from(title_spans)));
+    f.render_widget(artist, artist_area);
 
-      let duration_std = std::time::Duration::from_millis(display_duration_ms);
-      let perc = get_track_progress_percentage(progress_ms, duration_std);
+    let song_progress_label = playing.progress_label(app);
+    f.render_widget(
+      song_progress_gauge(app, &playing, &song_progress_label),
+      progress_area,
+    );
 
-      let song_progress_label = display_track_progress(progress_ms, duration_std);
-      let modifier = if app.user_config.behavior.enable_text_emphasis {
-        Modifier::ITALIC | Modifier::BOLD
-      } else {
-        Modifier::empty()
-      };
-      let song_progress = LineGauge::default()
-        .filled_style(
-          Style::default()
-            .fg(app.user_config.theme.playbar_progress)
-            .add_modifier(modifier),
-        )
-        .unfilled_style(
-          Style::default()
-            .fg(app.user_config.theme.playbar_background)
-            .add_modifier(modifier),
-        )
-        .ratio(perc as f64 / 100.0)
-        .filled_symbol("⣿")
-        .unfilled_symbol("⣉")
-        .label(Span::styled(
-          &song_progress_label,
-          Style::default().fg(app.user_config.theme.playbar_progress_text),
-        ));
-      f.render_widget(song_progress, progress_area);
-
-      // Draw "Like" animation (heart burst) if active
-      if let Some(frame) = app.liked_song_animation_frame {
-        let progress = (10 - frame) as f64;
-        let y_base = 20.0 + progress * 5.0; // Rise up
-
-        let canvas = Canvas::default()
-          .block(Block::default()) // No border, transparent
-          .x_bounds([0.0, 100.0])
-          .y_bounds([0.0, 100.0])
-          .paint(|ctx| {
-            let color = app.user_config.theme.selected;
-            // Center heart
-            ctx.print(50.0, y_base, Span::styled("♥", Style::default().fg(color)));
-            // Left particle (lagging slightly)
-            ctx.print(
-              48.0,
-              y_base - 3.0,
-              Span::styled("♥", Style::default().fg(color)),
-            );
-            // Right particle (lagging slightly)
-            ctx.print(
-              52.0,
-              y_base - 3.0,
-              Span::styled("♥", Style::default().fg(color)),
-            );
-          });
-
-        f.render_widget(canvas, layout_chunk);
+    let ab_points = match (app.ab_loop, app.ab_loop_start) {
+      (Some((a, b)), _) => vec![("A", a), ("B", b)],
+      (None, Some(a)) => vec![("A", a)],
+      (None, None) => Vec::new(),
+    };
+    let marker_style = Style::default()
+      .fg(app.user_config.theme.hint)
+      .add_modifier(Modifier::BOLD);
+    for (marker, ms) in ab_points {
+      if let Some(x) = gauge_column(
+        progress_area,
+        song_progress_label.width() as u16,
+        ms as u128,
+        playing.duration_ms as u128,
+      ) {
+        f.buffer_mut()[(x, progress_area.y)]
+          .set_symbol(marker)
+          .set_style(marker_style);
       }
+    }
 
-      #[cfg(feature = "cover-art")]
-      if app
-        .user_config
-        .do_draw_cover_art(app.cover_art.full_image_support())
-      {
-        if let Some(cover_art) = cover_art {
-          app.cover_art.render(f, cover_art);
-        }
-      }
+    // Draw "Like" animation (heart burst) if active
+    if let Some(frame) = app.liked_song_animation_frame {
+      let progress = (10 - frame) as f64;
+      let y_base = 20.0 + progress * 5.0; // Rise up
+
+      let canvas = Canvas::default()
+        .block(Block::default()) // No border, transparent
+        .x_bounds([0.0, 100.0])
+        .y_bounds([0.0, 100.0])
+        .paint(|ctx| {
+          let color = app.user_config.theme.selected;
+          // Center heart
+          ctx.print(50.0, y_base, Span::styled("♥", Style::default().fg(color)));
+          // Left particle (lagging slightly)
+          ctx.print(
+            48.0,
+            y_base - 3.0,
+            Span::styled("♥", Style::default().fg(color)),
+          );
+          // Right particle (lagging slightly)
+          ctx.print(
+            52.0,
+            y_base - 3.0,
+            Span::styled("♥", Style::default().fg(color)),
+          );
+        });
+
+      f.render_widget(canvas, layout_chunk);
+    }
 
-      drew_playbar = true;
+    #[cfg(feature = "cover-art")]
+    if app
+      .user_config
+      .do_draw_cover_art(app.cover_art.full_image_support())
+    {
+      if let Some(cover_art) = cover_art {
+        app.cover_art.render(f, cover_art);
+      }
     }
+
+    drew_playbar = true;
   }
 
   if !drew_playbar {
-    if let Some(message) = app.status_message.as_ref() {
+    if let Some(message) = app
+      .status_message
+      .as_ref()
+      .or(app.streaming_warning.as_ref())
+    {
       let title_block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
@@ -394,6 +811,58 @@ pub fn draw_playbar(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
   }
 }
 
+/// The playbar for a local preview clip, which has no playback context to show
+#[cfg(feature = "preview")]
+fn draw_preview_playbar(
+  f: &mut Frame<'_>,
+  app: &App,
+  preview: &crate::core::app::PreviewTrack,
+  layout_chunk: Rect,
+  artist_area: Rect,
+  progress_area: Rect,
+) {
+  let theme = app.user_config.theme;
+  let title_block = Block::default()
+    .borders(Borders::ALL)
+    .border_type(BorderType::Rounded)
+    .style(Style::default().bg(theme.playbar_background))
+    .title(Span::styled(
+      "Preview",
+      Style::default().fg(theme.playbar_text),
+    ))
+    .border_style(Style::default().fg(theme.inactive));
+  f.render_widget(title_block, layout_chunk);
+
+  let artist = Paragraph::new(Span::styled(
+    preview.artists.as_str(),
+    Style::default().fg(theme.playbar_text),
+  ))
+  .block(
+    Block::default().title(Span::styled(
+      format!("{} (preview)", preview.name),
+      Style::default()
+        .fg(theme.selected)
+        .add_modifier(Modifier::BOLD),
+    )),
+  );
+  f.render_widget(artist, artist_area);
+
+  let progress_ms = u128::from(preview.progress_ms());
+  let duration =
+    std::time::Duration::from_millis(crate::infra::preview::PREVIEW_DURATION_MS.into());
+  let progress = LineGauge::default()
+    .filled_style(Style::default().fg(theme.playbar_progress))
+    .unfilled_style(Style::default().fg(theme.playbar_background))
+    .ratio(get_track_progress_percentage(progress_ms, duration) as f64 / 100.0)
+    .filled_symbol("⣿")
+    .unfilled_symbol("⣉")
+    .label(Span::styled(
+      display_track_progress(progress_ms, duration),
+      Style::default().fg(theme.playbar_progress_text),
+    ));
+  f.render_widget(progress, progress_area);
+}
+
 pub fn draw_device_list(f: &mut Frame<'_>, app: &App) {
   let [instructions_area, list_area] = f
     .area()
@@ -456,6 +925,80 @@ pub fn draw_device_list(f: &mut Frame<'_>, app: &App) {
         .bg(app.user_config.theme.inactive)
         .add_modifier(Modifier::BOLD),
     )
-    .highlight_symbol(Line::from("▶ ").style(Style::default().fg(app.user_config.theme.active)));
+    .highlight_symbol(
+      Line::from(app.user_config.padded_selection_symbol())
+        .style(Style::default().fg(app.user_config.theme.active)),
+    );
   f.render_stateful_widget(list, list_area, &mut state);
 }
+
+/// The column a `LineGauge` fills up to at `position_ms`, for drawing markers on it.
+/// The gauge starts one cell after its label.
+fn gauge_column(area: Rect, label_width: u16, position_ms: u128, duration_ms: u128) -> Option<u16> {
+  let start = area.x + label_width + 1;
+  if start >= area.right() || duration_ms == 0 {
+    return None;
+  }
+  let width = (area.right() - start) as u128;
+  let offset = (width * position_ms.min(duration_ms) / duration_ms).min(width - 1);
+  Some(start + offset as u16)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::core::user_config::UserConfig;
+
+  #[test]
+  fn title_uses_configured_icons() {
+    let behavior = UserConfig::new().behavior;
+    assert_eq!(
+      playbar_title(&behavior, true, "Desk", true, RepeatState::Context, 70),
+      "▶ (Desk | Shuffle: 🔀 | Repeat: 🔁 | Volume: 70%)"
+    );
+    assert_eq!(
+      playbar_title(&behavior, false, "Desk", false, RepeatState::Track, 5),
+      "⏸ (Desk | Shuffle: Off | Repeat: 🔂 | Volume:  5%)"
+    );
+    assert_eq!(
+      playbar_title(&behavior, true, "Desk", false, RepeatState::Off, 130),
+      "▶ (Desk | Shuffle: Off | Repeat: Off | Volume: 130% BOOST)"
+    );
+  }
+
+  #[test]
+  fn title_falls_back_to_words_without_icons() {
+    let mut behavior = UserConfig::new().behavior;
+    behavior.playing_icon.clear();
+    behavior.shuffle_icon.clear();
+    behavior.repeat_context_icon.clear();
+    assert_eq!(
+      playbar_title(&behavior, true, "Desk", true, RepeatState::Context, 70),
+      "Playing (Desk | Shuffle: On | Repeat: All | Volume: 70%)"
+    );
+  }
+
+  #[test]
+  fn truncation_counts_emoji_as_two_cells() {
+    let title = "▶ (Desk | Shuffle: 🔀 | Repeat: 🔁)";
+    assert_eq!(truncate_to_width(title, 100), title);
+
+    let cut = truncate_to_width(title, 21);
+    assert_eq!(cut, "▶ (Desk | Shuffle: …");
+    assert!(cut.width() <= 21);
+
+    // The emoji doesn't fit in the last cell, so it is dropped whole
+    assert_eq!(truncate_to_width("Shuffle: 🔀 on", 11), "Shuffle: …");
+  }
+
+  #[test]
+  fn gauge_markers_land_inside_the_gauge() {
+    let area = Rect::new(2, 0, 31, 1);
+    // Label "0:00/1:40" is 9 cells, so the gauge spans x = 12..33
+    assert_eq!(gauge_column(area, 9, 0, 100_000), Some(12));
+    assert_eq!(gauge_column(area, 9, 50_000, 100_000), Some(22));
+    assert_eq!(gauge_column(area, 9, 100_000, 100_000), Some(32));
+    assert_eq!(gauge_column(area, 9, 10_000, 0), None);
+    assert_eq!(gauge_column(area, 40, 10_000, 100_000), None);
+  }
+}
@@ -1,4 +1,4 @@
-use crate::core::app::{ActiveBlock, AnnouncementLevel, App, DialogContext};
+use crate::core::app::{ActiveBlock, AnnouncementLevel, App, DialogContext, PendingBulkRemoval};
 use ratatui::{
   layout::{Alignment, Constraint, Direction, Layout, Rect},
   style::{Modifier, Style},
@@ -8,6 +8,7 @@ use ratatui::{
 };
 
 use super::help::get_help_docs;
+use crate::core::locale::{t, tf};
 
 pub fn draw_help_menu(f: &mut Frame<'_>, app: &App) {
   let [area] = f
@@ -20,8 +21,12 @@ pub fn draw_help_menu(f: &mut Frame<'_>, app: &App) {
     |r: Vec<String>| -> Vec<String> { vec![format!("{:50}{:40}{:20}", r[0], r[1], r[2])] };
 
   let help_menu_style = app.user_config.theme.base_style();
-  let header = ["Description", "Event", "Context"];
-  let header = format_row(header.iter().map(|s| s.to_string()).collect());
+  let header = [
+    "help.menu.description",
+    "help.menu.event",
+    "help.menu.context",
+  ];
+  let header = format_row(header.iter().map(|id| t(id)).collect());
 
   let help_docs = get_help_docs(&app.user_config.keys);
   let help_docs = help_docs
@@ -40,10 +45,7 @@ pub fn draw_help_menu(f: &mut Frame<'_>, app: &App) {
       Block::default()
         .borders(Borders::ALL)
         .style(help_menu_style)
-        .title(Span::styled(
-          "Help (press <Esc> to go back)",
-          help_menu_style,
-        ))
+        .title(Span::styled(t("help.menu.title"), help_menu_style))
         .border_style(help_menu_style),
     )
     .style(help_menu_style);
@@ -145,9 +147,59 @@ pub fn draw_dialog(f: &mut Frame<'_>, app: &App) {
         draw_confirmation_dialog(f, app, "Remove Track", text, 60);
       }
     }
+    DialogContext::BulkRemoveFromLibraryConfirm => {
+      if let Some(removal) = app.pending_bulk_removal.as_ref() {
+        let summary = match removal {
+          PendingBulkRemoval::Tracks(ids) => format!("{} tracks from Liked Songs", ids.len()),
+          PendingBulkRemoval::Albums(ids) => format!("{} saved albums", ids.len()),
+        };
+        let text = vec![
+          Line::from(Span::raw("Remove the selected items from your library?")),
+          Line::from(Span::styled(
+            summary,
+            Style::default().add_modifier(Modifier::BOLD),
+          )),
+        ];
+        draw_confirmation_dialog(f, app, "Remove From Library", text, 55);
+      }
+    }
+    DialogContext::DuplicateTrackAddConfirm => {
+      if let Some(add) = app.pending_duplicate_track_add.as_ref() {
+        let text = vec![
+          Line::from(Span::raw(
+            "This playlist already has the track. Add it again?",
+          )),
+          Line::from(Span::styled(
+            format!("Track: {}", add.track_name),
+            Style::default().add_modifier(Modifier::BOLD),
+          )),
+          Line::from(Span::styled(
+            format!("Playlist: {}", add.playlist_name),
+            Style::default().add_modifier(Modifier::BOLD),
+          )),
+        ];
+        draw_confirmation_dialog(f, app, "Duplicate Track", text, 60);
+      }
+    }
+    DialogContext::CachePlaylistOfflineConfirm => {
+      if let Some((_, name)) = app.pending_offline_cache.as_ref() {
+        let text = vec![
+          Line::from(Span::raw(t("popup.cache_offline_question"))),
+          Line::from(Span::styled(
+            tf("popup.cache_offline_playlist", &[("name", name)]),
+            Style::default().add_modifier(Modifier::BOLD),
+          )),
+          Line::from(Span::raw(t("popup.cache_offline_warning"))),
+        ];
+        draw_confirmation_dialog(f, app, &t("popup.cache_offline_title"), text, 60);
+      }
+    }
     DialogContext::AddTrackToPlaylistPicker => {
       draw_add_track_to_playlist_picker_dialog(f, app);
     }
+    DialogContext::ArtistPicker => {
+      draw_artist_picker_dialog(f, app);
+    }
   }
 }
 
@@ -279,7 +331,7 @@ fn draw_add_track_to_playlist_picker_dialog(f: &mut Frame<'_>, app: &App) {
     let list = List::new(items)
       .style(app.user_config.theme.base_style())
       .highlight_style(Style::default().fg(app.user_config.theme.hovered))
-      .highlight_symbol("▶ ");
+      .highlight_symbol(app.user_config.padded_selection_symbol());
 
     f.render_stateful_widget(list, vchunks[1], &mut list_state);
   }
@@ -290,6 +342,49 @@ fn draw_add_track_to_playlist_picker_dialog(f: &mut Frame<'_>, app: &App) {
   f.render_widget(footer, vchunks[2]);
 }
 
+fn draw_artist_picker_dialog(f: &mut Frame<'_>, app: &App) {
+  let height = app.artist_picker.len() as u16 + 4;
+  let rect = centered_modal_rect(f.area(), 50, height);
+  f.render_widget(Clear, rect);
+
+  let block = Block::default()
+    .title(Span::styled(
+      "Open Artist",
+      Style::default()
+        .fg(app.user_config.theme.header)
+        .add_modifier(Modifier::BOLD),
+    ))
+    .borders(Borders::ALL)
+    .style(app.user_config.theme.base_style())
+    .border_style(Style::default().fg(app.user_config.theme.inactive));
+  f.render_widget(block, rect);
+
+  let vchunks = Layout::default()
+    .direction(Direction::Vertical)
+    .margin(1)
+    .constraints([Constraint::Min(1), Constraint::Length(1)])
+    .split(rect);
+
+  let items: Vec<ListItem> = app
+    .artist_picker
+    .iter()
+    .map(|artist| ListItem::new(Span::raw(artist.name.as_str())))
+    .collect();
+  let mut list_state = ListState::default();
+  list_state.select(Some(app.artist_picker_selected_index));
+
+  let list = List::new(items)
+    .style(app.user_config.theme.base_style())
+    .highlight_style(Style::default().fg(app.user_config.theme.hovered))
+    .highlight_symbol(app.user_config.padded_selection_symbol());
+  f.render_stateful_widget(list, vchunks[0], &mut list_state);
+
+  let footer = Paragraph::new("Enter open | q cancel")
+    .style(Style::default().fg(app.user_config.theme.inactive))
+    .alignment(Alignment::Center);
+  f.render_widget(footer, vchunks[1]);
+}
+
 /// Draw the mandatory update prompt modal
 pub fn draw_update_prompt(f: &mut Frame<'_>, app: &App) {
   if let Some(update_info) = &app.update_available {
@@ -378,9 +473,14 @@ pub fn draw_announcement_prompt(f: &mut Frame<'_>, app: &App) {
     )));
   }
 
+  let footer = if app.is_song_count_prompt_open() {
+    "[Y] Join   [N] No thanks"
+  } else {
+    "[Press ENTER or ESC to dismiss]"
+  };
   text.push(Line::from(""));
   text.push(Line::from(Span::styled(
-    "[Press ENTER or ESC to dismiss]",
+    footer,
     Style::default().fg(app.user_config.theme.inactive),
   )));
 
@@ -517,10 +617,205 @@ pub fn draw_sort_menu(f: &mut Frame<'_>, app: &App) {
         .fg(app.user_config.theme.active)
         .add_modifier(Modifier::BOLD),
     )
-    .highlight_symbol(Line::from("▶ ").style(Style::default().fg(app.user_config.theme.active)));
+    .highlight_symbol(
+      Line::from(app.user_config.padded_selection_symbol())
+        .style(Style::default().fg(app.user_config.theme.active)),
+    );
 
   let mut state = ListState::default();
   state.select(Some(app.sort_menu_selected));
 
   f.render_stateful_widget(list, rect, &mut state);
 }
+
+pub fn draw_new_playlist_prompt(f: &mut Frame<'_>, app: &App) {
+  let Some((name, public)) = &app.new_playlist else {
+    return;
+  };
+
+  let width = std::cmp::min(f.area().width.saturating_sub(4), 50);
+  let rect = f
+    .area()
+    .centered(Constraint::Length(width), Constraint::Length(6));
+
+  f.render_widget(Clear, rect);
+
+  let theme = &app.user_config.theme;
+  let visibility = if *public { "Public" } else { "Private" };
+  let text = vec![
+    Line::from(vec![
+      Span::styled("Name: ", Style::default().fg(theme.text)),
+      Span::styled(format!("{}▏", name), Style::default().fg(theme.active)),
+    ]),
+    Line::from(vec![
+      Span::styled("Visibility: ", Style::default().fg(theme.text)),
+      Span::styled(visibility, Style::default().fg(theme.active)),
+    ]),
+    Line::from(Span::styled(
+      "Enter: Create | Tab: Public/Private | Esc: Cancel",
+      Style::default().fg(theme.inactive),
+    )),
+  ];
+
+  let paragraph = Paragraph::new(text).block(
+    Block::default()
+      .borders(Borders::ALL)
+      .style(theme.base_style())
+      .border_style(Style::default().fg(theme.active))
+      .title(Span::styled(
+        "New Playlist",
+        Style::default()
+          .fg(theme.active)
+          .add_modifier(Modifier::BOLD),
+      )),
+  );
+  f.render_widget(paragraph, rect);
+}
+
+pub fn draw_playlist_details_prompt(f: &mut Frame<'_>, app: &App) {
+  let Some(edit) = &app.playlist_details_edit else {
+    return;
+  };
+
+  let width = std::cmp::min(f.area().width.saturating_sub(4), 60);
+  let rect = f
+    .area()
+    .centered(Constraint::Length(width), Constraint::Length(6));
+
+  f.render_widget(Clear, rect);
+
+  let theme = &app.user_config.theme;
+  let field = |label: String, value: &str, editing: bool| {
+    let (value, style) = if editing {
+      (format!("{}▏", value), Style::default().fg(theme.active))
+    } else {
+      (value.to_string(), Style::default().fg(theme.text))
+    };
+    Line::from(vec![
+      Span::styled(label, Style::default().fg(theme.text)),
+      Span::styled(value, style),
+    ])
+  };
+  let text = vec![
+    field(
+      t("popup.edit_playlist_name"),
+      &edit.name,
+      !edit.editing_description,
+    ),
+    field(
+      t("popup.edit_playlist_description"),
+      &edit.description,
+      edit.editing_description,
+    ),
+    Line::from(Span::styled(
+      t("popup.edit_playlist_footer"),
+      Style::default().fg(theme.inactive),
+    )),
+  ];
+
+  let paragraph = Paragraph::new(text).block(
+    Block::default()
+      .borders(Borders::ALL)
+      .style(theme.base_style())
+      .border_style(Style::default().fg(theme.active))
+      .title(Span::styled(
+        t("popup.edit_playlist_title"),
+        Style::default()
+          .fg(theme.active)
+          .add_modifier(Modifier::BOLD),
+      )),
+  );
+  f.render_widget(paragraph, rect);
+}
+
+pub fn draw_library_search(f: &mut Frame<'_>, app: &App) {
+  let Some((query, selected)) = &app.library_search else {
+    return;
+  };
+
+  let width = std::cmp::min(f.area().width.saturating_sub(4), 70);
+  let height = std::cmp::min(f.area().height.saturating_sub(4), 20);
+  let rect = f
+    .area()
+    .centered(Constraint::Length(width), Constraint::Length(height));
+
+  f.render_widget(Clear, rect);
+
+  let theme = &app.user_config.theme;
+  let block = Block::default()
+    .borders(Borders::ALL)
+    .style(theme.base_style())
+    .border_style(Style::default().fg(theme.active))
+    .title(Span::styled(
+      t("popup.library_search_title"),
+      Style::default()
+        .fg(theme.active)
+        .add_modifier(Modifier::BOLD),
+    ));
+  let inner = block.inner(rect);
+  f.render_widget(block, rect);
+
+  let [input_area, results_area] = inner.layout(&Layout::vertical([
+    Constraint::Length(1),
+    Constraint::Min(1),
+  ]));
+
+  let input = Paragraph::new(Line::from(vec![
+    Span::styled(
+      t("popup.library_search_find"),
+      Style::default().fg(theme.text),
+    ),
+    Span::styled(format!("{}▏", query), Style::default().fg(theme.active)),
+  ]));
+  f.render_widget(input, input_area);
+
+  let matches = crate::core::library_search::search(app, query);
+  if matches.is_empty() {
+    let hint = if query.trim().is_empty() {
+      t("popup.library_search_hint")
+    } else {
+      t("popup.library_search_no_matches")
+    };
+    f.render_widget(
+      Paragraph::new(Span::styled(hint, Style::default().fg(theme.inactive))),
+      results_area,
+    );
+    return;
+  }
+
+  let items: Vec<ListItem> = matches
+    .iter()
+    .map(|found| {
+      let mut spans = vec![
+        Span::styled(
+          format!("{:<9}", found.item.kind()),
+          Style::default().fg(theme.inactive),
+        ),
+        Span::styled(found.name.clone(), Style::default().fg(theme.text)),
+      ];
+      if !found.detail.is_empty() {
+        spans.push(Span::styled(
+          format!(" - {}", found.detail),
+          Style::default().fg(theme.inactive),
+        ));
+      }
+      ListItem::new(Line::from(spans))
+    })
+    .collect();
+
+  let list = List::new(items)
+    .highlight_style(
+      Style::default()
+        .fg(theme.active)
+        .add_modifier(Modifier::BOLD),
+    )
+    .highlight_symbol(
+      Line::from(app.user_config.padded_selection_symbol())
+        .style(Style::default().fg(theme.active)),
+    );
+
+  let mut state = ListState::default();
+  state.select(Some(*selected));
+
+  f.render_stateful_widget(list, results_area, &mut state);
+}
@@ -1,4 +1,4 @@
-use crate::core::app::{ActiveBlock, App, SearchResultBlock};
+use crate::core::app::{ActiveBlock, App, MouseTarget, SearchResultBlock};
 use ratatui::{
   layout::{Constraint, Layout, Rect},
   style::Style,
@@ -12,13 +12,19 @@ use rspotify::prelude::Id;
 
 use super::util::{
   create_artist_string, draw_selectable_list, get_color, get_search_results_highlight_state,
-  SMALL_TERMINAL_WIDTH,
+  is_wide_layout,
 };
 
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
 const COMPACT_TOP_ROW_THRESHOLD: u16 = 60;
 const COMPACT_HELP_WIDTH: u16 = 8;
 const COMPACT_SETTINGS_WIDTH: u16 = 12;
 
+/// Spinner character for the current tick, advancing once per tick
+fn spinner_frame(animation_tick: u64) -> char {
+  SPINNER_FRAMES[(animation_tick % SPINNER_FRAMES.len() as u64) as usize]
+}
+
 pub fn draw_input_and_help_box(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
   let compact_top_row = layout_chunk.width < COMPACT_TOP_ROW_THRESHOLD;
 
@@ -29,9 +35,7 @@ pub fn draw_input_and_help_box(f: &mut Frame<'_>, app: &App, layout_chunk: Rect)
       Constraint::Length(COMPACT_HELP_WIDTH),
       Constraint::Length(COMPACT_SETTINGS_WIDTH),
     ]
-  } else if app.size.width >= SMALL_TERMINAL_WIDTH
-    && !app.user_config.behavior.enforce_wide_search_bar
-  {
+  } else if is_wide_layout(app) {
     [
       Constraint::Percentage(65),
       Constraint::Percentage(18),
@@ -86,8 +90,14 @@ pub fn draw_input_and_help_box(f: &mut Frame<'_>, app: &App, layout_chunk: Rect)
       .border_style(get_color(highlight_state, app.user_config.theme)),
   );
   f.render_widget(input, input_area);
+  app.record_mouse_region(MouseTarget::Input, input_area, 0);
 
-  let help_content = if show_loading {
+  let spinner;
+  let help_content = if !app.first_load_complete && app.user_config.behavior.show_loading_indicator
+  {
+    spinner = spinner_frame(app.animation_tick).to_string();
+    (app.user_config.theme.hint, "Connecting", spinner.as_str())
+  } else if show_loading {
     (app.user_config.theme.hint, "Help", "...")
   } else if compact_top_row {
     (app.user_config.theme.inactive, "Help", "?")
@@ -111,6 +121,7 @@ pub fn draw_input_and_help_box(f: &mut Frame<'_>, app: &App, layout_chunk: Rect)
       .bg(app.user_config.theme.background),
   );
   f.render_widget(help, help_area);
+  app.record_mouse_region(MouseTarget::Help, help_area, 0);
 
   let settings_content = if compact_top_row {
     ("Settings", "Open")
@@ -136,183 +147,197 @@ pub fn draw_input_and_help_box(f: &mut Frame<'_>, app: &App, layout_chunk: Rect)
         .bg(app.user_config.theme.background),
     );
   f.render_widget(settings, settings_area);
+  app.record_mouse_region(MouseTarget::Settings, settings_area, 0);
 }
 
 pub fn draw_search_results(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
-  let [song_artist_area, albums_playlist_area, podcasts_area] =
-    layout_chunk.layout(&Layout::vertical([
-      Constraint::Percentage(35),
-      Constraint::Percentage(35),
-      Constraint::Percentage(25),
-    ]));
-
-  {
-    let [songs_area, artists_area] = song_artist_area.layout(&Layout::horizontal([
-      Constraint::Percentage(50),
-      Constraint::Percentage(50),
-    ]));
-
-    let currently_playing_id = app
-      .current_playback_context
-      .clone()
-      .and_then(|context| {
-        context.item.and_then(|item| match item {
-          PlayableItem::Track(track) => track.id.map(|id| id.id().to_string()),
-          PlayableItem::Episode(episode) => Some(episode.id.id().to_string()),
-        })
-      })
-      .unwrap_or_default();
-
-    let songs = match &app.search_results.tracks {
-      Some(tracks) => tracks
-        .items
-        .iter()
-        .map(|item| {
-          let mut song_name = "".to_string();
-          let id = item
-            .clone()
-            .id
-            .map(|id| id.id().to_string())
-            .unwrap_or_else(|| "".to_string());
-          if currently_playing_id == id {
-            song_name += "▶ "
-          }
-          if app.liked_song_ids_set.contains(&id) {
-            song_name += &app.user_config.padded_liked_icon();
-          }
+  // Blocks go two to a row; a row's height and a block's width follow their weights
+  let rows = app.search_block_rows();
+  let row_areas = Layout::vertical(
+    rows
+      .iter()
+      .map(|row| Constraint::Fill(row.iter().map(|layout| layout.weight).sum())),
+  )
+  .split(layout_chunk);
 
-          song_name += &item.name;
-          song_name += &format!(" - {}", &create_artist_string(&item.artists));
-          song_name
-        })
-        .collect(),
-      None => vec![],
-    };
-
-    draw_selectable_list(
-      f,
-      app,
-      songs_area,
-      "Songs",
-      &songs,
-      get_search_results_highlight_state(app, SearchResultBlock::SongSearch),
-      app.search_results.selected_tracks_index,
-    );
+  for (row, row_area) in rows.iter().zip(row_areas.iter()) {
+    let block_areas =
+      Layout::horizontal(row.iter().map(|layout| Constraint::Fill(layout.weight))).split(*row_area);
+    for (layout, area) in row.iter().zip(block_areas.iter()) {
+      let offset = draw_search_result_block(f, app, layout.block, *area);
+      app.record_mouse_region(MouseTarget::SearchResult(layout.block), *area, offset);
+    }
+  }
+}
 
-    let artists = match &app.search_results.artists {
-      Some(artists) => artists
-        .items
-        .iter()
-        .map(|item| {
-          let mut artist = String::new();
-          if app.followed_artist_ids_set.contains(item.id.id()) {
-            artist.push_str(&app.user_config.padded_liked_icon());
-          }
-          artist.push_str(&item.name.to_owned());
-          artist
+/// Draws one block of the search results, returning the index of its first row shown
+fn draw_search_result_block(
+  f: &mut Frame<'_>,
+  app: &App,
+  block: SearchResultBlock,
+  layout_chunk: Rect,
+) -> usize {
+  match block {
+    SearchResultBlock::SongSearch => {
+      let currently_playing_id = app
+        .current_playback_context
+        .clone()
+        .and_then(|context| {
+          context.item.and_then(|item| match item {
+            PlayableItem::Track(track) => track.id.map(|id| id.id().to_string()),
+            PlayableItem::Episode(episode) => Some(episode.id.id().to_string()),
+          })
         })
-        .collect(),
-      None => vec![],
-    };
+        .unwrap_or_default();
 
-    draw_selectable_list(
-      f,
-      app,
-      artists_area,
-      "Artists",
-      &artists,
-      get_search_results_highlight_state(app, SearchResultBlock::ArtistSearch),
-      app.search_results.selected_artists_index,
-    );
-  }
-
-  {
-    let [albums_area, playlist_area] = albums_playlist_area.layout(&Layout::horizontal([
-      Constraint::Percentage(50),
-      Constraint::Percentage(50),
-    ]));
+      let songs = match &app.search_results.tracks {
+        Some(tracks) => tracks
+          .items
+          .iter()
+          .map(|item| {
+            let mut song_name = "".to_string();
+            let id = item
+              .clone()
+              .id
+              .map(|id| id.id().to_string())
+              .unwrap_or_else(|| "".to_string());
+            if currently_playing_id == id {
+              song_name += "▶ "
+            }
+            if app.liked_song_ids_set.contains(&id) {
+              song_name += &app.user_config.padded_liked_icon();
+            }
 
-    let albums = match &app.search_results.albums {
-      Some(albums) => albums
-        .items
-        .iter()
-        .map(|item| {
-          let mut album_artist = String::new();
-          if let Some(album_id) = &item.id {
-            if app.saved_album_ids_set.contains(album_id.id()) {
-              album_artist.push_str(&app.user_config.padded_liked_icon());
+            song_name += &item.name;
+            if item.explicit && app.user_config.behavior.show_track_badges {
+              song_name += " [E]";
             }
-          }
-          album_artist.push_str(&format!(
-            "{} - {} ({})",
-            item.name.to_owned(),
-            create_artist_string(&item.artists),
-            item.album_type.as_deref().unwrap_or("unknown")
-          ));
-          album_artist
-        })
-        .collect(),
-      None => vec![],
-    };
+            song_name += &format!(" - {}", &create_artist_string(&item.artists));
+            song_name
+          })
+          .collect(),
+        None => vec![],
+      };
 
-    draw_selectable_list(
-      f,
-      app,
-      albums_area,
-      "Albums",
-      &albums,
-      get_search_results_highlight_state(app, SearchResultBlock::AlbumSearch),
-      app.search_results.selected_album_index,
-    );
+      draw_selectable_list(
+        f,
+        app,
+        layout_chunk,
+        "Songs",
+        &songs,
+        get_search_results_highlight_state(app, SearchResultBlock::SongSearch),
+        app.search_results.selected_tracks_index,
+      )
+    }
+    SearchResultBlock::ArtistSearch => {
+      let artists = match &app.search_results.artists {
+        Some(artists) => artists
+          .items
+          .iter()
+          .map(|item| {
+            let mut artist = String::new();
+            if app.followed_artist_ids_set.contains(item.id.id()) {
+              artist.push_str(&app.user_config.padded_liked_icon());
+            }
+            artist.push_str(&item.name.to_owned());
+            artist
+          })
+          .collect(),
+        None => vec![],
+      };
 
-    let playlists = match &app.search_results.playlists {
-      Some(playlists) => playlists
-        .items
-        .iter()
-        .map(|item| item.name.to_owned())
-        .collect::<Vec<String>>(),
-      None => vec![],
-    };
+      draw_selectable_list(
+        f,
+        app,
+        layout_chunk,
+        "Artists",
+        &artists,
+        get_search_results_highlight_state(app, SearchResultBlock::ArtistSearch),
+        app.search_results.selected_artists_index,
+      )
+    }
+    SearchResultBlock::AlbumSearch => {
+      let albums = match &app.search_results.albums {
+        Some(albums) => albums
+          .items
+          .iter()
+          .map(|item| {
+            let mut album_artist = String::new();
+            if let Some(album_id) = &item.id {
+              if app.saved_album_ids_set.contains(album_id.id()) {
+                album_artist.push_str(&app.user_config.padded_liked_icon());
+              }
+            }
+            album_artist.push_str(&format!(
+              "{} - {} ({})",
+              item.name.to_owned(),
+              create_artist_string(&item.artists),
+              item.album_type.as_deref().unwrap_or("unknown")
+            ));
+            album_artist
+          })
+          .collect(),
+        None => vec![],
+      };
 
-    if playlists.is_empty() {
-      let warning_text = "Cannot display Spotify created playlists. Try a more specific search to find user-created playlists.";
-      let warning_paragraph = Paragraph::new(warning_text)
-        .wrap(Wrap { trim: true })
-        .style(Style::default().fg(app.user_config.theme.hint))
-        .block(
-          Block::default()
-            .title(Span::styled(
-              "Playlists",
-              get_color(
-                get_search_results_highlight_state(app, SearchResultBlock::PlaylistSearch),
-                app.user_config.theme,
-              ),
-            ))
-            .borders(Borders::ALL)
-            .border_style(get_color(
-              get_search_results_highlight_state(app, SearchResultBlock::PlaylistSearch),
-              app.user_config.theme,
-            )),
-        );
-      f.render_widget(warning_paragraph, playlist_area);
-    } else {
       draw_selectable_list(
         f,
         app,
-        playlist_area,
-        "Playlists",
-        &playlists,
-        get_search_results_highlight_state(app, SearchResultBlock::PlaylistSearch),
-        app.search_results.selected_playlists_index,
-      );
+        layout_chunk,
+        "Albums",
+        &albums,
+        get_search_results_highlight_state(app, SearchResultBlock::AlbumSearch),
+        app.search_results.selected_album_index,
+      )
     }
-  }
+    SearchResultBlock::PlaylistSearch => {
+      let playlists = match &app.search_results.playlists {
+        Some(playlists) => playlists
+          .items
+          .iter()
+          .map(|item| item.name.to_owned())
+          .collect::<Vec<String>>(),
+        None => vec![],
+      };
 
-  {
-    draw_selectable_list(
+      if playlists.is_empty() {
+        let warning_text = "Cannot display Spotify created playlists. Try a more specific search to find user-created playlists.";
+        let warning_paragraph = Paragraph::new(warning_text)
+          .wrap(Wrap { trim: true })
+          .style(Style::default().fg(app.user_config.theme.hint))
+          .block(
+            Block::default()
+              .title(Span::styled(
+                "Playlists",
+                get_color(
+                  get_search_results_highlight_state(app, SearchResultBlock::PlaylistSearch),
+                  app.user_config.theme,
+                ),
+              ))
+              .borders(Borders::ALL)
+              .border_style(get_color(
+                get_search_results_highlight_state(app, SearchResultBlock::PlaylistSearch),
+                app.user_config.theme,
+              )),
+          );
+        f.render_widget(warning_paragraph, layout_chunk);
+        0
+      } else {
+        draw_selectable_list(
+          f,
+          app,
+          layout_chunk,
+          "Playlists",
+          &playlists,
+          get_search_results_highlight_state(app, SearchResultBlock::PlaylistSearch),
+          app.search_results.selected_playlists_index,
+        )
+      }
+    }
+    SearchResultBlock::ShowSearch => draw_selectable_list(
       f,
       app,
-      podcasts_area,
+      layout_chunk,
       "Podcasts",
       &match &app.search_results.shows {
         Some(podcasts) => podcasts
@@ -331,6 +356,7 @@ pub fn draw_search_results(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
       },
       get_search_results_highlight_state(app, SearchResultBlock::ShowSearch),
       app.search_results.selected_shows_index,
-    );
+    ),
+    SearchResultBlock::Empty => 0,
   }
 }
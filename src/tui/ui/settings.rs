@@ -1,4 +1,5 @@
-use crate::app::{App, SettingValue, SettingsCategory};
+use crate::app::{App, MouseTarget, SettingValue, SettingsCategory};
+use crate::core::locale::{t, tf};
 use ratatui::{
   layout::{Alignment, Constraint, Layout, Rect},
   style::{Modifier, Style},
@@ -11,6 +12,7 @@ const UNSAVED_PROMPT_WIDTH: u16 = 58;
 const UNSAVED_PROMPT_HEIGHT: u16 = 9;
 
 pub fn draw_settings(f: &mut Frame<'_>, app: &App) {
+  app.clear_mouse_regions();
   let [tabs_area, list_area, help_area] = f.area().layout(
     &Layout::vertical([
       Constraint::Length(3), // Category tabs
@@ -52,13 +54,14 @@ fn draw_category_tabs(f: &mut Frame<'_>, app: &App, area: Rect) {
     .style(app.user_config.theme.base_style());
 
   f.render_widget(tabs, area);
+  app.record_mouse_region(MouseTarget::SettingsTabs, area, 0);
 }
 
 fn draw_settings_list(f: &mut Frame<'_>, app: &App, area: Rect) {
-  let items: Vec<ListItem> = app
-    .settings_items
+  let visible = app.visible_settings_indices();
+  let items: Vec<ListItem> = visible
     .iter()
-    .enumerate()
+    .map(|&i| (i, &app.settings_items[i]))
     .map(|(i, setting)| {
       let is_selected = i == app.settings_selected_index;
       let is_editing = is_selected && app.settings_edit_mode;
@@ -113,20 +116,39 @@ fn draw_settings_list(f: &mut Frame<'_>, app: &App, area: Rect) {
         Style::default().fg(app.user_config.theme.inactive)
       };
 
-      let line = Line::from(vec![
+      let mut spans = vec![
         Span::styled(format!("{}: ", setting.name), name_style),
         Span::styled(value_str, value_style),
-      ]);
+      ];
+      // The preset row always starts on the default, whatever the colors are
+      let is_preset = matches!(setting.value, SettingValue::Preset(_));
+      if !is_editing && !is_preset && app.default_setting_value(&setting.id) == Some(&setting.value)
+      {
+        spans.push(Span::styled(
+          " (default)",
+          Style::default().fg(app.user_config.theme.inactive),
+        ));
+      }
+      let line = Line::from(spans);
 
       ListItem::new(line)
     })
     .collect();
 
-  let title = format!(
-    "{} Settings ({} items)",
-    app.settings_category.name(),
-    app.settings_items.len()
-  );
+  let title = match &app.settings_filter {
+    Some(query) => format!(
+      "{} Settings - Filter: {}▏ ({} of {})",
+      app.settings_category.name(),
+      query,
+      visible.len(),
+      app.settings_items.len()
+    ),
+    None => format!(
+      "{} Settings ({} items)",
+      app.settings_category.name(),
+      app.settings_items.len()
+    ),
+  };
 
   let list = List::new(items)
     .block(
@@ -142,7 +164,7 @@ fn draw_settings_list(f: &mut Frame<'_>, app: &App, area: Rect) {
         .add_modifier(Modifier::BOLD),
     )
     .highlight_symbol(
-      Line::from("▶ ").style(
+      Line::from(app.user_config.padded_selection_symbol()).style(
         Style::default()
           .fg(app.user_config.theme.selected)
           .add_modifier(Modifier::BOLD),
@@ -150,6 +172,7 @@ fn draw_settings_list(f: &mut Frame<'_>, app: &App, area: Rect) {
     );
 
   f.render_widget(list, area);
+  app.record_mouse_region(MouseTarget::SettingsList, area, 0);
 }
 
 fn draw_settings_help(f: &mut Frame<'_>, app: &App, area: Rect) {
@@ -165,9 +188,17 @@ fn draw_settings_help(f: &mut Frame<'_>, app: &App, area: Rect) {
       },
       None => "",
     }
+  } else if app.settings_reset_prompt_visible {
+    &tf(
+      "popup.settings_reset",
+      &[("category", app.settings_category.name())],
+    )
+  } else if app.settings_filter.is_some() {
+    &t("popup.settings_filter_footer")
   } else {
     &format!(
-      "↑/↓: Select | ←/→: Switch Tab | Enter: Toggle/Edit | Mouse: Click/Scroll | {}: Save | Esc/q: Exit",
+      "↑/↓: Select | ←/→: Switch Tab | Enter: Toggle/Edit | {}: Filter | d/D: Reset Item/Tab | Mouse: Click/Scroll | {}: Save | Esc/q: Exit",
+      app.user_config.keys.search.primary(),
       app.user_config.keys.save_settings
     )
   };
@@ -237,6 +268,7 @@ fn draw_unsaved_changes_prompt(f: &mut Frame<'_>, app: &App) {
       app.user_config.theme.inactive
     }));
   f.render_widget(yes, yes_area);
+  app.record_mouse_region(MouseTarget::UnsavedPromptYes, yes_area, 0);
 
   let no = Paragraph::new("[ No ]")
     .alignment(Alignment::Center)
@@ -246,6 +278,7 @@ fn draw_unsaved_changes_prompt(f: &mut Frame<'_>, app: &App) {
       app.user_config.theme.hovered
     }));
   f.render_widget(no, no_area);
+  app.record_mouse_region(MouseTarget::UnsavedPromptNo, no_area, 0);
 
   let hint = Paragraph::new("Y: Yes | N: No | Enter: Select | Esc: Cancel")
     .alignment(Alignment::Center)
@@ -0,0 +1,246 @@
+//! Renders whole views into a `TestBackend` and compares the text on screen with
+//! the snapshots in `snapshots/`. Colors aren't compared, only what is drawn where.
+//!
+//! After an intended change to the UI, regenerate the snapshots with
+//! `UPDATE_SNAPSHOTS=1 cargo test snapshot_tests` and review the diff.
+
+use super::*;
+use crate::core::app::{
+  ActiveBlock, AlbumTableContext, PlaylistFolderItem, SearchResultBlock, SelectedFullAlbum,
+  TrackTableContext,
+};
+use crate::core::fixtures;
+use crate::core::sort::SortContext;
+use ratatui::{backend::TestBackend, buffer::Buffer, layout::Size, Terminal};
+use rspotify::model::device::DevicePayload;
+use std::path::PathBuf;
+
+/// Narrow, the default 80x24 and the wide layout past `behavior.wide_layout_min_width`
+const SIZES: [(u16, u16); 3] = [(60, 20), (80, 24), (160, 40)];
+
+/// An app with a few playlists and liked songs open, playing the first song
+fn app_with_library(is_playing: bool) -> App {
+  let mut app = App::default();
+  app.first_load_complete = true;
+  app.user = Some(fixtures::user("Sam"));
+  app.all_playlists = vec![
+    fixtures::playlist("Road Trip", "Sam", 42),
+    fixtures::playlist("Focus", "Spotify", 80),
+    fixtures::playlist("Late Night Jazz", "Sam", 17),
+  ];
+  app.playlist_folder_items = (0..app.all_playlists.len())
+    .map(|index| PlaylistFolderItem::Playlist {
+      index,
+      current_id: 0,
+    })
+    .collect();
+  app.selected_playlist_index = Some(0);
+  app.track_table.tracks = vec![
+    fixtures::track("Harbor Lights", "The Tides", "Coastline", 214_000),
+    fixtures::track("Paper Planes", "Northern Echo", "Drift", 187_000),
+    fixtures::track("Slow Burn", "Amber Fields", "Embers", 263_000),
+  ];
+  app.track_table.context = Some(TrackTableContext::SavedTracks);
+  app.track_table.selected_index = 1;
+
+  let device = fixtures::device("Desk", true);
+  app.current_playback_context = Some(fixtures::playback(
+    &device,
+    &app.track_table.tracks[0],
+    is_playing,
+    61_000,
+  ));
+  app.song_progress_ms = 61_000;
+  app.devices = Some(DevicePayload {
+    devices: vec![device, fixtures::device("Kitchen", false)],
+  });
+  app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+  app
+}
+
+/// Draws `app` the way the main loop does for its current route
+fn render(app: &mut App, (width, height): (u16, u16)) -> Buffer {
+  app.size = Size::new(width, height);
+  let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+  terminal
+    .draw(|f| match app.get_current_route().active_block {
+      ActiveBlock::SelectDevice => draw_device_list(f, app),
+      ActiveBlock::Settings => settings::draw_settings(f, app),
+      ActiveBlock::NowPlaying => draw_now_playing(f, app),
+      ActiveBlock::TvView => draw_tv_view(f, app),
+      _ => draw_main_layout(f, app),
+    })
+    .unwrap();
+  terminal.backend().buffer().clone()
+}
+
+/// The symbols in `buffer`, one line per row with trailing blanks trimmed
+fn buffer_text(buffer: &Buffer) -> String {
+  let area = buffer.area;
+  let mut text = String::new();
+  for y in area.top()..area.bottom() {
+    let mut line = String::new();
+    for x in area.left()..area.right() {
+      line.push_str(buffer[(x, y)].symbol());
+    }
+    text.push_str(line.trim_end());
+    text.push('\n');
+  }
+  text
+}
+
+/// Compare `app` drawn at every size in `SIZES` with the stored `name` snapshots
+fn assert_snapshots(name: &str, app: &mut App) {
+  for size in SIZES {
+    let actual = buffer_text(&render(app, size));
+    assert_snapshot(&format!("{}_{}x{}", name, size.0, size.1), &actual);
+  }
+}
+
+/// Compare `actual` with the stored `name` snapshot, or store it under `UPDATE_SNAPSHOTS`
+fn assert_snapshot(name: &str, actual: &str) {
+  let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/tui/ui/snapshots");
+  let path = dir.join(format!("{}.txt", name));
+  if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&path, actual).unwrap();
+    return;
+  }
+  let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+    panic!(
+      "missing snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it",
+      path.display()
+    )
+  });
+  assert!(
+    actual == expected,
+    "{} doesn't match, run with UPDATE_SNAPSHOTS=1 to accept the change\n\
+     --- expected\n{}\n--- actual\n{}",
+    path.display(),
+    expected,
+    actual
+  );
+}
+
+#[test]
+fn main_layout_with_liked_songs() {
+  let mut app = app_with_library(true);
+  assert_snapshots("main_layout", &mut app);
+}
+
+#[test]
+fn connecting_spinner_follows_the_tick() {
+  let mut app = app_with_library(true);
+  app.first_load_complete = false;
+  app.animation_tick = 1;
+  assert_snapshots("connecting", &mut app);
+
+  // Each tick turns the spinner on by a frame
+  app.update_on_tick();
+  let text = buffer_text(&render(&mut app, (80, 24)));
+  assert!(text.lines().nth(1).unwrap().contains("││-"));
+}
+
+#[test]
+fn playbar_playing_and_paused() {
+  // Only the playbar, at the height the main layout gives it
+  for (name, is_playing) in [("playbar_playing", true), ("playbar_paused", false)] {
+    let app = app_with_library(is_playing);
+    for width in [60, 80] {
+      let mut terminal = Terminal::new(TestBackend::new(width, 6)).unwrap();
+      terminal.draw(|f| draw_playbar(f, &app, f.area())).unwrap();
+      let actual = buffer_text(terminal.backend().buffer());
+      assert_snapshot(&format!("{}_{}x6", name, width), &actual);
+    }
+  }
+}
+
+#[test]
+fn search_results() {
+  let mut app = app_with_library(true);
+  app.input = "night".chars().collect();
+  app.search_results.tracks = Some(fixtures::page(vec![
+    fixtures::track("Night Drive", "The Tides", "Coastline", 201_000),
+    fixtures::track("Nightfall", "Amber Fields", "Embers", 245_000),
+  ]));
+  app.search_results.artists = Some(fixtures::page(vec![fixtures::artist("Night Owls")]));
+  app.search_results.playlists = Some(fixtures::page(vec![fixtures::playlist(
+    "Late Night Jazz",
+    "Sam",
+    17,
+  )]));
+  app.search_results.selected_tracks_index = Some(0);
+  app.search_results.hovered_block = SearchResultBlock::SongSearch;
+  app.push_navigation_stack(RouteId::Search, ActiveBlock::SearchResultBlock);
+  assert_snapshots("search_results", &mut app);
+}
+
+#[test]
+fn album_with_its_info() {
+  let mut app = app_with_library(true);
+  let mut album = fixtures::album("Coastline", "The Tides", &["Harbor Lights", "Low Tide"]);
+  album.label = Some("Seaside Records".to_string());
+  album.copyrights = serde_json::from_value(serde_json::json!([
+    { "text": "2020 Seaside Records", "type": "C" },
+    { "text": "2020 The Tides", "type": "P" },
+  ]))
+  .unwrap();
+  app.selected_album_full = Some(SelectedFullAlbum {
+    album,
+    selected_index: 0,
+  });
+  app.album_table_context = AlbumTableContext::Full;
+  app.push_navigation_stack(RouteId::AlbumTracks, ActiveBlock::AlbumTracks);
+  assert_snapshots("album_tracks", &mut app);
+}
+
+#[test]
+fn device_list() {
+  let mut app = app_with_library(true);
+  app.selected_device_index = Some(1);
+  app.push_navigation_stack(RouteId::SelectedDevice, ActiveBlock::SelectDevice);
+  assert_snapshots("device_list", &mut app);
+}
+
+#[test]
+fn settings() {
+  let mut app = app_with_library(true);
+  app.load_settings_for_category();
+  app.push_navigation_stack(RouteId::Settings, ActiveBlock::Settings);
+  assert_snapshots("settings", &mut app);
+}
+
+#[test]
+fn now_playing() {
+  let mut app = app_with_library(true);
+  app.push_navigation_stack(RouteId::NowPlaying, ActiveBlock::NowPlaying);
+  assert_snapshots("now_playing", &mut app);
+}
+
+#[test]
+fn tv_view() {
+  let mut app = app_with_library(true);
+  app.push_navigation_stack(RouteId::TvView, ActiveBlock::TvView);
+  assert_snapshots("tv_view", &mut app);
+
+  // A name too long for one row wraps without pushing the album off
+  let track = fixtures::track(
+    "An Unreasonably Long Song Title That Wraps Across Rows",
+    "The Tides",
+    "Coastline",
+    214_000,
+  );
+  let device = fixtures::device("Desk", true);
+  app.current_playback_context = Some(fixtures::playback(&device, &track, true, 61_000));
+  let text = buffer_text(&render(&mut app, (40, 20)));
+  assert!(text.contains("Wraps Across Rows"), "{}", text);
+  assert!(text.contains("Coastline"), "{}", text);
+}
+
+#[test]
+fn sort_menu() {
+  let mut app = app_with_library(true);
+  app.sort_context = Some(SortContext::PlaylistTracks);
+  app.sort_menu_visible = true;
+  assert_snapshots("sort_menu", &mut app);
+}
@@ -1,18 +1,24 @@
 use crate::core::app::{
-  ActiveBlock, AlbumTableContext, App, EpisodeTableContext, RecommendationsContext,
+  playlist_visibility_label, ActiveBlock, AlbumTableContext, App, EpisodeTableContext, MouseTarget,
+  RecommendationsContext, TrackTableContext,
 };
+use crate::core::availability;
+use crate::core::locale::{t, tf};
 use ratatui::{
-  layout::{Constraint, Rect},
+  layout::{Constraint, Layout, Rect},
   style::{Modifier, Style},
   text::Span,
-  widgets::{Block, Borders, Row, Table},
+  widgets::{Block, Borders, Paragraph, Row, Table},
   Frame,
 };
+use rspotify::model::album::FullAlbum;
 use rspotify::model::show::ResumePoint;
 use rspotify::model::PlayableItem;
 use rspotify::prelude::Id;
 
-use super::util::{create_artist_string, get_color, get_percentage_width, millis_to_minutes};
+use super::util::{
+  create_artist_string, get_color, get_percentage_width, millis_to_minutes, track_badges,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TableId {
@@ -23,6 +29,7 @@ pub enum TableId {
   Song,
   RecentlyPlayed,
   PodcastEpisodes,
+  Queue,
 }
 
 #[derive(Default, PartialEq)]
@@ -31,6 +38,7 @@ pub enum ColumnId {
   None,
   Title,
   Liked,
+  Badges,
 }
 
 pub struct TableHeader<'a> {
@@ -51,15 +59,58 @@ pub struct TableHeaderItem<'a> {
   pub width: u16,
 }
 
+#[derive(Default)]
 pub struct TableItem {
   pub id: String,
   pub format: Vec<String>,
+  /// A track Spotify says can't be played for this user
+  pub unavailable: bool,
 }
 
 struct AlbumUi {
   selected_index: usize,
   items: Vec<TableItem>,
+  badges: Vec<String>,
   title: String,
+  info: Vec<String>,
+}
+
+/// Track rows the album table keeps before the info above it is dropped
+const ALBUM_TABLE_MIN_ROWS: u16 = 6;
+
+/// The release date, track count and label, then the copyrights, leaving out
+/// whatever isn't known
+fn album_info_lines(
+  release_date: Option<&str>,
+  total_tracks: u32,
+  details: Option<&FullAlbum>,
+) -> Vec<String> {
+  let mut facts = Vec::new();
+  if let Some(release_date) = release_date {
+    facts.push(tf("popup.album_released", &[("date", release_date)]));
+  }
+  facts.push(match total_tracks {
+    1 => t("popup.album_one_track"),
+    n => tf("popup.album_tracks", &[("count", &n.to_string())]),
+  });
+  if let Some(label) = details.and_then(|album| album.label.as_deref()) {
+    facts.push(label.to_string());
+  }
+
+  let mut lines = vec![facts.join(" · ")];
+  let copyrights = details
+    .map(|album| {
+      album
+        .copyrights
+        .iter()
+        .map(|copyright| copyright.text.as_str())
+        .collect::<Vec<_>>()
+    })
+    .unwrap_or_default();
+  if !copyrights.is_empty() {
+    lines.push(copyrights.join(" · "));
+  }
+  lines
 }
 
 pub fn draw_artist_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
@@ -83,6 +134,7 @@ pub fn draw_artist_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     .map(|item| TableItem {
       id: item.id.id().to_string(),
       format: vec![item.name.to_owned()],
+      ..Default::default()
     })
     .collect::<Vec<TableItem>>();
 
@@ -131,6 +183,7 @@ pub fn draw_podcast_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
           show_page.show.name.to_owned(),
           show_page.show.publisher.to_owned(),
         ],
+        ..Default::default()
       })
       .collect::<Vec<TableItem>>();
 
@@ -147,7 +200,7 @@ pub fn draw_podcast_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
 }
 
 pub fn draw_album_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
-  let header = TableHeader {
+  let mut header = TableHeader {
     id: TableId::Album,
     items: vec![
       TableHeaderItem {
@@ -200,6 +253,7 @@ pub fn draw_album_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
                 .as_ref()
                 .map(|id| id.id().to_string())
                 .unwrap_or_else(|| "".to_string()),
+              unavailable: availability::is_unavailable(item, app.get_user_country()),
               format: vec![
                 "".to_string(),
                 item.track_number.to_string(),
@@ -209,12 +263,23 @@ pub fn draw_album_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
               ],
             })
             .collect::<Vec<TableItem>>(),
+          badges: selected_album_simplified
+            .tracks
+            .items
+            .iter()
+            .map(|item| track_badges(item.explicit, item.is_local))
+            .collect(),
           title: format!(
             "{} by {}",
             selected_album_simplified.album.name,
             create_artist_string(&selected_album_simplified.album.artists)
           ),
           selected_index: selected_album_simplified.selected_index,
+          info: album_info_lines(
+            selected_album_simplified.album.release_date.as_deref(),
+            selected_album_simplified.tracks.total,
+            selected_album_simplified.details.as_ref(),
+          ),
         })
     }
     AlbumTableContext::Full => match app.selected_album_full.clone() {
@@ -230,6 +295,7 @@ pub fn draw_album_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
               .as_ref()
               .map(|id| id.id().to_string())
               .unwrap_or_else(|| "".to_string()),
+            unavailable: availability::is_unavailable(item, app.get_user_country()),
             format: vec![
               "".to_string(),
               item.track_number.to_string(),
@@ -239,22 +305,58 @@ pub fn draw_album_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
             ],
           })
           .collect::<Vec<TableItem>>(),
+        badges: selected_album
+          .album
+          .tracks
+          .items
+          .iter()
+          .map(|item| track_badges(item.explicit, item.is_local))
+          .collect(),
         title: format!(
           "{} by {}",
           selected_album.album.name,
           create_artist_string(&selected_album.album.artists)
         ),
         selected_index: app.saved_album_tracks_index,
+        info: album_info_lines(
+          Some(&selected_album.album.release_date),
+          selected_album.album.tracks.total,
+          Some(&selected_album.album),
+        ),
       }),
       None => None,
     },
   };
 
-  if let Some(album_ui) = album_ui {
+  if let Some(mut album_ui) = album_ui {
+    album_ui
+      .title
+      .push_str(&unavailable_suffix(&album_ui.items));
+    add_badge_column(app, &mut header, &mut album_ui.items, album_ui.badges);
+
+    let info_height = album_ui.info.len() as u16 + 2;
+    // The table's borders and header take 3 rows
+    let table_area = if layout_chunk.height >= info_height + ALBUM_TABLE_MIN_ROWS + 3 {
+      let [info_area, table_area] = layout_chunk.layout(&Layout::vertical([
+        Constraint::Length(info_height),
+        Constraint::Min(1),
+      ]));
+      let info = Paragraph::new(album_ui.info.join("\n")).block(
+        Block::default()
+          .borders(Borders::ALL)
+          .style(app.user_config.theme.base_style())
+          .border_style(get_color((false, false), app.user_config.theme)),
+      );
+      f.render_widget(info, info_area);
+      table_area
+    } else {
+      layout_chunk
+    };
+
     draw_table(
       f,
       app,
-      layout_chunk,
+      table_area,
       (&album_ui.title, &header),
       &album_ui.items,
       album_ui.selected_index,
@@ -264,7 +366,7 @@ pub fn draw_album_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
 }
 
 pub fn draw_recommendations_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
-  let header = TableHeader {
+  let mut header = TableHeader {
     id: TableId::Song,
     items: vec![
       TableHeaderItem {
@@ -301,7 +403,7 @@ pub fn draw_recommendations_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
     current_route.hovered_block == ActiveBlock::TrackTable,
   );
 
-  let items = app
+  let mut items = app
     .track_table
     .tracks
     .iter()
@@ -311,6 +413,7 @@ pub fn draw_recommendations_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
         .as_ref()
         .map(|id| id.id().to_string())
         .unwrap_or_else(|| "".to_string()),
+      unavailable: availability::is_unavailable(item, app.get_user_country()),
       format: vec![
         "".to_string(),
         item.name.to_owned(),
@@ -320,6 +423,12 @@ pub fn draw_recommendations_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
       ],
     })
     .collect::<Vec<TableItem>>();
+  let badges = app
+    .track_table
+    .tracks
+    .iter()
+    .map(|item| track_badges(item.explicit, item.is_local));
+  add_badge_column(app, &mut header, &mut items, badges);
   // match RecommendedContext
   let recommendations_ui = match &app.recommendations_context {
     Some(RecommendationsContext::Song) => format!(
@@ -330,6 +439,10 @@ pub fn draw_recommendations_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
       "Recommendations based on Artist \'{}\'",
       &app.recommendations_seed
     ),
+    Some(RecommendationsContext::Library) => tf(
+      "recommendations.from_library",
+      &[("track", &app.recommendations_seed)],
+    ),
     None => "Recommendations".to_string(),
   };
   draw_table(
@@ -344,7 +457,7 @@ pub fn draw_recommendations_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
 }
 
 pub fn draw_song_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
-  let header = TableHeader {
+  let mut header = TableHeader {
     id: TableId::Song,
     items: vec![
       TableHeaderItem {
@@ -381,7 +494,7 @@ pub fn draw_song_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
     current_route.hovered_block == ActiveBlock::TrackTable,
   );
 
-  let items = app
+  let mut items = app
     .track_table
     .tracks
     .iter()
@@ -391,6 +504,7 @@ pub fn draw_song_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
         .as_ref()
         .map(|id| id.id().to_string())
         .unwrap_or_else(|| "".to_string()),
+      unavailable: availability::is_unavailable(item, app.get_user_country()),
       format: vec![
         "".to_string(),
         item.name.to_owned(),
@@ -400,12 +514,38 @@ pub fn draw_song_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
       ],
     })
     .collect::<Vec<TableItem>>();
+  let badges = app
+    .track_table
+    .tracks
+    .iter()
+    .map(|item| track_badges(item.explicit, item.is_local));
+  add_badge_column(app, &mut header, &mut items, badges);
+
+  // Owned playlists show their sharing state so it can be checked before toggling
+  let title = match (
+    &app.track_table.context,
+    app.active_playlist_index,
+    &app.user,
+  ) {
+    (Some(TrackTableContext::MyPlaylists), Some(index), Some(user)) => app
+      .all_playlists
+      .get(index)
+      .filter(|playlist| playlist.owner.id == user.id)
+      .map(|playlist| {
+        format!(
+          "Songs ({})",
+          playlist_visibility_label(playlist.public, playlist.collaborative)
+        )
+      }),
+    _ => None,
+  }
+  .unwrap_or_else(|| "Songs".to_string());
 
   draw_table(
     f,
     app,
     layout_chunk,
-    ("Songs", &header),
+    (&title, &header),
     &items,
     app.track_table.selected_index,
     highlight_state,
@@ -458,6 +598,7 @@ pub fn draw_album_list(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
           create_artist_string(&album_page.album.artists),
           album_page.album.release_date.to_owned(),
         ],
+        ..Default::default()
       })
       .collect::<Vec<TableItem>>();
 
@@ -542,6 +683,7 @@ pub fn draw_show_episodes(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
             episode.name.to_owned(),
             time_str,
           ],
+          ..Default::default()
         }
       })
       .collect::<Vec<TableItem>>();
@@ -582,7 +724,7 @@ pub fn draw_show_episodes(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
 }
 
 pub fn draw_recently_played_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
-  let header = TableHeader {
+  let mut header = TableHeader {
     id: TableId::RecentlyPlayed,
     items: vec![
       TableHeaderItem {
@@ -619,7 +761,7 @@ pub fn draw_recently_played_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
 
     let selected_song_index = app.recently_played.index;
 
-    let items = recently_played
+    let mut items = recently_played
       .items
       .iter()
       .map(|item| TableItem {
@@ -629,6 +771,7 @@ pub fn draw_recently_played_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
           .as_ref()
           .map(|id| id.id().to_string())
           .unwrap_or_else(|| "".to_string()),
+        unavailable: availability::is_unavailable(&item.track, app.get_user_country()),
         format: vec![
           "".to_string(),
           item.track.name.to_owned(),
@@ -637,6 +780,11 @@ pub fn draw_recently_played_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
         ],
       })
       .collect::<Vec<TableItem>>();
+    let badges = recently_played
+      .items
+      .iter()
+      .map(|item| track_badges(item.track.explicit, item.track.is_local));
+    add_badge_column(app, &mut header, &mut items, badges);
 
     draw_table(
       f,
@@ -650,6 +798,122 @@ pub fn draw_recently_played_table(f: &mut Frame<'_>, app: &App, layout_chunk: Re
   };
 }
 
+pub fn draw_queue_table(f: &mut Frame<'_>, app: &App, layout_chunk: Rect) {
+  let header = TableHeader {
+    id: TableId::Queue,
+    items: vec![
+      TableHeaderItem {
+        id: ColumnId::Liked,
+        text: "",
+        width: 2,
+      },
+      TableHeaderItem {
+        id: ColumnId::Title,
+        text: "Title",
+        width: get_percentage_width(layout_chunk.width, 2.0 / 5.0) - 2,
+      },
+      TableHeaderItem {
+        text: "Artist",
+        width: get_percentage_width(layout_chunk.width, 2.0 / 5.0),
+        ..Default::default()
+      },
+      TableHeaderItem {
+        text: "Length",
+        width: get_percentage_width(layout_chunk.width, 1.0 / 5.0),
+        ..Default::default()
+      },
+    ],
+  };
+
+  let current_route = app.get_current_route();
+  let highlight_state = (
+    current_route.active_block == ActiveBlock::Queue,
+    current_route.hovered_block == ActiveBlock::Queue,
+  );
+
+  let items = app
+    .queue
+    .result
+    .iter()
+    .map(|item| TableItem {
+      id: item.id.clone(),
+      format: vec![
+        "".to_string(),
+        if item.added_here {
+          format!("{} (queued by you)", item.name)
+        } else {
+          item.name.clone()
+        },
+        item.artist.clone(),
+        millis_to_minutes(u128::from(item.duration_ms)),
+      ],
+      ..Default::default()
+    })
+    .collect::<Vec<TableItem>>();
+
+  let title = if app.queue_stale {
+    "Up Next (stale)"
+  } else {
+    "Up Next"
+  };
+
+  draw_table(
+    f,
+    app,
+    layout_chunk,
+    (title, &header),
+    &items,
+    app.queue.index,
+    highlight_state,
+  )
+}
+
+const BADGE_COLUMN_WIDTH: u16 = 7;
+
+/// Insert the explicit/local badge column right after the title, taking its
+/// width from the title so the table keeps its overall size.
+fn add_badge_column(
+  app: &App,
+  header: &mut TableHeader,
+  items: &mut [TableItem],
+  badges: impl IntoIterator<Item = String>,
+) {
+  if !app.user_config.behavior.show_track_badges {
+    return;
+  }
+  let Some(title_idx) = header.get_index(ColumnId::Title) else {
+    return;
+  };
+
+  let title = &mut header.items[title_idx];
+  title.width = title.width.saturating_sub(BADGE_COLUMN_WIDTH);
+  header.items.insert(
+    title_idx + 1,
+    TableHeaderItem {
+      id: ColumnId::Badges,
+      text: "",
+      width: BADGE_COLUMN_WIDTH,
+    },
+  );
+  for (item, badge) in items.iter_mut().zip(badges) {
+    item.format.insert(title_idx + 1, badge);
+  }
+}
+
+/// Local files have no Spotify ID and restricted tracks are refused, so neither
+/// can be played from here
+fn is_unplayable_row(item: &TableItem) -> bool {
+  item.id.is_empty() || item.unavailable
+}
+
+/// " (N unavailable)" for a table title, or nothing when every row can play
+fn unavailable_suffix(items: &[TableItem]) -> String {
+  match items.iter().filter(|item| item.unavailable).count() {
+    0 => String::new(),
+    count => format!(" ({} unavailable)", count),
+  }
+}
+
 fn draw_table(
   f: &mut Frame<'_>,
   app: &App,
@@ -659,8 +923,12 @@ fn draw_table(
   selected_index: usize,
   highlight_state: (bool, bool),
 ) {
-  let selected_style = get_color(highlight_state, app.user_config.theme)
-    .add_modifier(Modifier::BOLD | Modifier::REVERSED);
+  let selected_style = app
+    .user_config
+    .selection_style(get_color(highlight_state, app.user_config.theme));
+  // Without reverse video the selected row needs the symbol to stand out
+  let selected_prefix = (!app.user_config.behavior.selection_reverse)
+    .then(|| app.user_config.padded_selection_symbol());
 
   let track_playing_index = app.current_playback_context.to_owned().and_then(|ctx| {
     ctx.item.and_then(|item| match item {
@@ -693,6 +961,7 @@ fn draw_table(
 
   let use_page_scroll = header.id == TableId::Song;
   let offset = table_scroll_offset(selected_index, visible_rows, use_page_scroll);
+  app.record_mouse_region(MouseTarget::ContentTable, layout_chunk, offset);
 
   let rows = items.iter().skip(offset).enumerate().map(|(i, item)| {
     let mut formatted_row = item.format.clone();
@@ -700,7 +969,7 @@ fn draw_table(
 
     // if table displays songs
     match header.id {
-      TableId::Song | TableId::RecentlyPlayed | TableId::Album => {
+      TableId::Song | TableId::RecentlyPlayed | TableId::Album | TableId::Queue => {
         // First check if the song should be highlighted because it is currently playing
         if let Some(title_idx) = header.get_index(ColumnId::Title) {
           if let Some(track_playing_offset_index) =
@@ -721,9 +990,37 @@ fn draw_table(
             formatted_row[liked_idx] = app.user_config.padded_liked_icon();
           }
         }
+
+        if header.id == TableId::Song
+          && app
+            .playlist_enhance
+            .as_ref()
+            .is_some_and(|enhance| enhance.is_suggestion(&item.id))
+        {
+          if let Some(title_idx) = header.get_index(ColumnId::Title) {
+            formatted_row[title_idx] = format!("✦ {}", &formatted_row[title_idx]);
+          }
+          style = Style::default().fg(app.user_config.theme.hint);
+        }
+        if is_unplayable_row(item) {
+          style = Style::default().fg(app.user_config.theme.inactive);
+        }
+        if item.unavailable {
+          if let Some(title_idx) = header.get_index(ColumnId::Title) {
+            formatted_row[title_idx].push_str(" (unavailable)");
+          }
+        }
       }
       TableId::PodcastEpisodes => {
         if let Some(name_idx) = header.get_index(ColumnId::Title) {
+          if app.saved_episode_ids_set.contains(item.id.as_str()) {
+            formatted_row[name_idx] = format!(
+              "{}{}",
+              app.user_config.padded_liked_icon(),
+              &formatted_row[name_idx]
+            );
+          }
+
           if let Some(track_playing_offset_index) =
             track_playing_index.and_then(|idx| idx.checked_sub(offset))
           {
@@ -739,9 +1036,24 @@ fn draw_table(
       _ => {}
     }
 
+    // Prefix rows marked for a bulk action
+    let marked_ids = match header.id {
+      TableId::Song => Some(&app.marked_track_ids),
+      TableId::AlbumList => Some(&app.marked_album_ids),
+      _ => None,
+    };
+    if marked_ids.is_some_and(|ids| ids.contains(&item.id)) {
+      if let Some(title_idx) = header.get_index(ColumnId::Title) {
+        formatted_row[title_idx] = format!("● {}", &formatted_row[title_idx]);
+      }
+    }
+
     // Next check if the item is under selection.
     if Some(i) == selected_index.checked_sub(offset) {
       style = selected_style;
+      if let (Some(prefix), Some(first)) = (&selected_prefix, formatted_row.first_mut()) {
+        first.insert_str(0, prefix);
+      }
     }
 
     // Return row styled data
@@ -2,7 +2,7 @@ use crate::core::app::{ActiveBlock, App, ArtistBlock, SearchResultBlock};
 use crate::core::user_config::Theme;
 use ratatui::{
   layout::Rect,
-  style::{Modifier, Style},
+  style::Style,
   text::{Line, Span},
   widgets::{Block, BorderType, Borders, List, ListItem, ListState},
   Frame,
@@ -11,7 +11,6 @@ use rspotify::model::artist::SimplifiedArtist;
 use std::time::Duration;
 
 pub const BASIC_VIEW_HEIGHT: u16 = 6;
-pub const SMALL_TERMINAL_WIDTH: u16 = 150;
 pub const SMALL_TERMINAL_HEIGHT: u16 = 45;
 
 pub fn get_search_results_highlight_state(
@@ -46,6 +45,8 @@ pub fn get_color((is_active, is_hovered): (bool, bool), theme: Theme) -> Style {
   }
 }
 
+/// Draws a bordered list with `selected_index` highlighted and scrolled into view,
+/// returning the index of the first item shown
 pub fn draw_selectable_list<S>(
   f: &mut Frame<'_>,
   app: &App,
@@ -54,7 +55,8 @@ pub fn draw_selectable_list<S>(
   items: &[S],
   highlight_state: (bool, bool),
   selected_index: Option<usize>,
-) where
+) -> usize
+where
   S: std::convert::AsRef<str>,
 {
   let mut state = ListState::default();
@@ -78,11 +80,16 @@ pub fn draw_selectable_list<S>(
     .block(block)
     .style(app.user_config.theme.base_style())
     .highlight_style(
-      get_color(highlight_state, app.user_config.theme)
-        .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+      app
+        .user_config
+        .selection_style(get_color(highlight_state, app.user_config.theme)),
     )
-    .highlight_symbol(Line::from("▶ ").style(get_color(highlight_state, app.user_config.theme)));
+    .highlight_symbol(
+      Line::from(app.user_config.padded_selection_symbol())
+        .style(get_color(highlight_state, app.user_config.theme)),
+    );
   f.render_stateful_widget(list, layout_chunk, &mut state);
+  state.offset()
 }
 
 pub fn create_artist_string(artists: &[SimplifiedArtist]) -> String {
@@ -93,6 +100,23 @@ pub fn create_artist_string(artists: &[SimplifiedArtist]) -> String {
     .join(", ")
 }
 
+/// Short markers for a track row: "E" for explicit, "LOCAL" for local files.
+pub fn track_badges(explicit: bool, is_local: bool) -> String {
+  let mut badges = Vec::new();
+  if explicit {
+    badges.push("E");
+  }
+  if is_local {
+    badges.push("LOCAL");
+  }
+  badges.join(" ")
+}
+
+/// A shareable link annotated with a playback position, e.g. `https://... @1:23`.
+pub fn timestamped_link(url: &str, progress_ms: u128) -> String {
+  format!("{} @{}", url, millis_to_minutes(progress_ms))
+}
+
 pub fn millis_to_minutes(millis: u128) -> String {
   let minutes = millis / 60000;
   let seconds = (millis % 60000) / 1000;
@@ -132,6 +156,13 @@ pub fn get_track_progress_percentage(song_progress_ms: u128, track_duration: Dur
   min_perc.max(track_perc) as u16
 }
 
+/// Whether the terminal is wide enough for the layout with search and the library
+/// in the sidebar, see `behavior.wide_layout_min_width`
+pub fn is_wide_layout(app: &App) -> bool {
+  app.size.width >= app.user_config.behavior.wide_layout_min_width
+    && !app.user_config.behavior.enforce_wide_search_bar
+}
+
 // Make better use of space on small terminals
 pub fn get_main_layout_margin(app: &App) -> u16 {
   if app.size.height > SMALL_TERMINAL_HEIGHT {
@@ -145,6 +176,21 @@ pub fn get_main_layout_margin(app: &App) -> u16 {
 mod tests {
   use super::*;
 
+  #[test]
+  fn track_badges_test() {
+    assert_eq!(track_badges(false, false), "");
+    assert_eq!(track_badges(true, false), "E");
+    assert_eq!(track_badges(true, true), "E LOCAL");
+  }
+
+  #[test]
+  fn timestamped_link_test() {
+    assert_eq!(
+      timestamped_link("https://open.spotify.com/track/abc", 83_000),
+      "https://open.spotify.com/track/abc @1:23"
+    );
+  }
+
   #[test]
   fn millis_to_minutes_test() {
     assert_eq!(millis_to_minutes(0), "0:00");
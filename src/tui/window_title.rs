@@ -0,0 +1,161 @@
+//! Terminal window title showing what's playing.
+//!
+//! `behavior.window_title_format` picks the layout, with `{title}`, `{artist}`,
+//! `{album}`, `{progress}`, `{duration}` and `{state}` filled from the current
+//! item. The terminal's own title is pushed before the first change and popped on
+//! exit.
+
+use crate::core::app::App;
+use crate::core::locale::interpolate;
+use crate::tui::ui::util::{create_artist_string, millis_to_minutes};
+use crossterm::{execute, terminal::SetTitle};
+use rspotify::model::PlayableItem;
+use std::io::{stdout, Write};
+use std::time::{Duration, Instant};
+
+/// Shown while nothing is playing
+pub const IDLE_TITLE: &str = "spt - spotatui";
+
+/// Progress in the title ticks every second, so don't retitle more often
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+// xterm title stack, understood by most terminals and ignored by the rest
+const PUSH_TITLE: &str = "\x1b[22;0t";
+const POP_TITLE: &str = "\x1b[23;0t";
+
+#[derive(Default)]
+pub struct WindowTitle {
+  last_title: Option<String>,
+  last_update: Option<Instant>,
+}
+
+impl WindowTitle {
+  /// Retitles the window for the playing item, at most once a second
+  pub fn update(&mut self, app: &App) {
+    if !app.user_config.behavior.set_window_title {
+      // Turned off in settings, hand the title back to the terminal
+      if let Err(e) = self.restore() {
+        log::warn!("could not restore the window title: {}", e);
+      }
+      return;
+    }
+    if self
+      .last_update
+      .is_some_and(|at| at.elapsed() < UPDATE_INTERVAL)
+    {
+      return;
+    }
+    self.last_update = Some(Instant::now());
+    let title = playing_title(app, &app.user_config.behavior.window_title_format)
+      .unwrap_or_else(|| IDLE_TITLE.to_string());
+    if self.last_title.as_ref() != Some(&title) {
+      if let Err(e) = self.set(title) {
+        log::warn!("could not set the window title: {}", e);
+      }
+    }
+  }
+
+  /// Puts back the title the terminal had before we first set one
+  pub fn restore(&mut self) -> std::io::Result<()> {
+    if self.last_title.take().is_none() {
+      return Ok(());
+    }
+    let mut out = stdout();
+    out.write_all(POP_TITLE.as_bytes())?;
+    out.flush()
+  }
+
+  fn set(&mut self, title: String) -> std::io::Result<()> {
+    let mut out = stdout();
+    if self.last_title.is_none() {
+      out.write_all(PUSH_TITLE.as_bytes())?;
+    }
+    execute!(out, SetTitle(&title))?;
+    self.last_title = Some(title);
+    Ok(())
+  }
+}
+
+/// The title for whatever is playing, or `None` when nothing is
+pub fn playing_title(app: &App, format: &str) -> Option<String> {
+  let (title, artist, album, duration_ms) = if let Some(native) = &app.native_track_info {
+    (
+      native.name.clone(),
+      native.artists_display.clone(),
+      native.album.clone(),
+      native.duration_ms as u128,
+    )
+  } else {
+    match app.current_playback_context.as_ref()?.item.as_ref()? {
+      PlayableItem::Track(track) => (
+        track.name.clone(),
+        create_artist_string(&track.artists),
+        track.album.name.clone(),
+        track.duration.num_milliseconds().max(0) as u128,
+      ),
+      PlayableItem::Episode(episode) => (
+        episode.name.clone(),
+        episode.show.name.clone(),
+        String::new(),
+        episode.duration.num_milliseconds().max(0) as u128,
+      ),
+    }
+  };
+  let is_playing = app
+    .native_is_playing
+    .or_else(|| app.current_playback_context.as_ref().map(|c| c.is_playing))
+    .unwrap_or(false);
+  let behavior = &app.user_config.behavior;
+  let state = if is_playing {
+    &behavior.playing_icon
+  } else {
+    &behavior.paused_icon
+  };
+  Some(format_title(
+    format,
+    &[
+      ("title", &title),
+      ("artist", &artist),
+      ("album", &album),
+      ("progress", &millis_to_minutes(app.song_progress_ms)),
+      ("duration", &millis_to_minutes(duration_ms)),
+      ("state", state),
+    ],
+  ))
+}
+
+/// Fills the placeholders and tidies what an empty one leaves behind, like the
+/// dangling " - " when an episode has no album
+fn format_title(format: &str, fields: &[(&str, &str)]) -> String {
+  let title = interpolate(format, fields);
+  let title = title.trim().trim_matches('-').trim();
+  title.chars().filter(|c| !c.is_control()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn format_fills_fields_and_trims() {
+    let fields = [
+      ("title", "Song"),
+      ("artist", "Band"),
+      ("album", ""),
+      ("progress", "1:23"),
+      ("duration", "3:45"),
+      ("state", "▶"),
+    ];
+    assert_eq!(format_title("{artist} - {title}", &fields), "Band - Song");
+    assert_eq!(
+      format_title("{artist} - {title} [{progress}/{duration}]", &fields),
+      "Band - Song [1:23/3:45]"
+    );
+    assert_eq!(format_title("{title} - {album}", &fields), "Song");
+    // A title can't smuggle escape sequences into the terminal
+    assert_eq!(
+      format_title("{title}", &[("title", "a\x1b]0;x\x07b")]),
+      "a]0;xb"
+    );
+  }
+}